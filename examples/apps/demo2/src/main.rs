@@ -42,7 +42,10 @@ fn main() -> Result<()> {
     // this size is to match the size of the terminal when running the demo
     // using vhs in a 1280x640 sized window (github social preview size)
     let viewport = Viewport::Fixed(Rect::new(0, 0, 81, 18));
-    let terminal = ratatui::init_with_options(TerminalOptions { viewport });
+    let terminal = ratatui::init_with_options(TerminalOptions {
+        viewport,
+        ..Default::default()
+    });
     execute!(stdout(), EnterAlternateScreen).expect("failed to enter alternate screen");
     let app_result = App::default().run(terminal);
     execute!(stdout(), LeaveAlternateScreen).expect("failed to leave alternate screen");
@@ -28,6 +28,7 @@ fn main() -> Result<()> {
     color_eyre::install()?;
     let mut terminal = ratatui::init_with_options(TerminalOptions {
         viewport: Viewport::Inline(8),
+        ..Default::default()
     });
 
     let (tx, rx) = mpsc::channel();
@@ -54,7 +54,10 @@ enum Rainbow {
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     let viewport = Viewport::Fixed(Rect::new(0, 0, 68, 16));
-    let terminal = ratatui::init_with_options(TerminalOptions { viewport });
+    let terminal = ratatui::init_with_options(TerminalOptions {
+        viewport,
+        ..Default::default()
+    });
     execute!(stdout(), EnterAlternateScreen).expect("failed to enter alternate screen");
     let result = run(terminal);
     execute!(stdout(), LeaveAlternateScreen).expect("failed to leave alternate screen");
@@ -10,8 +10,7 @@
 ///   * Pressing Backspace erases the left character before the cursor position
 ///   * Pressing Enter pushes the current input in the history of previous messages.
 ///
-/// **Note:** as this is a relatively simple example unicode characters are unsupported and
-/// their use will result in undefined behaviour.
+/// The input box is a [`TextInput`] widget, which handles unicode text and places the cursor.
 ///
 /// See also <https://github.com/rhysd/tui-textarea> and <https://github.com/sayanarijit/tui-input>/
 ///
@@ -22,10 +21,10 @@
 /// [`latest`]: https://github.com/ratatui/ratatui/tree/latest
 use color_eyre::Result;
 use crossterm::event::{self, KeyCode, KeyEventKind};
-use ratatui::layout::{Constraint, Layout, Position};
+use ratatui::layout::{Constraint, Layout};
 use ratatui::style::{Color, Modifier, Style, Stylize};
 use ratatui::text::{Line, Span, Text};
-use ratatui::widgets::{Block, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, List, ListItem, Paragraph, TextInput, TextInputState};
 use ratatui::{DefaultTerminal, Frame};
 
 fn main() -> Result<()> {
@@ -36,9 +35,7 @@ fn main() -> Result<()> {
 /// App holds the state of the application
 struct App {
     /// Current value of the input box
-    input: String,
-    /// Position of cursor in the editor area.
-    character_index: usize,
+    input: TextInputState,
     /// Current input mode
     input_mode: InputMode,
     /// History of recorded messages
@@ -51,77 +48,17 @@ enum InputMode {
 }
 
 impl App {
-    const fn new() -> Self {
+    fn new() -> Self {
         Self {
-            input: String::new(),
+            input: TextInputState::default(),
             input_mode: InputMode::Normal,
             messages: Vec::new(),
-            character_index: 0,
         }
     }
 
-    fn move_cursor_left(&mut self) {
-        let cursor_moved_left = self.character_index.saturating_sub(1);
-        self.character_index = self.clamp_cursor(cursor_moved_left);
-    }
-
-    fn move_cursor_right(&mut self) {
-        let cursor_moved_right = self.character_index.saturating_add(1);
-        self.character_index = self.clamp_cursor(cursor_moved_right);
-    }
-
-    fn enter_char(&mut self, new_char: char) {
-        let index = self.byte_index();
-        self.input.insert(index, new_char);
-        self.move_cursor_right();
-    }
-
-    /// Returns the byte index based on the character position.
-    ///
-    /// Since each character in a string can contain multiple bytes, it's necessary to calculate
-    /// the byte index based on the index of the character.
-    fn byte_index(&self) -> usize {
-        self.input
-            .char_indices()
-            .map(|(i, _)| i)
-            .nth(self.character_index)
-            .unwrap_or(self.input.len())
-    }
-
-    fn delete_char(&mut self) {
-        let is_not_cursor_leftmost = self.character_index != 0;
-        if is_not_cursor_leftmost {
-            // Method "remove" is not used on the saved text for deleting the selected char.
-            // Reason: Using remove on String works on bytes instead of the chars.
-            // Using remove would require special care because of char boundaries.
-
-            let current_index = self.character_index;
-            let from_left_to_current_index = current_index - 1;
-
-            // Getting all characters before the selected character.
-            let before_char_to_delete = self.input.chars().take(from_left_to_current_index);
-            // Getting all characters after selected character.
-            let after_char_to_delete = self.input.chars().skip(current_index);
-
-            // Put all characters together except the selected one.
-            // By leaving the selected one out, it is forgotten and therefore deleted.
-            self.input = before_char_to_delete.chain(after_char_to_delete).collect();
-            self.move_cursor_left();
-        }
-    }
-
-    fn clamp_cursor(&self, new_cursor_pos: usize) -> usize {
-        new_cursor_pos.clamp(0, self.input.chars().count())
-    }
-
-    const fn reset_cursor(&mut self) {
-        self.character_index = 0;
-    }
-
     fn submit_message(&mut self) {
-        self.messages.push(self.input.clone());
-        self.input.clear();
-        self.reset_cursor();
+        self.messages.push(self.input.text());
+        self.input = TextInputState::default().focused(true);
     }
 
     fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
@@ -133,6 +70,7 @@ impl App {
                     InputMode::Normal => match key.code {
                         KeyCode::Char('e') => {
                             self.input_mode = InputMode::Editing;
+                            self.input.set_focused(true);
                         }
                         KeyCode::Char('q') => {
                             return Ok(());
@@ -141,11 +79,16 @@ impl App {
                     },
                     InputMode::Editing if key.kind == KeyEventKind::Press => match key.code {
                         KeyCode::Enter => self.submit_message(),
-                        KeyCode::Char(to_insert) => self.enter_char(to_insert),
-                        KeyCode::Backspace => self.delete_char(),
-                        KeyCode::Left => self.move_cursor_left(),
-                        KeyCode::Right => self.move_cursor_right(),
-                        KeyCode::Esc => self.input_mode = InputMode::Normal,
+                        KeyCode::Char(to_insert) => self.input.insert_char(to_insert),
+                        KeyCode::Backspace => {
+                            self.input.delete_backward();
+                        }
+                        KeyCode::Left => self.input.move_left(),
+                        KeyCode::Right => self.input.move_right(),
+                        KeyCode::Esc => {
+                            self.input_mode = InputMode::Normal;
+                            self.input.set_focused(false);
+                        }
                         _ => {}
                     },
                     InputMode::Editing => {}
@@ -154,7 +97,7 @@ impl App {
         }
     }
 
-    fn render(&self, frame: &mut Frame) {
+    fn render(&mut self, frame: &mut Frame) {
         let layout = Layout::vertical([
             Constraint::Length(1),
             Constraint::Length(3),
@@ -188,28 +131,15 @@ impl App {
         let help_message = Paragraph::new(text);
         frame.render_widget(help_message, help_area);
 
-        let input = Paragraph::new(self.input.as_str())
+        let input = TextInput::new()
             .style(match self.input_mode {
                 InputMode::Normal => Style::default(),
                 InputMode::Editing => Style::default().fg(Color::Yellow),
             })
             .block(Block::bordered().title("Input"));
-        frame.render_widget(input, input_area);
-        match self.input_mode {
-            // Hide the cursor. `Frame` does this by default, so we don't need to do anything here
-            InputMode::Normal => {}
-
-            // Make the cursor visible and ask ratatui to put it at the specified coordinates after
-            // rendering
-            #[expect(clippy::cast_possible_truncation)]
-            InputMode::Editing => frame.set_cursor_position(Position::new(
-                // Draw the cursor at the current position in the input field.
-                // This position can be controlled via the left and right arrow key
-                input_area.x + self.character_index as u16 + 1,
-                // Move one line down, from the border to the input line
-                input_area.y + 1,
-            )),
-        }
+        // The input shows the cursor while it is focused. Otherwise `Frame` hides the cursor by
+        // default, so we don't need to do anything.
+        frame.render_stateful_widget(input, input_area, &mut self.input);
 
         let messages: Vec<ListItem> = self
             .messages
@@ -0,0 +1,54 @@
+//! Provides types to animate values and buffers over time.
+//!
+//! Animations in Ratatui are immediate mode, like everything else: an [`Animation`] does not own a
+//! timer or change any state by itself. Instead, it computes the value at a given point in time,
+//! and the application renders that value on each frame.
+//!
+//! - [`Clock`] keeps track of the current animation time, either from the frame counter of the
+//!   [`Terminal`] (so that animations advance by a fixed duration on each draw call), from the real
+//!   elapsed time, or from durations that the application advances manually.
+//! - [`Animation`] interpolates between two values over a [`Duration`], using an [`Easing`] curve
+//!   and an optional [`Repeat`] mode.
+//! - [`Interpolate`] is implemented for numbers, [`Color`] (in RGB space, or in HSL space with
+//!   [`HslColor`]), [`Rect`], [`Position`] and [`Size`].
+//! - [`Effect`]s such as [`Fade`], [`Dissolve`] and [`Sweep`] post-process a region of a [`Buffer`]
+//!   after the widgets have been rendered.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//!
+//! use ratatui::animation::{Animation, Clock, Easing, Effect, Fade};
+//! use ratatui::style::Color;
+//!
+//! // advance animations by 16ms on each draw call
+//! let mut clock = Clock::frames(Duration::from_millis(16));
+//! let fade_in = Animation::new(1.0, 0.0, Duration::from_millis(500)).easing(Easing::CubicOut);
+//!
+//! terminal.draw(|frame| {
+//!     let now = clock.tick(frame);
+//!     frame.render_widget(&app, frame.area());
+//!     Fade::new(Color::Black).apply(frame.area(), frame.buffer_mut(), fade_in.value(now));
+//! })?;
+//! ```
+//!
+//! [`Terminal`]: crate::terminal::Terminal
+//! [`Duration`]: core::time::Duration
+//! [`Color`]: crate::style::Color
+//! [`Rect`]: crate::layout::Rect
+//! [`Position`]: crate::layout::Position
+//! [`Size`]: crate::layout::Size
+//! [`Buffer`]: crate::buffer::Buffer
+
+mod animation;
+mod clock;
+mod easing;
+mod effect;
+mod interpolate;
+
+pub use animation::{Animation, Repeat};
+pub use clock::Clock;
+pub use easing::Easing;
+pub use effect::{Dissolve, Effect, Fade, Sweep, SweepDirection};
+pub use interpolate::{HslColor, Interpolate};
@@ -0,0 +1,216 @@
+use core::time::Duration;
+
+use strum::{Display, EnumString};
+
+use crate::animation::{Easing, Interpolate};
+
+/// An interpolation between two values over time.
+///
+/// An animation does not keep track of time itself: [`Animation::value`] returns the value at a
+/// given time, usually the elapsed time of a [`Clock`]. The animation starts at
+/// [`Animation::start_at`] (zero by default) and reaches its end value after its duration, with the
+/// progress shaped by an [`Easing`] curve.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use ratatui_core::animation::{Animation, Easing, Repeat};
+/// use ratatui_core::layout::Rect;
+///
+/// // slide a panel in from the left over half a second
+/// let slide = Animation::new(
+///     Rect::new(0, 0, 0, 10),
+///     Rect::new(0, 0, 20, 10),
+///     Duration::from_millis(500),
+/// )
+/// .easing(Easing::QuadraticOut);
+/// assert_eq!(slide.value(Duration::from_millis(250)), Rect::new(0, 0, 15, 10));
+/// assert_eq!(slide.value(Duration::from_secs(1)), Rect::new(0, 0, 20, 10));
+///
+/// // pulse a value back and forth forever
+/// let pulse = Animation::new(0.0, 1.0, Duration::from_secs(1)).repeat(Repeat::PingPong);
+/// assert_eq!(pulse.value(Duration::from_millis(1500)), 0.5);
+/// ```
+///
+/// [`Clock`]: crate::animation::Clock
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    start: Duration,
+    duration: Duration,
+    easing: Easing,
+    repeat: Repeat,
+}
+
+/// How an [`Animation`] continues after reaching its end value.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Repeat {
+    /// Stop at the end value.
+    #[default]
+    Once,
+    /// Restart from the start value.
+    Loop,
+    /// Go back to the start value, then forward again.
+    PingPong,
+}
+
+impl<T> Animation<T> {
+    /// Creates an animation from `from` to `to` over `duration`.
+    ///
+    /// The animation starts at time zero, with linear easing and no repetition.
+    pub const fn new(from: T, to: T, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            start: Duration::ZERO,
+            duration,
+            easing: Easing::Linear,
+            repeat: Repeat::Once,
+        }
+    }
+
+    /// Sets the easing curve of the animation.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets how the animation continues after reaching its end value.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Sets the time at which the animation starts.
+    ///
+    /// Before that time, the value of the animation is its start value. This is typically set to
+    /// the current time of the [`Clock`] when an event triggers the animation.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Clock`]: crate::animation::Clock
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn start_at(mut self, start: Duration) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Returns the time at which the animation starts.
+    pub const fn start(&self) -> Duration {
+        self.start
+    }
+
+    /// Returns the duration of a single run of the animation.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the eased progress of the animation at the given time, between `0.0` and `1.0`.
+    ///
+    /// This can be used to drive an [`Effect`] directly.
+    ///
+    /// [`Effect`]: crate::animation::Effect
+    pub fn progress(&self, now: Duration) -> f64 {
+        let elapsed = now.saturating_sub(self.start);
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let cycles = elapsed.as_secs_f64() / self.duration.as_secs_f64();
+        let completed = cycles as u64;
+        let linear = match self.repeat {
+            Repeat::Once => cycles.min(1.0),
+            Repeat::Loop => cycles - completed as f64,
+            Repeat::PingPong if completed % 2 == 0 => cycles - completed as f64,
+            Repeat::PingPong => 1.0 - (cycles - completed as f64),
+        };
+        self.easing.ease(linear)
+    }
+
+    /// Returns whether the animation has reached its end value at the given time.
+    ///
+    /// Repeating animations never finish.
+    pub fn is_finished(&self, now: Duration) -> bool {
+        self.repeat == Repeat::Once && now >= self.start.saturating_add(self.duration)
+    }
+}
+
+impl<T: Interpolate> Animation<T> {
+    /// Returns the value of the animation at the given time.
+    pub fn value(&self, now: Duration) -> T {
+        self.from.interpolate(&self.to, self.progress(now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[rstest]
+    #[case::before_start(0, 0)]
+    #[case::start(100, 0)]
+    #[case::halfway(150, 50)]
+    #[case::end(200, 100)]
+    #[case::after_end(300, 100)]
+    fn once(#[case] now: u64, #[case] expected: u16) {
+        let animation = Animation::new(0, 100, ms(100)).start_at(ms(100));
+        assert_eq!(animation.value(ms(now)), expected);
+    }
+
+    #[rstest]
+    #[case::start(0, 0)]
+    #[case::halfway(50, 50)]
+    #[case::restart(100, 0)]
+    #[case::second_run(125, 25)]
+    fn repeat_loop(#[case] now: u64, #[case] expected: u16) {
+        let animation = Animation::new(0, 100, ms(100)).repeat(Repeat::Loop);
+        assert_eq!(animation.value(ms(now)), expected);
+    }
+
+    #[rstest]
+    #[case::start(0, 0)]
+    #[case::halfway(50, 50)]
+    #[case::end(100, 100)]
+    #[case::back(125, 75)]
+    #[case::forward_again(225, 25)]
+    fn repeat_ping_pong(#[case] now: u64, #[case] expected: u16) {
+        let animation = Animation::new(0, 100, ms(100)).repeat(Repeat::PingPong);
+        assert_eq!(animation.value(ms(now)), expected);
+    }
+
+    #[test]
+    fn easing() {
+        let animation = Animation::new(0_u16, 100, ms(100)).easing(Easing::QuadraticIn);
+        assert_eq!(animation.value(ms(50)), 25);
+    }
+
+    #[test]
+    fn zero_duration() {
+        let animation = Animation::new(0_u16, 100, Duration::ZERO);
+        assert_eq!(animation.value(Duration::ZERO), 100);
+        assert!(animation.is_finished(Duration::ZERO));
+    }
+
+    #[test]
+    fn is_finished() {
+        let animation = Animation::new(0.0, 1.0, ms(100)).start_at(ms(50));
+        assert!(!animation.is_finished(ms(100)));
+        assert!(animation.is_finished(ms(150)));
+        assert!(!animation.repeat(Repeat::Loop).is_finished(ms(1000)));
+    }
+}
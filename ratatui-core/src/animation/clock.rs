@@ -0,0 +1,199 @@
+use core::time::Duration;
+
+use crate::terminal::Frame;
+
+/// The source of time for animations.
+///
+/// A clock keeps track of the elapsed animation time. Call [`Clock::tick`] once at the start of
+/// each [`Terminal::draw`] call to advance the clock and pass the returned time to
+/// [`Animation::value`]. How much the clock advances depends on how it was created:
+///
+/// - [`Clock::frames`]: by a fixed duration for every frame, based on the frame counter of the
+///   [`Terminal`]. This makes animations deterministic, which is useful for testing, and avoids
+///   skipping animation steps when frames are slow.
+/// - [`Clock::real_time`]: by the real time elapsed since the previous tick (requires the `std`
+///   feature).
+/// - [`Clock::manual`]: only when [`Clock::advance`] is called.
+///
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+/// [`Terminal`]: crate::terminal::Terminal
+/// [`Animation::value`]: crate::animation::Animation::value
+///
+/// # Examples
+///
+/// ```rust
+/// use core::time::Duration;
+///
+/// use ratatui_core::animation::Clock;
+///
+/// let mut clock = Clock::manual();
+/// clock.advance(Duration::from_millis(100));
+/// assert_eq!(clock.elapsed(), Duration::from_millis(100));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Clock {
+    elapsed: Duration,
+    source: Source,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+enum Source {
+    #[default]
+    Manual,
+    Frames {
+        frame_duration: Duration,
+        last_frame: Option<usize>,
+    },
+    #[cfg(feature = "std")]
+    RealTime {
+        last_tick: Option<std::time::Instant>,
+    },
+}
+
+impl Clock {
+    /// Creates a clock that only advances when [`Clock::advance`] is called.
+    pub const fn manual() -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            source: Source::Manual,
+        }
+    }
+
+    /// Creates a clock that advances by `frame_duration` for each frame drawn by the terminal.
+    ///
+    /// The first tick starts the clock at zero. If frames were drawn without ticking the clock,
+    /// the clock advances by the duration of all of them on the next tick.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    ///
+    /// use ratatui_core::animation::Clock;
+    ///
+    /// // 60 frames per second
+    /// let clock = Clock::frames(Duration::from_secs(1) / 60);
+    /// ```
+    pub const fn frames(frame_duration: Duration) -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            source: Source::Frames {
+                frame_duration,
+                last_frame: None,
+            },
+        }
+    }
+
+    /// Creates a clock that advances by the real time elapsed between ticks.
+    ///
+    /// The first tick starts the clock at zero.
+    #[cfg(feature = "std")]
+    pub const fn real_time() -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            source: Source::RealTime { last_tick: None },
+        }
+    }
+
+    /// Returns the time elapsed on the clock.
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Advances the clock by the given duration, regardless of how the clock was created.
+    pub const fn advance(&mut self, duration: Duration) {
+        self.elapsed = self.elapsed.saturating_add(duration);
+    }
+
+    /// Resets the elapsed time to zero.
+    ///
+    /// The next tick of a frame or real time clock advances it from the current frame or time.
+    pub const fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Advances the clock for the frame being drawn and returns the elapsed time.
+    ///
+    /// This should be called once at the start of each draw call. Manual clocks are not advanced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// terminal.draw(|frame| {
+    ///     let now = clock.tick(frame);
+    ///     let area = slide_in.value(now);
+    ///     frame.render_widget(&panel, area);
+    /// })?;
+    /// ```
+    pub fn tick(&mut self, frame: &Frame) -> Duration {
+        self.tick_frame(frame.count())
+    }
+
+    /// Advances the clock to the given frame count
+    fn tick_frame(&mut self, count: usize) -> Duration {
+        match &mut self.source {
+            Source::Manual => {}
+            Source::Frames {
+                frame_duration,
+                last_frame,
+            } => {
+                if let Some(last) = last_frame.replace(count) {
+                    let frames = u32::try_from(count.wrapping_sub(last)).unwrap_or(u32::MAX);
+                    self.elapsed = self
+                        .elapsed
+                        .saturating_add(frame_duration.saturating_mul(frames));
+                }
+            }
+            #[cfg(feature = "std")]
+            Source::RealTime { last_tick } => {
+                let now = std::time::Instant::now();
+                if let Some(last) = last_tick.replace(now) {
+                    self.elapsed = self.elapsed.saturating_add(now - last);
+                }
+            }
+        }
+        self.elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual() {
+        let mut clock = Clock::manual();
+        assert_eq!(clock.tick_frame(5), Duration::ZERO);
+        clock.advance(Duration::from_millis(30));
+        assert_eq!(clock.tick_frame(6), Duration::from_millis(30));
+        clock.reset();
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn frames() {
+        let mut clock = Clock::frames(Duration::from_millis(10));
+        assert_eq!(clock.tick_frame(3), Duration::ZERO);
+        assert_eq!(clock.tick_frame(4), Duration::from_millis(10));
+        assert_eq!(clock.tick_frame(7), Duration::from_millis(40));
+        assert_eq!(clock.tick_frame(7), Duration::from_millis(40));
+        clock.advance(Duration::from_millis(5));
+        assert_eq!(clock.tick_frame(8), Duration::from_millis(55));
+    }
+
+    #[test]
+    fn frames_wrap_around() {
+        let mut clock = Clock::frames(Duration::from_millis(10));
+        clock.tick_frame(usize::MAX);
+        assert_eq!(clock.tick_frame(1), Duration::from_millis(20));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn real_time() {
+        let mut clock = Clock::real_time();
+        assert_eq!(clock.tick_frame(0), Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(clock.tick_frame(1) >= Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,96 @@
+use strum::{Display, EnumString};
+
+/// A curve that maps the linear progress of an animation to the progress of its value.
+///
+/// The `In` variants start slowly and accelerate, the `Out` variants start quickly and decelerate,
+/// and the `InOut` variants do both. See <https://easings.net> for a visual comparison.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// Quadratic acceleration from zero speed.
+    QuadraticIn,
+    /// Quadratic deceleration to zero speed.
+    QuadraticOut,
+    /// Quadratic acceleration until halfway, then deceleration.
+    QuadraticInOut,
+    /// Cubic acceleration from zero speed.
+    CubicIn,
+    /// Cubic deceleration to zero speed.
+    CubicOut,
+    /// Cubic acceleration until halfway, then deceleration.
+    CubicInOut,
+}
+
+impl Easing {
+    /// Applies the easing curve to a linear progress between `0.0` and `1.0`.
+    ///
+    /// The progress is clamped to that range, and the result is always `0.0` at the start and
+    /// `1.0` at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::animation::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.ease(0.25), 0.25);
+    /// assert_eq!(Easing::QuadraticIn.ease(0.5), 0.25);
+    /// assert_eq!(Easing::QuadraticOut.ease(0.5), 0.75);
+    /// ```
+    pub fn ease(self, progress: f64) -> f64 {
+        let t = progress.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadraticIn => t * t,
+            Self::QuadraticOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::QuadraticInOut if t < 0.5 => 2.0 * t * t,
+            Self::QuadraticInOut => 1.0 - 2.0 * (1.0 - t) * (1.0 - t),
+            Self::CubicIn => t * t * t,
+            Self::CubicOut => 1.0 - (1.0 - t) * (1.0 - t) * (1.0 - t),
+            Self::CubicInOut if t < 0.5 => 4.0 * t * t * t,
+            Self::CubicInOut => 1.0 - 4.0 * (1.0 - t) * (1.0 - t) * (1.0 - t),
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::float_cmp)]
+mod tests {
+    use alloc::string::ToString;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::linear(Easing::Linear, 0.5)]
+    #[case::quadratic_in(Easing::QuadraticIn, 0.25)]
+    #[case::quadratic_out(Easing::QuadraticOut, 0.75)]
+    #[case::quadratic_in_out(Easing::QuadraticInOut, 0.5)]
+    #[case::cubic_in(Easing::CubicIn, 0.125)]
+    #[case::cubic_out(Easing::CubicOut, 0.875)]
+    #[case::cubic_in_out(Easing::CubicInOut, 0.5)]
+    fn ease(#[case] easing: Easing, #[case] halfway: f64) {
+        assert_eq!(easing.ease(0.0), 0.0);
+        assert_eq!(easing.ease(0.5), halfway);
+        assert_eq!(easing.ease(1.0), 1.0);
+        assert_eq!(easing.ease(-1.0), 0.0);
+        assert_eq!(easing.ease(2.0), 1.0);
+    }
+
+    #[test]
+    fn in_out_is_symmetric() {
+        assert_eq!(Easing::QuadraticInOut.ease(0.25), 0.125);
+        assert_eq!(Easing::QuadraticInOut.ease(0.75), 0.875);
+        assert_eq!(Easing::CubicInOut.ease(0.25), 0.0625);
+        assert_eq!(Easing::CubicInOut.ease(0.75), 0.9375);
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("CubicOut".parse(), Ok(Easing::CubicOut));
+        assert_eq!(Easing::QuadraticInOut.to_string(), "QuadraticInOut");
+    }
+}
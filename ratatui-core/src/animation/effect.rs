@@ -0,0 +1,242 @@
+use strum::{Display, EnumString};
+
+use crate::animation::Interpolate;
+use crate::buffer::Buffer;
+use crate::layout::{Position, Rect};
+use crate::style::Color;
+
+/// A post-processing effect that modifies a region of a [`Buffer`] based on a progress value.
+///
+/// Effects are applied after widgets have been rendered to the buffer, usually with the progress
+/// of an [`Animation`]. A progress of `0.0` leaves the buffer unchanged, and a progress of `1.0`
+/// applies the effect fully.
+///
+/// [`Animation`]: crate::animation::Animation
+pub trait Effect {
+    /// Applies the effect to the cells of `buf` inside `area`.
+    fn apply(&self, area: Rect, buf: &mut Buffer, progress: f64);
+}
+
+/// Fades the foreground and background colors of cells toward a color.
+///
+/// Colors are interpolated in RGB space (see [`Interpolate`] for how [`Color::Reset`] is handled).
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::animation::{Effect, Fade};
+/// use ratatui_core::buffer::Buffer;
+/// use ratatui_core::layout::Rect;
+/// use ratatui_core::style::{Color, Style};
+///
+/// let area = Rect::new(0, 0, 1, 1);
+/// let mut buf = Buffer::empty(area);
+/// buf.set_style(area, Style::new().fg(Color::White).bg(Color::Black));
+/// Fade::new(Color::Black).apply(area, &mut buf, 0.5);
+/// assert_eq!(buf[(0, 0)].fg, Color::Rgb(128, 128, 128));
+/// assert_eq!(buf[(0, 0)].bg, Color::Rgb(0, 0, 0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Fade {
+    color: Color,
+}
+
+impl Fade {
+    /// Creates a fade toward the given color.
+    pub const fn new(color: Color) -> Self {
+        Self { color }
+    }
+}
+
+impl Effect for Fade {
+    fn apply(&self, area: Rect, buf: &mut Buffer, progress: f64) {
+        if progress <= 0.0 {
+            return;
+        }
+        for position in area.intersection(buf.area).positions() {
+            let cell = &mut buf[position];
+            cell.fg = cell.fg.interpolate(&self.color, progress);
+            cell.bg = cell.bg.interpolate(&self.color, progress);
+        }
+    }
+}
+
+/// Clears cells in a pseudo-random order.
+///
+/// Each cell disappears once the progress reaches a threshold derived from its position and the
+/// seed, so the same seed always produces the same pattern. Use [`Animation`] with a decreasing
+/// progress to make the content appear instead.
+///
+/// [`Animation`]: crate::animation::Animation
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Dissolve {
+    seed: u64,
+}
+
+impl Dissolve {
+    /// Creates a dissolve effect with the given seed.
+    pub const fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the progress at which the cell at the given position disappears, in `0.0..1.0`
+    const fn threshold(self, position: Position) -> f64 {
+        // SplitMix64 finalizer
+        let mut hash = self.seed ^ ((position.x as u64) << 16 | position.y as u64);
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        hash ^= hash >> 31;
+        (hash >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+impl Effect for Dissolve {
+    fn apply(&self, area: Rect, buf: &mut Buffer, progress: f64) {
+        for position in area.intersection(buf.area).positions() {
+            if self.threshold(position) < progress {
+                buf[position].reset();
+            }
+        }
+    }
+}
+
+/// Clears cells progressively from one edge of the area to the other.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::animation::{Effect, Sweep, SweepDirection};
+/// use ratatui_core::buffer::Buffer;
+/// use ratatui_core::layout::Rect;
+///
+/// let mut buf = Buffer::with_lines(["abcd"]);
+/// Sweep::new(SweepDirection::LeftToRight).apply(buf.area, &mut buf, 0.5);
+/// assert_eq!(buf, Buffer::with_lines(["  cd"]));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Sweep {
+    direction: SweepDirection,
+}
+
+/// The direction in which a [`Sweep`] effect progresses.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SweepDirection {
+    /// From the left edge to the right edge.
+    #[default]
+    LeftToRight,
+    /// From the right edge to the left edge.
+    RightToLeft,
+    /// From the top edge to the bottom edge.
+    TopToBottom,
+    /// From the bottom edge to the top edge.
+    BottomToTop,
+}
+
+impl Sweep {
+    /// Creates a sweep effect in the given direction.
+    pub const fn new(direction: SweepDirection) -> Self {
+        Self { direction }
+    }
+}
+
+impl Effect for Sweep {
+    fn apply(&self, area: Rect, buf: &mut Buffer, progress: f64) {
+        let area = area.intersection(buf.area);
+        let length = match self.direction {
+            SweepDirection::LeftToRight | SweepDirection::RightToLeft => area.width,
+            SweepDirection::TopToBottom | SweepDirection::BottomToTop => area.height,
+        };
+        let swept = (f64::from(length) * progress.clamp(0.0, 1.0) + 0.5) as u16;
+        let cleared = match self.direction {
+            SweepDirection::LeftToRight => Rect {
+                width: swept,
+                ..area
+            },
+            SweepDirection::RightToLeft => Rect {
+                x: area.right() - swept,
+                width: swept,
+                ..area
+            },
+            SweepDirection::TopToBottom => Rect {
+                height: swept,
+                ..area
+            },
+            SweepDirection::BottomToTop => Rect {
+                y: area.bottom() - swept,
+                height: swept,
+                ..area
+            },
+        };
+        for position in cleared.positions() {
+            buf[position].reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn fade() {
+        let mut buf = Buffer::with_lines(["ab"]);
+        buf.set_style(buf.area, Style::new().fg(Color::Rgb(200, 100, 0)));
+        Fade::new(Color::Rgb(0, 0, 0)).apply(Rect::new(1, 0, 5, 5), &mut buf, 0.5);
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(200, 100, 0));
+        assert_eq!(buf[(1, 0)].fg, Color::Rgb(100, 50, 0));
+        assert_eq!(buf[(1, 0)].bg, Color::Rgb(0, 0, 0));
+        assert_eq!(buf[(1, 0)].symbol(), "b");
+    }
+
+    #[test]
+    fn fade_start_leaves_buffer_unchanged() {
+        let mut buf = Buffer::with_lines(["ab"]);
+        Fade::new(Color::Red).apply(buf.area, &mut buf, 0.0);
+        assert_eq!(buf, Buffer::with_lines(["ab"]));
+    }
+
+    #[test]
+    fn dissolve() {
+        let lines = ["abcdefghij"; 10];
+        let dissolve = Dissolve::new(42);
+
+        let mut buf = Buffer::with_lines(lines);
+        dissolve.apply(buf.area, &mut buf, 0.0);
+        assert_eq!(buf, Buffer::with_lines(lines));
+
+        let mut half = Buffer::with_lines(lines);
+        dissolve.apply(half.area, &mut half, 0.5);
+        let cleared = half.content.iter().filter(|cell| cell.symbol() == " ");
+        assert!((30..70).contains(&cleared.count()));
+
+        let mut again = Buffer::with_lines(lines);
+        dissolve.apply(again.area, &mut again, 0.5);
+        assert_eq!(again, half);
+
+        let mut buf = Buffer::with_lines(lines);
+        dissolve.apply(buf.area, &mut buf, 1.0);
+        assert_eq!(buf, Buffer::empty(buf.area));
+    }
+
+    #[rstest]
+    #[case::left_to_right(SweepDirection::LeftToRight, ["  cd", "  gh"])]
+    #[case::right_to_left(SweepDirection::RightToLeft, ["ab  ", "ef  "])]
+    #[case::top_to_bottom(SweepDirection::TopToBottom, ["    ", "efgh"])]
+    #[case::bottom_to_top(SweepDirection::BottomToTop, ["abcd", "    "])]
+    fn sweep(#[case] direction: SweepDirection, #[case] expected: [&str; 2]) {
+        let mut buf = Buffer::with_lines(["abcd", "efgh"]);
+        Sweep::new(direction).apply(buf.area, &mut buf, 0.5);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn sweep_inner_area() {
+        let mut buf = Buffer::with_lines(["abcd", "efgh"]);
+        Sweep::new(SweepDirection::RightToLeft).apply(Rect::new(1, 1, 2, 1), &mut buf, 1.0);
+        assert_eq!(buf, Buffer::with_lines(["abcd", "e  h"]));
+    }
+}
@@ -0,0 +1,275 @@
+use crate::layout::{Position, Rect, Size};
+use crate::style::Color;
+
+/// A value that can be interpolated between a start and an end value.
+///
+/// This is used by [`Animation`] to compute intermediate values.
+///
+/// [`Animation`]: crate::animation::Animation
+pub trait Interpolate: Sized {
+    /// Returns the value at `t` between `self` (at `0.0`) and `other` (at `1.0`).
+    #[must_use]
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        f64::from(*self).interpolate(&f64::from(*other), t) as Self
+    }
+}
+
+impl Interpolate for u8 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        round(f64::from(*self).interpolate(&f64::from(*other), t)) as Self
+    }
+}
+
+impl Interpolate for u16 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        round(f64::from(*self).interpolate(&f64::from(*other), t)) as Self
+    }
+}
+
+impl Interpolate for u32 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        round(f64::from(*self).interpolate(&f64::from(*other), t)) as Self
+    }
+}
+
+impl Interpolate for i32 {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        round(f64::from(*self).interpolate(&f64::from(*other), t)) as Self
+    }
+}
+
+impl Interpolate for Position {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Self::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Self::new(
+            self.width.interpolate(&other.width, t),
+            self.height.interpolate(&other.height, t),
+        )
+    }
+}
+
+impl Interpolate for Rect {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        Self::from((
+            self.as_position().interpolate(&other.as_position(), t),
+            self.as_size().interpolate(&other.as_size(), t),
+        ))
+    }
+}
+
+/// Interpolates colors in RGB space.
+///
+/// Named and indexed colors are converted to RGB using the default xterm palette. As
+/// [`Color::Reset`] has no RGB equivalent, interpolating from or to it switches between the two
+/// colors halfway instead.
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        match (self.to_rgb(), other.to_rgb()) {
+            (Some((r1, g1, b1)), Some((r2, g2, b2))) => Self::Rgb(
+                r1.interpolate(&r2, t),
+                g1.interpolate(&g2, t),
+                b1.interpolate(&b2, t),
+            ),
+            _ if t < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+/// A [`Color`] that is interpolated in HSL space rather than RGB space.
+///
+/// Interpolating the hue, saturation and lightness keeps intermediate colors saturated, e.g. going
+/// from red to blue passes through magenta rather than a dark purple. The hue takes the shortest
+/// way around the color wheel.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::animation::{HslColor, Interpolate};
+/// use ratatui_core::style::Color;
+///
+/// let red = HslColor(Color::Rgb(255, 0, 0));
+/// let blue = HslColor(Color::Rgb(0, 0, 255));
+/// assert_eq!(red.interpolate(&blue, 0.5), HslColor(Color::Rgb(255, 0, 255)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct HslColor(pub Color);
+
+impl From<Color> for HslColor {
+    fn from(color: Color) -> Self {
+        Self(color)
+    }
+}
+
+impl From<HslColor> for Color {
+    fn from(color: HslColor) -> Self {
+        color.0
+    }
+}
+
+impl Interpolate for HslColor {
+    fn interpolate(&self, other: &Self, t: f64) -> Self {
+        let (Some(from), Some(to)) = (self.0.to_rgb(), other.0.to_rgb()) else {
+            return Self(self.0.interpolate(&other.0, t));
+        };
+        let (h1, s1, l1) = rgb_to_hsl(from);
+        let (h2, s2, l2) = rgb_to_hsl(to);
+        // the signed hue difference, wrapped to -180..180
+        let delta = (h2 - h1 + 540.0) % 360.0 - 180.0;
+        let hue = (h1 + delta * t + 360.0) % 360.0;
+        let (r, g, b) = hsl_to_rgb(hue, s1.interpolate(&s2, t), l1.interpolate(&l2, t));
+        Self(Color::Rgb(r, g, b))
+    }
+}
+
+// Used instead of `f64::round` directly, to provide fallback for `no_std`.
+#[cfg(feature = "std")]
+#[inline]
+fn round(value: f64) -> f64 {
+    value.round()
+}
+
+// A rounding fallback for `no_std` in pure rust.
+#[cfg(not(feature = "std"))]
+#[inline]
+fn round(value: f64) -> f64 {
+    (value + 0.5f64.copysign(value)) as i64 as f64
+}
+
+const fn abs(value: f64) -> f64 {
+    if value < 0.0 { -value } else { value }
+}
+
+/// Converts RGB components to hue (in degrees), saturation and lightness (between 0 and 1)
+fn rgb_to_hsl((red, green, blue): (u8, u8, u8)) -> (f64, f64, f64) {
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = f64::midpoint(f64::from(max), f64::from(min)) / 255.0;
+    if max == min {
+        return (0.0, 0.0, lightness);
+    }
+    let chroma = f64::from(max - min) / 255.0;
+    let saturation = chroma / (1.0 - abs(2.0 * lightness - 1.0));
+    let component = |value: u8| f64::from(value) / 255.0;
+    let (r, g, b) = (component(red), component(green), component(blue));
+    let hue = if max == red {
+        60.0 * (((g - b) / chroma + 6.0) % 6.0)
+    } else if max == green {
+        60.0 * ((b - r) / chroma + 2.0)
+    } else {
+        60.0 * ((r - g) / chroma + 4.0)
+    };
+    (hue, saturation, lightness)
+}
+
+/// Converts hue (in degrees), saturation and lightness (between 0 and 1) to RGB components
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (u8, u8, u8) {
+    let chroma = (1.0 - abs(2.0 * lightness - 1.0)) * saturation;
+    let sector = hue / 60.0;
+    let second = chroma * (1.0 - abs(sector % 2.0 - 1.0));
+    let (red, green, blue) = match sector as u8 {
+        0 => (chroma, second, 0.0),
+        1 => (second, chroma, 0.0),
+        2 => (0.0, chroma, second),
+        3 => (0.0, second, chroma),
+        4 => (second, 0.0, chroma),
+        _ => (chroma, 0.0, second),
+    };
+    let offset = lightness - chroma / 2.0;
+    let component = |value: f64| round((value + offset).clamp(0.0, 1.0) * 255.0) as u8;
+    (component(red), component(green), component(blue))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    #[expect(clippy::float_cmp)]
+    fn numbers() {
+        assert_eq!(0.0_f64.interpolate(&10.0, 0.25), 2.5);
+        assert_eq!(0.0_f32.interpolate(&10.0, 0.25), 2.5);
+        assert_eq!(0_u8.interpolate(&255, 0.5), 128);
+        assert_eq!(10_u16.interpolate(&0, 0.25), 8);
+        assert_eq!(0_u32.interpolate(&3, 0.5), 2);
+        assert_eq!(10_i32.interpolate(&20, 1.0), 20);
+    }
+
+    #[rstest]
+    #[case::negative(-10, -20, 0.5, -15)]
+    #[case::rounds_away_from_zero(-10, -15, 0.5, -13)]
+    #[case::through_zero(-10, 10, 0.25, -5)]
+    fn negative_i32(#[case] from: i32, #[case] to: i32, #[case] t: f64, #[case] expected: i32) {
+        assert_eq!(from.interpolate(&to, t), expected);
+    }
+
+    #[test]
+    fn rect() {
+        let from = Rect::new(0, 0, 10, 10);
+        let to = Rect::new(10, 20, 30, 0);
+        assert_eq!(from.interpolate(&to, 0.0), from);
+        assert_eq!(from.interpolate(&to, 0.5), Rect::new(5, 10, 20, 5));
+        assert_eq!(from.interpolate(&to, 1.0), to);
+    }
+
+    #[rstest]
+    #[case::rgb(Color::Rgb(0, 0, 0), Color::Rgb(255, 100, 10), Color::Rgb(128, 50, 5))]
+    #[case::named(Color::Black, Color::White, Color::Rgb(128, 128, 128))]
+    #[case::indexed(Color::Indexed(16), Color::Indexed(231), Color::Rgb(128, 128, 128))]
+    #[case::grayscale(Color::Indexed(232), Color::Indexed(255), Color::Rgb(123, 123, 123))]
+    fn color_rgb(#[case] from: Color, #[case] to: Color, #[case] expected: Color) {
+        assert_eq!(from.interpolate(&to, 0.5), expected);
+    }
+
+    #[test]
+    fn color_reset() {
+        assert_eq!(Color::Reset.interpolate(&Color::Red, 0.4), Color::Reset);
+        assert_eq!(Color::Reset.interpolate(&Color::Red, 0.5), Color::Red);
+    }
+
+    #[rstest]
+    #[case::red_to_blue((255, 0, 0), (0, 0, 255), (255, 0, 255))]
+    #[case::shortest_hue((255, 0, 128), (255, 128, 0), (255, 0, 0))]
+    #[case::gray((0, 0, 0), (255, 255, 255), (128, 128, 128))]
+    fn color_hsl(
+        #[case] from: (u8, u8, u8),
+        #[case] to: (u8, u8, u8),
+        #[case] expected: (u8, u8, u8),
+    ) {
+        let from = HslColor(Color::from(from));
+        let to = HslColor(Color::from(to));
+        assert_eq!(from.interpolate(&to, 0.0), from);
+        assert_eq!(from.interpolate(&to, 0.5), HslColor(Color::from(expected)));
+        assert_eq!(from.interpolate(&to, 1.0), to);
+    }
+
+    #[rstest]
+    #[case::white((255, 255, 255))]
+    #[case::orange((255, 165, 0))]
+    #[case::teal((0, 128, 128))]
+    #[case::purple((128, 0, 128))]
+    fn hsl_round_trip(#[case] color: (u8, u8, u8)) {
+        let (h, s, l) = rgb_to_hsl(color);
+        assert_eq!(hsl_to_rgb(h, s, l), color);
+    }
+}
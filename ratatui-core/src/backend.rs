@@ -9,7 +9,9 @@
 //! - [Termion]: enable the `termion` feature and use [`TermionBackend`]
 //! - [Termwiz]: enable the `termwiz` feature and use [`TermwizBackend`]
 //!
-//! Additionally, a [`TestBackend`] is provided for testing purposes.
+//! Additionally, a [`TestBackend`] is provided for testing purposes, along with a
+//! [`VirtualTerminal`] that interprets the output of the other backends. Backends that write
+//! escape sequences can use a [`DrawPlanner`] to write fewer bytes when drawing.
 //!
 //! See the [Backend Comparison] section of the [Ratatui Website] for more details on the different
 //! backends.
@@ -103,11 +105,28 @@
 
 use strum::{Display, EnumString};
 
-use crate::buffer::Cell;
+use crate::buffer::{Buffer, Cell};
 use crate::layout::{Position, Size};
 
+#[cfg(feature = "asciicast")]
+mod asciicast;
+mod command;
+mod draw_plan;
+mod graphics;
+mod query;
 mod test;
+mod virtual_terminal;
+#[cfg(feature = "asciicast")]
+pub use self::asciicast::{
+    Asciicast, AsciicastBackend, AsciicastError, AsciicastEvent, AsciicastEventKind,
+    AsciicastFrame, ParseAsciicastError,
+};
+pub use self::command::{CommandBackend, TaskbarProgress, TerminalCommand};
+pub use self::draw_plan::{DrawCommand, DrawPlanner, StyleChange};
+pub use self::graphics::{GraphicsBackend, GraphicsProtocol};
+pub use self::query::{QueryBackend, TerminalCapabilities};
 pub use self::test::TestBackend;
+pub use self::virtual_terminal::VirtualTerminal;
 
 /// Enum representing the different types of clearing operations that can be performed
 /// on the terminal screen.
@@ -125,6 +144,59 @@ pub enum ClearType {
     UntilNewLine,
 }
 
+/// The shape of the cursor and whether it blinks.
+///
+/// Terminals select the style with the DECSCUSR escape sequence. [`CursorStyle::Default`] restores
+/// the style configured by the user, which should be done before exiting when the style was
+/// changed.
+///
+/// The style is set for each frame with [`Frame::set_cursor_style`], or on a backend with
+/// [`Backend::set_cursor_style`].
+///
+/// [`Frame::set_cursor_style`]: crate::terminal::Frame::set_cursor_style
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CursorStyle {
+    /// The style configured by the user, usually a blinking block.
+    #[default]
+    Default,
+    /// A blinking block cursor.
+    BlinkingBlock,
+    /// A steady block cursor.
+    SteadyBlock,
+    /// A blinking underline cursor.
+    BlinkingUnderline,
+    /// A steady underline cursor.
+    SteadyUnderline,
+    /// A blinking vertical bar cursor.
+    BlinkingBar,
+    /// A steady vertical bar cursor.
+    SteadyBar,
+}
+
+impl CursorStyle {
+    /// Returns the parameter of the DECSCUSR sequence (`CSI n SP q`) that selects this style.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::backend::CursorStyle;
+    ///
+    /// assert_eq!(CursorStyle::SteadyBar.decscusr(), 6);
+    /// ```
+    pub const fn decscusr(self) -> u8 {
+        match self {
+            Self::Default => 0,
+            Self::BlinkingBlock => 1,
+            Self::SteadyBlock => 2,
+            Self::BlinkingUnderline => 3,
+            Self::SteadyUnderline => 4,
+            Self::BlinkingBar => 5,
+            Self::SteadyBar => 6,
+        }
+    }
+}
+
 /// The window size in characters (columns / rows) as well as pixels.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct WindowSize {
@@ -138,6 +210,28 @@ pub struct WindowSize {
     pub pixels: Size,
 }
 
+/// Counters of the output written by a backend, as returned by [`Backend::output_stats`].
+///
+/// The counters start at zero when the backend is created and wrap around on overflow.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct OutputStats {
+    /// The number of bytes written to the terminal.
+    pub bytes_written: usize,
+    /// The number of times the cursor was moved.
+    pub cursor_moves: usize,
+}
+
+impl OutputStats {
+    /// Returns the output written since the `earlier` counters were read.
+    #[must_use]
+    pub const fn since(self, earlier: Self) -> Self {
+        Self {
+            bytes_written: self.bytes_written.wrapping_sub(earlier.bytes_written),
+            cursor_moves: self.cursor_moves.wrapping_sub(earlier.cursor_moves),
+        }
+    }
+}
+
 /// The `Backend` trait provides an abstraction over different terminal libraries. It defines the
 /// methods required to draw content, manipulate the cursor, and clear the terminal screen.
 ///
@@ -158,6 +252,28 @@ pub trait Backend {
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>;
 
+    /// Draw the changes between two frames to the terminal screen.
+    ///
+    /// The updates are the changes of the next buffer, as returned by [`Buffer::diff`]. Unlike
+    /// [`draw`], backends can use the unchanged cells of the next buffer to write fewer bytes,
+    /// e.g. by planning the output with a [`DrawPlanner`]. The width of the screen is passed by
+    /// the [`Terminal`] when it is known, i.e. for all viewports but fixed ones, so that backends
+    /// do not need to query the size of the terminal for each frame.
+    ///
+    /// The default implementation draws the updates with [`draw`].
+    ///
+    /// [`draw`]: Self::draw
+    /// [`Terminal`]: crate::terminal::Terminal
+    fn draw_diff(
+        &mut self,
+        next: &Buffer,
+        updates: &[(u16, u16, &Cell)],
+        screen_width: Option<u16>,
+    ) -> Result<(), Self::Error> {
+        let _ = (next, screen_width);
+        self.draw(updates.iter().copied())
+    }
+
     /// Insert `n` line breaks to the terminal screen.
     ///
     /// This method is optional and may not be implemented by all backends.
@@ -192,6 +308,17 @@ pub trait Backend {
     /// [`hide_cursor`]: Self::hide_cursor
     fn show_cursor(&mut self) -> Result<(), Self::Error>;
 
+    /// Sets the shape of the cursor and whether it blinks.
+    ///
+    /// The style applies until it is set again, including after the application exits, so it
+    /// should be reset to [`CursorStyle::Default`] before exiting.
+    ///
+    /// This method is optional and does nothing by default.
+    fn set_cursor_style(&mut self, style: CursorStyle) -> Result<(), Self::Error> {
+        let _ = style;
+        Ok(())
+    }
+
     /// Get the current cursor position on the terminal screen.
     ///
     /// The returned tuple contains the x and y coordinates of the cursor.
@@ -302,6 +429,53 @@ pub trait Backend {
     /// Flush any buffered content to the terminal screen.
     fn flush(&mut self) -> Result<(), Self::Error>;
 
+    /// Returns the counters of the output written by the backend, or `None` if the backend does
+    /// not count its output.
+    ///
+    /// The [`Terminal`] reads the counters before and after drawing each frame to report the
+    /// output of the frame in its statistics. The default implementation returns `None`.
+    ///
+    /// [`Terminal`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html
+    fn output_stats(&self) -> Option<OutputStats> {
+        None
+    }
+
+    /// Returns whether the backend can bracket its output with
+    /// [`begin_synchronized_update`] and [`end_synchronized_update`].
+    ///
+    /// The [`Terminal`] only starts synchronized updates when this returns `true` and they were
+    /// enabled in its options. The default implementation returns `false`.
+    ///
+    /// [`begin_synchronized_update`]: Self::begin_synchronized_update
+    /// [`end_synchronized_update`]: Self::end_synchronized_update
+    /// [`Terminal`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html
+    fn supports_synchronized_output(&self) -> bool {
+        false
+    }
+
+    /// Begins a synchronized update.
+    ///
+    /// Terminals that support [synchronized output] (DEC private mode 2026) keep displaying the
+    /// previous frame until [`end_synchronized_update`] is called, so that a large redraw is shown
+    /// at once instead of tearing. Terminals that do not support it ignore the sequence.
+    ///
+    /// This method is optional and does nothing by default.
+    ///
+    /// [synchronized output]: https://gist.github.com/christianparpart/d8a62cc1ab659194337d73e399004036
+    /// [`end_synchronized_update`]: Self::end_synchronized_update
+    fn begin_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Ends a synchronized update started with [`begin_synchronized_update`].
+    ///
+    /// This method is optional and does nothing by default.
+    ///
+    /// [`begin_synchronized_update`]: Self::begin_synchronized_update
+    fn end_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// Scroll a region of the screen upwards, where a region is specified by a (half-open) range
     /// of rows.
     ///
@@ -402,4 +576,37 @@ mod tests {
         );
         assert_eq!("".parse::<ClearType>(), Err(ParseError::VariantNotFound));
     }
+
+    #[test]
+    fn cursor_style_from_str() {
+        assert_eq!("Default".parse::<CursorStyle>(), Ok(CursorStyle::Default));
+        assert_eq!(
+            "SteadyBar".parse::<CursorStyle>(),
+            Ok(CursorStyle::SteadyBar)
+        );
+        assert_eq!(
+            CursorStyle::BlinkingUnderline.to_string(),
+            "BlinkingUnderline"
+        );
+        assert_eq!("".parse::<CursorStyle>(), Err(ParseError::VariantNotFound));
+    }
+
+    #[test]
+    fn output_stats_since() {
+        let earlier = OutputStats {
+            bytes_written: usize::MAX,
+            cursor_moves: 2,
+        };
+        let later = OutputStats {
+            bytes_written: 9,
+            cursor_moves: 5,
+        };
+        assert_eq!(
+            later.since(earlier),
+            OutputStats {
+                bytes_written: 10,
+                cursor_moves: 3,
+            }
+        );
+    }
 }
@@ -0,0 +1,806 @@
+//! This module provides the [`AsciicastBackend`], which records the output of another backend to
+//! an [asciicast v2] file, and [`Asciicast`], which replays such recordings.
+//!
+//! [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write as _;
+use core::str::FromStr;
+use core::time::Duration;
+use std::io;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::backend::draw_plan::{ColorCodes, modifier_codes};
+use crate::backend::{
+    Backend, ClearType, CommandBackend, CursorStyle, DrawPlanner, GraphicsBackend,
+    GraphicsProtocol, OutputStats, QueryBackend, TerminalCapabilities, TerminalCommand,
+    VirtualTerminal, WindowSize,
+};
+use crate::buffer::{Buffer, Cell};
+use crate::layout::{Position, Rect, Size};
+use crate::style::Color;
+use crate::terminal::Terminal;
+
+/// A [`Backend`] that records everything drawn through another backend to an [asciicast v2] file.
+///
+/// Every draw, cursor change, clear and scroll is forwarded to the inner backend and written to
+/// the recording as escape sequences, with the time elapsed since the recording started. The
+/// output is written as one event each time the backend is flushed, which [`Terminal::draw`] does
+/// once per frame. Changes of the size of the inner backend are recorded as resize events.
+///
+/// The backend implements [`CommandBackend`], [`QueryBackend`] and [`GraphicsBackend`] when the
+/// inner backend does, so that recording does not take features away from the application.
+///
+/// Recordings can be played with [asciinema] or converted to GIFs with tools such as [agg], and
+/// replayed through a [`Terminal`] with [`Asciicast`], e.g. to check a recording in a test.
+///
+/// This backend is available with the `asciicast` feature.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::fs::File;
+///
+/// use ratatui::Terminal;
+/// use ratatui::backend::{AsciicastBackend, CrosstermBackend};
+///
+/// let file = File::create("session.cast")?;
+/// let backend = AsciicastBackend::new(CrosstermBackend::new(std::io::stdout()), file)?;
+/// let mut terminal = Terminal::new(backend)?;
+/// terminal.draw(|frame| frame.render_widget("Hello World!", frame.area()))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+/// [asciinema]: https://asciinema.org
+/// [agg]: https://github.com/asciinema/agg
+#[derive(Debug)]
+pub struct AsciicastBackend<B, W> {
+    backend: B,
+    writer: W,
+    start: Instant,
+    size: Size,
+    /// The escape sequences written since the last flush
+    output: String,
+}
+
+/// An error of an [`AsciicastBackend`].
+#[derive(Debug, Error)]
+pub enum AsciicastError<E> {
+    /// The inner backend failed.
+    #[error(transparent)]
+    Backend(E),
+    /// Writing the recording failed.
+    #[error("cannot write the recording: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// The header of an asciicast v2 file
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    version: u8,
+    width: u16,
+    height: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp: Option<u64>,
+}
+
+impl<B, W> AsciicastBackend<B, W>
+where
+    B: Backend,
+    W: io::Write,
+{
+    /// Creates a new `AsciicastBackend` that forwards to the backend and writes the recording to
+    /// the writer.
+    ///
+    /// The header of the recording is written immediately, with the current size of the backend.
+    pub fn new(backend: B, mut writer: W) -> Result<Self, AsciicastError<B::Error>> {
+        let size = backend.size().map_err(AsciicastError::Backend)?;
+        let header = Header {
+            version: 2,
+            width: size.width,
+            height: size.height,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .map(|elapsed| elapsed.as_secs()),
+        };
+        serde_json::to_writer(&mut writer, &header).map_err(io::Error::from)?;
+        writer.write_all(b"\n")?;
+        Ok(Self {
+            backend,
+            writer,
+            start: Instant::now(),
+            size,
+            output: String::new(),
+        })
+    }
+
+    /// Returns a reference to the inner backend.
+    pub const fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns a mutable reference to the inner backend.
+    pub const fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    /// Returns a reference to the writer of the recording.
+    pub const fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `AsciicastBackend`, returning the inner backend and the writer.
+    ///
+    /// The output that was not flushed yet is not recorded.
+    pub fn into_inner(self) -> (B, W) {
+        (self.backend, self.writer)
+    }
+
+    fn write_event(&mut self, code: &str, data: &str) -> io::Result<()> {
+        let time = self.start.elapsed().as_micros() as f64 / 1_000_000.0;
+        serde_json::to_writer(&mut self.writer, &(time, code, data)).map_err(io::Error::from)?;
+        self.writer.write_all(b"\n")
+    }
+
+    fn write_style(&mut self, cell: &Cell) {
+        self.output.push_str("\x1b[0");
+        for code in modifier_codes(cell.modifier) {
+            self.output.push(';');
+            self.output.push_str(code);
+        }
+        #[cfg(feature = "underline-color")]
+        let underline_color = cell.underline_color;
+        #[cfg(not(feature = "underline-color"))]
+        let underline_color = Color::Reset;
+        for (color, code) in [(cell.fg, 38), (cell.bg, 48), (underline_color, 58)] {
+            if color != Color::Reset {
+                let _ = write!(self.output, ";{}", ColorCodes { color, code });
+            }
+        }
+        self.output.push('m');
+    }
+}
+
+impl<B, W> Backend for AsciicastBackend<B, W>
+where
+    B: Backend,
+    W: io::Write,
+{
+    type Error = AsciicastError<B::Error>;
+
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), Self::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let content: Vec<_> = content.collect();
+        let mut style = Cell::EMPTY.style();
+        let mut hyperlink = None;
+        let mut last_position: Option<Position> = None;
+        for &(x, y, cell) in &content {
+            if !matches!(last_position, Some(p) if x == p.x + 1 && y == p.y) {
+                let _ = write!(self.output, "\x1b[{};{}H", y + 1, x + 1);
+            }
+            last_position = Some(Position { x, y });
+            if cell.style() != style {
+                self.write_style(cell);
+                style = cell.style();
+            }
+            if cell.hyperlink() != hyperlink {
+                let url = cell.hyperlink().unwrap_or_default();
+                let _ = write!(self.output, "\x1b]8;;{url}\x1b\\");
+                hyperlink = cell.hyperlink();
+            }
+            self.output.push_str(cell.symbol());
+        }
+        if hyperlink.is_some() {
+            self.output.push_str("\x1b]8;;\x1b\\");
+        }
+        self.output.push_str("\x1b[0m");
+        self.backend
+            .draw(content.into_iter())
+            .map_err(AsciicastError::Backend)
+    }
+
+    /// Records the changes as planned by a [`DrawPlanner`], and draws them with the inner
+    /// backend, which can plan its own output.
+    fn draw_diff(
+        &mut self,
+        next: &Buffer,
+        updates: &[(u16, u16, &Cell)],
+        screen_width: Option<u16>,
+    ) -> Result<(), Self::Error> {
+        let planner = screen_width.map_or_else(DrawPlanner::new, |width| {
+            DrawPlanner::new().with_screen_width(width)
+        });
+        for command in planner.plan(next, updates) {
+            let _ = write!(self.output, "{command}");
+        }
+        self.backend
+            .draw_diff(next, updates, screen_width)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn append_lines(&mut self, n: u16) -> Result<(), Self::Error> {
+        for _ in 0..n {
+            self.output.push('\n');
+        }
+        self.backend
+            .append_lines(n)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.output.push_str("\x1b[?25l");
+        self.backend.hide_cursor().map_err(AsciicastError::Backend)
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.output.push_str("\x1b[?25h");
+        self.backend.show_cursor().map_err(AsciicastError::Backend)
+    }
+
+    fn set_cursor_style(&mut self, style: CursorStyle) -> Result<(), Self::Error> {
+        let _ = write!(self.output, "\x1b[{} q", style.decscusr());
+        self.backend
+            .set_cursor_style(style)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn get_cursor_position(&mut self) -> Result<Position, Self::Error> {
+        self.backend
+            .get_cursor_position()
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> Result<(), Self::Error> {
+        let Position { x, y } = position.into();
+        let _ = write!(self.output, "\x1b[{};{}H", y + 1, x + 1);
+        self.backend
+            .set_cursor_position(Position { x, y })
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.output.push_str("\x1b[2J");
+        self.backend.clear().map_err(AsciicastError::Backend)
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> Result<(), Self::Error> {
+        self.output.push_str(match clear_type {
+            ClearType::All => "\x1b[2J",
+            ClearType::AfterCursor => "\x1b[J",
+            ClearType::BeforeCursor => "\x1b[1J",
+            ClearType::CurrentLine => "\x1b[2K",
+            ClearType::UntilNewLine => "\x1b[K",
+        });
+        self.backend
+            .clear_region(clear_type)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn size(&self) -> Result<Size, Self::Error> {
+        self.backend.size().map_err(AsciicastError::Backend)
+    }
+
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        self.backend.window_size().map_err(AsciicastError::Backend)
+    }
+
+    /// Flushes the inner backend and writes the output since the last flush to the recording.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.backend.flush().map_err(AsciicastError::Backend)?;
+        let size = self.size()?;
+        if size != self.size {
+            self.size = size;
+            self.write_event("r", &format!("{}x{}", size.width, size.height))?;
+        }
+        if !self.output.is_empty() {
+            let output = core::mem::take(&mut self.output);
+            self.write_event("o", &output)?;
+            self.output = output;
+            self.output.clear();
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    fn output_stats(&self) -> Option<OutputStats> {
+        self.backend.output_stats()
+    }
+
+    fn supports_synchronized_output(&self) -> bool {
+        self.backend.supports_synchronized_output()
+    }
+
+    fn begin_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        self.backend
+            .begin_synchronized_update()
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn end_synchronized_update(&mut self) -> Result<(), Self::Error> {
+        self.backend
+            .end_synchronized_update()
+            .map_err(AsciicastError::Backend)
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_up(
+        &mut self,
+        region: core::ops::Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        let (start, end) = (region.start + 1, region.end);
+        let _ = write!(self.output, "\x1b[{start};{end}r\x1b[{line_count}S\x1b[r");
+        self.backend
+            .scroll_region_up(region, line_count)
+            .map_err(AsciicastError::Backend)
+    }
+
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_region_down(
+        &mut self,
+        region: core::ops::Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        let (start, end) = (region.start + 1, region.end);
+        let _ = write!(self.output, "\x1b[{start};{end}r\x1b[{line_count}T\x1b[r");
+        self.backend
+            .scroll_region_down(region, line_count)
+            .map_err(AsciicastError::Backend)
+    }
+}
+
+impl<B, W> CommandBackend for AsciicastBackend<B, W>
+where
+    B: CommandBackend,
+    W: io::Write,
+{
+    /// Records the command, except for the clipboard contents, which can be secret, and executes
+    /// it with the inner backend.
+    fn execute_command(&mut self, command: &TerminalCommand) -> Result<(), Self::Error> {
+        if !matches!(command, TerminalCommand::SetClipboard(_)) {
+            let _ = write!(self.output, "{command}");
+        }
+        self.backend
+            .execute_command(command)
+            .map_err(AsciicastError::Backend)?;
+        self.flush()
+    }
+}
+
+impl<B, W> QueryBackend for AsciicastBackend<B, W>
+where
+    B: QueryBackend,
+    W: io::Write,
+{
+    /// Queries the inner backend. Neither the request nor the responses are recorded.
+    fn query(
+        &mut self,
+        request: &[u8],
+        timeout: Duration,
+        is_complete: fn(&[u8]) -> bool,
+    ) -> Result<Vec<u8>, Self::Error> {
+        self.backend
+            .query(request, timeout, is_complete)
+            .map_err(AsciicastError::Backend)
+    }
+
+    fn query_capabilities(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<TerminalCapabilities, Self::Error> {
+        self.backend
+            .query_capabilities(timeout)
+            .map_err(AsciicastError::Backend)
+    }
+}
+
+impl<B, W> GraphicsBackend for AsciicastBackend<B, W>
+where
+    B: GraphicsBackend,
+    W: io::Write,
+{
+    fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        self.backend.graphics_protocol()
+    }
+
+    /// Records the payload, which players without support for images ignore, and draws it with
+    /// the inner backend.
+    fn draw_graphics(&mut self, position: Position, payload: &[u8]) -> Result<(), Self::Error> {
+        let Position { x, y } = position;
+        let _ = write!(
+            self.output,
+            "\x1b[{};{}H{}",
+            y + 1,
+            x + 1,
+            String::from_utf8_lossy(payload)
+        );
+        self.backend
+            .draw_graphics(position, payload)
+            .map_err(AsciicastError::Backend)
+    }
+}
+
+/// A recording in the [asciicast v2] format, which can be replayed through a [`Terminal`].
+///
+/// The output of the recording is interpreted by a [`VirtualTerminal`], so recordings made by
+/// other tools, such as [asciinema], can be replayed as well.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::{Asciicast, TestBackend};
+/// use ratatui_core::terminal::Terminal;
+///
+/// let recording: Asciicast = r#"{"version": 2, "width": 5, "height": 1}
+/// [0.1, "o", "Hello"]"#
+///     .parse()?;
+///
+/// let mut terminal = Terminal::new(TestBackend::new(5, 1))?;
+/// recording.replay(&mut terminal)?;
+/// terminal.backend().assert_buffer_lines(["Hello"]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+/// [asciinema]: https://asciinema.org
+#[derive(Debug, Clone, PartialEq)]
+pub struct Asciicast {
+    /// The initial size of the terminal
+    pub size: Size,
+    /// The events of the recording, in chronological order
+    pub events: Vec<AsciicastEvent>,
+}
+
+/// An event of an [`Asciicast`] recording.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsciicastEvent {
+    /// The time elapsed since the start of the recording
+    pub time: Duration,
+    /// What happened
+    pub kind: AsciicastEventKind,
+}
+
+/// The kind of an [`AsciicastEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AsciicastEventKind {
+    /// Data written to the terminal
+    Output(String),
+    /// Data read from the terminal, such as key presses
+    Input(String),
+    /// The terminal was resized
+    Resize(Size),
+    /// A marker, such as a chapter, with its label
+    Marker(String),
+}
+
+/// A frame of a replayed [`Asciicast`], i.e. the content of the screen after an output event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AsciicastFrame {
+    /// The time elapsed since the start of the recording
+    pub time: Duration,
+    /// The content of the screen
+    pub buffer: Buffer,
+    /// The position of the cursor, or `None` if it is hidden
+    pub cursor_position: Option<Position>,
+}
+
+/// An error that occurs when parsing an [`Asciicast`].
+#[derive(Debug, Error)]
+pub enum ParseAsciicastError {
+    /// The recording does not start with a header.
+    #[error("the recording has no header")]
+    MissingHeader,
+    /// The version of the recording is not 2.
+    #[error("unsupported asciicast version {0}")]
+    UnsupportedVersion(u8),
+    /// A line is not valid JSON or has an unexpected structure.
+    #[error("invalid line {line}: {source}")]
+    InvalidLine {
+        /// The number of the line, starting at 1
+        line: usize,
+        /// The cause of the error
+        source: serde_json::Error,
+    },
+    /// A resize event does not have a size such as `80x24`.
+    #[error("invalid size {size:?} on line {line}")]
+    InvalidSize {
+        /// The number of the line, starting at 1
+        line: usize,
+        /// The invalid size
+        size: String,
+    },
+}
+
+impl FromStr for Asciicast {
+    type Err = ParseAsciicastError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+        let (line, header) = lines.next().ok_or(ParseAsciicastError::MissingHeader)?;
+        let header: Header = serde_json::from_str(header)
+            .map_err(|source| ParseAsciicastError::InvalidLine { line, source })?;
+        if header.version != 2 {
+            return Err(ParseAsciicastError::UnsupportedVersion(header.version));
+        }
+        let mut events = Vec::new();
+        for (line, event) in lines {
+            let (time, code, data): (f64, String, String) = serde_json::from_str(event)
+                .map_err(|source| ParseAsciicastError::InvalidLine { line, source })?;
+            let kind = match code.as_str() {
+                "o" => AsciicastEventKind::Output(data),
+                "i" => AsciicastEventKind::Input(data),
+                "m" => AsciicastEventKind::Marker(data),
+                "r" => {
+                    let size = data
+                        .split_once('x')
+                        .and_then(|(width, height)| {
+                            Some(Size::new(width.parse().ok()?, height.parse().ok()?))
+                        })
+                        .ok_or(ParseAsciicastError::InvalidSize { line, size: data })?;
+                    AsciicastEventKind::Resize(size)
+                }
+                _ => continue,
+            };
+            let time = Duration::try_from_secs_f64(time).unwrap_or_default();
+            events.push(AsciicastEvent { time, kind });
+        }
+        Ok(Self {
+            size: Size::new(header.width, header.height),
+            events,
+        })
+    }
+}
+
+impl Asciicast {
+    /// Returns the frames of the recording, i.e. the content of the screen after each output
+    /// event.
+    pub fn frames(&self) -> impl Iterator<Item = AsciicastFrame> + '_ {
+        let mut terminal = VirtualTerminal::new(self.size.width, self.size.height);
+        self.events
+            .iter()
+            .filter_map(move |event| match &event.kind {
+                AsciicastEventKind::Output(data) => {
+                    terminal.process(data.as_bytes());
+                    Some(AsciicastFrame {
+                        time: event.time,
+                        buffer: terminal.buffer().clone(),
+                        cursor_position: terminal
+                            .is_cursor_visible()
+                            .then(|| terminal.cursor_position()),
+                    })
+                }
+                AsciicastEventKind::Resize(size) => {
+                    terminal.resize(size.width, size.height);
+                    None
+                }
+                AsciicastEventKind::Input(_) | AsciicastEventKind::Marker(_) => None,
+            })
+    }
+
+    /// Draws each frame of the recording to the terminal, as fast as possible.
+    ///
+    /// This is useful to check the content of a recording in a test with a [`TestBackend`]. The
+    /// frames are drawn at the top left of the terminal, and are cut to its size.
+    ///
+    /// [`TestBackend`]: crate::backend::TestBackend
+    pub fn replay<B: Backend>(&self, terminal: &mut Terminal<B>) -> Result<(), B::Error> {
+        for frame in self.frames() {
+            draw_frame(terminal, &frame)?;
+        }
+        Ok(())
+    }
+
+    /// Draws each frame of the recording to the terminal at the time it was recorded.
+    ///
+    /// See [`Asciicast::replay`] for details.
+    pub fn play<B: Backend>(&self, terminal: &mut Terminal<B>) -> Result<(), B::Error> {
+        let start = Instant::now();
+        for frame in self.frames() {
+            std::thread::sleep(frame.time.saturating_sub(start.elapsed()));
+            draw_frame(terminal, &frame)?;
+        }
+        Ok(())
+    }
+}
+
+fn draw_frame<B: Backend>(
+    terminal: &mut Terminal<B>,
+    frame: &AsciicastFrame,
+) -> Result<(), B::Error> {
+    terminal.draw(|target| {
+        let area = target.area();
+        let source = Rect::new(0, 0, area.width, area.height).intersection(frame.buffer.area);
+        for Position { x, y } in source.positions() {
+            target.buffer_mut()[(area.x + x, area.y + y)] = frame.buffer[(x, y)].clone();
+        }
+        if let Some(Position { x, y }) = frame.cursor_position {
+            if source.contains(Position { x, y }) {
+                target.set_cursor_position((area.x + x, area.y + y));
+            }
+        }
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::backend::TestBackend;
+    use crate::style::{Style, Stylize};
+    use crate::text::Line;
+    use crate::widgets::Widget;
+
+    type RecordingTerminal = Terminal<AsciicastBackend<TestBackend, Vec<u8>>>;
+
+    fn recording_terminal(width: u16, height: u16) -> RecordingTerminal {
+        let backend = AsciicastBackend::new(TestBackend::new(width, height), Vec::new()).unwrap();
+        Terminal::new(backend).unwrap()
+    }
+
+    fn recording(terminal: &RecordingTerminal) -> &str {
+        core::str::from_utf8(terminal.backend().writer()).unwrap()
+    }
+
+    #[test]
+    fn header() {
+        let terminal = recording_terminal(10, 2);
+        let header = recording(&terminal).lines().next().unwrap();
+        let header: serde_json::Value = serde_json::from_str(header).unwrap();
+        assert_eq!(header["version"], 2);
+        assert_eq!(header["width"], 10);
+        assert_eq!(header["height"], 2);
+        assert!(header["timestamp"].is_u64());
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let mut terminal = recording_terminal(12, 2);
+        terminal
+            .draw(|frame| {
+                let line = Line::from_iter(["Hello ".bold().red(), "界".on_blue()]);
+                line.render(frame.area(), frame.buffer_mut());
+                frame.buffer_mut()[(0, 1)]
+                    .set_symbol("x")
+                    .set_style(Style::new().fg(Color::Rgb(1, 2, 3)).italic())
+                    .set_hyperlink(Some("https://ratatui.rs"));
+                frame.set_cursor_position((3, 1));
+            })
+            .unwrap();
+        terminal
+            .draw(|frame| frame.render_widget("Bye".dim(), frame.area()))
+            .unwrap();
+        let recording: Asciicast = recording(&terminal).parse().unwrap();
+        assert_eq!(recording.size, Size::new(12, 2));
+
+        let frames: Vec<_> = recording.frames().collect();
+        assert_eq!(frames.len(), 2);
+        let first = terminal.backend().backend().buffer().clone();
+        assert_eq!(frames[1].buffer, first);
+        assert_eq!(frames[1].cursor_position, None);
+        assert_eq!(frames[0].cursor_position, Some(Position::new(3, 1)));
+
+        let mut replay = Terminal::new(TestBackend::new(12, 2)).unwrap();
+        recording.replay(&mut replay).unwrap();
+        replay.backend().assert_buffer(&first);
+    }
+
+    #[test]
+    fn records_resize() {
+        let mut terminal = recording_terminal(4, 1);
+        terminal.backend_mut().backend_mut().resize(3, 2);
+        terminal
+            .draw(|frame| frame.render_widget("abc", frame.area()))
+            .unwrap();
+        let recording: Asciicast = recording(&terminal).parse().unwrap();
+        let kinds: Vec<_> = recording
+            .events
+            .into_iter()
+            .map(|event| event.kind)
+            .collect();
+        assert_eq!(kinds[0], AsciicastEventKind::Resize(Size::new(3, 2)));
+        assert!(matches!(&kinds[1], AsciicastEventKind::Output(output) if output.contains("abc")));
+    }
+
+    #[test]
+    fn forwards_commands_queries_and_graphics() {
+        let mut terminal = recording_terminal(4, 1);
+        let backend = terminal.backend_mut();
+        backend.set_title("title").unwrap();
+        backend.set_clipboard("secret").unwrap();
+        backend.backend_mut().push_query_response("answer");
+        let response = backend.query(b"request", Duration::ZERO, |_| true).unwrap();
+        backend
+            .draw_graphics(Position::new(1, 0), b"image")
+            .unwrap();
+        backend.flush().unwrap();
+
+        assert_eq!(response, b"answer");
+        let inner = terminal.backend().backend();
+        assert_eq!(
+            inner.commands(),
+            [
+                TerminalCommand::SetTitle("title".into()),
+                TerminalCommand::SetClipboard("secret".into()),
+            ]
+        );
+        assert_eq!(inner.queries(), [b"request".to_vec()]);
+        assert_eq!(inner.graphics(), [(Position::new(1, 0), b"image".to_vec())]);
+        let recording = recording(&terminal);
+        assert!(recording.contains(r"\u001b]0;title"));
+        assert!(!recording.contains(r"\u001b]52"));
+        assert!(recording.contains(r"\u001b[1;2Himage"));
+    }
+
+    #[test]
+    fn parse() {
+        let recording: Asciicast = r#"{"version": 2, "width": 80, "height": 24, "env": {}}
+
+            [0.5, "o", "a\u001b[1mb"]
+            [1.25, "i", "q"]
+            [2, "r", "100x40"]
+            [3, "m", "chapter"]
+            [4, "x", "unknown"]"#
+            .parse()
+            .unwrap();
+        assert_eq!(
+            recording,
+            Asciicast {
+                size: Size::new(80, 24),
+                events: vec![
+                    AsciicastEvent {
+                        time: Duration::from_millis(500),
+                        kind: AsciicastEventKind::Output("a\x1b[1mb".into()),
+                    },
+                    AsciicastEvent {
+                        time: Duration::from_millis(1250),
+                        kind: AsciicastEventKind::Input("q".into()),
+                    },
+                    AsciicastEvent {
+                        time: Duration::from_secs(2),
+                        kind: AsciicastEventKind::Resize(Size::new(100, 40)),
+                    },
+                    AsciicastEvent {
+                        time: Duration::from_secs(3),
+                        kind: AsciicastEventKind::Marker("chapter".into()),
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            "".parse::<Asciicast>(),
+            Err(ParseAsciicastError::MissingHeader)
+        ));
+        assert!(matches!(
+            r#"{"version": 1, "width": 80, "height": 24}"#.parse::<Asciicast>(),
+            Err(ParseAsciicastError::UnsupportedVersion(1))
+        ));
+        assert!(matches!(
+            "{\"version\": 2, \"width\": 80, \"height\": 24}\n[0, \"o\"]".parse::<Asciicast>(),
+            Err(ParseAsciicastError::InvalidLine { line: 2, .. })
+        ));
+        assert!(matches!(
+            "{\"version\": 2, \"width\": 80, \"height\": 24}\n[0, \"r\", \"80\"]"
+                .parse::<Asciicast>(),
+            Err(ParseAsciicastError::InvalidSize { line: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,328 @@
+//! This module provides the [`CommandBackend`] extension trait for backends that can send commands
+//! that act outside of the screen of the terminal, such as copying to the clipboard, setting the
+//! window title or showing a desktop notification.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+
+use crate::backend::query::{Sequence, Sequences};
+use crate::backend::{Backend, QueryBackend, TerminalCapabilities};
+
+/// The progress of a long running task, which some terminals show in the taskbar or in the tab of
+/// the application (OSC 9;4).
+///
+/// This is supported by Windows Terminal, `ConEmu`, Ghostty and `WezTerm`. Percentages above 100
+/// are clamped.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TaskbarProgress {
+    /// No progress is shown.
+    #[default]
+    Hidden,
+    /// The task is running, with the given percentage done.
+    Normal(u8),
+    /// The task failed, with the given percentage done.
+    Error(u8),
+    /// The task is running, but how much of it is done is unknown.
+    Indeterminate,
+    /// The task is paused, with the given percentage done.
+    Paused(u8),
+}
+
+/// A command that acts outside of the screen of the terminal.
+///
+/// Commands are sent with [`CommandBackend::execute_command`], and are formatted as the escape
+/// sequences that the terminal understands with their [`Display`](fmt::Display) implementation.
+/// Control characters are removed from the text of commands, so that the text cannot end the
+/// escape sequence early.
+///
+/// Terminals ignore the commands that they do not support.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TerminalCommand {
+    /// Copies the text to the system clipboard (OSC 52).
+    ///
+    /// Some terminals need this to be enabled in their configuration, and multiplexers such as
+    /// tmux only forward it to the outer terminal when configured to.
+    SetClipboard(String),
+    /// Sets the title of the window and its icon (OSC 0).
+    SetTitle(String),
+    /// Saves the title of the window and its icon on the title stack of the terminal (XTWINOPS
+    /// 22), so that it can be restored with [`TerminalCommand::RestoreTitle`].
+    SaveTitle,
+    /// Restores the title of the window and its icon that was last saved with
+    /// [`TerminalCommand::SaveTitle`] (XTWINOPS 23).
+    RestoreTitle,
+    /// Shows a desktop notification.
+    ///
+    /// Notifications with a title are sent with OSC 777, which is supported by foot, Ghostty,
+    /// `WezTerm` and urxvt. Notifications without a title are sent with OSC 9, which is supported
+    /// by iTerm2, kitty, Ghostty and `WezTerm`.
+    Notify {
+        /// The title of the notification
+        title: Option<String>,
+        /// The text of the notification
+        body: String,
+    },
+    /// Sets the progress of a long running task.
+    SetProgress(TaskbarProgress),
+}
+
+/// The string terminator that ends operating system commands.
+const ST: &str = "\x1b\\";
+
+impl fmt::Display for TerminalCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SetClipboard(text) => write!(f, "\x1b]52;c;{}{ST}", Base64(text.as_bytes())),
+            Self::SetTitle(title) => write!(f, "\x1b]0;{}{ST}", Sanitized(title)),
+            Self::SaveTitle => f.write_str("\x1b[22;0t"),
+            Self::RestoreTitle => f.write_str("\x1b[23;0t"),
+            Self::Notify {
+                title: Some(title),
+                body,
+            } => write!(
+                f,
+                "\x1b]777;notify;{};{}{ST}",
+                Sanitized(title),
+                Sanitized(body)
+            ),
+            Self::Notify { title: None, body } => write!(f, "\x1b]9;{}{ST}", Sanitized(body)),
+            Self::SetProgress(progress) => {
+                let (state, percent) = match *progress {
+                    TaskbarProgress::Hidden => (0, 0),
+                    TaskbarProgress::Normal(percent) => (1, percent),
+                    TaskbarProgress::Error(percent) => (2, percent),
+                    TaskbarProgress::Indeterminate => (3, 0),
+                    TaskbarProgress::Paused(percent) => (4, percent),
+                };
+                write!(f, "\x1b]9;4;{state};{}{ST}", percent.min(100))
+            }
+        }
+    }
+}
+
+/// Formats text without its control characters.
+struct Sanitized<'a>(&'a str);
+
+impl fmt::Display for Sanitized<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in self.0.split(char::is_control) {
+            f.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+/// The alphabet of the standard base64 encoding.
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Formats bytes with the standard base64 encoding, with padding.
+struct Base64<'a>(&'a [u8]);
+
+impl fmt::Display for Base64<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        for chunk in self.0.chunks(3) {
+            let bytes = [
+                chunk[0],
+                *chunk.get(1).unwrap_or(&0),
+                *chunk.get(2).unwrap_or(&0),
+            ];
+            let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+            for index in 0..4 {
+                if index <= chunk.len() {
+                    let sextet = (bits >> (18 - 6 * index)) & 0x3f;
+                    f.write_char(char::from(BASE64[sextet as usize]))?;
+                } else {
+                    f.write_char('=')?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes bytes encoded with the standard base64 encoding, with or without padding.
+fn decode_base64(encoded: &[u8]) -> Option<Vec<u8>> {
+    let encoded = encoded
+        .strip_suffix(b"==")
+        .or_else(|| encoded.strip_suffix(b"="))
+        .unwrap_or(encoded);
+    let mut decoded = Vec::with_capacity(encoded.len() * 3 / 4);
+    for chunk in encoded.chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut bits = 0;
+        for (index, byte) in chunk.iter().enumerate() {
+            let sextet = BASE64.iter().position(|digit| digit == byte)?;
+            bits |= (sextet as u32) << (18 - 6 * index);
+        }
+        decoded.extend_from_slice(&bits.to_be_bytes()[1..chunk.len()]);
+    }
+    Some(decoded)
+}
+
+/// The request for the content of the clipboard, followed by a Primary Device Attributes request
+/// to detect the end of the responses.
+const CLIPBOARD_REQUEST: &str = "\x1b]52;c;?\x1b\\\x1b[c";
+
+/// Parses the content of the clipboard from the responses to [`CLIPBOARD_REQUEST`].
+fn parse_clipboard(response: &[u8]) -> Option<String> {
+    let body = Sequences(response).find_map(|sequence| match sequence {
+        Sequence::Osc(body) => body.strip_prefix(b"52;"),
+        _ => None,
+    })?;
+    let (_, encoded) = body.split_at(body.iter().position(|&byte| byte == b';')? + 1);
+    String::from_utf8(decode_base64(encoded)?).ok()
+}
+
+/// An extension of the [`Backend`] trait for backends that can send [`TerminalCommand`]s.
+///
+/// Commands do not move the cursor or change the content of the screen, so they can be sent at
+/// any time without disturbing the rendering of the [`Terminal`].
+///
+/// [`Terminal`]: crate::terminal::Terminal
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use ratatui::backend::{CommandBackend, TaskbarProgress};
+///
+/// let mut terminal = ratatui::init();
+/// let backend = terminal.backend_mut();
+/// backend.set_title("report.csv")?;
+/// backend.set_progress(TaskbarProgress::Indeterminate)?;
+/// // ...
+/// backend.set_progress(TaskbarProgress::Hidden)?;
+/// backend.notify(Some("Export"), "report.csv was exported")?;
+/// ```
+pub trait CommandBackend: Backend {
+    /// Writes the command to the terminal and flushes it.
+    fn execute_command(&mut self, command: &TerminalCommand) -> Result<(), Self::Error>;
+
+    /// Copies the text to the system clipboard.
+    ///
+    /// See [`TerminalCommand::SetClipboard`].
+    fn set_clipboard(&mut self, text: &str) -> Result<(), Self::Error> {
+        self.execute_command(&TerminalCommand::SetClipboard(text.into()))
+    }
+
+    /// Returns the text of the system clipboard, or `None` if the terminal did not answer within
+    /// the timeout or the clipboard does not contain text.
+    ///
+    /// Most terminals refuse to share the clipboard unless configured to, as it can contain
+    /// secrets.
+    fn get_clipboard(&mut self, timeout: Duration) -> Result<Option<String>, Self::Error>
+    where
+        Self: QueryBackend,
+    {
+        let response = self.query(
+            CLIPBOARD_REQUEST.as_bytes(),
+            timeout,
+            TerminalCapabilities::is_complete,
+        )?;
+        Ok(parse_clipboard(&response))
+    }
+
+    /// Sets the title of the window.
+    ///
+    /// The title is not restored when the application exits, unless it was saved with
+    /// [`CommandBackend::save_title`] and restored with [`CommandBackend::restore_title`], which
+    /// `ratatui::init()` and `ratatui::restore()` do unless disabled with
+    /// `ratatui::set_save_title(false)`.
+    fn set_title(&mut self, title: &str) -> Result<(), Self::Error> {
+        self.execute_command(&TerminalCommand::SetTitle(title.into()))
+    }
+
+    /// Saves the title of the window on the title stack of the terminal.
+    fn save_title(&mut self) -> Result<(), Self::Error> {
+        self.execute_command(&TerminalCommand::SaveTitle)
+    }
+
+    /// Restores the title of the window that was last saved with [`CommandBackend::save_title`].
+    fn restore_title(&mut self) -> Result<(), Self::Error> {
+        self.execute_command(&TerminalCommand::RestoreTitle)
+    }
+
+    /// Shows a desktop notification, with an optional title.
+    ///
+    /// See [`TerminalCommand::Notify`] for the terminals that support notifications with and
+    /// without a title.
+    fn notify(&mut self, title: Option<&str>, body: &str) -> Result<(), Self::Error> {
+        self.execute_command(&TerminalCommand::Notify {
+            title: title.map(Into::into),
+            body: body.into(),
+        })
+    }
+
+    /// Sets the progress of a long running task.
+    fn set_progress(&mut self, progress: TaskbarProgress) -> Result<(), Self::Error> {
+        self.execute_command(&TerminalCommand::SetProgress(progress))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::clipboard(TerminalCommand::SetClipboard("hello".into()), "\x1b]52;c;aGVsbG8=\x1b\\")]
+    #[case::title(TerminalCommand::SetTitle("a\x07b\x1b]".into()), "\x1b]0;ab]\x1b\\")]
+    #[case::save_title(TerminalCommand::SaveTitle, "\x1b[22;0t")]
+    #[case::restore_title(TerminalCommand::RestoreTitle, "\x1b[23;0t")]
+    #[case::notify(
+        TerminalCommand::Notify { title: Some("Done".into()), body: "Exported".into() },
+        "\x1b]777;notify;Done;Exported\x1b\\"
+    )]
+    #[case::notify_without_title(
+        TerminalCommand::Notify { title: None, body: "Exported".into() },
+        "\x1b]9;Exported\x1b\\"
+    )]
+    #[case::progress(
+        TerminalCommand::SetProgress(TaskbarProgress::Normal(42)),
+        "\x1b]9;4;1;42\x1b\\"
+    )]
+    #[case::progress_clamped(
+        TerminalCommand::SetProgress(TaskbarProgress::Error(200)),
+        "\x1b]9;4;2;100\x1b\\"
+    )]
+    #[case::progress_hidden(
+        TerminalCommand::SetProgress(TaskbarProgress::Hidden),
+        "\x1b]9;4;0;0\x1b\\"
+    )]
+    fn display(#[case] command: TerminalCommand, #[case] expected: &str) {
+        assert_eq!(command.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::one_byte("a")]
+    #[case::two_bytes("ab")]
+    #[case::three_bytes("abc")]
+    #[case::unicode("héllo wörld ✓")]
+    fn base64_round_trip(#[case] text: &str) {
+        let encoded = Base64(text.as_bytes()).to_string();
+        assert_eq!(encoded.len() % 4, 0);
+        assert_eq!(decode_base64(encoded.as_bytes()).unwrap(), text.as_bytes());
+        let unpadded = encoded.trim_end_matches('=');
+        assert_eq!(decode_base64(unpadded.as_bytes()).unwrap(), text.as_bytes());
+    }
+
+    #[rstest]
+    #[case::text(b"\x1b]52;c;aGVsbG8=\x07\x1b[?62c", Some("hello"))]
+    #[case::no_selection(b"\x1b]52;;aGVsbG8=\x1b\\\x1b[?62c", Some("hello"))]
+    #[case::denied(b"\x1b]52;c;?\x1b\\\x1b[?62c", None)]
+    #[case::unsupported(b"\x1b[?62c", None)]
+    fn clipboard(#[case] response: &[u8], #[case] expected: Option<&str>) {
+        assert_eq!(parse_clipboard(response).as_deref(), expected);
+    }
+}
@@ -0,0 +1,1059 @@
+//! This module provides the [`DrawPlanner`], which plans the escape sequences that draw the changes
+//! of a buffer with as few bytes as possible.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::buffer::{Buffer, Cell};
+use crate::layout::Position;
+use crate::style::{Color, ColorDepth, Modifier};
+
+/// A command of a [`DrawPlanner`] plan.
+///
+/// Each command corresponds to a single escape sequence (or text), which the [`Display`]
+/// implementation writes. Backends that write to a terminal through a library can instead map
+/// each command to the equivalent command of the library, as long as it writes the same bytes.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DrawCommand<'a> {
+    /// Moves the cursor to the position (CUP).
+    MoveTo(Position),
+    /// Moves the cursor to the column of the current row (CHA).
+    MoveToColumn(u16),
+    /// Moves the cursor right by a number of columns (CUF).
+    MoveRight(u16),
+    /// Moves the cursor left by a number of columns (CUB).
+    MoveLeft(u16),
+    /// Moves the cursor down by a number of rows, to the first column (CNL).
+    MoveToNextLine(u16),
+    /// Changes the colors and modifiers of the printed text (SGR).
+    SetStyle(StyleChange),
+    /// Starts or ends an [OSC 8] hyperlink.
+    ///
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    SetHyperlink(Option<&'a str>),
+    /// Prints the symbol of a cell.
+    Print(&'a str),
+    /// Repeats the last printed character a number of times (REP).
+    Repeat(u16),
+    /// Erases a number of cells from the cursor with the current background color, without
+    /// moving the cursor (ECH).
+    EraseCharacters(u16),
+    /// Erases the cells from the cursor to the end of the line with the current background color
+    /// (EL).
+    ClearUntilNewLine,
+}
+
+/// A change of the colors and modifiers of the printed text, written as a single SGR sequence.
+///
+/// The attributes are applied in order: the reset, the removed modifiers, the added modifiers and
+/// then the colors. As in terminals, removing [`Modifier::BOLD`] or [`Modifier::DIM`] removes
+/// both, and removing [`Modifier::SLOW_BLINK`] or [`Modifier::RAPID_BLINK`] removes both, so the
+/// modifiers that must remain are part of the added modifiers.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct StyleChange {
+    /// Whether all colors and modifiers are reset first
+    pub reset: bool,
+    /// The modifiers to remove
+    pub remove_modifier: Modifier,
+    /// The modifiers to add
+    pub add_modifier: Modifier,
+    /// The new foreground color
+    pub fg: Option<Color>,
+    /// The new background color
+    pub bg: Option<Color>,
+    /// The new underline color
+    #[cfg(feature = "underline-color")]
+    pub underline_color: Option<Color>,
+}
+
+/// Plans the commands that draw the changes of a buffer with as few bytes as possible.
+///
+/// [`Buffer::diff`] returns the cells that changed, and drawing each of them with an absolute
+/// cursor move and its full style writes many bytes, which is slow over a network connection.
+/// The planner instead estimates the number of bytes of the alternatives with a cost model and
+/// picks the cheapest:
+///
+/// - relative or absolute cursor moves, or printing the unchanged cells between two changes
+/// - erasing runs of blank cells with [`EraseCharacters`] or [`ClearUntilNewLine`]
+/// - repeating runs of the same character with [`Repeat`], when
+///   [enabled](Self::with_repeat_sequences)
+/// - resetting the style or only changing the attributes that differ
+///
+/// The cursor position is assumed to be unknown and the style to be the default at the start of
+/// the plan, and the style and hyperlink are reset at its end. Blank cells are erased with their
+/// background color only, as their foreground color is not displayed. The rows are only erased up
+/// to the end of the line when the [width of the screen](Self::with_screen_width) is known to end
+/// at the right edge of the buffer. Erasing cells with a background color relies on the terminal
+/// supporting [background color erase](Self::with_background_color_erase).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::{DrawCommand, DrawPlanner};
+/// use ratatui_core::buffer::Buffer;
+/// use ratatui_core::layout::Position;
+///
+/// let previous = Buffer::with_lines(["hello world"]);
+/// let next = Buffer::with_lines(["hello there"]);
+/// let updates = previous.diff(&next);
+/// let commands = DrawPlanner::new().plan(&next, &updates);
+/// assert_eq!(commands[0], DrawCommand::MoveTo(Position::new(6, 0)));
+///
+/// let output: String = commands.iter().map(ToString::to_string).collect();
+/// assert_eq!(output, "\x1b[1;7Hthere");
+/// ```
+///
+/// [`EraseCharacters`]: DrawCommand::EraseCharacters
+/// [`ClearUntilNewLine`]: DrawCommand::ClearUntilNewLine
+/// [`Repeat`]: DrawCommand::Repeat
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct DrawPlanner {
+    color_depth: ColorDepth,
+    repeat: bool,
+    screen_width: Option<u16>,
+    background_color_erase: bool,
+}
+
+/// The colors and modifiers of the printed text
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Pen {
+    fg: Color,
+    bg: Color,
+    #[cfg(feature = "underline-color")]
+    underline_color: Color,
+    modifier: Modifier,
+}
+
+/// The state of the terminal while planning
+struct Plan<'a> {
+    planner: DrawPlanner,
+    next: &'a Buffer,
+    commands: Vec<DrawCommand<'a>>,
+    /// The position of the cursor, or `None` if it is unknown
+    cursor: Option<Position>,
+    pen: Pen,
+    hyperlink: Option<&'a str>,
+}
+
+impl DrawPlanner {
+    /// Creates a new `DrawPlanner` for a terminal that displays all colors and supports background
+    /// color erase, and without repeat sequences.
+    pub const fn new() -> Self {
+        Self {
+            color_depth: ColorDepth::TrueColor,
+            repeat: false,
+            screen_width: None,
+            background_color_erase: true,
+        }
+    }
+
+    /// Sets the colors that the terminal can display.
+    ///
+    /// The colors of the cells are [quantized](Cell::quantize) to the color depth.
+    #[must_use]
+    pub const fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Sets whether runs of the same character are drawn with [`DrawCommand::Repeat`].
+    ///
+    /// The REP sequence is supported by most modern terminals, but not by all of them (e.g. the
+    /// Linux console), so it is disabled by default.
+    #[must_use]
+    pub const fn with_repeat_sequences(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Sets the width of the screen, which allows erasing rows with
+    /// [`DrawCommand::ClearUntilNewLine`] when the buffer ends at the right edge of the screen.
+    #[must_use]
+    pub const fn with_screen_width(mut self, width: u16) -> Self {
+        self.screen_width = Some(width);
+        self
+    }
+
+    /// Sets whether the terminal erases cells with the current background color.
+    ///
+    /// Most terminals support background color erase (BCE), so it is enabled by default. Terminals
+    /// without it (e.g. some `TERM=screen` or `tmux` configurations) erase cells with their
+    /// default background color instead, so when it is disabled, only the cells with the
+    /// [reset](Color::Reset) background color are drawn with [`DrawCommand::EraseCharacters`] or
+    /// [`DrawCommand::ClearUntilNewLine`].
+    #[must_use]
+    pub const fn with_background_color_erase(mut self, background_color_erase: bool) -> Self {
+        self.background_color_erase = background_color_erase;
+        self
+    }
+
+    /// Plans the commands that draw the updates of the next buffer, as returned by
+    /// [`Buffer::diff`].
+    ///
+    /// The unchanged cells of the next buffer are assumed to be displayed already, so that they
+    /// can be printed again or erased when it is cheaper.
+    pub fn plan<'a>(
+        self,
+        next: &'a Buffer,
+        updates: &[(u16, u16, &'a Cell)],
+    ) -> Vec<DrawCommand<'a>> {
+        let mut plan = Plan {
+            planner: self,
+            next,
+            commands: Vec::new(),
+            cursor: None,
+            pen: Pen::DEFAULT,
+            hyperlink: None,
+        };
+        let mut index = 0;
+        while index < updates.len() {
+            index += plan.update(&updates[index..]);
+        }
+        plan.finish();
+        plan.commands
+    }
+}
+
+impl Default for DrawPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Plan<'a> {
+    /// Plans the first updates, returning the number of updates that were drawn.
+    fn update(&mut self, updates: &[(u16, u16, &'a Cell)]) -> usize {
+        let (x, y, cell) = updates[0];
+        let position = Position { x, y };
+        if let Some(bg) = self.erasable_background(position) {
+            if !self.is_covered(position) {
+                if let Some(count) = self.clear_until_new_line(updates, bg) {
+                    return count;
+                }
+                if let Some(count) = self.erase_characters(updates, bg) {
+                    return count;
+                }
+            }
+        }
+
+        self.move_to(position);
+        self.set_style(Pen::new(cell, self.planner.color_depth));
+        self.set_hyperlink(cell.hyperlink());
+        let symbol = cell.symbol();
+        self.commands.push(DrawCommand::Print(symbol));
+        self.advance(position, symbol.width() as u16);
+        1 + self.repeat(updates)
+    }
+
+    /// Erases the rest of the row when the updates and the unchanged cells up to the end of the
+    /// row are blank, and this is cheaper than drawing the updates of the row.
+    fn clear_until_new_line(
+        &mut self,
+        updates: &[(u16, u16, &'a Cell)],
+        bg: Color,
+    ) -> Option<usize> {
+        let (x, y, _) = updates[0];
+        if self.planner.screen_width != Some(self.next.area.right()) {
+            return None;
+        }
+        let blank = (x..self.next.area.right())
+            .all(|column| self.blank_background(Position::new(column, y)) == Some(bg));
+        if !blank {
+            return None;
+        }
+        let count = updates.iter().take_while(|(_, row, _)| *row == y).count();
+        if DrawCommand::ClearUntilNewLine.cost() >= Self::print_cost(&updates[..count]) {
+            return None;
+        }
+        self.erase(Position { x, y }, bg);
+        self.commands.push(DrawCommand::ClearUntilNewLine);
+        Some(count)
+    }
+
+    /// Erases a run of blank updates when this is cheaper than printing them.
+    ///
+    /// The run includes the unchanged blank cells between the updates, which are erased again.
+    fn erase_characters(&mut self, updates: &[(u16, u16, &'a Cell)], bg: Color) -> Option<usize> {
+        let (x, y, _) = updates[0];
+        let right = self.next.area.right();
+        let blank_end = (x..right)
+            .find(|&column| self.blank_background(Position::new(column, y)) != Some(bg))
+            .unwrap_or(right);
+        let count = updates
+            .iter()
+            .take_while(|(column, row, _)| *row == y && *column < blank_end)
+            .count();
+        let end = Position::new(updates[count - 1].0 + 1, y);
+        let erase = DrawCommand::EraseCharacters(end.x - x);
+        let (erase_cost, print_cost) = match updates.get(count) {
+            Some(&(next_x, next_y, _)) => {
+                let target = Position::new(next_x, next_y);
+                let after_print = (end.x < right).then_some(end);
+                (
+                    self.move_cost(Some(Position { x, y }), target),
+                    self.move_cost(after_print, target),
+                )
+            }
+            None => (0, 0),
+        };
+        if erase_cost + erase.cost() >= print_cost + Self::print_cost(&updates[..count]) {
+            return None;
+        }
+        self.erase(Position { x, y }, bg);
+        self.commands.push(erase);
+        Some(count)
+    }
+
+    /// Returns the estimated cost of printing the updates of a row.
+    fn print_cost(updates: &[(u16, u16, &Cell)]) -> usize {
+        let mut cost = 0;
+        let mut cursor = updates.first().map_or(0, |(x, _, _)| *x);
+        for &(column, _, cell) in updates {
+            let gap = column.saturating_sub(cursor);
+            if gap > 0 {
+                cost += DrawCommand::MoveRight(gap).cost().min(usize::from(gap));
+            }
+            cost += cell.symbol().len();
+            cursor = column + 1;
+        }
+        cost
+    }
+
+    /// Moves to the position and sets the background color of erased cells.
+    fn erase(&mut self, position: Position, bg: Color) {
+        self.move_to(position);
+        self.set_hyperlink(None);
+        let mut pen = self.pen;
+        pen.bg = bg;
+        // some terminals erase with the foreground color when the colors are reversed
+        pen.modifier.remove(Modifier::REVERSED);
+        self.set_style(pen);
+    }
+
+    /// Repeats the last printed character for the following identical updates, when this is
+    /// cheaper than printing them, returning the number of repeated updates.
+    fn repeat(&mut self, updates: &[(u16, u16, &'a Cell)]) -> usize {
+        let (x, y, cell) = updates[0];
+        let symbol = cell.symbol();
+        if !self.planner.repeat || symbol.chars().count() != 1 || symbol.width() != 1 {
+            return 0;
+        }
+        let pen = Pen::new(cell, self.planner.color_depth);
+        let count = updates[1..]
+            .iter()
+            .zip(x + 1..)
+            .take_while(|&(&(column, row, other), expected)| {
+                column == expected
+                    && row == y
+                    && other.symbol() == symbol
+                    && other.hyperlink() == cell.hyperlink()
+                    && Pen::new(other, self.planner.color_depth) == pen
+            })
+            .count();
+        let command = DrawCommand::Repeat(count as u16);
+        if count == 0 || command.cost() >= count * symbol.len() {
+            return 0;
+        }
+        self.commands.push(command);
+        self.advance(Position::new(x + 1, y), count as u16);
+        count
+    }
+
+    /// Moves the cursor to the position with the cheapest command.
+    fn move_to(&mut self, target: Position) {
+        if self.cursor == Some(target) {
+            return;
+        }
+        let mut best = (DrawCommand::MoveTo(target).cost(), None);
+        for command in self.relative_moves(target).into_iter().flatten() {
+            let cost = command.cost();
+            if cost < best.0 {
+                best = (cost, Some(command));
+            }
+        }
+        if let Some(cost) = self.fill_cost(target) {
+            if cost < best.0 {
+                let cursor = self.cursor.unwrap_or(target);
+                for x in cursor.x..target.x {
+                    let symbol = self.next[(x, target.y)].symbol();
+                    self.commands.push(DrawCommand::Print(symbol));
+                }
+                self.cursor = Some(target);
+                return;
+            }
+        }
+        let command = best.1.unwrap_or(DrawCommand::MoveTo(target));
+        self.commands.push(command);
+        self.cursor = Some(target);
+    }
+
+    /// Returns the cost of the cheapest way to move from the cursor to the target.
+    fn move_cost(&self, cursor: Option<Position>, target: Position) -> usize {
+        if cursor == Some(target) {
+            return 0;
+        }
+        let plan = Plan {
+            cursor,
+            commands: Vec::new(),
+            ..*self
+        };
+        plan.relative_moves(target)
+            .into_iter()
+            .flatten()
+            .map(|command| command.cost())
+            .chain(plan.fill_cost(target))
+            .chain([DrawCommand::MoveTo(target).cost()])
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// Returns the relative moves from the cursor to the target.
+    const fn relative_moves(&self, target: Position) -> [Option<DrawCommand<'a>>; 2] {
+        let Some(cursor) = self.cursor else {
+            return [None, None];
+        };
+        if cursor.y == target.y {
+            let relative = if target.x > cursor.x {
+                DrawCommand::MoveRight(target.x - cursor.x)
+            } else {
+                DrawCommand::MoveLeft(cursor.x - target.x)
+            };
+            [Some(DrawCommand::MoveToColumn(target.x)), Some(relative)]
+        } else if target.y > cursor.y && target.x == 0 {
+            [Some(DrawCommand::MoveToNextLine(target.y - cursor.y)), None]
+        } else {
+            // the cursor moves to another row with an absolute move
+            [None, None]
+        }
+    }
+
+    /// Returns the cost of moving right to the target by printing the unchanged cells in between,
+    /// if they can be printed with the current style and hyperlink.
+    fn fill_cost(&self, target: Position) -> Option<usize> {
+        let cursor = self.cursor?;
+        if cursor.y != target.y || cursor.x >= target.x || self.is_covered(cursor) {
+            return None;
+        }
+        let mut cost = 0;
+        for x in cursor.x..target.x {
+            let cell = &self.next[(x, target.y)];
+            let symbol = cell.symbol();
+            if cell.skip
+                || symbol.width() != 1
+                || cell.hyperlink() != self.hyperlink
+                || Pen::new(cell, self.planner.color_depth) != self.pen
+            {
+                return None;
+            }
+            cost += symbol.len();
+        }
+        Some(cost)
+    }
+
+    /// Moves the cursor after a printed cell.
+    fn advance(&mut self, position: Position, width: u16) {
+        let x = position.x.saturating_add(width);
+        // the cursor stays in the last column of the screen, which may be the last column of the
+        // buffer, until the next character is printed
+        self.cursor = (x < self.next.area.right()).then_some(Position { x, ..position });
+    }
+
+    fn set_style(&mut self, pen: Pen) {
+        if pen != self.pen {
+            self.commands
+                .push(DrawCommand::SetStyle(StyleChange::between(self.pen, pen)));
+            self.pen = pen;
+        }
+    }
+
+    fn set_hyperlink(&mut self, hyperlink: Option<&'a str>) {
+        if hyperlink != self.hyperlink {
+            self.commands.push(DrawCommand::SetHyperlink(hyperlink));
+            self.hyperlink = hyperlink;
+        }
+    }
+
+    /// Returns the background color of the cell at the position if it is blank, i.e. if erasing
+    /// the cell with that background color displays it.
+    ///
+    /// The foreground color of a space is not displayed, nor are the modifiers other than
+    /// [`Pen::VISIBLE_ON_SPACE`], so they are not kept by erasing.
+    fn blank_background(&self, position: Position) -> Option<Color> {
+        let cell = &self.next[position];
+        let pen = Pen::new(cell, self.planner.color_depth);
+        (cell.symbol() == " "
+            && !cell.skip
+            && cell.hyperlink().is_none()
+            && !pen.modifier.intersects(Pen::VISIBLE_ON_SPACE))
+        .then_some(pen.bg)
+    }
+
+    /// Returns the background color of a blank cell, if the terminal erases cells with it.
+    fn erasable_background(&self, position: Position) -> Option<Color> {
+        self.blank_background(position)
+            .filter(|&bg| self.planner.background_color_erase || bg == Color::Reset)
+    }
+
+    /// Returns whether the cell at the position is hidden by a preceding wide character, which
+    /// would be erased by printing over it.
+    fn is_covered(&self, position: Position) -> bool {
+        let area = self.next.area;
+        (1..=position.x.saturating_sub(area.x).min(3)).any(|offset| {
+            let cell = &self.next[(position.x - offset, position.y)];
+            cell.symbol().width() > usize::from(offset)
+        })
+    }
+
+    /// Resets the hyperlink and the style at the end of the plan.
+    fn finish(&mut self) {
+        self.set_hyperlink(None);
+        if self.pen != Pen::DEFAULT {
+            self.commands.push(DrawCommand::SetStyle(StyleChange {
+                reset: true,
+                ..StyleChange::default()
+            }));
+        }
+    }
+}
+
+impl Pen {
+    /// The modifiers that change how a space is displayed
+    const VISIBLE_ON_SPACE: Modifier = Modifier::ANY_UNDERLINE
+        .union(Modifier::REVERSED)
+        .union(Modifier::CROSSED_OUT)
+        .union(Modifier::OVERLINED);
+
+    const DEFAULT: Self = Self {
+        fg: Color::Reset,
+        bg: Color::Reset,
+        #[cfg(feature = "underline-color")]
+        underline_color: Color::Reset,
+        modifier: Modifier::empty(),
+    };
+
+    fn new(cell: &Cell, color_depth: ColorDepth) -> Self {
+        let cell = cell.quantize(color_depth);
+        Self {
+            fg: cell.fg,
+            bg: cell.bg,
+            #[cfg(feature = "underline-color")]
+            underline_color: cell.underline_color,
+            modifier: cell.modifier,
+        }
+    }
+}
+
+impl StyleChange {
+    /// The modifiers that are removed together
+    const GROUPS: [Modifier; 3] = [
+        Modifier::BOLD.union(Modifier::DIM),
+        Modifier::SLOW_BLINK.union(Modifier::RAPID_BLINK),
+        Modifier::ANY_UNDERLINE,
+    ];
+
+    /// Returns the cheapest change from one pen to another, either by changing the attributes
+    /// that differ or by resetting all of them.
+    fn between(from: Pen, to: Pen) -> Self {
+        let changed = |from: Color, to: Color| (from != to).then_some(to);
+        let remove_modifier = from.modifier - to.modifier;
+        let mut add_modifier = to.modifier - from.modifier;
+        for group in Self::GROUPS {
+            if remove_modifier.intersects(group) {
+                add_modifier |= to.modifier & group;
+            }
+        }
+        // only one underline style is displayed, which depends on all the underline modifiers
+        if add_modifier.intersects(Modifier::ANY_UNDERLINE) {
+            add_modifier |= to.modifier & Modifier::ANY_UNDERLINE;
+        }
+        let update = Self {
+            reset: false,
+            remove_modifier,
+            add_modifier,
+            fg: changed(from.fg, to.fg),
+            bg: changed(from.bg, to.bg),
+            #[cfg(feature = "underline-color")]
+            underline_color: changed(from.underline_color, to.underline_color),
+        };
+        let reset = Self {
+            reset: true,
+            remove_modifier: Modifier::empty(),
+            add_modifier: to.modifier,
+            fg: changed(Color::Reset, to.fg),
+            bg: changed(Color::Reset, to.bg),
+            #[cfg(feature = "underline-color")]
+            underline_color: changed(Color::Reset, to.underline_color),
+        };
+        let cost = |change: Self| DrawCommand::SetStyle(change).cost();
+        if cost(reset) < cost(update) {
+            reset
+        } else {
+            update
+        }
+    }
+}
+
+impl DrawCommand<'_> {
+    /// Returns the number of bytes that the command writes.
+    pub fn cost(&self) -> usize {
+        /// Counts the written bytes
+        struct Counter(usize);
+
+        impl fmt::Write for Counter {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+
+        let mut counter = Counter(0);
+        let _ = fmt::write(&mut counter, format_args!("{self}"));
+        counter.0
+    }
+
+    /// Returns whether the command moves the cursor to another cell, rather than printing or
+    /// erasing cells.
+    pub const fn is_cursor_move(&self) -> bool {
+        matches!(
+            self,
+            Self::MoveTo(_)
+                | Self::MoveToColumn(_)
+                | Self::MoveRight(_)
+                | Self::MoveLeft(_)
+                | Self::MoveToNextLine(_)
+        )
+    }
+}
+
+impl fmt::Display for DrawCommand<'_> {
+    /// Writes the command as an escape sequence.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MoveTo(Position { x, y }) => write!(f, "\x1b[{};{}H", y + 1, x + 1),
+            Self::MoveToColumn(x) => write!(f, "\x1b[{}G", x + 1),
+            Self::MoveRight(count) => write!(f, "\x1b[{count}C"),
+            Self::MoveLeft(count) => write!(f, "\x1b[{count}D"),
+            Self::MoveToNextLine(count) => write!(f, "\x1b[{count}E"),
+            Self::SetStyle(change) => write!(f, "\x1b[{change}m"),
+            Self::SetHyperlink(url) => write!(f, "\x1b]8;;{}\x1b\\", url.unwrap_or_default()),
+            Self::Print(symbol) => f.write_str(symbol),
+            Self::Repeat(count) => write!(f, "\x1b[{count}b"),
+            Self::EraseCharacters(count) => write!(f, "\x1b[{count}X"),
+            Self::ClearUntilNewLine => f.write_str("\x1b[K"),
+        }
+    }
+}
+
+/// Returns the SGR parameters that add the modifiers.
+///
+/// Only one underline style can be set at a time, so only the underline style that the modifiers
+/// display is added (see [`Modifier::underline_style`]). The extended underline styles use the
+/// `4:x` form, which terminals that do not support them display as a plain underline or ignore.
+pub(super) fn modifier_codes(modifier: Modifier) -> impl Iterator<Item = &'static str> {
+    let underline = match modifier.underline_style() {
+        Modifier::DOUBLE_UNDERLINED => "4:2",
+        Modifier::CURLY_UNDERLINED => "4:3",
+        Modifier::DOTTED_UNDERLINED => "4:4",
+        Modifier::DASHED_UNDERLINED => "4:5",
+        _ => "4",
+    };
+    [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::ANY_UNDERLINE, underline),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+        (Modifier::OVERLINED, "53"),
+    ]
+    .into_iter()
+    .filter(move |(flag, _)| modifier.intersects(*flag))
+    .map(|(_, code)| code)
+}
+
+impl fmt::Display for StyleChange {
+    /// Writes the parameters of the SGR sequence, separated by semicolons.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        let mut param = |f: &mut fmt::Formatter<'_>, param: fmt::Arguments<'_>| {
+            let result = write!(f, "{separator}{param}");
+            separator = ";";
+            result
+        };
+        if self.reset {
+            param(f, format_args!("0"))?;
+        }
+        let remove = self.remove_modifier;
+        for (modifier, code) in [
+            (Modifier::BOLD | Modifier::DIM, 22),
+            (Modifier::ITALIC, 23),
+            (Modifier::ANY_UNDERLINE, 24),
+            (Modifier::SLOW_BLINK | Modifier::RAPID_BLINK, 25),
+            (Modifier::REVERSED, 27),
+            (Modifier::HIDDEN, 28),
+            (Modifier::CROSSED_OUT, 29),
+            (Modifier::OVERLINED, 55),
+        ] {
+            if remove.intersects(modifier) {
+                param(f, format_args!("{code}"))?;
+            }
+        }
+        for code in modifier_codes(self.add_modifier) {
+            param(f, format_args!("{code}"))?;
+        }
+        #[cfg(feature = "underline-color")]
+        let underline_color = self.underline_color;
+        #[cfg(not(feature = "underline-color"))]
+        let underline_color = None;
+        for (color, code) in [(self.fg, 38), (self.bg, 48), (underline_color, 58)] {
+            if let Some(color) = color {
+                param(f, format_args!("{}", ColorCodes { color, code }))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The SGR parameters that set a color, where `code` is 38 for the foreground, 48 for the
+/// background and 58 for the underline.
+///
+/// The named colors of the foreground and background use the short `30–37`/`90–97` and
+/// `40–47`/`100–107` forms, which are shorter and do not depend on 256 color support.
+pub(super) struct ColorCodes {
+    pub(super) color: Color,
+    pub(super) code: u8,
+}
+
+impl fmt::Display for ColorCodes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = self.code;
+        match self.color {
+            Color::Reset => write!(f, "{}", code + 1),
+            Color::Indexed(index) => write!(f, "{code};5;{index}"),
+            Color::Rgb(r, g, b) => write!(f, "{code};2;{r};{g};{b}"),
+            named => {
+                let index: u8 = (0..)
+                    .zip(Color::ANSI)
+                    .find_map(|(index, ansi)| (ansi == named).then_some(index))
+                    .unwrap_or_default();
+                match code {
+                    38 | 48 if index < 8 => write!(f, "{}", code - 8 + index),
+                    38 | 48 => write!(f, "{}", code + 52 + index - 8),
+                    _ => write!(f, "{code};5;{index}"),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::backend::VirtualTerminal;
+    use crate::layout::Rect;
+    use crate::style::{Style, Stylize};
+
+    fn plan<'a>(planner: DrawPlanner, previous: &Buffer, next: &'a Buffer) -> Vec<DrawCommand<'a>> {
+        planner.plan(next, &previous.diff(next))
+    }
+
+    fn output(commands: &[DrawCommand]) -> String {
+        commands.iter().map(ToString::to_string).collect()
+    }
+
+    /// Returns the buffer as displayed, without the attributes of blank cells that are not visible.
+    fn visible(buffer: &Buffer) -> Buffer {
+        let mut visible = buffer.clone();
+        for cell in &mut visible.content {
+            if cell.symbol() == " " && !cell.modifier.intersects(Pen::VISIBLE_ON_SPACE) {
+                cell.fg = Color::Reset;
+                cell.modifier = Modifier::empty();
+                #[cfg(feature = "underline-color")]
+                {
+                    cell.underline_color = Color::Reset;
+                }
+            }
+        }
+        visible
+    }
+
+    /// Draws the previous buffer, then the plan of the changes, and checks that the terminal
+    /// displays the next buffer.
+    fn assert_plan_draws(planner: DrawPlanner, previous: &Buffer, next: &Buffer) {
+        let mut terminal = VirtualTerminal::new(next.area.right(), next.area.bottom());
+        let empty = Buffer::empty(previous.area);
+        terminal.process(output(&plan(planner, &empty, previous)).as_bytes());
+        terminal.process(output(&plan(planner, previous, next)).as_bytes());
+        let mut displayed = terminal.buffer().clone();
+        displayed.resize(next.area);
+        for position in next.area.positions() {
+            displayed[position] = terminal.buffer()[position].clone();
+        }
+        assert_eq!(visible(&displayed), visible(next));
+    }
+
+    #[test]
+    fn cost() {
+        for command in [
+            DrawCommand::MoveTo(Position::new(9, 99)),
+            DrawCommand::MoveRight(1),
+            DrawCommand::Print("界"),
+            DrawCommand::SetHyperlink(Some("https://ratatui.rs")),
+            DrawCommand::SetStyle(StyleChange::between(
+                Pen::DEFAULT,
+                Pen::new(
+                    &Cell::new("a").set_style(Style::new().red().bold()).clone(),
+                    ColorDepth::TrueColor,
+                ),
+            )),
+        ] {
+            assert_eq!(command.cost(), command.to_string().len());
+        }
+    }
+
+    #[rstest]
+    #[case::absolute(Position::new(1, 0), Position::new(5, 2), "\x1b[3;6H")]
+    #[case::right(Position::new(11, 0), Position::new(15, 0), "\x1b[4C")]
+    #[case::left(Position::new(15, 0), Position::new(14, 0), "\x1b[1D")]
+    #[case::column(Position::new(15, 0), Position::new(0, 0), "\x1b[1G")]
+    #[case::next_line(Position::new(15, 0), Position::new(0, 1), "\x1b[1E")]
+    fn move_to(#[case] cursor: Position, #[case] target: Position, #[case] expected: &str) {
+        let next = Buffer::empty(Rect::new(0, 0, 20, 3));
+        let mut plan = Plan {
+            planner: DrawPlanner::new(),
+            next: &next,
+            commands: Vec::new(),
+            cursor: Some(cursor),
+            pen: Pen::DEFAULT,
+            hyperlink: None,
+        };
+        plan.move_to(target);
+        assert_eq!(output(&plan.commands), expected);
+    }
+
+    #[test]
+    fn fills_small_gaps() {
+        let previous = Buffer::with_lines(["abcdef"]);
+        let next = Buffer::with_lines(["xbcxef"]);
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;1Hxbcx");
+    }
+
+    #[test]
+    fn does_not_fill_gaps_with_other_styles() {
+        let mut previous = Buffer::with_lines(["abcdef"]);
+        previous[(1, 0)].set_style(Style::new().bold());
+        let mut next = Buffer::with_lines(["xbcxef"]);
+        next[(1, 0)].set_style(Style::new().bold());
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;1Hx\x1b[4Gx");
+    }
+
+    #[test]
+    fn clears_until_new_line() {
+        let previous = Buffer::with_lines(["hello world", "hello world"]);
+        let next = Buffer::with_lines(["hello      ", "hello w    "]);
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;7H\x1b[5X\x1b[2;8H    ");
+        let commands = plan(DrawPlanner::new().with_screen_width(11), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;7H\x1b[K\x1b[2;8H\x1b[K");
+        assert_plan_draws(DrawPlanner::new().with_screen_width(11), &previous, &next);
+    }
+
+    #[test]
+    fn erases_characters() {
+        let previous = Buffer::with_lines(["a0123456789b"]);
+        let next = Buffer::with_lines(["a          c"]);
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;2H \x1b[9X\x1b[9Cc");
+        assert_plan_draws(DrawPlanner::new(), &previous, &next);
+
+        let previous = Buffer::with_lines(["xab cd ef gh ijx"]);
+        let next = Buffer::with_lines(["x              x"]);
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;2H\x1b[14X");
+        assert_plan_draws(DrawPlanner::new(), &previous, &next);
+
+        // the foreground color of blank cells is not displayed
+        let mut next = next.clone();
+        next.set_style(next.area, Style::new().red());
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(
+            output(&commands),
+            "\x1b[1;1H\x1b[31mx\x1b[14X\x1b[16Gx\x1b[0m"
+        );
+        assert_plan_draws(DrawPlanner::new(), &previous, &next);
+    }
+
+    #[test]
+    fn erases_only_reset_background_without_background_color_erase() {
+        let previous = Buffer::with_lines(["a0123456789b", "a0123456789b"]);
+        let mut next = Buffer::with_lines(["a          c", "a          c"]);
+        next.set_style(Rect::new(0, 1, 12, 1), Style::new().on_blue());
+        let planner = DrawPlanner::new()
+            .with_screen_width(12)
+            .with_background_color_erase(false);
+        let commands = plan(planner, &previous, &next);
+        assert_eq!(
+            output(&commands),
+            "\x1b[1;2H \x1b[9X\x1b[9Cc\x1b[2;1H\x1b[44ma          c\x1b[0m"
+        );
+        assert_plan_draws(planner, &previous, &next);
+    }
+
+    #[test]
+    fn erases_with_background_color() {
+        let previous = Buffer::with_lines(["hello world"]);
+        let mut next = Buffer::empty(previous.area);
+        next.set_style(next.area, Style::new().on_blue());
+        let commands = plan(DrawPlanner::new().with_screen_width(11), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;1H\x1b[44m\x1b[K\x1b[0m");
+        assert_plan_draws(DrawPlanner::new().with_screen_width(11), &previous, &next);
+    }
+
+    #[test]
+    fn repeats_characters() {
+        let previous = Buffer::empty(Rect::new(0, 0, 12, 1));
+        let next = Buffer::with_lines(["a==========b"]);
+        let commands = plan(
+            DrawPlanner::new().with_repeat_sequences(true),
+            &previous,
+            &next,
+        );
+        assert_eq!(output(&commands), "\x1b[1;1Ha=\x1b[9bb");
+        assert_plan_draws(
+            DrawPlanner::new().with_repeat_sequences(true),
+            &previous,
+            &next,
+        );
+
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;1Ha==========b");
+    }
+
+    #[rstest]
+    #[case::add(Style::new().bold(), Style::new().bold().italic(), "\x1b[3m")]
+    #[case::remove_intensity(Style::new().bold().dim().red(), Style::new().dim().red(), "\x1b[22;2m")]
+    #[case::remove_blink(Style::new().slow_blink().rapid_blink().red(), Style::new().slow_blink().red(), "\x1b[25;5m")]
+    #[case::colors(Style::new().red().on_blue(), Style::new().red().on_green(), "\x1b[42m")]
+    #[case::bright_colors(Style::new().red(), Style::new().light_red().on_white(), "\x1b[91;107m")]
+    #[case::indexed_colors(Style::new(), Style::new().fg(Color::Indexed(3)), "\x1b[38;5;3m")]
+    #[case::reset(Style::new().bold().italic().underlined().red(), Style::new().dim(), "\x1b[0;2m")]
+    #[case::curly_underline(Style::new().underlined(), Style::new().underlined().curly_underlined(), "\x1b[4:3m")]
+    #[case::remove_curly_underline(Style::new().underlined().curly_underlined().red(), Style::new().underlined().red(), "\x1b[24;4m")]
+    #[case::keep_curly_underline(Style::new().curly_underlined(), Style::new().curly_underlined().underlined(), "\x1b[4:3m")]
+    #[case::overline(Style::new().bold(), Style::new().bold().overlined(), "\x1b[53m")]
+    #[case::remove_overline(Style::new().bold().overlined(), Style::new().bold(), "\x1b[55m")]
+    fn set_style(#[case] from: Style, #[case] to: Style, #[case] expected: &str) {
+        let mut cell = Cell::new("a");
+        let from = Pen::new(cell.set_style(from), ColorDepth::TrueColor);
+        let mut cell = Cell::new("a");
+        let to = Pen::new(cell.set_style(to), ColorDepth::TrueColor);
+        let change = DrawCommand::SetStyle(StyleChange::between(from, to));
+        assert_eq!(change.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::move_to(DrawCommand::MoveTo(Position::new(1, 2)), true)]
+    #[case::move_left(DrawCommand::MoveLeft(2), true)]
+    #[case::next_line(DrawCommand::MoveToNextLine(1), true)]
+    #[case::print(DrawCommand::Print("a"), false)]
+    #[case::erase(DrawCommand::EraseCharacters(3), false)]
+    fn is_cursor_move(#[case] command: DrawCommand, #[case] expected: bool) {
+        assert_eq!(command.is_cursor_move(), expected);
+    }
+
+    #[test]
+    fn resets_style_and_hyperlink_at_end() {
+        let previous = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut next = Buffer::with_lines(["abc".red()]);
+        next[(2, 0)].set_hyperlink(Some("https://ratatui.rs"));
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(
+            output(&commands),
+            "\x1b[1;1H\x1b[31mab\x1b]8;;https://ratatui.rs\x1b\\c\x1b]8;;\x1b\\\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn wide_characters() {
+        let previous = Buffer::with_lines(["ab界cd"]);
+        let next = Buffer::with_lines(["xb界cx"]);
+        assert_plan_draws(DrawPlanner::new(), &previous, &next);
+        let previous = Buffer::with_lines(["界      "]);
+        let next = Buffer::with_lines(["界 a    "]);
+        let commands = plan(DrawPlanner::new(), &previous, &next);
+        assert_eq!(output(&commands), "\x1b[1;4Ha");
+    }
+
+    #[test]
+    fn quantizes_colors() {
+        let previous = Buffer::empty(Rect::new(0, 0, 1, 1));
+        let next = Buffer::with_lines(["a".fg(Color::Rgb(255, 0, 0))]);
+        let commands = plan(
+            DrawPlanner::new().with_color_depth(ColorDepth::Ansi256),
+            &previous,
+            &next,
+        );
+        assert_eq!(output(&commands), "\x1b[1;1H\x1b[38;5;196ma\x1b[0m");
+    }
+
+    #[test]
+    fn draws_random_changes() {
+        // a deterministic pseudo-random sequence, so that failures can be reproduced
+        let mut state = 0x2545_f491_u32;
+        let mut random = move |max: u32| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state % max
+        };
+        let styles = [
+            Style::new(),
+            Style::new().bold(),
+            Style::new().red().on_blue(),
+            Style::new().dim().italic(),
+            Style::new().on_blue(),
+            Style::new().fg(Color::Rgb(1, 2, 3)).slow_blink(),
+        ];
+        let symbols = [" ", " ", " ", "a", "b", "界", "="];
+        let area = Rect::new(2, 1, 16, 6);
+        let mut previous = Buffer::empty(area);
+        for planner in [
+            DrawPlanner::new(),
+            DrawPlanner::new().with_repeat_sequences(true),
+            DrawPlanner::new().with_screen_width(area.right()),
+        ] {
+            for _ in 0..50 {
+                let mut next = previous.clone();
+                for _ in 0..random(40) {
+                    let x = area.x + random(u32::from(area.width)) as u16;
+                    let y = area.y + random(u32::from(area.height)) as u16;
+                    let symbol = symbols[random(symbols.len() as u32) as usize];
+                    let style = styles[random(styles.len() as u32) as usize];
+                    let count = random(6) as usize + 1;
+                    let text = vec![symbol; count].concat();
+                    // as widgets do, replace the wide character that would hide the text
+                    if x > area.x && next[(x - 1, y)].symbol().width() > 1 {
+                        next[(x - 1, y)].reset();
+                    }
+                    next.set_stringn(x, y, text, usize::from(area.right() - x), style);
+                }
+                assert_plan_draws(planner, &previous, &next);
+                previous = next;
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! This module provides the [`GraphicsBackend`] extension trait for backends that can display
+//! images using one of the terminal graphics protocols.
+
+use strum::{Display, EnumString};
+
+use crate::backend::Backend;
+use crate::layout::Position;
+
+/// A terminal graphics protocol used to display pixel data inline with text.
+///
+/// Support for these protocols varies between terminal emulators:
+///
+/// - [`Kitty`](GraphicsProtocol::Kitty): kitty, Ghostty, Konsole and `WezTerm`
+/// - [`Sixel`](GraphicsProtocol::Sixel): xterm (with `-ti vt340`), foot, mlterm and Windows
+///   Terminal
+/// - [`Iterm2`](GraphicsProtocol::Iterm2): `iTerm2`, `WezTerm` and mintty
+///
+/// See [`GraphicsProtocol::from_env`] for a simple way to guess the protocol of the current
+/// terminal.
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GraphicsProtocol {
+    /// The [Kitty graphics protocol](https://sw.kovidgoyal.net/kitty/graphics-protocol/).
+    Kitty,
+    /// The [Sixel](https://vt100.net/docs/vt3xx-gp/chapter14.html) graphics format.
+    Sixel,
+    /// The [iTerm2 inline images protocol](https://iterm2.com/documentation-images.html).
+    Iterm2,
+}
+
+impl GraphicsProtocol {
+    /// Guesses the graphics protocol supported by the current terminal from the `TERM` and
+    /// `TERM_PROGRAM` environment variables.
+    ///
+    /// This is a heuristic: it returns `None` for terminals that are not known to support any
+    /// graphics protocol, and can be wrong when running inside a multiplexer such as tmux.
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Option<Self> {
+        let term = std::env::var("TERM").unwrap_or_default();
+        let term_program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+        Self::detect(&term, &term_program)
+    }
+
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    fn detect(term: &str, term_program: &str) -> Option<Self> {
+        match term_program {
+            "iTerm.app" | "mintty" => return Some(Self::Iterm2),
+            "WezTerm" | "ghostty" => return Some(Self::Kitty),
+            "mlterm" => return Some(Self::Sixel),
+            _ => {}
+        }
+        if term.contains("kitty") || term.contains("ghostty") {
+            Some(Self::Kitty)
+        } else if term.starts_with("foot") || term.contains("sixel") {
+            Some(Self::Sixel)
+        } else {
+            None
+        }
+    }
+}
+
+/// An extension of the [`Backend`] trait for backends that can display images.
+///
+/// Images are not part of the [`Buffer`]. Instead, a widget reserves the cells covered by an image
+/// by marking them as skipped (see [`Cell::set_skip`]) so that the diff written by the
+/// [`Terminal`] leaves them alone, and the encoded image is written separately with
+/// [`GraphicsBackend::draw_graphics`] after the frame has been drawn.
+///
+/// [`Buffer`]: crate::buffer::Buffer
+/// [`Cell::set_skip`]: crate::buffer::Cell::set_skip
+/// [`Terminal`]: crate::terminal::Terminal
+pub trait GraphicsBackend: Backend {
+    /// Returns the graphics protocol that the terminal supports, or `None` if images are not
+    /// supported and should be rendered with text cells instead.
+    fn graphics_protocol(&self) -> Option<GraphicsProtocol>;
+
+    /// Writes an encoded image payload with its top left corner at the given position.
+    ///
+    /// The payload must be a complete escape sequence encoded for the protocol returned by
+    /// [`GraphicsBackend::graphics_protocol`]. The cursor position after this call is unspecified.
+    fn draw_graphics(&mut self, position: Position, payload: &[u8]) -> Result<(), Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::kitty("xterm-kitty", "", Some(GraphicsProtocol::Kitty))]
+    #[case::ghostty("xterm-ghostty", "ghostty", Some(GraphicsProtocol::Kitty))]
+    #[case::wezterm("xterm-256color", "WezTerm", Some(GraphicsProtocol::Kitty))]
+    #[case::iterm2("xterm-256color", "iTerm.app", Some(GraphicsProtocol::Iterm2))]
+    #[case::foot("foot", "", Some(GraphicsProtocol::Sixel))]
+    #[case::xterm("xterm-256color", "", None)]
+    #[case::empty("", "", None)]
+    fn detect(
+        #[case] term: &str,
+        #[case] term_program: &str,
+        #[case] expected: Option<GraphicsProtocol>,
+    ) {
+        assert_eq!(GraphicsProtocol::detect(term, term_program), expected);
+    }
+}
@@ -0,0 +1,518 @@
+//! This module provides the [`QueryBackend`] extension trait for backends that can query the
+//! terminal, and the [`TerminalCapabilities`] parsed from the responses.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::backend::{Backend, GraphicsProtocol};
+use crate::style::{Color, ColorDepth};
+
+/// The capabilities and colors of a terminal, as reported by the terminal itself.
+///
+/// Unlike [`ColorDepth::from_env`] and [`GraphicsProtocol::from_env`], which guess from
+/// environment variables, these capabilities are queried with escape sequences that the terminal
+/// answers (see [`QueryBackend::query_capabilities`]). Terminals ignore the requests that they do
+/// not support, so each field is `None` (or empty) when the terminal did not answer it:
+///
+/// - `foreground` and `background`: the default colors (OSC 10 and OSC 11)
+/// - `palette`: the colors of the 16 ANSI colors (OSC 4)
+/// - `version`: the name and version of the terminal (XTVERSION)
+/// - `device_attributes`: the Primary Device Attributes (DA1), which all terminals answer
+/// - `synchronized_output`: whether synchronized output is supported (DECRQM for mode 2026)
+/// - `true_color`: whether 24-bit colors are supported, found by setting an RGB color and reading
+///   it back (DECRQSS)
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::TerminalCapabilities;
+/// use ratatui_core::style::Color;
+///
+/// let response = b"\x1b]11;rgb:1e1e/1e1e/2e2e\x1b\\\x1b[?62;4c";
+/// let capabilities = TerminalCapabilities::parse(response);
+/// assert_eq!(capabilities.background, Some(Color::Rgb(30, 30, 46)));
+/// assert_eq!(capabilities.is_dark(), Some(true));
+/// assert!(TerminalCapabilities::is_complete(response));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TerminalCapabilities {
+    /// The default foreground color, as a [`Color::Rgb`].
+    pub foreground: Option<Color>,
+    /// The default background color, as a [`Color::Rgb`].
+    pub background: Option<Color>,
+    /// The colors of the 16 ANSI colors, in the order of their index, as [`Color::Rgb`]s.
+    pub palette: [Option<Color>; 16],
+    /// The name and version of the terminal, e.g. `"WezTerm 20240203"` or `"xterm(390)"`.
+    pub version: Option<String>,
+    /// The parameters of the Primary Device Attributes, or `None` if the terminal did not answer.
+    ///
+    /// The first parameter is the conformance level, and the others are the supported features,
+    /// e.g. `4` for sixel graphics.
+    pub device_attributes: Option<Vec<u16>>,
+    /// Whether the terminal supports synchronized output.
+    pub synchronized_output: Option<bool>,
+    /// Whether the terminal supports 24-bit RGB colors.
+    pub true_color: Option<bool>,
+}
+
+impl TerminalCapabilities {
+    /// The escape sequences that request the capabilities.
+    ///
+    /// The request ends with a Primary Device Attributes request, which all terminals answer, so
+    /// that the end of the responses can be detected with [`TerminalCapabilities::is_complete`].
+    pub const REQUEST: &'static str = concat!(
+        "\x1b[>0q",
+        "\x1b]10;?\x1b\\",
+        "\x1b]11;?\x1b\\",
+        "\x1b]4;0;?\x1b\\\x1b]4;1;?\x1b\\\x1b]4;2;?\x1b\\\x1b]4;3;?\x1b\\",
+        "\x1b]4;4;?\x1b\\\x1b]4;5;?\x1b\\\x1b]4;6;?\x1b\\\x1b]4;7;?\x1b\\",
+        "\x1b]4;8;?\x1b\\\x1b]4;9;?\x1b\\\x1b]4;10;?\x1b\\\x1b]4;11;?\x1b\\",
+        "\x1b]4;12;?\x1b\\\x1b]4;13;?\x1b\\\x1b]4;14;?\x1b\\\x1b]4;15;?\x1b\\",
+        "\x1b[?2026$p",
+        // sets an RGB background color and reads the style back, then resets the style
+        "\x1b[48;2;1;2;3m\x1bP$qm\x1b\\\x1b[m",
+        "\x1b[c",
+    );
+
+    /// Returns whether the response contains the answer to the Primary Device Attributes request
+    /// that ends [`TerminalCapabilities::REQUEST`], after which no other answers are expected.
+    pub fn is_complete(response: &[u8]) -> bool {
+        Sequences(response).any(
+            |sequence| matches!(sequence, Sequence::Csi(params, b'c') if params.starts_with(b"?")),
+        )
+    }
+
+    /// Parses the responses of a terminal to [`TerminalCapabilities::REQUEST`].
+    ///
+    /// Bytes that are not part of a response, such as keys pressed while the terminal was
+    /// queried, are ignored.
+    pub fn parse(response: &[u8]) -> Self {
+        let mut capabilities = Self::default();
+        for sequence in Sequences(response) {
+            match sequence {
+                Sequence::Osc(body) => capabilities.parse_osc(body),
+                Sequence::Dcs(body) => capabilities.parse_dcs(body),
+                Sequence::Csi(params, action) => capabilities.parse_csi(params, action),
+            }
+        }
+        capabilities
+    }
+
+    fn parse_osc(&mut self, body: &[u8]) {
+        let body = core::str::from_utf8(body).unwrap_or_default();
+        let mut fields = body.splitn(3, ';');
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some("10"), Some(color), None) => self.foreground = parse_color(color),
+            (Some("11"), Some(color), None) => self.background = parse_color(color),
+            (Some("4"), Some(index), Some(color)) => {
+                if let Some(entry) = index
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| self.palette.get_mut(index))
+                {
+                    *entry = parse_color(color);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn parse_dcs(&mut self, body: &[u8]) {
+        if let Some(version) = body.strip_prefix(b">|") {
+            self.version = Some(String::from_utf8_lossy(version).into_owned());
+        } else if let Some(state) = body.strip_prefix(b"1$r") {
+            // the state is read back with colons or semicolons, and with or without the color
+            // space, e.g. `0;48:2::1:2:3m` or `48;2;1;2;3m`
+            let state = core::str::from_utf8(state).unwrap_or_default();
+            let params: Vec<&str> = state
+                .trim_end_matches('m')
+                .split([';', ':'])
+                .filter(|param| !param.is_empty())
+                .collect();
+            self.true_color = Some(params.windows(5).any(|window| window == TRUE_COLOR_PARAMS));
+        } else if body.starts_with(b"0$r") {
+            self.true_color = Some(false);
+        } else {
+            // responses to requests that are not sent by `REQUEST` are ignored
+        }
+    }
+
+    fn parse_csi(&mut self, params: &[u8], action: u8) {
+        let params = core::str::from_utf8(params).unwrap_or_default();
+        match (params.strip_prefix('?'), action) {
+            (Some(params), b'c') => {
+                let attributes = params.split(';').filter_map(|param| param.parse().ok());
+                self.device_attributes = Some(attributes.collect());
+            }
+            (Some(params), b'y') => {
+                if let Some(state) = params
+                    .strip_suffix('$')
+                    .and_then(|params| params.strip_prefix("2026;"))
+                {
+                    // 1 and 2 are set and reset, 3 is permanently set, 0 and 4 are not recognized
+                    // and permanently reset
+                    self.synchronized_output = Some(matches!(state, "1" | "2" | "3"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns whether the background of the terminal is dark, or `None` if it is not known.
+    ///
+    /// This can be used to pick a light or dark theme.
+    pub fn is_dark(&self) -> Option<bool> {
+        Some(self.background?.luma()? < 128 * 1000)
+    }
+
+    /// Returns [`ColorDepth::TrueColor`] if the terminal reported that it supports 24-bit colors,
+    /// or `None` if the color depth is not known.
+    ///
+    /// Terminals that only support 256 or 16 colors cannot be told apart by querying, so use
+    /// [`ColorDepth::from_env`] when this returns `None`.
+    pub fn color_depth(&self) -> Option<ColorDepth> {
+        (self.true_color == Some(true)).then_some(ColorDepth::TrueColor)
+    }
+
+    /// Returns the graphics protocol that the terminal supports, as far as can be told from its
+    /// version and device attributes.
+    pub fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        let name = self.version.as_deref().unwrap_or_default();
+        if ["kitty", "WezTerm", "ghostty"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        {
+            Some(GraphicsProtocol::Kitty)
+        } else if name.starts_with("iTerm2") {
+            Some(GraphicsProtocol::Iterm2)
+        } else if self
+            .device_attributes
+            .as_ref()
+            .is_some_and(|attributes| attributes.iter().skip(1).any(|&attribute| attribute == 4))
+        {
+            Some(GraphicsProtocol::Sixel)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the RGB components of a color as the terminal displays it.
+    ///
+    /// The named colors and the first 16 indexed colors are converted with the palette of the
+    /// terminal when it is known, and other colors with [`Color::to_rgb`]. [`Color::Reset`]
+    /// returns `None`, as it is either the [`foreground`] or the [`background`] color.
+    ///
+    /// [`foreground`]: Self::foreground
+    /// [`background`]: Self::background
+    pub fn rgb(&self, color: Color) -> Option<(u8, u8, u8)> {
+        let index = match color {
+            Color::Indexed(index) => usize::from(index),
+            _ => Color::ANSI
+                .iter()
+                .position(|&named| named == color)
+                .unwrap_or(usize::MAX),
+        };
+        self.palette
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(color)
+            .to_rgb()
+    }
+}
+
+/// The parameters of the background color set by the true color request.
+const TRUE_COLOR_PARAMS: [&str; 5] = ["48", "2", "1", "2", "3"];
+
+/// Parses a color reported in the X11 `rgb:r/g/b` format, with 1 to 4 hex digits per component,
+/// or in the `#rrggbb` format.
+fn parse_color(color: &str) -> Option<Color> {
+    let component = |digits: &str| {
+        if !(1..=4).contains(&digits.len()) {
+            return None;
+        }
+        let value = u32::from_str_radix(digits, 16).ok()?;
+        let max = (1_u32 << (4 * digits.len())) - 1;
+        u8::try_from(value * 255 / max).ok()
+    };
+    if let Some(components) = color
+        .strip_prefix("rgb:")
+        .or_else(|| color.strip_prefix("rgba:"))
+    {
+        let mut components = components.split('/').map(component);
+        let (r, g, b) = (
+            components.next()??,
+            components.next()??,
+            components.next()??,
+        );
+        Some(Color::Rgb(r, g, b))
+    } else {
+        let hex = color
+            .strip_prefix('#')
+            .filter(|hex| hex.len() == 6 && hex.bytes().all(|byte| byte.is_ascii_hexdigit()))?;
+        let [_, r, g, b] = u32::from_str_radix(hex, 16).ok()?.to_be_bytes();
+        Some(Color::Rgb(r, g, b))
+    }
+}
+
+/// An escape sequence of a response.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(super) enum Sequence<'a> {
+    /// A control sequence, with its parameters and final byte
+    Csi(&'a [u8], u8),
+    /// An operating system command
+    Osc(&'a [u8]),
+    /// A device control string
+    Dcs(&'a [u8]),
+}
+
+/// An iterator over the complete escape sequences of a response.
+pub(super) struct Sequences<'a>(pub(super) &'a [u8]);
+
+impl<'a> Iterator for Sequences<'a> {
+    type Item = Sequence<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.0.iter().position(|&byte| byte == 0x1b)?;
+            let rest = &self.0[start + 1..];
+            let (sequence, length) = match rest.first()? {
+                b'[' => {
+                    let end = rest[1..]
+                        .iter()
+                        .position(|byte| (0x40..=0x7e).contains(byte))?;
+                    (Some(Sequence::Csi(&rest[1..=end], rest[end + 1])), end + 2)
+                }
+                kind @ (b']' | b'P') => {
+                    // strings end with BEL or ST (ESC \)
+                    let end = rest[1..]
+                        .iter()
+                        .position(|&byte| byte == 0x07 || byte == 0x1b)?;
+                    let body = &rest[1..=end];
+                    let length = match rest.get(end + 1) {
+                        Some(0x1b) if rest.get(end + 2) == Some(&b'\\') => end + 3,
+                        Some(0x1b) if rest.len() == end + 2 => return None,
+                        _ => end + 2,
+                    };
+                    let sequence = if *kind == b']' {
+                        Sequence::Osc(body)
+                    } else {
+                        Sequence::Dcs(body)
+                    };
+                    (Some(sequence), length)
+                }
+                _ => (None, 1),
+            };
+            self.0 = &rest[length..];
+            if let Some(sequence) = sequence {
+                return Some(sequence);
+            }
+        }
+    }
+}
+
+/// An extension of the [`Backend`] trait for backends that can query the terminal.
+///
+/// Queries write escape sequences that the terminal answers by sending responses to the input of
+/// the application, the same way it sends key presses. The terminal must be in raw mode, or the
+/// backend must disable line buffering while reading, for the responses to be read.
+///
+/// Queries should be made before the application starts reading events, e.g. right after
+/// initializing the terminal, as the responses could otherwise be read as key presses.
+///
+/// The crossterm and termion backends implement this trait. Termwiz does not give access to the
+/// raw responses of the terminal, so the termwiz backend does not.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use std::time::Duration;
+///
+/// use ratatui::backend::QueryBackend;
+///
+/// let mut terminal = ratatui::init();
+/// let capabilities = terminal
+///     .backend_mut()
+///     .query_capabilities(Duration::from_millis(100))?;
+/// let theme = if capabilities.is_dark().unwrap_or(true) { "dark" } else { "light" };
+/// ```
+pub trait QueryBackend: Backend {
+    /// Writes a request to the terminal and returns the bytes of the responses.
+    ///
+    /// Bytes are read until `is_complete` returns `true` for the bytes read so far, or until the
+    /// timeout elapses, in which case the bytes read so far are returned. Terminals ignore the
+    /// requests that they do not support, so a request is usually followed by a Primary Device
+    /// Attributes request, which all terminals answer, to avoid waiting for the whole timeout.
+    fn query(
+        &mut self,
+        request: &[u8],
+        timeout: Duration,
+        is_complete: fn(&[u8]) -> bool,
+    ) -> Result<Vec<u8>, Self::Error>;
+
+    /// Queries the capabilities and colors of the terminal.
+    ///
+    /// The terminal is given at most `timeout` to answer, which should leave some room for the
+    /// latency of remote connections (e.g. 100 milliseconds). The capabilities that were not
+    /// answered in time are unknown.
+    fn query_capabilities(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<TerminalCapabilities, Self::Error> {
+        let response = self.query(
+            TerminalCapabilities::REQUEST.as_bytes(),
+            timeout,
+            TerminalCapabilities::is_complete,
+        )?;
+        Ok(TerminalCapabilities::parse(&response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn request_ends_with_device_attributes() {
+        let request = TerminalCapabilities::REQUEST.as_bytes();
+        assert!(request.ends_with(b"\x1b[c"));
+        assert!(!TerminalCapabilities::is_complete(request));
+    }
+
+    #[test]
+    fn parse() {
+        let response = concat!(
+            "\x1bP>|WezTerm 20240203\x1b\\",
+            "\x1b]10;rgb:ffff/ffff/ffff\x1b\\",
+            "\x1b]11;rgb:0000/0000/0000\x07",
+            "\x1b]4;1;rgb:cdcd/0000/0000\x1b\\",
+            "\x1b]4;12;rgb:5c/5c/ff\x1b\\",
+            "\x1b[?2026;2$y",
+            "\x1bP1$r0;48:2::1:2:3m\x1b\\",
+            "\x1b[?65;4;22c",
+        );
+        let mut palette = [None; 16];
+        palette[1] = Some(Color::Rgb(205, 0, 0));
+        palette[12] = Some(Color::Rgb(92, 92, 255));
+        assert_eq!(
+            TerminalCapabilities::parse(response.as_bytes()),
+            TerminalCapabilities {
+                foreground: Some(Color::Rgb(255, 255, 255)),
+                background: Some(Color::Rgb(0, 0, 0)),
+                palette,
+                version: Some("WezTerm 20240203".to_string()),
+                device_attributes: Some(vec![65, 4, 22]),
+                synchronized_output: Some(true),
+                true_color: Some(true),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_no_answers() {
+        let capabilities = TerminalCapabilities::parse(b"\x1b[?1;2cx\x1b[A");
+        assert_eq!(
+            capabilities,
+            TerminalCapabilities {
+                device_attributes: Some(vec![1, 2]),
+                ..Default::default()
+            }
+        );
+        assert_eq!(capabilities.is_dark(), None);
+        assert_eq!(capabilities.color_depth(), None);
+        assert_eq!(capabilities.graphics_protocol(), None);
+    }
+
+    #[rstest]
+    #[case::complete(b"\x1b]11;rgb:0/0/0\x07\x1b[?62c", true)]
+    #[case::partial(b"\x1b]11;rgb:0/0/0\x07\x1b[?62", false)]
+    #[case::empty(b"", false)]
+    #[case::other_csi(b"\x1b[?2026;0$y", false)]
+    fn is_complete(#[case] response: &[u8], #[case] expected: bool) {
+        assert_eq!(TerminalCapabilities::is_complete(response), expected);
+    }
+
+    #[rstest]
+    #[case::four_digits("rgb:ffff/8080/0000", Some(Color::Rgb(255, 128, 0)))]
+    #[case::two_digits("rgb:ff/80/00", Some(Color::Rgb(255, 128, 0)))]
+    #[case::one_digit("rgb:f/8/0", Some(Color::Rgb(255, 136, 0)))]
+    #[case::rgba("rgba:ffff/8080/0000/ffff", Some(Color::Rgb(255, 128, 0)))]
+    #[case::hex("#ff8000", Some(Color::Rgb(255, 128, 0)))]
+    #[case::missing_component("rgb:ff/80", None)]
+    #[case::invalid("rgb:gg/00/00", None)]
+    #[case::too_long("rgb:fffff/0/0", None)]
+    fn parse_color(#[case] color: &str, #[case] expected: Option<Color>) {
+        assert_eq!(super::parse_color(color), expected);
+    }
+
+    #[rstest]
+    #[case::set(b"\x1b[?2026;1$y", Some(true))]
+    #[case::reset(b"\x1b[?2026;2$y", Some(true))]
+    #[case::not_recognized(b"\x1b[?2026;0$y", Some(false))]
+    #[case::other_mode(b"\x1b[?1049;1$y", None)]
+    fn synchronized_output(#[case] response: &[u8], #[case] expected: Option<bool>) {
+        let capabilities = TerminalCapabilities::parse(response);
+        assert_eq!(capabilities.synchronized_output, expected);
+    }
+
+    #[rstest]
+    #[case::colons(b"\x1bP1$r0;48:2::1:2:3m\x1b\\", Some(true))]
+    #[case::semicolons(b"\x1bP1$r48;2;1;2;3m\x1b\\", Some(true))]
+    #[case::quantized(b"\x1bP1$r48;5;16m\x1b\\", Some(false))]
+    #[case::invalid(b"\x1bP0$r\x1b\\", Some(false))]
+    #[case::no_answer(b"", None)]
+    fn true_color(#[case] response: &[u8], #[case] expected: Option<bool>) {
+        let capabilities = TerminalCapabilities::parse(response);
+        assert_eq!(capabilities.true_color, expected);
+        let color_depth = (expected == Some(true)).then_some(ColorDepth::TrueColor);
+        assert_eq!(capabilities.color_depth(), color_depth);
+    }
+
+    #[rstest]
+    #[case::dark(Color::Rgb(30, 30, 46), Some(true))]
+    #[case::light(Color::Rgb(239, 241, 245), Some(false))]
+    fn is_dark(#[case] background: Color, #[case] expected: Option<bool>) {
+        let capabilities = TerminalCapabilities {
+            background: Some(background),
+            ..Default::default()
+        };
+        assert_eq!(capabilities.is_dark(), expected);
+    }
+
+    #[rstest]
+    #[case::kitty(Some("kitty(0.35.2)"), None, Some(GraphicsProtocol::Kitty))]
+    #[case::iterm2(Some("iTerm2 3.5.0"), None, Some(GraphicsProtocol::Iterm2))]
+    #[case::sixel(Some("foot(1.16.2)"), Some(vec![62, 4, 22]), Some(GraphicsProtocol::Sixel))]
+    #[case::vt420(None, Some(vec![64, 1, 2, 6]), None)]
+    fn graphics_protocol(
+        #[case] version: Option<&str>,
+        #[case] device_attributes: Option<Vec<u16>>,
+        #[case] expected: Option<GraphicsProtocol>,
+    ) {
+        let capabilities = TerminalCapabilities {
+            version: version.map(ToString::to_string),
+            device_attributes,
+            ..Default::default()
+        };
+        assert_eq!(capabilities.graphics_protocol(), expected);
+    }
+
+    #[test]
+    fn rgb() {
+        let mut capabilities = TerminalCapabilities::default();
+        capabilities.palette[1] = Some(Color::Rgb(255, 85, 85));
+        assert_eq!(capabilities.rgb(Color::Red), Some((255, 85, 85)));
+        assert_eq!(capabilities.rgb(Color::Indexed(1)), Some((255, 85, 85)));
+        assert_eq!(capabilities.rgb(Color::Green), Color::Green.to_rgb());
+        assert_eq!(capabilities.rgb(Color::Indexed(196)), Some((255, 0, 0)));
+        assert_eq!(capabilities.rgb(Color::Rgb(1, 2, 3)), Some((1, 2, 3)));
+        assert_eq!(capabilities.rgb(Color::Reset), None);
+    }
+}
@@ -1,16 +1,24 @@
 //! This module provides the `TestBackend` implementation for the [`Backend`] trait.
 //! It is used in the integration tests to verify the correctness of the library.
 
+use alloc::collections::VecDeque;
 use alloc::string::String;
 use alloc::vec;
+use alloc::vec::Vec;
 use core::fmt::{self, Write};
 use core::iter;
+use core::time::Duration;
 
 use unicode_width::UnicodeWidthStr;
 
-use crate::backend::{Backend, ClearType, WindowSize};
+use crate::backend::{
+    Backend, ClearType, CommandBackend, CursorStyle, GraphicsBackend, GraphicsProtocol,
+    QueryBackend, TerminalCommand, WindowSize,
+};
 use crate::buffer::{Buffer, Cell};
+use crate::event::{Event, EventReader};
 use crate::layout::{Position, Rect, Size};
+use crate::style::ColorDepth;
 
 /// A [`Backend`] implementation used for integration testing that renders to an memory buffer.
 ///
@@ -35,7 +43,17 @@ pub struct TestBackend {
     buffer: Buffer,
     scrollback: Buffer,
     cursor: bool,
+    cursor_style: CursorStyle,
     pos: (u16, u16),
+    graphics_protocol: Option<GraphicsProtocol>,
+    graphics: Vec<(Position, Vec<u8>)>,
+    events: VecDeque<Event>,
+    queries: Vec<Vec<u8>>,
+    query_responses: VecDeque<Vec<u8>>,
+    commands: Vec<TerminalCommand>,
+    color_depth: ColorDepth,
+    synchronized_update: bool,
+    synchronized_frames: Vec<Buffer>,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -74,7 +92,17 @@ impl TestBackend {
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
             scrollback: Buffer::empty(Rect::new(0, 0, width, 0)),
             cursor: false,
+            cursor_style: CursorStyle::Default,
             pos: (0, 0),
+            graphics_protocol: None,
+            graphics: Vec::new(),
+            events: VecDeque::new(),
+            queries: Vec::new(),
+            query_responses: VecDeque::new(),
+            commands: Vec::new(),
+            color_depth: ColorDepth::TrueColor,
+            synchronized_update: false,
+            synchronized_frames: Vec::new(),
         }
     }
 
@@ -96,7 +124,17 @@ impl TestBackend {
             buffer,
             scrollback,
             cursor: false,
+            cursor_style: CursorStyle::Default,
             pos: (0, 0),
+            graphics_protocol: None,
+            graphics: Vec::new(),
+            events: VecDeque::new(),
+            queries: Vec::new(),
+            query_responses: VecDeque::new(),
+            commands: Vec::new(),
+            color_depth: ColorDepth::TrueColor,
+            synchronized_update: false,
+            synchronized_frames: Vec::new(),
         }
     }
 
@@ -122,6 +160,78 @@ impl TestBackend {
         &self.scrollback
     }
 
+    /// Sets the graphics protocol that the `TestBackend` reports through
+    /// [`GraphicsBackend::graphics_protocol`].
+    ///
+    /// By default, no protocol is reported.
+    #[must_use]
+    pub const fn with_graphics_protocol(mut self, protocol: Option<GraphicsProtocol>) -> Self {
+        self.graphics_protocol = protocol;
+        self
+    }
+
+    /// Sets the colors that the `TestBackend` can display.
+    ///
+    /// Drawn cells are stored in the buffer as a terminal with that color depth would display them
+    /// (see [`Cell::quantize`]). By default, all colors are stored as is.
+    #[must_use]
+    pub const fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Returns the image payloads written with [`GraphicsBackend::draw_graphics`], in the order
+    /// they were drawn, along with the position they were drawn at.
+    pub fn graphics(&self) -> &[(Position, Vec<u8>)] {
+        &self.graphics
+    }
+
+    /// Returns the cursor style last set with [`Backend::set_cursor_style`].
+    pub const fn cursor_style(&self) -> CursorStyle {
+        self.cursor_style
+    }
+
+    /// Returns whether a synchronized update was begun and not yet ended.
+    pub const fn is_synchronized_update(&self) -> bool {
+        self.synchronized_update
+    }
+
+    /// Returns the content of the buffer at the end of each synchronized update, in the order the
+    /// updates were ended.
+    ///
+    /// This is what a terminal that supports synchronized output would have displayed, which makes
+    /// it possible to check that every frame was drawn in a single update.
+    pub fn synchronized_frames(&self) -> &[Buffer] {
+        &self.synchronized_frames
+    }
+
+    /// Queues an event to be returned by [`EventReader::poll_event`].
+    ///
+    /// Events are returned in the order they were pushed. Once the queue is empty, the backend
+    /// returns `None` without waiting, as no other events can arrive.
+    pub fn push_event<E: Into<Event>>(&mut self, event: E) {
+        self.events.push_back(event.into());
+    }
+
+    /// Queues the response returned by the next call to [`QueryBackend::query`].
+    ///
+    /// Responses are returned in the order they were pushed. Once the queue is empty, queries
+    /// return no bytes, as a terminal that does not answer would.
+    pub fn push_query_response<R: Into<Vec<u8>>>(&mut self, response: R) {
+        self.query_responses.push_back(response.into());
+    }
+
+    /// Returns the requests written with [`QueryBackend::query`], in the order they were written.
+    pub fn queries(&self) -> &[Vec<u8>] {
+        &self.queries
+    }
+
+    /// Returns the commands sent with [`CommandBackend::execute_command`], in the order they were
+    /// sent.
+    pub fn commands(&self) -> &[TerminalCommand] {
+        &self.commands
+    }
+
     /// Resizes the `TestBackend` to the specified width and height.
     pub fn resize(&mut self, width: u16, height: u16) {
         self.buffer.resize(Rect::new(0, 0, width, height));
@@ -171,6 +281,7 @@ impl TestBackend {
                 ..Rect::ZERO
             },
             content: vec![],
+            ..Buffer::default()
         };
         self.assert_scrollback(&expected);
     }
@@ -222,6 +333,27 @@ impl TestBackend {
         let actual = self.get_cursor_position().unwrap();
         assert_eq!(actual, position.into());
     }
+
+    /// Asserts that the hyperlink target of the cell at the given position is equal to the
+    /// expected one.
+    ///
+    /// This is a shortcut for `assert_eq!(self.buffer()[position].hyperlink(), expected)`.
+    ///
+    /// # Panics
+    ///
+    /// When they are not equal, a panic occurs with a detailed error message showing the
+    /// differences between the expected and actual hyperlink. Also panics if the position is
+    /// outside the buffer.
+    #[track_caller]
+    pub fn assert_hyperlink<P: Into<Position>>(&self, position: P, expected: Option<&str>) {
+        let position = position.into();
+        let actual = self.buffer[position].hyperlink();
+        assert_eq!(
+            actual, expected,
+            "hyperlink mismatch at ({}, {})",
+            position.x, position.y
+        );
+    }
 }
 
 impl fmt::Display for TestBackend {
@@ -242,7 +374,7 @@ impl Backend for TestBackend {
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
         for (x, y, c) in content {
-            self.buffer[(x, y)] = c.clone();
+            self.buffer[(x, y)] = c.quantize(self.color_depth).into_owned();
         }
         Ok(())
     }
@@ -257,6 +389,11 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    fn set_cursor_style(&mut self, style: CursorStyle) -> Result<()> {
+        self.cursor_style = style;
+        Ok(())
+    }
+
     fn get_cursor_position(&mut self) -> Result<Position> {
         Ok(self.pos.into())
     }
@@ -368,6 +505,39 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    fn supports_synchronized_output(&self) -> bool {
+        true
+    }
+
+    /// Begins a synchronized update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a synchronized update was already begun, as terminals do not nest them.
+    fn begin_synchronized_update(&mut self) -> Result<()> {
+        assert!(
+            !self.synchronized_update,
+            "synchronized update was already begun"
+        );
+        self.synchronized_update = true;
+        Ok(())
+    }
+
+    /// Ends a synchronized update and records the content of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no synchronized update was begun.
+    fn end_synchronized_update(&mut self) -> Result<()> {
+        assert!(
+            self.synchronized_update,
+            "synchronized update was not begun"
+        );
+        self.synchronized_update = false;
+        self.synchronized_frames.push(self.buffer.clone());
+        Ok(())
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: core::ops::Range<u16>, scroll_by: u16) -> Result<()> {
         let width: usize = self.buffer.area.width.into();
@@ -436,10 +606,48 @@ impl Backend for TestBackend {
     }
 }
 
+impl GraphicsBackend for TestBackend {
+    fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        self.graphics_protocol
+    }
+
+    fn draw_graphics(&mut self, position: Position, payload: &[u8]) -> Result<()> {
+        self.graphics.push((position, payload.to_vec()));
+        Ok(())
+    }
+}
+
+impl QueryBackend for TestBackend {
+    fn query(
+        &mut self,
+        request: &[u8],
+        _timeout: Duration,
+        _is_complete: fn(&[u8]) -> bool,
+    ) -> Result<Vec<u8>> {
+        self.queries.push(request.to_vec());
+        Ok(self.query_responses.pop_front().unwrap_or_default())
+    }
+}
+
+impl CommandBackend for TestBackend {
+    fn execute_command(&mut self, command: &TerminalCommand) -> Result<()> {
+        self.commands.push(command.clone());
+        Ok(())
+    }
+}
+
+impl EventReader for TestBackend {
+    type Error = core::convert::Infallible;
+
+    fn poll_event(&mut self, _timeout: Option<Duration>) -> Result<Option<Event>> {
+        Ok(self.events.pop_front())
+    }
+}
+
 /// Append the provided cells to the bottom of a scrollback buffer. The number of cells must be a
 /// multiple of the buffer's width. If the scrollback buffer ends up larger than 65535 lines tall,
 /// then lines will be removed from the top to get it down to size.
-fn append_to_scrollback(scrollback: &mut Buffer, cells: impl IntoIterator<Item = Cell>) {
+pub(super) fn append_to_scrollback(scrollback: &mut Buffer, cells: impl IntoIterator<Item = Cell>) {
     scrollback.content.extend(cells);
     let width = scrollback.area.width as usize;
     let new_height = (scrollback.content.len() / width).min(u16::MAX as usize);
@@ -467,7 +675,17 @@ mod tests {
                 buffer: Buffer::with_lines(["          "; 2]),
                 scrollback: Buffer::empty(Rect::new(0, 0, 10, 0)),
                 cursor: false,
+                cursor_style: CursorStyle::Default,
                 pos: (0, 0),
+                graphics_protocol: None,
+                graphics: Vec::new(),
+                events: VecDeque::new(),
+                queries: Vec::new(),
+                query_responses: VecDeque::new(),
+                commands: Vec::new(),
+                color_depth: ColorDepth::TrueColor,
+                synchronized_update: false,
+                synchronized_frames: Vec::new(),
             }
         );
     }
@@ -503,6 +721,21 @@ mod tests {
         backend.assert_buffer_lines(["     "; 5]);
     }
 
+    #[test]
+    fn poll_event() {
+        use crate::event::{KeyCode, KeyEvent};
+
+        let mut backend = TestBackend::new(10, 2);
+        backend.push_event(KeyEvent::from(KeyCode::Char('a')));
+        backend.push_event(Event::FocusLost);
+        assert_eq!(
+            backend.poll_event(None),
+            Ok(Some(Event::Key(KeyEvent::from(KeyCode::Char('a')))))
+        );
+        assert_eq!(backend.read_event(), Ok(Event::FocusLost));
+        assert_eq!(backend.poll_event(Some(Duration::ZERO)), Ok(None));
+    }
+
     #[test]
     fn assert_buffer() {
         let backend = TestBackend::new(10, 2);
@@ -538,6 +771,110 @@ mod tests {
         backend.assert_buffer_lines(["a         "; 2]);
     }
 
+    #[test]
+    fn draw_with_color_depth() {
+        let mut backend = TestBackend::new(1, 1).with_color_depth(ColorDepth::Ansi256);
+        let mut cell = Cell::new("a");
+        cell.set_fg(crate::style::Color::Rgb(255, 0, 0));
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        assert_eq!(
+            backend.buffer()[(0, 0)].fg,
+            crate::style::Color::Indexed(196)
+        );
+    }
+
+    #[test]
+    fn synchronized_update() {
+        let mut backend = TestBackend::new(1, 1);
+        assert!(backend.supports_synchronized_output());
+        backend.begin_synchronized_update().unwrap();
+        assert!(backend.is_synchronized_update());
+        backend.draw([(0, 0, &Cell::new("a"))].into_iter()).unwrap();
+        backend.end_synchronized_update().unwrap();
+        assert!(!backend.is_synchronized_update());
+        assert_eq!(backend.synchronized_frames(), [Buffer::with_lines(["a"])]);
+    }
+
+    #[test]
+    #[should_panic = "synchronized update was not begun"]
+    fn end_synchronized_update_without_begin() {
+        let mut backend = TestBackend::new(1, 1);
+        backend.end_synchronized_update().unwrap();
+    }
+
+    #[test]
+    fn draw_hyperlink() {
+        let mut backend = TestBackend::new(10, 2);
+        let mut cell = Cell::new("a");
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        backend.draw([(1, 0, &cell)].into_iter()).unwrap();
+        backend.assert_hyperlink((0, 0), None);
+        backend.assert_hyperlink((1, 0), Some("https://ratatui.rs"));
+    }
+
+    #[test]
+    #[should_panic = "hyperlink mismatch at (0, 0)"]
+    fn assert_hyperlink_panics() {
+        let backend = TestBackend::new(10, 2);
+        backend.assert_hyperlink((0, 0), Some("https://ratatui.rs"));
+    }
+
+    #[test]
+    fn draw_graphics() {
+        let mut backend =
+            TestBackend::new(10, 2).with_graphics_protocol(Some(GraphicsProtocol::Kitty));
+        assert_eq!(backend.graphics_protocol(), Some(GraphicsProtocol::Kitty));
+        backend
+            .draw_graphics(Position::new(1, 1), b"\x1b_Ga=T;\x1b\\")
+            .unwrap();
+        assert_eq!(
+            backend.graphics(),
+            [(Position::new(1, 1), b"\x1b_Ga=T;\x1b\\".to_vec())]
+        );
+    }
+
+    #[test]
+    fn query_capabilities() {
+        let mut backend = TestBackend::new(10, 2);
+        backend.push_query_response(b"\x1b]11;rgb:ffff/ffff/ffff\x1b\\\x1b[?62c".as_slice());
+        let capabilities = backend.query_capabilities(Duration::ZERO).unwrap();
+        assert_eq!(capabilities.is_dark(), Some(false));
+        assert_eq!(capabilities.device_attributes, Some(vec![62]));
+        assert_eq!(
+            backend.queries(),
+            [crate::backend::TerminalCapabilities::REQUEST.as_bytes()]
+        );
+
+        // no more responses, as if the terminal did not answer
+        let capabilities = backend.query_capabilities(Duration::ZERO).unwrap();
+        assert_eq!(capabilities.device_attributes, None);
+    }
+
+    #[test]
+    fn execute_commands() {
+        let mut backend = TestBackend::new(10, 2);
+        backend.set_title("title").unwrap();
+        backend.set_clipboard("copied").unwrap();
+        backend
+            .set_progress(crate::backend::TaskbarProgress::Indeterminate)
+            .unwrap();
+        assert_eq!(
+            backend.commands(),
+            [
+                TerminalCommand::SetTitle("title".into()),
+                TerminalCommand::SetClipboard("copied".into()),
+                TerminalCommand::SetProgress(crate::backend::TaskbarProgress::Indeterminate),
+            ]
+        );
+
+        backend.push_query_response(b"\x1b]52;c;cGFzdGVk\x1b\\\x1b[?62c".as_slice());
+        assert_eq!(
+            backend.get_clipboard(Duration::ZERO).unwrap().as_deref(),
+            Some("pasted")
+        );
+        assert_eq!(backend.get_clipboard(Duration::ZERO).unwrap(), None);
+    }
+
     #[test]
     fn hide_cursor() {
         let mut backend = TestBackend::new(10, 2);
@@ -552,6 +889,14 @@ mod tests {
         assert!(backend.cursor);
     }
 
+    #[test]
+    fn set_cursor_style() {
+        let mut backend = TestBackend::new(10, 2);
+        assert_eq!(backend.cursor_style(), CursorStyle::Default);
+        backend.set_cursor_style(CursorStyle::SteadyBar).unwrap();
+        assert_eq!(backend.cursor_style(), CursorStyle::SteadyBar);
+    }
+
     #[test]
     fn get_cursor_position() {
         let mut backend = TestBackend::new(10, 2);
@@ -951,6 +1296,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[0..10 * 5].to_vec(),
+                ..Buffer::default()
             },
             Buffer::with_lines([
                 "         6",
@@ -966,6 +1312,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[10 * 65530..10 * 65535].to_vec(),
+                ..Buffer::default()
             },
             Buffer::with_lines([
                 "     65536",
@@ -0,0 +1,1033 @@
+//! This module provides the [`VirtualTerminal`], a headless terminal emulator used to check the
+//! escape sequences written by backends.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter;
+use core::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::backend::CursorStyle;
+use crate::backend::test::append_to_scrollback;
+use crate::buffer::{Buffer, Cell};
+use crate::layout::{Position, Rect};
+use crate::style::{Color, Modifier};
+
+/// A headless terminal emulator that reconstructs the screen from the bytes written by a backend.
+///
+/// The [`TestBackend`] stores the drawn cells as is, so it cannot catch bugs in the escape
+/// sequences that a real backend writes. A `VirtualTerminal` interprets these bytes the way a
+/// terminal would, which makes it possible to check that what a terminal displays is what was
+/// rendered. Feed it the bytes with [`VirtualTerminal::process`], or use it as the writer of a
+/// backend, as it implements [`std::io::Write`] when the `std` feature is enabled.
+///
+/// The sequences written by the backends of Ratatui are supported: cursor movement,
+/// visibility and style, colors and modifiers, erasing, scrolling regions, [OSC 8] hyperlinks and the
+/// alternate screen. Other sequences, such as images and terminal queries, are ignored.
+///
+/// A few details make the result comparable to a rendered [`Buffer`]:
+///
+/// - Colors 0 to 15 of the 256-color palette are reported as the named colors (e.g. `Color::Red`
+///   rather than `Color::Indexed(1)`), as backends write named colors as indexed colors.
+/// - The cells hidden by a wide character are empty, as in a [`Buffer`].
+/// - Lines that scroll off the top of the screen are moved to the scrollback, in the same way as
+///   with the [`TestBackend`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::VirtualTerminal;
+/// use ratatui_core::buffer::Buffer;
+/// use ratatui_core::layout::{Position, Rect};
+/// use ratatui_core::style::{Color, Style};
+///
+/// let mut terminal = VirtualTerminal::new(5, 1);
+/// terminal.process(b"\x1b[1;2H\x1b[31mab\x1b[0m");
+///
+/// let mut expected = Buffer::with_lines([" ab  "]);
+/// expected.set_style(Rect::new(1, 0, 2, 1), Style::new().fg(Color::Red));
+/// assert_eq!(terminal.buffer(), &expected);
+/// assert_eq!(terminal.cursor_position(), Position::new(3, 0));
+/// ```
+///
+/// [`TestBackend`]: crate::backend::TestBackend
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VirtualTerminal {
+    buffer: Buffer,
+    scrollback: Buffer,
+    /// The main screen, kept aside while the alternate screen is shown
+    main_screen: Option<Buffer>,
+    cursor: Position,
+    saved_cursor: Position,
+    cursor_visible: bool,
+    cursor_style: CursorStyle,
+    /// Whether the cursor is past the last column, so that the next character is printed on the
+    /// next line
+    pending_wrap: bool,
+    /// The style and hyperlink of the printed characters
+    pen: Cell,
+    /// The position of the last printed character, to which zero-width characters are appended
+    last_printed: Option<Position>,
+    /// The rows that scroll when the cursor moves past the bottom
+    scroll_region: Range<u16>,
+    state: State,
+    /// The bytes of the escape sequence being parsed
+    sequence: Vec<u8>,
+    /// The text that has not been printed yet
+    text: Vec<u8>,
+}
+
+/// The state of the escape sequence parser.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum State {
+    Ground,
+    Escape,
+    /// An escape sequence with intermediate bytes, such as the character set selection
+    EscapeIntermediate,
+    Csi,
+    Osc,
+    OscEscape,
+    /// A device control, application program command or privacy message, which are ignored
+    String,
+    StringEscape,
+}
+
+impl VirtualTerminal {
+    /// Creates a new `VirtualTerminal` with an empty screen of the given size.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            buffer: Buffer::empty(Rect::new(0, 0, width, height)),
+            scrollback: Buffer::empty(Rect::new(0, 0, width, 0)),
+            main_screen: None,
+            cursor: Position::ORIGIN,
+            saved_cursor: Position::ORIGIN,
+            cursor_visible: true,
+            cursor_style: CursorStyle::Default,
+            pending_wrap: false,
+            pen: Cell::EMPTY,
+            last_printed: None,
+            scroll_region: 0..height,
+            state: State::Ground,
+            sequence: Vec::new(),
+            text: Vec::new(),
+        }
+    }
+
+    /// Returns the content of the screen.
+    pub const fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Returns the lines that scrolled off the top of the screen, oldest first.
+    pub const fn scrollback(&self) -> &Buffer {
+        &self.scrollback
+    }
+
+    /// Returns the position of the cursor.
+    pub const fn cursor_position(&self) -> Position {
+        self.cursor
+    }
+
+    /// Returns whether the cursor is visible.
+    pub const fn is_cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Returns the style of the cursor.
+    pub const fn cursor_style(&self) -> CursorStyle {
+        self.cursor_style
+    }
+
+    /// Resizes the screen, keeping its content.
+    ///
+    /// The scrolling region is reset to the whole screen.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.buffer = resized(&self.buffer, width, height);
+        self.scrollback = resized(&self.scrollback, width, self.scrollback.area.height);
+        self.main_screen = self
+            .main_screen
+            .as_ref()
+            .map(|screen| resized(screen, width, height));
+        self.cursor.x = self.cursor.x.min(width.saturating_sub(1));
+        self.cursor.y = self.cursor.y.min(height.saturating_sub(1));
+        self.pending_wrap = false;
+        self.last_printed = None;
+        self.scroll_region = 0..height;
+    }
+
+    /// Interprets the bytes written to the terminal.
+    ///
+    /// The bytes do not need to end at a sequence or character boundary: an incomplete escape
+    /// sequence or UTF-8 character is completed by the next call.
+    pub fn process(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            match self.state {
+                State::Ground => self.ground(byte),
+                State::Escape => self.escape(byte),
+                State::EscapeIntermediate => {
+                    if !(0x20..=0x2f).contains(&byte) {
+                        self.state = State::Ground;
+                    }
+                }
+                State::Csi => self.csi(byte),
+                State::Osc | State::OscEscape => self.osc(byte),
+                State::String | State::StringEscape => self.string(byte),
+            }
+        }
+        self.print_text(true);
+    }
+
+    fn ground(&mut self, byte: u8) {
+        match byte {
+            0x1b => {
+                self.print_text(false);
+                self.state = State::Escape;
+            }
+            b'\n' | 0x0b | 0x0c => {
+                self.print_text(false);
+                self.line_feed();
+            }
+            b'\r' => {
+                self.print_text(false);
+                self.carriage_return();
+            }
+            0x08 => {
+                self.print_text(false);
+                self.pending_wrap = false;
+                self.cursor.x = self.cursor.x.saturating_sub(1);
+            }
+            b'\t' => {
+                self.print_text(false);
+                self.pending_wrap = false;
+                let next_tab_stop = (self.cursor.x / 8 + 1) * 8;
+                self.cursor.x = next_tab_stop.min(self.last_column());
+            }
+            0x00..=0x1f | 0x7f => self.print_text(false),
+            _ => self.text.push(byte),
+        }
+    }
+
+    fn escape(&mut self, byte: u8) {
+        self.state = State::Ground;
+        self.sequence.clear();
+        match byte {
+            b'[' => self.state = State::Csi,
+            b']' => self.state = State::Osc,
+            b'P' | b'_' | b'X' | b'^' => self.state = State::String,
+            0x20..=0x2f => self.state = State::EscapeIntermediate,
+            b'7' => self.saved_cursor = self.cursor,
+            b'8' => self.move_to(self.saved_cursor.x, self.saved_cursor.y),
+            b'D' => self.line_feed(),
+            b'E' => {
+                self.carriage_return();
+                self.line_feed();
+            }
+            b'M' => self.reverse_index(),
+            b'c' => {
+                let scrollback = core::mem::take(&mut self.scrollback);
+                *self = Self::new(self.buffer.area.width, self.buffer.area.height);
+                self.scrollback = scrollback;
+            }
+            _ => {
+                // other sequences, including the string terminator, have no effect
+            }
+        }
+    }
+
+    fn csi(&mut self, byte: u8) {
+        match byte {
+            0x40..=0x7e => {
+                self.state = State::Ground;
+                let sequence = core::mem::take(&mut self.sequence);
+                self.csi_dispatch(&sequence, byte);
+                self.sequence = sequence;
+            }
+            0x1b => self.state = State::Escape,
+            0x20..=0x3f => self.sequence.push(byte),
+            _ => {
+                // control characters within a sequence are ignored
+            }
+        }
+    }
+
+    fn osc(&mut self, byte: u8) {
+        match (self.state, byte) {
+            (_, 0x07) | (State::OscEscape, b'\\') => {
+                self.state = State::Ground;
+                let sequence = core::mem::take(&mut self.sequence);
+                self.osc_dispatch(&sequence);
+                self.sequence = sequence;
+            }
+            (State::OscEscape, _) => self.escape(byte),
+            (_, 0x1b) => self.state = State::OscEscape,
+            _ => self.sequence.push(byte),
+        }
+    }
+
+    const fn string(&mut self, byte: u8) {
+        self.state = match (self.state, byte) {
+            (State::StringEscape, b'\\') => State::Ground,
+            (_, 0x1b) => State::StringEscape,
+            _ => State::String,
+        };
+    }
+
+    fn csi_dispatch(&mut self, sequence: &[u8], action: u8) {
+        let (private, rest) = match sequence {
+            [marker @ (b'?' | b'<' | b'=' | b'>'), rest @ ..] => (Some(*marker), rest),
+            _ => (None, sequence),
+        };
+        let intermediates = rest
+            .iter()
+            .position(|byte| (0x20..=0x2f).contains(byte))
+            .unwrap_or(rest.len());
+        let (params, intermediates) = rest.split_at(intermediates);
+        let params = core::str::from_utf8(params).unwrap_or_default();
+        match (private, intermediates, action) {
+            (None, [], b'm') => self.select_graphic_rendition(params),
+            (Some(b'?'), [], b'h') => self.set_private_modes(params, true),
+            (Some(b'?'), [], b'l') => self.set_private_modes(params, false),
+            (None, [b' '], b'q') => self.set_cursor_style(param(params, 0, 0)),
+            (None, [], _) => {
+                self.pending_wrap = false;
+                self.control_sequence(params, action);
+            }
+            _ => {
+                // other sequences, such as terminal queries, have no effect
+            }
+        }
+    }
+
+    fn control_sequence(&mut self, params: &str, action: u8) {
+        let count = param(params, 0, 1);
+        let Position { x, y } = self.cursor;
+        match action {
+            b'A' => {
+                let top = if y >= self.scroll_region.start {
+                    self.scroll_region.start
+                } else {
+                    0
+                };
+                self.cursor.y = y.saturating_sub(count).max(top);
+            }
+            b'B' | b'e' => self.cursor.y = y.saturating_add(count).min(self.bottom_margin()),
+            b'C' | b'a' => self.cursor.x = x.saturating_add(count).min(self.last_column()),
+            b'D' => self.cursor.x = x.saturating_sub(count),
+            b'E' => {
+                self.cursor.y = y.saturating_add(count).min(self.bottom_margin());
+                self.cursor.x = 0;
+            }
+            b'F' => {
+                self.cursor.y = y.saturating_sub(count);
+                self.cursor.x = 0;
+            }
+            b'G' | b'`' => self.move_to(count - 1, y),
+            b'd' => self.move_to(x, count - 1),
+            b'H' | b'f' => self.move_to(param(params, 1, 1) - 1, count - 1),
+            b'J' => self.erase_display(param(params, 0, 0)),
+            b'K' => self.erase_line(param(params, 0, 0)),
+            b'X' => {
+                let end = x.saturating_add(count).min(self.buffer.area.width);
+                self.erase(x, end, y);
+            }
+            b'@' => self.insert_characters(count),
+            b'P' => self.delete_characters(count),
+            b'L' | b'M' if self.scroll_region.contains(&y) => {
+                let region = y..self.scroll_region.end;
+                if action == b'L' {
+                    self.scroll_down(region, count);
+                } else {
+                    self.scroll_up(region, count, false);
+                }
+                self.cursor.x = 0;
+            }
+            b'S' => self.scroll_up(self.scroll_region.clone(), count, true),
+            b'T' if !params.contains(';') => self.scroll_down(self.scroll_region.clone(), count),
+            b'b' => {
+                if let Some(position) = self.last_printed {
+                    let symbol = String::from(self.buffer[position].symbol());
+                    for _ in 0..count {
+                        self.print(&symbol);
+                    }
+                }
+            }
+            b'r' => {
+                let height = self.buffer.area.height;
+                let top = param(params, 0, 1) - 1;
+                let bottom = param(params, 1, height).min(height);
+                if top < bottom {
+                    self.scroll_region = top..bottom;
+                    self.move_to(0, 0);
+                }
+            }
+            b's' => self.saved_cursor = self.cursor,
+            b'u' => self.move_to(self.saved_cursor.x, self.saved_cursor.y),
+            _ => {
+                // other sequences have no effect
+            }
+        }
+    }
+
+    const fn set_cursor_style(&mut self, param: u16) {
+        self.cursor_style = match param {
+            0 => CursorStyle::Default,
+            1 => CursorStyle::BlinkingBlock,
+            2 => CursorStyle::SteadyBlock,
+            3 => CursorStyle::BlinkingUnderline,
+            4 => CursorStyle::SteadyUnderline,
+            5 => CursorStyle::BlinkingBar,
+            6 => CursorStyle::SteadyBar,
+            _ => return,
+        };
+    }
+
+    fn set_private_modes(&mut self, params: &str, enable: bool) {
+        for mode in params.split(';') {
+            match mode {
+                "25" => self.cursor_visible = enable,
+                "47" | "1047" | "1049" => self.set_alternate_screen(enable, mode == "1049"),
+                _ => {
+                    // other modes, such as mouse capture, have no effect
+                }
+            }
+        }
+    }
+
+    fn set_alternate_screen(&mut self, enable: bool, save_cursor: bool) {
+        if enable && self.main_screen.is_none() {
+            if save_cursor {
+                self.saved_cursor = self.cursor;
+            }
+            let blank = Buffer::empty(self.buffer.area);
+            self.main_screen = Some(core::mem::replace(&mut self.buffer, blank));
+        } else if let (false, Some(main_screen)) = (enable, self.main_screen.take()) {
+            self.buffer = main_screen;
+            if save_cursor {
+                self.move_to(self.saved_cursor.x, self.saved_cursor.y);
+            }
+        } else {
+            // the screen is already shown
+        }
+        self.last_printed = None;
+    }
+
+    fn select_graphic_rendition(&mut self, params: &str) {
+        let mut groups = params.split(';');
+        while let Some(group) = groups.next() {
+            let mut subparams = group.split(':');
+            let code = subparams.next().unwrap_or_default();
+            let modifiers = &mut self.pen.modifier;
+            match code.parse::<u8>().unwrap_or(0) {
+                0 => {
+                    let hyperlink = self.pen.hyperlink().map(String::from);
+                    self.pen = Cell::EMPTY;
+                    self.pen.set_hyperlink(hyperlink.as_deref());
+                }
+                1 => modifiers.insert(Modifier::BOLD),
+                2 => modifiers.insert(Modifier::DIM),
+                3 => modifiers.insert(Modifier::ITALIC),
+                4 => {
+                    let style = match subparams.next() {
+                        Some("0") => Modifier::empty(),
+                        Some("2") => Modifier::DOUBLE_UNDERLINED,
+                        Some("3") => Modifier::CURLY_UNDERLINED,
+                        Some("4") => Modifier::DOTTED_UNDERLINED,
+                        Some("5") => Modifier::DASHED_UNDERLINED,
+                        _ => Modifier::UNDERLINED,
+                    };
+                    modifiers.remove(Modifier::ANY_UNDERLINE);
+                    modifiers.insert(style);
+                }
+                // 21 is a double underline rather than the end of bold text on most terminals
+                21 => {
+                    modifiers.remove(Modifier::ANY_UNDERLINE);
+                    modifiers.insert(Modifier::DOUBLE_UNDERLINED);
+                }
+                5 => modifiers.insert(Modifier::SLOW_BLINK),
+                6 => modifiers.insert(Modifier::RAPID_BLINK),
+                7 => modifiers.insert(Modifier::REVERSED),
+                8 => modifiers.insert(Modifier::HIDDEN),
+                9 => modifiers.insert(Modifier::CROSSED_OUT),
+                22 => modifiers.remove(Modifier::BOLD | Modifier::DIM),
+                23 => modifiers.remove(Modifier::ITALIC),
+                24 => modifiers.remove(Modifier::ANY_UNDERLINE),
+                25 => modifiers.remove(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+                27 => modifiers.remove(Modifier::REVERSED),
+                28 => modifiers.remove(Modifier::HIDDEN),
+                29 => modifiers.remove(Modifier::CROSSED_OUT),
+                53 => modifiers.insert(Modifier::OVERLINED),
+                55 => modifiers.remove(Modifier::OVERLINED),
+                code @ 30..=37 => self.pen.fg = Color::ANSI[usize::from(code - 30)],
+                code @ 90..=97 => self.pen.fg = Color::ANSI[usize::from(code - 90 + 8)],
+                code @ 40..=47 => self.pen.bg = Color::ANSI[usize::from(code - 40)],
+                code @ 100..=107 => self.pen.bg = Color::ANSI[usize::from(code - 100 + 8)],
+                38 => self.pen.fg = extended_color(group, &mut groups).unwrap_or(self.pen.fg),
+                48 => self.pen.bg = extended_color(group, &mut groups).unwrap_or(self.pen.bg),
+                39 => self.pen.fg = Color::Reset,
+                49 => self.pen.bg = Color::Reset,
+                #[cfg(feature = "underline-color")]
+                58 => {
+                    let color = extended_color(group, &mut groups);
+                    self.pen.underline_color = color.unwrap_or(self.pen.underline_color);
+                }
+                #[cfg(not(feature = "underline-color"))]
+                58 => {
+                    extended_color(group, &mut groups);
+                }
+                #[cfg(feature = "underline-color")]
+                59 => self.pen.underline_color = Color::Reset,
+                _ => {
+                    // other attributes are not supported by cells
+                }
+            }
+        }
+    }
+
+    fn osc_dispatch(&mut self, sequence: &[u8]) {
+        let sequence = String::from_utf8_lossy(sequence);
+        let mut params = sequence.splitn(3, ';');
+        if params.next() == Some("8") {
+            let url = params.nth(1).unwrap_or_default();
+            self.pen
+                .set_hyperlink(Some(url).filter(|url| !url.is_empty()));
+        }
+    }
+
+    /// Prints the pending text.
+    ///
+    /// An incomplete UTF-8 character at the end of the text is kept for later when `keep_partial`
+    /// is set.
+    fn print_text(&mut self, keep_partial: bool) {
+        if self.text.is_empty() {
+            return;
+        }
+        let text = core::mem::take(&mut self.text);
+        let complete = match core::str::from_utf8(&text) {
+            Err(err) if keep_partial && err.error_len().is_none() => err.valid_up_to(),
+            _ => text.len(),
+        };
+        let (complete, partial) = text.split_at(complete);
+        for grapheme in String::from_utf8_lossy(complete).graphemes(true) {
+            self.print(grapheme);
+        }
+        self.text.extend_from_slice(partial);
+    }
+
+    fn print(&mut self, grapheme: &str) {
+        let width = grapheme.width() as u16;
+        if width == 0 {
+            if let Some(position) = self.last_printed {
+                let cell = &mut self.buffer[position];
+                let symbol = format!("{}{grapheme}", cell.symbol());
+                cell.set_symbol(&symbol);
+            }
+            return;
+        }
+        let area_width = self.buffer.area.width;
+        if width > area_width {
+            return;
+        }
+        if self.pending_wrap || self.cursor.x + width > area_width {
+            self.carriage_return();
+            self.line_feed();
+        }
+        let Position { x, y } = self.cursor;
+        self.erase_wide_characters(x, x + width, y);
+        let mut cell = self.pen.clone();
+        cell.set_symbol(grapheme);
+        self.buffer[(x, y)] = cell;
+        for hidden_x in x + 1..x + width {
+            let mut hidden = Cell::EMPTY;
+            hidden.set_hyperlink(self.pen.hyperlink());
+            self.buffer[(hidden_x, y)] = hidden;
+        }
+        self.last_printed = Some(Position { x, y });
+        if x + width < area_width {
+            self.cursor.x = x + width;
+        } else {
+            self.cursor.x = area_width - 1;
+            self.pending_wrap = true;
+        }
+    }
+
+    /// Erases the wide characters that are partially covered by the columns `start..end` of the
+    /// row `y`, as terminals do not display half of a character.
+    fn erase_wide_characters(&mut self, start: u16, end: u16, y: u16) {
+        if start > 0 && self.buffer[(start - 1, y)].symbol().width() > 1 {
+            self.buffer[(start - 1, y)] = self.blank();
+        }
+        if end > start
+            && end < self.buffer.area.width
+            && self.buffer[(end - 1, y)].symbol().width() > 1
+        {
+            self.buffer[(end, y)] = self.blank();
+        }
+    }
+
+    /// Returns an erased cell, which has the current background color like on most terminals.
+    const fn blank(&self) -> Cell {
+        let mut cell = Cell::EMPTY;
+        cell.bg = self.pen.bg;
+        cell
+    }
+
+    const fn last_column(&self) -> u16 {
+        self.buffer.area.width.saturating_sub(1)
+    }
+
+    /// Returns the last row the cursor can move down to without scrolling.
+    const fn bottom_margin(&self) -> u16 {
+        if self.cursor.y < self.scroll_region.end {
+            self.scroll_region.end.saturating_sub(1)
+        } else {
+            self.buffer.area.height.saturating_sub(1)
+        }
+    }
+
+    fn move_to(&mut self, x: u16, y: u16) {
+        self.pending_wrap = false;
+        self.cursor = Position {
+            x: x.min(self.last_column()),
+            y: y.min(self.buffer.area.height.saturating_sub(1)),
+        };
+    }
+
+    const fn carriage_return(&mut self) {
+        self.pending_wrap = false;
+        self.cursor.x = 0;
+    }
+
+    fn line_feed(&mut self) {
+        self.pending_wrap = false;
+        if self.cursor.y + 1 == self.scroll_region.end {
+            self.scroll_up(self.scroll_region.clone(), 1, true);
+        } else if self.cursor.y + 1 < self.buffer.area.height {
+            self.cursor.y += 1;
+        } else {
+            // the cursor is at the bottom of the screen, below the scrolling region
+        }
+    }
+
+    fn reverse_index(&mut self) {
+        self.pending_wrap = false;
+        if self.cursor.y == self.scroll_region.start {
+            self.scroll_down(self.scroll_region.clone(), 1);
+        } else {
+            self.cursor.y = self.cursor.y.saturating_sub(1);
+        }
+    }
+
+    /// Scrolls the rows of the region up, filling the bottom with blank rows.
+    ///
+    /// When the region starts at the top of the main screen and `to_scrollback` is set, the rows
+    /// that scroll off are moved to the scrollback.
+    fn scroll_up(&mut self, region: Range<u16>, count: u16, to_scrollback: bool) {
+        let width = usize::from(self.buffer.area.width);
+        let start = width * usize::from(region.start);
+        let end = width * usize::from(region.end.min(self.buffer.area.height));
+        let len = end.saturating_sub(start);
+        let scrolled = len.min(width * usize::from(count));
+        let blank = self.blank();
+        if to_scrollback && region.start == 0 && self.main_screen.is_none() {
+            let blank_rows = usize::from(count).saturating_sub(len / width.max(1));
+            append_to_scrollback(
+                &mut self.scrollback,
+                self.buffer.content[start..start + scrolled]
+                    .iter()
+                    .cloned()
+                    .chain(iter::repeat_n(Cell::EMPTY, blank_rows * width)),
+            );
+        }
+        self.buffer.content[start..end].rotate_left(scrolled);
+        self.buffer.content[end - scrolled..end].fill(blank);
+    }
+
+    /// Scrolls the rows of the region down, filling the top with blank rows.
+    fn scroll_down(&mut self, region: Range<u16>, count: u16) {
+        let width = usize::from(self.buffer.area.width);
+        let start = width * usize::from(region.start);
+        let end = width * usize::from(region.end.min(self.buffer.area.height));
+        let scrolled = end.saturating_sub(start).min(width * usize::from(count));
+        let blank = self.blank();
+        self.buffer.content[start..end].rotate_right(scrolled);
+        self.buffer.content[start..start + scrolled].fill(blank);
+    }
+
+    /// Erases the columns `start..end` of the row `y`.
+    fn erase(&mut self, start: u16, end: u16, y: u16) {
+        self.erase_wide_characters(start, end, y);
+        let blank = self.blank();
+        for x in start..end {
+            self.buffer[(x, y)] = blank.clone();
+        }
+    }
+
+    fn erase_line(&mut self, mode: u16) {
+        let Position { x, y } = self.cursor;
+        let width = self.buffer.area.width;
+        match mode {
+            0 => self.erase(x, width, y),
+            1 => self.erase(0, x + 1, y),
+            2 => self.erase(0, width, y),
+            _ => {}
+        }
+    }
+
+    fn erase_display(&mut self, mode: u16) {
+        let Position { x, y } = self.cursor;
+        let Rect { width, height, .. } = self.buffer.area;
+        match mode {
+            0 => {
+                self.erase(x, width, y);
+                for row in y + 1..height {
+                    self.erase(0, width, row);
+                }
+            }
+            1 => {
+                for row in 0..y {
+                    self.erase(0, width, row);
+                }
+                self.erase(0, x + 1, y);
+            }
+            2 => {
+                for row in 0..height {
+                    self.erase(0, width, row);
+                }
+            }
+            3 => self.scrollback = Buffer::empty(Rect::new(0, 0, width, 0)),
+            _ => {}
+        }
+    }
+
+    fn insert_characters(&mut self, count: u16) {
+        let Position { x, y } = self.cursor;
+        let width = self.buffer.area.width;
+        let count = count.min(width - x);
+        self.erase_wide_characters(x, x, y);
+        let start = self.buffer.index_of(x, y);
+        let end = self.buffer.index_of(0, y) + usize::from(width);
+        self.buffer.content[start..end].rotate_right(usize::from(count));
+        self.erase(x, x + count, y);
+    }
+
+    fn delete_characters(&mut self, count: u16) {
+        let Position { x, y } = self.cursor;
+        let width = self.buffer.area.width;
+        let count = count.min(width - x);
+        self.erase_wide_characters(x, x + count, y);
+        let start = self.buffer.index_of(x, y);
+        let end = self.buffer.index_of(0, y) + usize::from(width);
+        self.buffer.content[start..end].rotate_left(usize::from(count));
+        self.erase(width - count, width, y);
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for VirtualTerminal {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.process(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns a copy of the buffer with the given size, in which each row keeps its content.
+fn resized(buffer: &Buffer, width: u16, height: u16) -> Buffer {
+    let mut resized = Buffer::empty(Rect::new(0, 0, width, height));
+    let area = buffer.area.intersection(resized.area);
+    for position in area.positions() {
+        resized[position] = buffer[position].clone();
+    }
+    resized
+}
+
+/// Returns the numeric parameter at the index, or the default if it is missing or zero.
+fn param(params: &str, index: usize, default: u16) -> u16 {
+    params
+        .split(';')
+        .nth(index)
+        .and_then(|param| param.split(':').next()?.parse().ok())
+        .filter(|&value| value != 0)
+        .unwrap_or(default)
+}
+
+/// Parses an indexed or RGB color, written either as `38;5;n` and `38;2;r;g;b`, or with colons as
+/// `38:5:n` and `38:2::r:g:b`.
+fn extended_color<'a>(group: &str, groups: &mut impl Iterator<Item = &'a str>) -> Option<Color> {
+    let number = |value: &str| value.parse::<u8>().ok();
+    if group.contains(':') {
+        let subparams: Vec<&str> = group.split(':').skip(1).collect();
+        match subparams.as_slice() {
+            ["5", index] => number(index).map(indexed_color),
+            ["2", r, g, b] | ["2", _, r, g, b, ..] => {
+                Some(Color::Rgb(number(r)?, number(g)?, number(b)?))
+            }
+            _ => None,
+        }
+    } else {
+        match groups.next()? {
+            "5" => groups.next().and_then(number).map(indexed_color),
+            "2" => {
+                let [r, g, b] = [groups.next(), groups.next(), groups.next()];
+                Some(Color::Rgb(number(r?)?, number(g?)?, number(b?)?))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn indexed_color(index: u8) -> Color {
+    Color::ANSI
+        .get(usize::from(index))
+        .copied()
+        .unwrap_or(Color::Indexed(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Style;
+
+    fn process(width: u16, height: u16, bytes: &[u8]) -> VirtualTerminal {
+        let mut terminal = VirtualTerminal::new(width, height);
+        terminal.process(bytes);
+        terminal
+    }
+
+    #[test]
+    fn new() {
+        let terminal = VirtualTerminal::new(3, 2);
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["   "; 2]));
+        assert_eq!(terminal.scrollback(), &Buffer::empty(Rect::new(0, 0, 3, 0)));
+        assert_eq!(terminal.cursor_position(), Position::ORIGIN);
+        assert!(terminal.is_cursor_visible());
+    }
+
+    #[test]
+    fn print() {
+        let terminal = process(5, 2, b"ab\r\ncd");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["ab   ", "cd   "]));
+        assert_eq!(terminal.cursor_position(), Position::new(2, 1));
+    }
+
+    #[test]
+    fn print_wraps_at_end_of_line() {
+        let terminal = process(3, 2, b"abc");
+        assert_eq!(terminal.cursor_position(), Position::new(2, 0));
+        let terminal = process(3, 2, b"abcd");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["abc", "d  "]));
+        assert_eq!(terminal.cursor_position(), Position::new(1, 1));
+    }
+
+    #[test]
+    fn print_wide_characters() {
+        let terminal = process(5, 1, "a界b".as_bytes());
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["a界b "]));
+        assert_eq!(terminal.cursor_position(), Position::new(4, 0));
+
+        // overwriting half of a wide character erases it
+        let terminal = process(5, 1, "a界b\x1b[3Gc".as_bytes());
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["a cb "]));
+    }
+
+    #[test]
+    fn print_combining_characters() {
+        let terminal = process(3, 1, "e\u{301}".as_bytes());
+        assert_eq!(terminal.buffer()[(0, 0)].symbol(), "e\u{301}");
+        assert_eq!(terminal.cursor_position(), Position::new(1, 0));
+    }
+
+    #[test]
+    fn process_split_sequences() {
+        let mut terminal = VirtualTerminal::new(3, 1);
+        let bytes = "\x1b[31m界".as_bytes();
+        for byte in bytes {
+            terminal.process(&[*byte]);
+        }
+        let mut expected = Buffer::with_lines(["界 "]);
+        expected[(0, 0)].set_fg(Color::Red);
+        assert_eq!(terminal.buffer(), &expected);
+    }
+
+    #[rstest]
+    #[case::position(b"\x1b[2;3H", Position::new(2, 1))]
+    #[case::position_default(b"\x1b[2;3H\x1b[H", Position::new(0, 0))]
+    #[case::position_clamped(b"\x1b[99;99H", Position::new(4, 2))]
+    #[case::up(b"\x1b[3;3H\x1b[A", Position::new(2, 1))]
+    #[case::down(b"\x1b[2B", Position::new(0, 2))]
+    #[case::forward(b"\x1b[3C", Position::new(3, 0))]
+    #[case::back(b"\x1b[1;4H\x1b[2D", Position::new(1, 0))]
+    #[case::column(b"\x1b[2;1H\x1b[4G", Position::new(3, 1))]
+    #[case::row(b"\x1b[1;2H\x1b[3d", Position::new(1, 2))]
+    #[case::next_line(b"\x1b[1;3H\x1b[E", Position::new(0, 1))]
+    #[case::save_restore(b"\x1b[2;2H\x1b7\x1b[H\x1b8", Position::new(1, 1))]
+    #[case::backspace(b"ab\x08", Position::new(1, 0))]
+    #[case::tab(b"\t", Position::new(4, 0))]
+    fn cursor_movement(#[case] bytes: &[u8], #[case] expected: Position) {
+        let terminal = process(5, 3, bytes);
+        assert_eq!(terminal.cursor_position(), expected);
+    }
+
+    #[test]
+    fn cursor_visibility() {
+        let mut terminal = VirtualTerminal::new(1, 1);
+        terminal.process(b"\x1b[?25l");
+        assert!(!terminal.is_cursor_visible());
+        terminal.process(b"\x1b[?25h");
+        assert!(terminal.is_cursor_visible());
+    }
+
+    #[rstest]
+    #[case::default(b"\x1b[5 q\x1b[ q", CursorStyle::Default)]
+    #[case::blinking_block(b"\x1b[1 q", CursorStyle::BlinkingBlock)]
+    #[case::steady_underline(b"\x1b[4 q", CursorStyle::SteadyUnderline)]
+    #[case::steady_bar(b"\x1b[6 q", CursorStyle::SteadyBar)]
+    #[case::unknown(b"\x1b[6 q\x1b[9 q", CursorStyle::SteadyBar)]
+    fn cursor_style(#[case] bytes: &[u8], #[case] expected: CursorStyle) {
+        let terminal = process(1, 1, bytes);
+        assert_eq!(terminal.cursor_style(), expected);
+    }
+
+    #[rstest]
+    #[case::named(b"\x1b[31;42m", Style::new().red().on_green())]
+    #[case::bright(b"\x1b[91;102m", Style::new().light_red().on_light_green())]
+    #[case::indexed_named(b"\x1b[38;5;1;48;5;15m", Style::new().red().on_white())]
+    #[case::indexed(b"\x1b[38;5;22;48;5;255m", Style::new().fg(Color::Indexed(22)).bg(Color::Indexed(255)))]
+    #[case::rgb(b"\x1b[38;2;1;2;3;48;2;4;5;6m", Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Rgb(4, 5, 6)))]
+    #[case::colons(b"\x1b[38:5:22;48:2::4:5:6m", Style::new().fg(Color::Indexed(22)).bg(Color::Rgb(4, 5, 6)))]
+    #[case::default_colors(b"\x1b[31;42m\x1b[39;49m", Style::new().fg(Color::Reset).bg(Color::Reset))]
+    #[case::modifiers(b"\x1b[1;3;4;7;9m", Style::new().bold().italic().underlined().reversed().crossed_out())]
+    #[case::blink(b"\x1b[5;6;8m", Style::new().slow_blink().rapid_blink().hidden())]
+    #[case::normal_intensity(b"\x1b[1;2;3m\x1b[22m", Style::new().italic())]
+    #[case::double_underline(b"\x1b[21m", Style::new().double_underlined())]
+    #[case::double_underline_style(b"\x1b[4:2m", Style::new().double_underlined())]
+    #[case::curly_underline(b"\x1b[4:3m", Style::new().curly_underlined())]
+    #[case::dotted_underline(b"\x1b[4:4m", Style::new().dotted_underlined())]
+    #[case::dashed_underline(b"\x1b[4:5m", Style::new().dashed_underlined())]
+    #[case::replace_underline(b"\x1b[4:3m\x1b[4m", Style::new().underlined())]
+    #[case::no_underline(b"\x1b[4m\x1b[4:0m", Style::new())]
+    #[case::overline(b"\x1b[53m", Style::new().overlined())]
+    #[case::remove(b"\x1b[3;4:3;5;7;8;9;53m\x1b[23;24;25;27;28;29;55m", Style::new())]
+    #[case::reset(b"\x1b[1;31;42m\x1b[m", Style::new())]
+    fn select_graphic_rendition(#[case] bytes: &[u8], #[case] style: Style) {
+        let mut terminal = process(1, 1, bytes);
+        terminal.process(b"a");
+        let mut expected = Cell::new("a");
+        expected.set_style(style);
+        assert_eq!(terminal.buffer()[(0, 0)], expected);
+    }
+
+    #[test]
+    fn hyperlink() {
+        let terminal = process(
+            4,
+            1,
+            "\x1b]8;;https://ratatui.rs\x1b\\a界\x1b]8;;\x07b".as_bytes(),
+        );
+        let mut expected = Buffer::with_lines(["a界b"]);
+        for x in 0..3 {
+            expected[(x, 0)].set_hyperlink(Some("https://ratatui.rs"));
+        }
+        assert_eq!(terminal.buffer(), &expected);
+    }
+
+    #[rstest]
+    #[case::line_after(b"\x1b[2;3H\x1b[K", ["abcd", "ab  ", "abcd"])]
+    #[case::line_before(b"\x1b[2;3H\x1b[1K", ["abcd", "   d", "abcd"])]
+    #[case::line(b"\x1b[2;3H\x1b[2K", ["abcd", "    ", "abcd"])]
+    #[case::display_after(b"\x1b[2;3H\x1b[J", ["abcd", "ab  ", "    "])]
+    #[case::display_before(b"\x1b[2;3H\x1b[1J", ["    ", "   d", "abcd"])]
+    #[case::display(b"\x1b[2J", ["    "; 3])]
+    #[case::characters(b"\x1b[2;2H\x1b[2X", ["abcd", "a  d", "abcd"])]
+    #[case::insert_characters(b"\x1b[2;2H\x1b[@", ["abcd", "a bc", "abcd"])]
+    #[case::delete_characters(b"\x1b[2;2H\x1b[P", ["abcd", "acd ", "abcd"])]
+    #[case::insert_lines(b"\x1b[2;2H\x1b[L", ["abcd", "    ", "abcd"])]
+    #[case::delete_lines(b"\x1b[1;2H\x1b[M", ["abcd", "abcd", "    "])]
+    #[case::repeat(b"\x1b[2;1Hx\x1b[2b", ["abcd", "xxxd", "abcd"])]
+    fn erase_and_edit(#[case] bytes: &[u8], #[case] expected: [&str; 3]) {
+        let mut terminal = process(4, 3, b"abcdabcdabcd");
+        terminal.process(bytes);
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn erase_uses_background_color() {
+        let terminal = process(2, 1, b"\x1b[44m\x1b[2K");
+        let mut expected = Buffer::with_lines(["  "]);
+        expected.set_style(expected.area, Style::new().on_blue());
+        assert_eq!(terminal.buffer(), &expected);
+    }
+
+    #[test]
+    fn line_feed_scrolls_into_scrollback() {
+        let terminal = process(2, 2, b"a\r\nb\r\nc\r\nd");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["c ", "d "]));
+        assert_eq!(terminal.scrollback(), &Buffer::with_lines(["a ", "b "]));
+    }
+
+    #[test]
+    fn scroll_region() {
+        let mut terminal = process(1, 4, b"a\r\nb\r\nc\r\nd");
+        // scroll the rows 2 and 3 up then the rows 1 to 3 down
+        terminal.process(b"\x1b[2;3r\x1b[S\x1b[r\x1b[1;3r\x1b[2T\x1b[r");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines([" ", " ", "a", "d"]));
+        assert_eq!(terminal.scrollback(), &Buffer::empty(Rect::new(0, 0, 1, 0)));
+
+        // rows that scroll off the top of a region at the top of the screen go to the scrollback
+        terminal.process(b"\x1b[1;3r\x1b[3S\x1b[r");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines([" ", " ", " ", "d"]));
+        assert_eq!(terminal.scrollback(), &Buffer::with_lines([" ", " ", "a"]));
+    }
+
+    #[test]
+    fn line_feed_in_scroll_region() {
+        let terminal = process(1, 3, b"a\r\nb\r\nc\x1b[1;2r\x1b[2;1H\nd\x1b[r");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["b", "d", "c"]));
+        assert_eq!(terminal.scrollback(), &Buffer::with_lines(["a"]));
+    }
+
+    #[test]
+    fn alternate_screen() {
+        let mut terminal = process(2, 1, b"ab\x1b[?1049h");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["  "]));
+        terminal.process(b"\x1b[Hc\x1b[?1049l");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["ab"]));
+        assert_eq!(terminal.cursor_position(), Position::new(1, 0));
+    }
+
+    #[test]
+    fn ignores_unsupported_sequences() {
+        let terminal = process(
+            2,
+            1,
+            b"\x1b_Gf=100;AAAA\x1b\\\x1bPq#0~\x1b\\\x1b(Ba\x1b[6n\x1b]0;title\x07\x1b[?2026hb",
+        );
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["ab"]));
+    }
+
+    #[test]
+    fn resize() {
+        let mut terminal = process(3, 2, b"abc\r\nd");
+        terminal.resize(2, 3);
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["ab", "d ", "  "]));
+        assert_eq!(terminal.cursor_position(), Position::new(1, 1));
+    }
+
+    #[test]
+    fn reset() {
+        let mut terminal = process(2, 1, b"a\r\nb\x1b[31m");
+        terminal.process(b"\x1bcc");
+        assert_eq!(terminal.buffer(), &Buffer::with_lines(["c "]));
+        assert_eq!(terminal.scrollback(), &Buffer::with_lines(["a "]));
+        assert_eq!(terminal.buffer()[(0, 0)].fg, Color::Reset);
+    }
+}
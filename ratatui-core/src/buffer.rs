@@ -4,6 +4,10 @@
 mod assert;
 mod buffer;
 mod cell;
+mod screenshot;
+mod view;
 
 pub use buffer::Buffer;
 pub use cell::Cell;
+pub use screenshot::Screenshot;
+pub use view::{BufferView, OverlappingAreasError};
@@ -6,9 +6,9 @@ use core::{cmp, fmt};
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::buffer::Cell;
+use crate::buffer::{BufferView, Cell, OverlappingAreasError};
 use crate::layout::{Position, Rect};
-use crate::style::Style;
+use crate::style::{Style, Theme};
 use crate::text::{Line, Span};
 
 /// A buffer that maps to the desired content of the terminal after the draw call
@@ -69,6 +69,13 @@ pub struct Buffer {
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
     pub content: Vec<Cell>,
+    /// The theme that widgets use for their default styles when rendered to this buffer
+    ///
+    /// The [`Terminal`] sets this to its theme for the buffers of each frame.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub theme: Theme,
 }
 
 impl Buffer {
@@ -83,7 +90,11 @@ impl Buffer {
     pub fn filled(area: Rect, cell: Cell) -> Self {
         let size = area.area() as usize;
         let content = vec![cell; size];
-        Self { area, content }
+        Self {
+            area,
+            content,
+            theme: Theme::new(),
+        }
     }
 
     /// Returns a Buffer containing the given lines
@@ -377,6 +388,7 @@ impl Buffer {
                 remaining_width as usize,
                 line.style.patch(span.style),
             );
+            self.set_hyperlink_between(x, pos.0, y, span.hyperlink.as_deref());
             let w = pos.0.saturating_sub(x);
             x = pos.0;
             remaining_width = remaining_width.saturating_sub(w);
@@ -386,7 +398,17 @@ impl Buffer {
 
     /// Print a span, starting at the position (x, y)
     pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
-        self.set_stringn(x, y, &span.content, max_width as usize, span.style)
+        let pos = self.set_stringn(x, y, &span.content, max_width as usize, span.style);
+        self.set_hyperlink_between(x, pos.0, y, span.hyperlink.as_deref());
+        pos
+    }
+
+    /// Sets the hyperlink of the cells in the row `y` from `start` (inclusive) to `end`
+    /// (exclusive).
+    fn set_hyperlink_between(&mut self, start: u16, end: u16, y: u16, hyperlink: Option<&str>) {
+        for x in start..end {
+            self[(x, y)].set_hyperlink(hyperlink);
+        }
     }
 
     /// Set the style of all cells in the given area.
@@ -405,6 +427,22 @@ impl Buffer {
         }
     }
 
+    /// Splits the buffer into a mutable [`BufferView`] for each of the given areas.
+    ///
+    /// The areas are clipped to the area of the buffer, and must not overlap. As the views borrow
+    /// disjoint cells of the buffer, they can be written to at the same time, e.g. to render
+    /// independent panels of a layout on separate threads. See [`BufferView`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OverlappingAreasError`] if two of the areas overlap.
+    pub fn split_mut(
+        &mut self,
+        areas: &[Rect],
+    ) -> Result<Vec<BufferView<'_>>, OverlappingAreasError> {
+        BufferView::split(self, areas)
+    }
+
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
     pub fn resize(&mut self, area: Rect) {
@@ -456,6 +494,9 @@ impl Buffer {
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
     /// self to other.
     ///
+    /// Cells are compared on their symbol, style, and hyperlink, so a cell whose hyperlink target
+    /// changed is part of the update even if its content is unchanged.
+    ///
     /// We're assuming that buffers are well-formed, that is no double-width cell is followed by
     /// a non-blank cell.
     ///
@@ -604,6 +645,8 @@ impl fmt::Debug for Buffer {
     /// * `content`: displayed as a list of strings representing the content of the buffer
     /// * `styles`: displayed as a list of: `{ x: 1, y: 2, fg: Color::Red, bg: Color::Blue,
     ///   modifier: Modifier::BOLD }` only showing a value when there is a change in style.
+    /// * `theme`: displayed as `Theme { .. }`, only when the buffer has a theme other than
+    ///   [`Theme::new`].
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_fmt(format_args!("Buffer {{\n    area: {:?}", &self.area))?;
 
@@ -614,6 +657,8 @@ impl fmt::Debug for Buffer {
         f.write_str(",\n    content: [\n")?;
         let mut last_style = None;
         let mut styles = vec![];
+        let mut last_hyperlink = None;
+        let mut hyperlinks = vec![];
         for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
             let mut overwritten = vec![];
             let mut skip: usize = 0;
@@ -625,6 +670,10 @@ impl fmt::Debug for Buffer {
                     overwritten.push((x, c.symbol()));
                 }
                 skip = cmp::max(skip, c.symbol().width()).saturating_sub(1);
+                if last_hyperlink != Some(c.hyperlink()) {
+                    last_hyperlink = Some(c.hyperlink());
+                    hyperlinks.push((x, y, c.hyperlink()));
+                }
                 #[cfg(feature = "underline-color")]
                 {
                     let style = (c.fg, c.bg, c.underline_color, c.modifier);
@@ -663,7 +712,25 @@ impl fmt::Debug for Buffer {
                 s.0, s.1, s.2, s.3, s.4
             ))?;
         }
-        f.write_str("    ]\n}")?;
+        f.write_str("    ]")?;
+        // Only show the hyperlinks when there are any, to keep the output compact for the common
+        // case of a buffer without links.
+        if hyperlinks
+            .iter()
+            .any(|(_, _, hyperlink)| hyperlink.is_some())
+        {
+            f.write_str(",\n    hyperlinks: [\n")?;
+            for (x, y, hyperlink) in hyperlinks {
+                f.write_fmt(format_args!(
+                    "        x: {x}, y: {y}, hyperlink: {hyperlink:?},\n"
+                ))?;
+            }
+            f.write_str("    ]")?;
+        }
+        if self.theme != Theme::new() {
+            f.write_fmt(format_args!(",\n    theme: {:?}", self.theme))?;
+        }
+        f.write_str("\n}")?;
         Ok(())
     }
 }
@@ -711,6 +778,52 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn debug_hyperlink() {
+        let buffer = Buffer::with_lines([Line::from(vec![
+            Span::raw("a"),
+            Span::raw("bc").hyperlink("https://ratatui.rs"),
+        ])]);
+        let result = format!("{buffer:?}");
+        println!("{result}");
+        #[cfg(feature = "underline-color")]
+        let expected = indoc::indoc!(
+            r#"
+            Buffer {
+                area: Rect { x: 0, y: 0, width: 3, height: 1 },
+                content: [
+                    "abc",
+                ],
+                styles: [
+                    x: 0, y: 0, fg: Reset, bg: Reset, underline: Reset, modifier: NONE,
+                ],
+                hyperlinks: [
+                    x: 0, y: 0, hyperlink: None,
+                    x: 1, y: 0, hyperlink: Some("https://ratatui.rs"),
+                ]
+            }"#
+        );
+        #[cfg(not(feature = "underline-color"))]
+        let expected = indoc::indoc!(
+            r#"
+            Buffer {
+                area: Rect { x: 0, y: 0, width: 3, height: 1 },
+                content: [
+                    "abc",
+                ],
+                styles: [
+                    x: 0, y: 0, fg: Reset, bg: Reset, modifier: NONE,
+                ],
+                hyperlinks: [
+                    x: 0, y: 0, hyperlink: None,
+                    x: 1, y: 0, hyperlink: Some("https://ratatui.rs"),
+                ]
+            }"#
+        );
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn debug_some_example() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
@@ -1150,6 +1263,30 @@ mod tests {
         assert_eq!(diff, [(0, 0, &Cell::new("4"))],);
     }
 
+    #[test]
+    fn diff_hyperlink() {
+        let prev = Buffer::with_lines(["ab"]);
+        let next = Buffer::with_lines([Line::from(vec![
+            Span::raw("a"),
+            Span::raw("b").hyperlink("https://ratatui.rs"),
+        ])]);
+
+        let diff = prev.diff(&next);
+        let mut expected = Cell::new("b");
+        expected.set_hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(diff, [(1, 0, &expected)]);
+    }
+
+    #[test]
+    fn set_span_hyperlink() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let span = Span::raw("abc").hyperlink("https://ratatui.rs");
+        buffer.set_span(1, 0, &span, 5);
+        let hyperlinks = buffer.content.iter().map(Cell::hyperlink).collect_vec();
+        let link = Some("https://ratatui.rs");
+        assert_eq!(hyperlinks, [None, link, link, link, None]);
+    }
+
     #[rstest]
     #[case(Rect::new(0, 0, 2, 2), Rect::new(0, 2, 2, 2), ["11", "11", "22", "22"])]
     #[case(Rect::new(2, 2, 2, 2), Rect::new(0, 0, 2, 2), ["22  ", "22  ", "  11", "  11"])]
@@ -1,6 +1,8 @@
+use alloc::borrow::Cow;
+
 use compact_str::CompactString;
 
-use crate::style::{Color, Modifier, Style};
+use crate::style::{Color, ColorDepth, Modifier, Style};
 use crate::symbols::merge::MergeStrategy;
 
 /// A buffer cell
@@ -32,6 +34,18 @@ pub struct Cell {
 
     /// Whether the cell should be skipped when copying (diffing) the buffer to the screen.
     pub skip: bool,
+
+    /// The target of the [OSC 8] hyperlink that the cell is part of.
+    ///
+    /// Like the symbol, this is stored as a [`CompactString`] so that short URLs do not require
+    /// a heap allocation.
+    ///
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    hyperlink: Option<CompactString>,
 }
 
 impl Cell {
@@ -44,6 +58,7 @@ impl Cell {
         underline_color: Color::Reset,
         modifier: Modifier::empty(),
         skip: false,
+        hyperlink: None,
     };
 
     /// Creates a new `Cell` with the given symbol.
@@ -189,10 +204,111 @@ impl Cell {
         self
     }
 
+    /// Gets the hyperlink target of the cell, if any.
+    ///
+    /// Backends use this to wrap the cell content in [OSC 8] escape sequences so that terminals
+    /// that support hyperlinks make the content clickable.
+    ///
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    #[must_use]
+    pub fn hyperlink(&self) -> Option<&str> {
+        self.hyperlink.as_deref()
+    }
+
+    /// Sets the hyperlink target of the cell.
+    ///
+    /// Passing `None` removes the hyperlink from the cell. Like the style of the cell, the
+    /// hyperlink is kept when the symbol of the cell changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Cell;
+    ///
+    /// let mut cell = Cell::new("a");
+    /// cell.set_hyperlink(Some("https://ratatui.rs"));
+    /// assert_eq!(cell.hyperlink(), Some("https://ratatui.rs"));
+    /// ```
+    pub fn set_hyperlink(&mut self, hyperlink: Option<&str>) -> &mut Self {
+        self.hyperlink = hyperlink.map(CompactString::new);
+        self
+    }
+
     /// Resets the cell to the empty state.
     pub fn reset(&mut self) {
         *self = Self::EMPTY;
     }
+
+    /// Returns the cell with its colors quantized to the given color depth.
+    ///
+    /// Each color is replaced with the nearest color that can be displayed (see
+    /// [`Color::quantize`]). In [`ColorDepth::Monochrome`], the colors are dropped and the emphasis
+    /// they gave is kept with modifiers instead:
+    ///
+    /// - a cell with both colors set is [reversed](Modifier::REVERSED) when it is displayed as dark
+    ///   text on a light background (e.g. a selection highlight), and not reversed otherwise, as
+    ///   the default colors of most terminals are light text on a dark background. Cells painted
+    ///   with the base colors of a dark theme are therefore not reversed.
+    /// - a cell with only a foreground color is [bold](Modifier::BOLD).
+    /// - a cell with only a background color (e.g. a highlight that keeps the text color) is
+    ///   [reversed](Modifier::REVERSED).
+    ///
+    /// The cell is borrowed rather than cloned if it does not change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::Cell;
+    /// use ratatui_core::style::{Color, ColorDepth, Modifier};
+    ///
+    /// let mut cell = Cell::new("a");
+    /// cell.set_fg(Color::Rgb(255, 0, 0)).set_bg(Color::White);
+    ///
+    /// let quantized = cell.quantize(ColorDepth::Ansi256);
+    /// assert_eq!(quantized.fg, Color::Indexed(196));
+    ///
+    /// let monochrome = cell.quantize(ColorDepth::Monochrome);
+    /// assert_eq!(monochrome.bg, Color::Reset);
+    /// assert_eq!(monochrome.modifier, Modifier::REVERSED);
+    /// ```
+    pub fn quantize(&self, depth: ColorDepth) -> Cow<'_, Self> {
+        let fg = self.fg.quantize(depth);
+        let bg = self.bg.quantize(depth);
+        #[cfg(feature = "underline-color")]
+        let underline_color = self.underline_color.quantize(depth);
+        let mut modifier = self.modifier;
+        if depth == ColorDepth::Monochrome {
+            match (self.fg.luma(), self.bg.luma()) {
+                (Some(fg), Some(bg)) => {
+                    let (fg, bg) = if self.modifier.contains(Modifier::REVERSED) {
+                        (bg, fg)
+                    } else {
+                        (fg, bg)
+                    };
+                    modifier.set(Modifier::REVERSED, bg > fg);
+                }
+                (Some(_), None) => modifier.insert(Modifier::BOLD),
+                (None, Some(_)) => modifier.insert(Modifier::REVERSED),
+                (None, None) => {}
+            }
+        }
+        #[cfg(feature = "underline-color")]
+        let unchanged_underline = underline_color == self.underline_color;
+        #[cfg(not(feature = "underline-color"))]
+        let unchanged_underline = true;
+        if fg == self.fg && bg == self.bg && modifier == self.modifier && unchanged_underline {
+            return Cow::Borrowed(self);
+        }
+        let mut cell = self.clone();
+        cell.fg = fg;
+        cell.bg = bg;
+        #[cfg(feature = "underline-color")]
+        {
+            cell.underline_color = underline_color;
+        }
+        cell.modifier = modifier;
+        Cow::Owned(cell)
+    }
 }
 
 impl PartialEq for Cell {
@@ -216,6 +332,7 @@ impl PartialEq for Cell {
             && self.bg == other.bg
             && self.modifier == other.modifier
             && self.skip == other.skip
+            && self.hyperlink == other.hyperlink
     }
 }
 
@@ -234,6 +351,7 @@ impl core::hash::Hash for Cell {
         self.underline_color.hash(state);
         self.modifier.hash(state);
         self.skip.hash(state);
+        self.hyperlink.hash(state);
     }
 }
 
@@ -247,6 +365,8 @@ impl From<char> for Cell {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -262,6 +382,7 @@ mod tests {
                 underline_color: Color::Reset,
                 modifier: Modifier::empty(),
                 skip: false,
+                hyperlink: None,
             }
         );
     }
@@ -325,6 +446,17 @@ mod tests {
         assert!(cell.skip);
     }
 
+    #[test]
+    fn set_hyperlink() {
+        let mut cell = Cell::new("a");
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(cell.hyperlink(), Some("https://ratatui.rs"));
+        cell.set_symbol("b");
+        assert_eq!(cell.hyperlink(), Some("https://ratatui.rs"));
+        cell.set_hyperlink(None);
+        assert_eq!(cell.hyperlink(), None);
+    }
+
     #[test]
     fn reset() {
         let mut cell = Cell::EMPTY;
@@ -332,11 +464,13 @@ mod tests {
         cell.set_fg(Color::Red);
         cell.set_bg(Color::Blue);
         cell.set_skip(true);
+        cell.set_hyperlink(Some("https://ratatui.rs"));
         cell.reset();
         assert_eq!(cell.symbol(), " ");
         assert_eq!(cell.fg, Color::Reset);
         assert_eq!(cell.bg, Color::Reset);
         assert!(!cell.skip);
+        assert_eq!(cell.hyperlink(), None);
     }
 
     #[test]
@@ -374,4 +508,57 @@ mod tests {
         let cell2 = Cell::new("い");
         assert_ne!(cell1, cell2);
     }
+
+    #[test]
+    fn cell_ne_hyperlink() {
+        let cell1 = Cell::new("a");
+        let mut cell2 = Cell::new("a");
+        cell2.set_hyperlink(Some("https://ratatui.rs"));
+        assert_ne!(cell1, cell2);
+    }
+
+    #[test]
+    fn quantize_unchanged() {
+        let mut cell = Cell::new("a");
+        cell.set_fg(Color::Rgb(1, 2, 3));
+        assert!(matches!(
+            cell.quantize(ColorDepth::TrueColor),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            Cell::new("a").quantize(ColorDepth::Monochrome),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn quantize_ansi16() {
+        let mut cell = Cell::new("a");
+        cell.set_fg(Color::Indexed(9))
+            .set_bg(Color::Rgb(0, 0, 0))
+            .modifier = Modifier::ITALIC;
+        let quantized = cell.quantize(ColorDepth::Ansi16);
+        assert_eq!(quantized.symbol(), "a");
+        assert_eq!(quantized.fg, Color::LightRed);
+        assert_eq!(quantized.bg, Color::Black);
+        assert_eq!(quantized.modifier, Modifier::ITALIC);
+    }
+
+    #[rstest]
+    #[case::highlight(Style::new().black().on_cyan(), Modifier::REVERSED)]
+    #[case::dark_theme(Style::new().fg(Color::Rgb(229, 231, 235)).bg(Color::Rgb(3, 7, 18)), Modifier::empty())]
+    #[case::light_theme(Style::new().fg(Color::Rgb(3, 7, 18)).bg(Color::Rgb(229, 231, 235)), Modifier::REVERSED)]
+    #[case::background(Style::new().on_blue(), Modifier::REVERSED)]
+    #[case::background_bold(Style::new().on_blue().bold(), Modifier::REVERSED | Modifier::BOLD)]
+    #[case::foreground(Style::new().red().italic(), Modifier::BOLD | Modifier::ITALIC)]
+    #[case::reversed_highlight(Style::new().white().on_black().reversed(), Modifier::REVERSED)]
+    #[case::reversed_dark(Style::new().black().on_white().reversed(), Modifier::empty())]
+    fn quantize_monochrome(#[case] style: Style, #[case] expected: Modifier) {
+        let mut cell = Cell::new("a");
+        cell.set_style(style);
+        let quantized = cell.quantize(ColorDepth::Monochrome);
+        assert_eq!(quantized.fg, Color::Reset);
+        assert_eq!(quantized.bg, Color::Reset);
+        assert_eq!(quantized.modifier, expected);
+    }
 }
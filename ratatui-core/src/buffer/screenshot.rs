@@ -0,0 +1,769 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::buffer::{Buffer, Cell};
+use crate::style::{Color, Modifier};
+use crate::terminal::CompletedFrame;
+
+/// Renders the content of a [`Buffer`] as an SVG image or a standalone HTML page.
+///
+/// This is useful to generate screenshots of an application for documentation or bug reports
+/// without a real terminal, e.g. from a test that renders to a [`TestBackend`].
+///
+/// The foreground, background and underline colors of each cell and all [`Modifier`]s are
+/// rendered. Named and indexed colors use the default xterm palette, and [`Color::Reset`] uses the
+/// [default colors](Screenshot::default_colors) of the screenshot. Wide graphemes take the space of
+/// two cells, and cell hyperlinks are rendered as links.
+///
+/// In SVG images, each cell is placed on a grid of the configured [cell size](Screenshot::cell_size),
+/// so the layout does not depend on the font. HTML pages use the font for the width of the
+/// cells, and the cell height as the line height.
+///
+/// Raster formats such as PNG are not supported, as they would require rasterizing fonts. The SVG
+/// output can be converted to PNG with tools such as `resvg` or a browser.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::buffer::{Buffer, Screenshot};
+/// use ratatui_core::style::{Color, Stylize};
+/// use ratatui_core::text::Line;
+///
+/// let buffer = Buffer::with_lines([Line::from(vec!["Hello ".bold(), "world".red()])]);
+/// let svg = Screenshot::new(&buffer)
+///     .font_family("Fira Code, monospace")
+///     .cell_size(10, 20)
+///     .to_svg();
+/// assert!(svg.starts_with("<svg"));
+///
+/// let html = Screenshot::new(&buffer)
+///     .default_colors(Color::Black, Color::White)
+///     .to_html();
+/// assert!(html.starts_with("<!DOCTYPE html>"));
+/// ```
+///
+/// [`TestBackend`]: crate::backend::TestBackend
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Screenshot<'a> {
+    buffer: &'a Buffer,
+    font_family: Cow<'static, str>,
+    font_size: u16,
+    cell_width: u16,
+    cell_height: u16,
+    foreground: Color,
+    background: Color,
+}
+
+/// The resolved style of a cell
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct CellStyle {
+    fg: (u8, u8, u8),
+    bg: (u8, u8, u8),
+    underline: (u8, u8, u8),
+    modifier: Modifier,
+}
+
+/// Formats RGB components as a CSS hex color
+struct Hex((u8, u8, u8));
+
+/// Formats a string with the characters that are special in XML and HTML escaped
+struct Escape<'a>(&'a str);
+
+impl<'a> Screenshot<'a> {
+    /// The default font family, which prefers common monospace fonts
+    const DEFAULT_FONT_FAMILY: &'static str =
+        "ui-monospace, 'Cascadia Mono', 'DejaVu Sans Mono', Menlo, Consolas, monospace";
+
+    /// Creates a screenshot of the given buffer.
+    ///
+    /// The screenshot uses a 15px monospace font, 9x18px cells, and light gray text on a black
+    /// background for cells with [`Color::Reset`].
+    pub const fn new(buffer: &'a Buffer) -> Self {
+        Self {
+            buffer,
+            font_family: Cow::Borrowed(Self::DEFAULT_FONT_FAMILY),
+            font_size: 15,
+            cell_width: 9,
+            cell_height: 18,
+            foreground: Color::Rgb(229, 229, 229),
+            background: Color::Rgb(0, 0, 0),
+        }
+    }
+
+    /// Sets the CSS font family used to render the text.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn font_family<T: Into<Cow<'static, str>>>(mut self, font_family: T) -> Self {
+        self.font_family = font_family.into();
+        self
+    }
+
+    /// Sets the font size in pixels.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn font_size(mut self, font_size: u16) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Sets the width and height of a cell in pixels.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn cell_size(mut self, width: u16, height: u16) -> Self {
+        self.cell_width = width;
+        self.cell_height = height;
+        self
+    }
+
+    /// Sets the colors used for cells with a [`Color::Reset`] foreground or background.
+    ///
+    /// These are the colors that the terminal would use by default. If either color is
+    /// [`Color::Reset`] itself, white or black is used instead.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn default_colors(mut self, foreground: Color, background: Color) -> Self {
+        self.foreground = foreground;
+        self.background = background;
+        self
+    }
+
+    /// Renders the screenshot as an SVG image.
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        self.write_svg(&mut svg)
+            .expect("writing to a String cannot fail");
+        svg
+    }
+
+    /// Renders the screenshot as a standalone HTML page.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        self.write_html(&mut html)
+            .expect("writing to a String cannot fail");
+        html
+    }
+
+    /// Writes the screenshot as an SVG image.
+    pub fn write_svg<W: Write>(&self, out: &mut W) -> fmt::Result {
+        let area = self.buffer.area;
+        let (cell_width, cell_height) = (u32::from(self.cell_width), u32::from(self.cell_height));
+        let width = u32::from(area.width) * cell_width;
+        let height = u32::from(area.height) * cell_height;
+        let background = Hex(self.default_background());
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        )?;
+        writeln!(out, "<style>")?;
+        writeln!(
+            out,
+            "text {{ font-family: {}; font-size: {}px; white-space: pre; dominant-baseline: central; }}",
+            Escape(&self.font_family),
+            self.font_size
+        )?;
+        write_modifier_classes(out)?;
+        writeln!(out, "</style>")?;
+        writeln!(
+            out,
+            r#"<rect width="100%" height="100%" fill="{background}"/>"#
+        )?;
+
+        for y in area.top()..area.bottom() {
+            let top = u32::from(y - area.top()) * cell_height;
+            // backgrounds are merged into runs of cells with the same color
+            let mut runs: Vec<(u16, u16, (u8, u8, u8))> = Vec::new();
+            for (x, width, cell) in self.line_cells(y) {
+                let bg = self.cell_style(cell).bg;
+                match runs.last_mut() {
+                    Some((_, len, color)) if *color == bg => *len += width,
+                    _ => runs.push((x, width, bg)),
+                }
+            }
+            for (start, len, color) in runs {
+                if color != self.default_background() {
+                    writeln!(
+                        out,
+                        r#"<rect x="{}" y="{top}" width="{}" height="{cell_height}" fill="{}"/>"#,
+                        u32::from(start - area.left()) * cell_width,
+                        u32::from(len) * cell_width,
+                        Hex(color),
+                    )?;
+                }
+            }
+        }
+
+        for y in area.top()..area.bottom() {
+            let top = u32::from(y - area.top()) * cell_height;
+            for (x, width, cell) in self.line_cells(y) {
+                if cell.skip {
+                    continue;
+                }
+                let style = self.cell_style(cell);
+                // blank cells have no text, but still show their lines
+                let blank = cell.symbol().trim().is_empty();
+                let lines = Modifier::ANY_UNDERLINE | Modifier::OVERLINED | Modifier::CROSSED_OUT;
+                if blank && !style.modifier.intersects(lines) {
+                    continue;
+                }
+                let left = u32::from(x - area.left()) * cell_width;
+                let width = u32::from(width) * cell_width;
+                if let Some(link) = cell.hyperlink() {
+                    write!(out, r#"<a href="{}">"#, Escape(link))?;
+                }
+                if !blank && !style.modifier.contains(Modifier::HIDDEN) {
+                    write!(
+                        out,
+                        r#"<text x="{left}" y="{}" fill="{}""#,
+                        top + cell_height / 2,
+                        Hex(style.fg)
+                    )?;
+                    write_class(out, style.modifier)?;
+                    writeln!(out, ">{}</text>", Escape(cell.symbol()))?;
+                }
+                write_underline(
+                    out,
+                    style,
+                    left,
+                    (top + cell_height).saturating_sub(2),
+                    width,
+                )?;
+                if style.modifier.contains(Modifier::OVERLINED) {
+                    writeln!(
+                        out,
+                        r#"<rect x="{left}" y="{top}" width="{width}" height="1" fill="{}"/>"#,
+                        Hex(style.fg)
+                    )?;
+                }
+                if style.modifier.contains(Modifier::CROSSED_OUT) {
+                    writeln!(
+                        out,
+                        r#"<rect x="{left}" y="{}" width="{width}" height="1" fill="{}"/>"#,
+                        top + cell_height / 2,
+                        Hex(style.fg)
+                    )?;
+                }
+                if cell.hyperlink().is_some() {
+                    writeln!(out, "</a>")?;
+                }
+            }
+        }
+        writeln!(out, "</svg>")
+    }
+
+    /// Writes the screenshot as a standalone HTML page.
+    pub fn write_html<W: Write>(&self, out: &mut W) -> fmt::Result {
+        let area = self.buffer.area;
+        writeln!(out, "<!DOCTYPE html>")?;
+        writeln!(out, "<html>")?;
+        writeln!(out, "<head>")?;
+        writeln!(out, r#"<meta charset="utf-8">"#)?;
+        writeln!(out, "<style>")?;
+        writeln!(
+            out,
+            "pre {{ display: inline-block; margin: 0; font-family: {}; font-size: {}px; line-height: {}px; color: {}; background: {}; }}",
+            Escape(&self.font_family),
+            self.font_size,
+            self.cell_height,
+            Hex(self.default_foreground()),
+            Hex(self.default_background()),
+        )?;
+        writeln!(out, "a {{ color: inherit; }}")?;
+        write_modifier_classes(out)?;
+        writeln!(out, "</style>")?;
+        writeln!(out, "</head>")?;
+        writeln!(out, "<body>")?;
+        write!(out, "<pre>")?;
+        for y in area.top()..area.bottom() {
+            if y > area.top() {
+                writeln!(out)?;
+            }
+            // cells are merged into spans of cells with the same style and hyperlink
+            let mut span: Option<(CellStyle, Option<&str>)> = None;
+            for (_, _, cell) in self.line_cells(y) {
+                let current = (self.cell_style(cell), cell.hyperlink());
+                if span != Some(current) {
+                    if let Some(previous) = span {
+                        close_span(out, previous.1)?;
+                    }
+                    self.open_span(out, current.0, current.1)?;
+                    span = Some(current);
+                }
+                let symbol = if cell.skip { " " } else { cell.symbol() };
+                write!(out, "{}", Escape(symbol))?;
+            }
+            if let Some(previous) = span {
+                close_span(out, previous.1)?;
+            }
+        }
+        writeln!(out, "</pre>")?;
+        writeln!(out, "</body>")?;
+        writeln!(out, "</html>")
+    }
+
+    /// Writes the opening tags of a span of cells with the same style
+    fn open_span<W: Write>(
+        &self,
+        out: &mut W,
+        style: CellStyle,
+        link: Option<&str>,
+    ) -> fmt::Result {
+        if let Some(link) = link {
+            write!(out, r#"<a href="{}">"#, Escape(link))?;
+        }
+        write!(out, r#"<span style="color: {}"#, Hex(style.fg))?;
+        if style.bg != self.default_background() {
+            write!(out, "; background: {}", Hex(style.bg))?;
+        }
+        let underline = style.modifier.underline_style();
+        let overlined = style.modifier.contains(Modifier::OVERLINED);
+        let crossed_out = style.modifier.contains(Modifier::CROSSED_OUT);
+        if !underline.is_empty() || overlined || crossed_out {
+            write!(out, "; text-decoration-line:")?;
+            if !underline.is_empty() {
+                write!(out, " underline")?;
+            }
+            if overlined {
+                write!(out, " overline")?;
+            }
+            if crossed_out {
+                write!(out, " line-through")?;
+            }
+        }
+        let decoration_style = match underline {
+            Modifier::DOUBLE_UNDERLINED => "double",
+            Modifier::CURLY_UNDERLINED => "wavy",
+            Modifier::DOTTED_UNDERLINED => "dotted",
+            Modifier::DASHED_UNDERLINED => "dashed",
+            _ => "",
+        };
+        if !decoration_style.is_empty() {
+            write!(out, "; text-decoration-style: {decoration_style}")?;
+        }
+        if !underline.is_empty() {
+            write!(out, "; text-decoration-color: {}", Hex(style.underline))?;
+        }
+        write!(out, "\"")?;
+        write_class(out, style.modifier)?;
+        write!(out, ">")
+    }
+
+    /// Returns the cells of a line with their column and width in cells.
+    ///
+    /// The cells that are covered by a preceding wide grapheme are left out.
+    fn line_cells(&self, y: u16) -> impl Iterator<Item = (u16, u16, &Cell)> {
+        let area = self.buffer.area;
+        let mut x = area.left();
+        core::iter::from_fn(move || {
+            if x >= area.right() {
+                return None;
+            }
+            let cell = &self.buffer[(x, y)];
+            let width = u16::try_from(cell.symbol().width())
+                .unwrap_or(u16::MAX)
+                .clamp(1, area.right() - x);
+            let item = (x, width, cell);
+            x += width;
+            Some(item)
+        })
+    }
+
+    /// Resolves the colors of a cell, taking the default colors and modifiers into account
+    fn cell_style(&self, cell: &Cell) -> CellStyle {
+        let mut fg = cell
+            .fg
+            .to_rgb()
+            .unwrap_or_else(|| self.default_foreground());
+        let mut bg = cell
+            .bg
+            .to_rgb()
+            .unwrap_or_else(|| self.default_background());
+        if cell.modifier.contains(Modifier::REVERSED) {
+            core::mem::swap(&mut fg, &mut bg);
+        }
+        #[cfg(feature = "underline-color")]
+        let underline = cell.underline_color.to_rgb().unwrap_or(fg);
+        #[cfg(not(feature = "underline-color"))]
+        let underline = fg;
+        CellStyle {
+            fg,
+            bg,
+            underline,
+            // reversed is already applied to the colors
+            modifier: cell.modifier - Modifier::REVERSED,
+        }
+    }
+
+    fn default_foreground(&self) -> (u8, u8, u8) {
+        self.foreground.to_rgb().unwrap_or((255, 255, 255))
+    }
+
+    fn default_background(&self) -> (u8, u8, u8) {
+        self.background.to_rgb().unwrap_or((0, 0, 0))
+    }
+}
+
+impl<'a> From<&'a Buffer> for Screenshot<'a> {
+    fn from(buffer: &'a Buffer) -> Self {
+        Self::new(buffer)
+    }
+}
+
+impl<'a> From<&CompletedFrame<'a>> for Screenshot<'a> {
+    fn from(frame: &CompletedFrame<'a>) -> Self {
+        Self::new(frame.buffer)
+    }
+}
+
+/// Writes the CSS classes for the modifiers that are not rendered with colors or decorations
+fn write_modifier_classes<W: Write>(out: &mut W) -> fmt::Result {
+    writeln!(out, ".bold {{ font-weight: bold; }}")?;
+    writeln!(out, ".dim {{ opacity: 0.5; }}")?;
+    writeln!(out, ".italic {{ font-style: italic; }}")?;
+    writeln!(out, ".hidden {{ visibility: hidden; }}")?;
+    writeln!(
+        out,
+        ".slow-blink {{ animation: blink 1s step-end infinite; }}"
+    )?;
+    writeln!(
+        out,
+        ".rapid-blink {{ animation: blink 0.25s step-end infinite; }}"
+    )?;
+    writeln!(out, "@keyframes blink {{ 50% {{ opacity: 0; }} }}")
+}
+
+/// Writes the underline of a cell in the SVG format, with its top at the given `y`
+fn write_underline<W: Write>(
+    out: &mut W,
+    style: CellStyle,
+    left: u32,
+    y: u32,
+    width: u32,
+) -> fmt::Result {
+    let color = Hex(style.underline);
+    let dasharray = match style.modifier.underline_style() {
+        Modifier::UNDERLINED => {
+            return writeln!(
+                out,
+                r#"<rect x="{left}" y="{y}" width="{width}" height="1" fill="{color}"/>"#
+            );
+        }
+        Modifier::DOUBLE_UNDERLINED => {
+            for y in [y.saturating_sub(2), y] {
+                writeln!(
+                    out,
+                    r#"<rect x="{left}" y="{y}" width="{width}" height="1" fill="{color}"/>"#
+                )?;
+            }
+            return Ok(());
+        }
+        Modifier::CURLY_UNDERLINED => {
+            // a wave made of quadratic curves that are 2px wide
+            write!(out, r#"<path d="M{left} {y}.5 q1 -2 2 0"#)?;
+            for _ in 1..width / 2 {
+                write!(out, " t2 0")?;
+            }
+            return writeln!(out, r#"" fill="none" stroke="{color}"/>"#);
+        }
+        Modifier::DOTTED_UNDERLINED => "1 1",
+        Modifier::DASHED_UNDERLINED => "3 2",
+        _ => return Ok(()),
+    };
+    writeln!(
+        out,
+        r#"<line x1="{left}" y1="{y}.5" x2="{}" y2="{y}.5" stroke="{color}" stroke-dasharray="{dasharray}"/>"#,
+        left + width
+    )
+}
+
+/// Writes the `class` attribute for the modifiers of a cell, if any
+fn write_class<W: Write>(out: &mut W, modifier: Modifier) -> fmt::Result {
+    const CLASSES: [(Modifier, &str); 6] = [
+        (Modifier::BOLD, "bold"),
+        (Modifier::DIM, "dim"),
+        (Modifier::ITALIC, "italic"),
+        (Modifier::HIDDEN, "hidden"),
+        (Modifier::SLOW_BLINK, "slow-blink"),
+        (Modifier::RAPID_BLINK, "rapid-blink"),
+    ];
+    let mut classes = CLASSES
+        .iter()
+        .filter(|(flag, _)| modifier.contains(*flag))
+        .map(|(_, class)| class);
+    if let Some(first) = classes.next() {
+        write!(out, r#" class="{first}"#)?;
+        for class in classes {
+            write!(out, " {class}")?;
+        }
+        write!(out, "\"")?;
+    }
+    Ok(())
+}
+
+/// Writes the closing tags of a span of cells
+fn close_span<W: Write>(out: &mut W, link: Option<&str>) -> fmt::Result {
+    write!(out, "</span>")?;
+    if link.is_some() {
+        write!(out, "</a>")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (r, g, b) = self.0;
+        write!(f, "#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+impl fmt::Display for Escape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&#39;")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::layout::Rect;
+    use crate::style::{Style, Stylize};
+    use crate::terminal::FrameStats;
+    use crate::text::Line;
+
+    #[test]
+    fn svg_size() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let svg = Screenshot::new(&buffer).cell_size(10, 20).to_svg();
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="40" viewBox="0 0 40 40">"#
+        ));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn svg_text() {
+        let buffer = Buffer::with_lines([Line::from(vec!["a".red(), " ".into(), "<".bold()])]);
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r##"<text x="0" y="9" fill="#cd0000">a</text>"##));
+        assert!(svg.contains(r##"<text x="18" y="9" fill="#e5e5e5" class="bold">&lt;</text>"##));
+        // spaces are not drawn
+        assert_eq!(svg.matches("<text").count(), 2);
+    }
+
+    #[test]
+    fn svg_background_runs() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        buffer.set_style(Rect::new(1, 0, 3, 1), Style::new().on_blue());
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r##"<rect x="9" y="0" width="27" height="18" fill="#0000ee"/>"##));
+        assert_eq!(svg.matches("<rect").count(), 2);
+    }
+
+    #[test]
+    fn svg_reversed() {
+        let buffer = Buffer::with_lines([Line::from("x".red().on_green().reversed())]);
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r##"fill="#cd0000"/>"##));
+        assert!(svg.contains(r##"<text x="0" y="9" fill="#00cd00">x</text>"##));
+    }
+
+    #[test]
+    fn svg_decorations() {
+        let buffer = Buffer::with_lines([Line::from(vec![
+            "u".underlined(),
+            "c".crossed_out(),
+            "h".hidden(),
+        ])]);
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r##"<rect x="0" y="16" width="9" height="1" fill="#e5e5e5"/>"##));
+        assert!(svg.contains(r##"<rect x="9" y="9" width="9" height="1" fill="#e5e5e5"/>"##));
+        assert!(!svg.contains(">h</text>"));
+    }
+
+    #[test]
+    fn svg_extended_decorations() {
+        let buffer = Buffer::with_lines([Line::from(vec![
+            "d".double_underlined(),
+            "c".curly_underlined(),
+            "o".dotted_underlined(),
+            "a".dashed_underlined(),
+            "v".overlined(),
+        ])]);
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r##"<rect x="0" y="14" width="9" height="1" fill="#e5e5e5"/>"##));
+        assert!(svg.contains(r##"<rect x="0" y="16" width="9" height="1" fill="#e5e5e5"/>"##));
+        assert!(svg.contains(
+            r##"<path d="M9 16.5 q1 -2 2 0 t2 0 t2 0 t2 0" fill="none" stroke="#e5e5e5"/>"##
+        ));
+        assert!(svg.contains(
+            r##"<line x1="18" y1="16.5" x2="27" y2="16.5" stroke="#e5e5e5" stroke-dasharray="1 1"/>"##
+        ));
+        assert!(svg.contains(
+            r##"<line x1="27" y1="16.5" x2="36" y2="16.5" stroke="#e5e5e5" stroke-dasharray="3 2"/>"##
+        ));
+        assert!(svg.contains(r##"<rect x="36" y="0" width="9" height="1" fill="#e5e5e5"/>"##));
+    }
+
+    #[test]
+    fn svg_decorated_blank_cells() {
+        let buffer = Buffer::with_lines([Line::from(vec![
+            " ".underlined(),
+            " ".crossed_out(),
+            " ".overlined(),
+        ])]);
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r##"<rect x="0" y="16" width="9" height="1" fill="#e5e5e5"/>"##));
+        assert!(svg.contains(r##"<rect x="9" y="9" width="9" height="1" fill="#e5e5e5"/>"##));
+        assert!(svg.contains(r##"<rect x="18" y="0" width="9" height="1" fill="#e5e5e5"/>"##));
+        assert!(!svg.contains("<text"));
+    }
+
+    #[test]
+    fn svg_small_cells() {
+        let buffer =
+            Buffer::with_lines([Line::from(vec!["u".underlined(), "d".double_underlined()])]);
+        let svg = Screenshot::new(&buffer).cell_size(1, 1).to_svg();
+        assert!(svg.contains(r##"<rect x="0" y="0" width="1" height="1" fill="#e5e5e5"/>"##));
+        assert_eq!(
+            svg.matches(r##"<rect x="1" y="0" width="1" height="1" fill="#e5e5e5"/>"##)
+                .count(),
+            2
+        );
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn svg_underline_color() {
+        let buffer = Buffer::with_lines([Line::from(crate::text::Span::styled(
+            "u",
+            Style::new().underlined().underline_color(Color::Blue),
+        ))]);
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r##"<rect x="0" y="16" width="9" height="1" fill="#0000ee"/>"##));
+    }
+
+    #[test]
+    fn svg_wide_grapheme() {
+        let buffer = Buffer::with_lines([Line::from("称x").underlined()]);
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r##"<text x="0" y="9" fill="#e5e5e5">称</text>"##));
+        assert!(svg.contains(r##"<text x="18" y="9" fill="#e5e5e5">x</text>"##));
+        assert!(svg.contains(r#"<rect x="0" y="16" width="18" height="1""#));
+    }
+
+    #[test]
+    fn svg_hyperlink() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buffer[(0, 0)]
+            .set_symbol("l")
+            .set_hyperlink(Some("https://ratatui.rs/?a=1&b=2"));
+        let svg = Screenshot::new(&buffer).to_svg();
+        assert!(svg.contains(r#"<a href="https://ratatui.rs/?a=1&amp;b=2"><text"#));
+    }
+
+    #[test]
+    fn svg_font() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        let svg = Screenshot::new(&buffer)
+            .font_family("Fira Code")
+            .font_size(12)
+            .to_svg();
+        assert!(svg.contains("font-family: Fira Code; font-size: 12px;"));
+    }
+
+    #[test]
+    fn html() {
+        let buffer = Buffer::with_lines([
+            Line::from(vec!["ab".red(), "c".into()]),
+            Line::from("d".on_blue().italic()),
+        ]);
+        let html = Screenshot::new(&buffer)
+            .default_colors(Color::Black, Color::White)
+            .to_html();
+        let pre = html
+            .lines()
+            .skip_while(|line| !line.starts_with("<pre>"))
+            .take(2)
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            pre,
+            [
+                r#"<pre><span style="color: #cd0000">ab</span><span style="color: #000000">c</span>"#,
+                r#"<span style="color: #000000; background: #0000ee" class="italic">d</span><span style="color: #000000">  </span></pre>"#,
+            ]
+            .join("\n")
+        );
+        assert!(html.contains("color: #000000; background: #ffffff;"));
+    }
+
+    #[test]
+    fn html_decorations() {
+        let buffer = Buffer::with_lines([Line::from("x".underlined().crossed_out().green())]);
+        let html = Screenshot::new(&buffer).to_html();
+        assert!(html.contains(
+            r#"<span style="color: #00cd00; text-decoration-line: underline line-through; text-decoration-color: #00cd00">x</span>"#
+        ));
+    }
+
+    #[test]
+    fn html_extended_decorations() {
+        let buffer = Buffer::with_lines([Line::from("x".curly_underlined().overlined().green())]);
+        let html = Screenshot::new(&buffer).to_html();
+        assert!(html.contains(
+            r#"<span style="color: #00cd00; text-decoration-line: underline overline; text-decoration-style: wavy; text-decoration-color: #00cd00">x</span>"#
+        ));
+    }
+
+    #[test]
+    fn html_wide_grapheme_and_hyperlink() {
+        let mut buffer = Buffer::with_lines(["称x"]);
+        buffer[(2, 0)].set_hyperlink(Some("https://ratatui.rs"));
+        let html = Screenshot::new(&buffer).to_html();
+        assert!(html.contains(
+            r#"<pre><span style="color: #e5e5e5">称</span><a href="https://ratatui.rs"><span style="color: #e5e5e5">x</span></a></pre>"#
+        ));
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(
+            Escape(r#"<a href="x">'&'</a>"#).to_string(),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn from_completed_frame() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        let frame = CompletedFrame {
+            buffer: &buffer,
+            area: buffer.area,
+            count: 0,
+            stats: FrameStats::default(),
+        };
+        assert_eq!(Screenshot::from(&frame), Screenshot::new(&buffer));
+    }
+}
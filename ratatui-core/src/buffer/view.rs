@@ -0,0 +1,294 @@
+use alloc::vec::Vec;
+use core::{fmt, mem};
+
+use crate::buffer::{Buffer, Cell};
+use crate::layout::{Position, Rect};
+use crate::style::{Style, Theme};
+use crate::widgets::{StatefulWidget, Widget};
+
+/// A mutable view of an area of a [`Buffer`], as returned by [`Buffer::split_mut`].
+///
+/// The views of a buffer cover areas that do not overlap, so they can be written to at the same
+/// time, e.g. to render independent panels on separate threads. A view uses the coordinates of the
+/// buffer, and ignores the writes outside of its area.
+///
+/// Widgets are rendered to a view with [`BufferView::render_widget`], which renders them to a
+/// buffer that covers only the area of the view.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::buffer::Buffer;
+/// use ratatui_core::layout::{Constraint, Layout, Rect};
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+/// let areas = Layout::horizontal([Constraint::Fill(1); 2]).split(buffer.area);
+/// let views = buffer.split_mut(&areas).unwrap();
+/// std::thread::scope(|scope| {
+///     for (mut view, text) in views.into_iter().zip(["left", "right"]) {
+///         scope.spawn(move || view.render_widget(text));
+///     }
+/// });
+/// assert_eq!(buffer, Buffer::with_lines(["left      right     "]));
+/// ```
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct BufferView<'a> {
+    /// The area of the buffer covered by the view
+    area: Rect,
+    /// The cells of each row of the area
+    rows: Vec<&'a mut [Cell]>,
+    /// The theme of the buffer, used by the widgets rendered to the view
+    theme: Theme,
+}
+
+/// Error returned by [`Buffer::split_mut`] when two of the areas overlap.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct OverlappingAreasError {
+    /// The indices of the two areas that overlap
+    pub indices: (usize, usize),
+}
+
+impl fmt::Display for OverlappingAreasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (first, second) = self.indices;
+        write!(f, "areas {first} and {second} of the buffer overlap")
+    }
+}
+
+impl core::error::Error for OverlappingAreasError {}
+
+impl<'a> BufferView<'a> {
+    /// Splits the buffer into a view for each area, clipped to the area of the buffer.
+    pub(super) fn split(
+        buffer: &'a mut Buffer,
+        areas: &[Rect],
+    ) -> Result<Vec<Self>, OverlappingAreasError> {
+        let areas: Vec<Rect> = areas
+            .iter()
+            .map(|area| buffer.area.intersection(*area))
+            .collect();
+        for (first, area) in areas.iter().enumerate() {
+            let overlapping = areas[first + 1..]
+                .iter()
+                .position(|other| !area.is_empty() && !other.is_empty() && area.intersects(*other));
+            if let Some(offset) = overlapping {
+                return Err(OverlappingAreasError {
+                    indices: (first, first + 1 + offset),
+                });
+            }
+        }
+
+        let mut views: Vec<Self> = areas
+            .iter()
+            .map(|&area| Self {
+                area,
+                rows: Vec::with_capacity(usize::from(area.height)),
+                theme: buffer.theme,
+            })
+            .collect();
+        if buffer.area.is_empty() {
+            return Ok(views);
+        }
+        // each row of the buffer is split from left to right between the areas that cover it
+        let mut from_left: Vec<usize> = (0..areas.len())
+            .filter(|&index| !areas[index].is_empty())
+            .collect();
+        from_left.sort_by_key(|&index| areas[index].x);
+        let width = usize::from(buffer.area.width);
+        let rows = buffer.content.chunks_exact_mut(width);
+        for (y, mut rest) in (buffer.area.top()..buffer.area.bottom()).zip(rows) {
+            let mut x = buffer.area.left();
+            for &index in &from_left {
+                let area = areas[index];
+                if y < area.top() || y >= area.bottom() {
+                    continue;
+                }
+                let (_, cells) = mem::take(&mut rest).split_at_mut(usize::from(area.x - x));
+                let (cells, remaining) = cells.split_at_mut(usize::from(area.width));
+                views[index].rows.push(cells);
+                rest = remaining;
+                x = area.right();
+            }
+        }
+        Ok(views)
+    }
+
+    /// Returns the area of the buffer covered by the view.
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns a reference to the [`Cell`] at the given position of the buffer, or [`None`] if the
+    /// position is outside the area of the view.
+    #[must_use]
+    pub fn cell<P: Into<Position>>(&self, position: P) -> Option<&Cell> {
+        let position = position.into();
+        if !self.area.contains(position) {
+            return None;
+        }
+        let row = &self.rows[usize::from(position.y - self.area.y)];
+        row.get(usize::from(position.x - self.area.x))
+    }
+
+    /// Returns a mutable reference to the [`Cell`] at the given position of the buffer, or
+    /// [`None`] if the position is outside the area of the view.
+    #[must_use]
+    pub fn cell_mut<P: Into<Position>>(&mut self, position: P) -> Option<&mut Cell> {
+        let position = position.into();
+        if !self.area.contains(position) {
+            return None;
+        }
+        let row = &mut self.rows[usize::from(position.y - self.area.y)];
+        row.get_mut(usize::from(position.x - self.area.x))
+    }
+
+    /// Sets the style of all cells of the given area that are inside the view.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn set_style<S: Into<Style>>(&mut self, area: Rect, style: S) {
+        let style = style.into();
+        let area = self.area.intersection(area);
+        for position in area.positions() {
+            if let Some(cell) = self.cell_mut(position) {
+                cell.set_style(style);
+            }
+        }
+    }
+
+    /// Renders a widget to the area of the view.
+    ///
+    /// The widget is rendered to a buffer that covers the area of the view and holds its cells, so
+    /// it cannot write outside of the view.
+    pub fn render_widget<W: Widget>(&mut self, widget: W) {
+        let mut buffer = self.take_buffer();
+        widget.render(self.area, &mut buffer);
+        self.restore_buffer(buffer);
+    }
+
+    /// Renders a [`StatefulWidget`] to the area of the view.
+    ///
+    /// The widget is rendered to a buffer that covers the area of the view and holds its cells, so
+    /// it cannot write outside of the view.
+    pub fn render_stateful_widget<W: StatefulWidget>(&mut self, widget: W, state: &mut W::State) {
+        let mut buffer = self.take_buffer();
+        widget.render(self.area, &mut buffer, state);
+        self.restore_buffer(buffer);
+    }
+
+    /// Moves the cells of the view to a buffer that covers its area
+    fn take_buffer(&mut self) -> Buffer {
+        let content = self
+            .rows
+            .iter_mut()
+            .flat_map(|row| row.iter_mut().map(mem::take))
+            .collect();
+        Buffer {
+            area: self.area,
+            content,
+            theme: self.theme,
+        }
+    }
+
+    /// Moves the cells of a buffer taken with [`Self::take_buffer`] back to the view
+    fn restore_buffer(&mut self, buffer: Buffer) {
+        let cells = self.rows.iter_mut().flat_map(|row| row.iter_mut());
+        for (cell, rendered) in cells.zip(buffer.content) {
+            *cell = rendered;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::style::{Color, Stylize};
+    use crate::text::Line;
+
+    #[test]
+    fn split_mut() {
+        let mut buffer = Buffer::with_lines(["abcdef", "ghijkl", "mnopqr"]);
+        let areas = [Rect::new(3, 0, 3, 2), Rect::new(0, 0, 2, 3)];
+        let mut views = buffer.split_mut(&areas).unwrap();
+        assert_eq!(views[0].area(), areas[0]);
+        assert_eq!(views[0].cell((4, 1)).map(Cell::symbol), Some("k"));
+        assert_eq!(views[1].cell((1, 2)).map(Cell::symbol), Some("n"));
+        // writes outside of the view are ignored
+        assert_eq!(views[0].cell((2, 0)), None);
+        assert_eq!(views[1].cell_mut((2, 2)), None);
+        views[0].cell_mut((3, 0)).unwrap().set_symbol("X");
+        views[1].cell_mut((0, 2)).unwrap().set_symbol("Y");
+        assert_eq!(buffer, Buffer::with_lines(["abcXef", "ghijkl", "Ynopqr"]));
+    }
+
+    #[test]
+    fn split_mut_clips_to_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(2, 2, 4, 4));
+        let views = buffer
+            .split_mut(&[Rect::new(0, 0, 4, 4), Rect::new(5, 5, 10, 10), Rect::ZERO])
+            .unwrap();
+        let areas: Vec<Rect> = views.iter().map(BufferView::area).collect();
+        assert_eq!(
+            areas,
+            [
+                Rect::new(2, 2, 2, 2),
+                Rect::new(5, 5, 1, 1),
+                Rect::new(2, 2, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn split_mut_overlapping() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let areas = [
+            Rect::new(0, 0, 5, 5),
+            Rect::new(5, 0, 5, 5),
+            Rect::new(4, 4, 2, 2),
+        ];
+        let error = buffer.split_mut(&areas).unwrap_err();
+        assert_eq!(error, OverlappingAreasError { indices: (0, 2) });
+        assert_eq!(error.to_string(), "areas 0 and 2 of the buffer overlap");
+    }
+
+    #[test]
+    fn render_widget() {
+        let mut buffer = Buffer::with_lines(["xxxxxxxx", "xxxxxxxx"]);
+        let areas = [Rect::new(0, 0, 3, 2), Rect::new(4, 1, 4, 1)];
+        let mut views = buffer.split_mut(&areas).unwrap();
+        views[0].render_widget(Line::from("hello").red());
+        views[1].render_widget("hi");
+        views[1].set_style(Rect::new(0, 0, 5, 5), Color::Blue);
+        let mut expected = Buffer::with_lines(["helxxxxx", "xxxxhixx"]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Color::Red);
+        expected.set_style(Rect::new(4, 1, 1, 1), Color::Blue);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn render_in_threads() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        let areas = [
+            Rect::new(0, 0, 2, 2),
+            Rect::new(2, 0, 2, 2),
+            Rect::new(0, 2, 2, 2),
+            Rect::new(2, 2, 2, 2),
+        ];
+        let views = buffer.split_mut(&areas).unwrap();
+        std::thread::scope(|scope| {
+            for (mut view, symbol) in views.into_iter().zip(["a", "b", "c", "d"]) {
+                scope.spawn(move || {
+                    for position in view.area().positions() {
+                        view.cell_mut(position).unwrap().set_symbol(symbol);
+                    }
+                });
+            }
+        });
+        assert_eq!(buffer, Buffer::with_lines(["aabb", "aabb", "ccdd", "ccdd"]));
+    }
+}
@@ -0,0 +1,182 @@
+#![warn(missing_docs)]
+//! A backend-agnostic model of terminal input events.
+//!
+//! Each terminal library has its own types for input events, so an application written against
+//! the events of one backend has to rewrite its event handling to switch to another backend. This
+//! module provides neutral types that the backend crates convert their events to:
+//!
+//! - [`Event`]: an input event, which is a key press, mouse action, resize, paste or focus change
+//! - [`KeyEvent`], [`KeyCode`] and [`KeyModifiers`]: a key press with its modifiers
+//! - [`MouseEvent`], [`MouseEventKind`] and [`MouseButton`]: a mouse action at a position
+//!
+//! Events are read with an [`EventReader`], which is implemented by each backend (or a companion
+//! type of the backend), and by [`TestBackend`] for testing. The conversions from the events of
+//! each terminal library are available as `FromCrossterm`, `FromTermion` and `FromTermwiz`
+//! implementations in the backend crates.
+//!
+//! Not every terminal library reports every kind of event. For example, termion does not report
+//! resizes, pastes, focus changes or key releases, and termwiz reports the mouse buttons that are
+//! held rather than presses and releases. See the documentation of each conversion for details.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui_core::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+//!
+//! fn should_quit(event: &Event) -> bool {
+//!     match event.as_key_press_event() {
+//!         Some(key) => {
+//!             key.code == KeyCode::Char('q')
+//!                 || key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)
+//!         }
+//!         None => false,
+//!     }
+//! }
+//!
+//! assert!(should_quit(&Event::Key(KeyEvent::from(KeyCode::Char('q')))));
+//! ```
+//!
+//! [`TestBackend`]: crate::backend::TestBackend
+
+use alloc::string::String;
+use core::time::Duration;
+
+use crate::layout::Size;
+
+mod key;
+mod mouse;
+
+pub use key::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MediaKeyCode, ModifierKeyCode};
+pub use mouse::{MouseButton, MouseEvent, MouseEventKind};
+
+/// A terminal input event.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// A key was pressed, repeated or released.
+    Key(KeyEvent),
+    /// A mouse button was pressed, released or dragged, the mouse moved, or the wheel scrolled.
+    Mouse(MouseEvent),
+    /// The terminal was resized to the given size in columns and rows.
+    Resize(Size),
+    /// Text was pasted while bracketed paste was enabled.
+    Paste(String),
+    /// The terminal gained focus.
+    FocusGained,
+    /// The terminal lost focus.
+    FocusLost,
+}
+
+impl Event {
+    /// Returns the key event if this is a key event of any kind.
+    pub const fn as_key_event(&self) -> Option<KeyEvent> {
+        match self {
+            Self::Key(key) => Some(*key),
+            _ => None,
+        }
+    }
+
+    /// Returns the key event if this is a key press.
+    ///
+    /// Key repeats and releases, which are only reported by some terminals, are ignored. This is
+    /// usually what an application wants to react to.
+    pub const fn as_key_press_event(&self) -> Option<KeyEvent> {
+        match self {
+            Self::Key(key) if key.is_press() => Some(*key),
+            _ => None,
+        }
+    }
+
+    /// Returns the mouse event if this is a mouse event.
+    pub const fn as_mouse_event(&self) -> Option<MouseEvent> {
+        match self {
+            Self::Mouse(mouse) => Some(*mouse),
+            _ => None,
+        }
+    }
+}
+
+impl From<KeyEvent> for Event {
+    fn from(key: KeyEvent) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<MouseEvent> for Event {
+    fn from(mouse: MouseEvent) -> Self {
+        Self::Mouse(mouse)
+    }
+}
+
+/// A source of [`Event`]s that can be polled.
+///
+/// This trait is implemented by the backends, or by a companion type when the backend does not
+/// own the input of the terminal, so that an application can read input without depending on a
+/// particular terminal library.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::backend::TestBackend;
+/// use ratatui_core::event::{Event, EventReader, KeyCode, KeyEvent};
+///
+/// let mut backend = TestBackend::new(10, 2);
+/// backend.push_event(KeyEvent::from(KeyCode::Enter));
+///
+/// let event = backend.read_event()?;
+/// assert_eq!(event, Event::Key(KeyEvent::from(KeyCode::Enter)));
+/// assert_eq!(backend.poll_event(None)?, None);
+/// # Result::<(), core::convert::Infallible>::Ok(())
+/// ```
+pub trait EventReader {
+    /// Error type associated with reading events.
+    type Error: core::error::Error;
+
+    /// Waits for the next event.
+    ///
+    /// Returns `None` if no event was available within the timeout. If the timeout is `None`,
+    /// waits until an event is available, unless the reader knows that no more events can arrive
+    /// (e.g. the queued events of a [`TestBackend`]), in which case it returns `None` as well.
+    ///
+    /// [`TestBackend`]: crate::backend::TestBackend
+    fn poll_event(&mut self, timeout: Option<Duration>) -> Result<Option<Event>, Self::Error>;
+
+    /// Waits until an event is available and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the reader reports that no more events can arrive.
+    fn read_event(&mut self) -> Result<Event, Self::Error> {
+        Ok(self
+            .poll_event(None)?
+            .expect("the event reader has no more events"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_key_event() {
+        let release = KeyEvent::from(KeyCode::Esc).kind(KeyEventKind::Release);
+        assert_eq!(Event::Key(release).as_key_event(), Some(release));
+        assert_eq!(Event::FocusGained.as_key_event(), None);
+    }
+
+    #[test]
+    fn as_key_press_event() {
+        let press = KeyEvent::from(KeyCode::Esc);
+        let release = press.kind(KeyEventKind::Release);
+        assert_eq!(Event::Key(press).as_key_press_event(), Some(press));
+        assert_eq!(Event::Key(release).as_key_press_event(), None);
+        assert_eq!(Event::Resize(Size::new(1, 1)).as_key_press_event(), None);
+    }
+
+    #[test]
+    fn as_mouse_event() {
+        let mouse = MouseEvent::new(MouseEventKind::Moved, crate::layout::Position::new(1, 2));
+        assert_eq!(Event::from(mouse).as_mouse_event(), Some(mouse));
+        assert_eq!(Event::FocusLost.as_mouse_event(), None);
+    }
+}
@@ -0,0 +1,261 @@
+use bitflags::bitflags;
+
+/// A key event, which is a key code with the modifiers that were held and the kind of event.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+///
+/// let key = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+/// assert!(key.is_press());
+///
+/// let release = KeyEvent::from(KeyCode::Enter).kind(KeyEventKind::Release);
+/// assert!(!release.is_press());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyEvent {
+    /// The key that was pressed.
+    pub code: KeyCode,
+    /// The modifiers that were held.
+    pub modifiers: KeyModifiers,
+    /// Whether the key was pressed, repeated or released.
+    pub kind: KeyEventKind,
+}
+
+/// A key of the keyboard.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyCode {
+    /// Backspace key.
+    Backspace,
+    /// Enter key.
+    Enter,
+    /// Left arrow key.
+    Left,
+    /// Right arrow key.
+    Right,
+    /// Up arrow key.
+    Up,
+    /// Down arrow key.
+    Down,
+    /// Home key.
+    Home,
+    /// End key.
+    End,
+    /// Page up key.
+    PageUp,
+    /// Page down key.
+    PageDown,
+    /// Tab key.
+    Tab,
+    /// Shift + Tab key.
+    BackTab,
+    /// Delete key.
+    Delete,
+    /// Insert key.
+    Insert,
+    /// A function key, e.g. `F(1)` for F1.
+    F(u8),
+    /// A character key, e.g. `Char('a')`.
+    Char(char),
+    /// Null.
+    Null,
+    /// Escape key.
+    Esc,
+    /// Caps lock key.
+    CapsLock,
+    /// Scroll lock key.
+    ScrollLock,
+    /// Num lock key.
+    NumLock,
+    /// Print screen key.
+    PrintScreen,
+    /// Pause key.
+    Pause,
+    /// Menu key.
+    Menu,
+    /// The "begin" key, which is the 5 of the keypad when num lock is off.
+    KeypadBegin,
+    /// A media key.
+    Media(MediaKeyCode),
+    /// A modifier key, which is only reported on its own by some terminals.
+    Modifier(ModifierKeyCode),
+}
+
+/// A media key.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MediaKeyCode {
+    /// Play media key.
+    Play,
+    /// Pause media key.
+    Pause,
+    /// Play/Pause media key.
+    PlayPause,
+    /// Reverse media key.
+    Reverse,
+    /// Stop media key.
+    Stop,
+    /// Fast-forward media key.
+    FastForward,
+    /// Rewind media key.
+    Rewind,
+    /// Next track media key.
+    TrackNext,
+    /// Previous track media key.
+    TrackPrevious,
+    /// Record media key.
+    Record,
+    /// Lower volume media key.
+    LowerVolume,
+    /// Raise volume media key.
+    RaiseVolume,
+    /// Mute volume media key.
+    MuteVolume,
+}
+
+/// A modifier key.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModifierKeyCode {
+    /// Left Shift key.
+    LeftShift,
+    /// Left Control key.
+    LeftControl,
+    /// Left Alt key.
+    LeftAlt,
+    /// Left Super key.
+    LeftSuper,
+    /// Left Hyper key.
+    LeftHyper,
+    /// Left Meta key.
+    LeftMeta,
+    /// Right Shift key.
+    RightShift,
+    /// Right Control key.
+    RightControl,
+    /// Right Alt key.
+    RightAlt,
+    /// Right Super key.
+    RightSuper,
+    /// Right Hyper key.
+    RightHyper,
+    /// Right Meta key.
+    RightMeta,
+    /// Iso Level3 Shift key.
+    IsoLevel3Shift,
+    /// Iso Level5 Shift key.
+    IsoLevel5Shift,
+}
+
+/// Whether a key was pressed, repeated or released.
+///
+/// Most terminals only report key presses. Repeats and releases are only reported by terminals
+/// that support and enabled an enhanced keyboard protocol.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyEventKind {
+    /// The key was pressed.
+    #[default]
+    Press,
+    /// The key is held down and repeats.
+    Repeat,
+    /// The key was released.
+    Release,
+}
+
+bitflags! {
+    /// The modifier keys that were held during a key or mouse event.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::event::KeyModifiers;
+    ///
+    /// let modifiers = KeyModifiers::CONTROL | KeyModifiers::SHIFT;
+    /// assert!(modifiers.contains(KeyModifiers::CONTROL));
+    /// ```
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct KeyModifiers: u8 {
+        /// The Shift key.
+        const SHIFT   = 0b0000_0001;
+        /// The Control key.
+        const CONTROL = 0b0000_0010;
+        /// The Alt key, which is the Option key on macOS.
+        const ALT     = 0b0000_0100;
+        /// The Super key, which is the Windows key or the Command key on macOS.
+        const SUPER   = 0b0000_1000;
+        /// The Hyper key.
+        const HYPER   = 0b0001_0000;
+        /// The Meta key.
+        const META    = 0b0010_0000;
+    }
+}
+
+impl KeyEvent {
+    /// Creates a key press event with the given code and modifiers.
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    /// Sets the kind of the event.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn kind(mut self, kind: KeyEventKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Returns whether the key was pressed, as opposed to repeated or released.
+    pub const fn is_press(&self) -> bool {
+        matches!(self.kind, KeyEventKind::Press)
+    }
+}
+
+impl From<KeyCode> for KeyEvent {
+    /// Creates a key press event without modifiers.
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let key = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::ALT);
+        assert_eq!(
+            key,
+            KeyEvent {
+                code: KeyCode::Char('a'),
+                modifiers: KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+            }
+        );
+        assert!(key.is_press());
+    }
+
+    #[test]
+    fn from_key_code() {
+        let key = KeyEvent::from(KeyCode::F(5));
+        assert_eq!(key.code, KeyCode::F(5));
+        assert_eq!(key.modifiers, KeyModifiers::empty());
+    }
+
+    #[test]
+    fn kind() {
+        let key = KeyEvent::from(KeyCode::Tab).kind(KeyEventKind::Repeat);
+        assert_eq!(key.kind, KeyEventKind::Repeat);
+        assert!(!key.is_press());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::event::KeyModifiers;
+use crate::layout::Position;
+
+/// A mouse event, which is a mouse action at a position of the terminal.
+///
+/// Mouse events are only reported while mouse capture is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+/// use ratatui_core::layout::{Position, Rect};
+///
+/// let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), Position::new(3, 1));
+/// assert!(Rect::new(0, 0, 10, 2).contains(click.position));
+/// assert_eq!(click.modifiers, KeyModifiers::empty());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MouseEvent {
+    /// The action of the mouse.
+    pub kind: MouseEventKind,
+    /// The cell of the terminal where the action happened, starting at `(0, 0)`.
+    pub position: Position,
+    /// The modifier keys that were held.
+    pub modifiers: KeyModifiers,
+}
+
+/// The action of a mouse event.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseEventKind {
+    /// A button was pressed.
+    Down(MouseButton),
+    /// A button was released.
+    Up(MouseButton),
+    /// The mouse moved while a button was held.
+    Drag(MouseButton),
+    /// The mouse moved without a button held.
+    Moved,
+    /// The wheel scrolled down.
+    ScrollDown,
+    /// The wheel scrolled up.
+    ScrollUp,
+    /// The wheel scrolled left.
+    ScrollLeft,
+    /// The wheel scrolled right.
+    ScrollRight,
+}
+
+/// A mouse button.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseButton {
+    /// Left mouse button.
+    Left,
+    /// Right mouse button.
+    Right,
+    /// Middle mouse button.
+    Middle,
+}
+
+impl MouseEvent {
+    /// Creates a mouse event at the given position without modifiers.
+    pub const fn new(kind: MouseEventKind, position: Position) -> Self {
+        Self {
+            kind,
+            position,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+
+    /// Sets the modifier keys that were held.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+}
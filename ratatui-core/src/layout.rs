@@ -52,6 +52,7 @@
 //! ## Core Layout Types
 //!
 //! - [`Layout`] - The primary layout engine that divides space using constraints and direction
+//! - [`Grid`] - A two-dimensional layout that places items on shared rows and columns
 //! - [`Rect`] - Represents a rectangular area with position and dimensions
 //! - [`Constraint`] - Defines how space should be allocated (length, percentage, ratio, etc.)
 //! - [`Direction`] - Specifies layout orientation (horizontal or vertical)
@@ -314,6 +315,7 @@ mod alignment;
 mod constraint;
 mod direction;
 mod flex;
+mod grid;
 mod layout;
 mod margin;
 mod position;
@@ -324,6 +326,7 @@ pub use alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
 pub use constraint::Constraint;
 pub use direction::Direction;
 pub use flex::Flex;
+pub use grid::{Grid, GridCell};
 pub use layout::{Layout, Spacing};
 pub use margin::Margin;
 pub use position::Position;
@@ -0,0 +1,385 @@
+use alloc::vec::Vec;
+
+use crate::layout::{Constraint, Flex, Layout, Rect, Spacing};
+
+/// A two-dimensional layout that splits an area into rows and columns.
+///
+/// A grid is defined by a set of row tracks and a set of column tracks, each sized by a
+/// [`Constraint`]. Unlike nesting a horizontal [`Layout`] inside a vertical one, all the cells of a
+/// grid share the same track sizes, so the columns line up across every row. Items are placed on
+/// the grid with a [`GridCell`], which can span multiple rows and columns.
+///
+/// The tracks of each axis are solved with a [`Layout`], so the grid supports the same constraints,
+/// [`Spacing`] (gaps between tracks) and [`Flex`] (distribution of excess space) options, and the
+/// results are stored in the layout cache when the `layout-cache` feature is enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::layout::Constraint::{Fill, Length};
+/// use ratatui_core::layout::{Grid, GridCell, Rect};
+///
+/// // a header spanning both columns, above a sidebar and a main area
+/// let grid = Grid::new([Length(1), Fill(1)], [Length(10), Fill(1)]).spacing(1);
+/// let area = Rect::new(0, 0, 31, 10);
+/// let [header, sidebar, main] = grid.areas(
+///     area,
+///     [
+///         GridCell::new(0, 0).span(1, 2),
+///         GridCell::new(1, 0),
+///         GridCell::new(1, 1),
+///     ],
+/// );
+/// assert_eq!(header, Rect::new(0, 0, 31, 1));
+/// assert_eq!(sidebar, Rect::new(0, 2, 10, 8));
+/// assert_eq!(main, Rect::new(11, 2, 20, 8));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid {
+    rows: Layout,
+    columns: Layout,
+}
+
+/// The placement of an item in a [`Grid`].
+///
+/// A cell is identified by the index of its first row and column, and covers `row_span` rows and
+/// `column_span` columns (both default to 1). The area of a spanning cell includes the gaps between
+/// the tracks it covers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridCell {
+    /// The index of the first row of the cell.
+    pub row: usize,
+    /// The index of the first column of the cell.
+    pub column: usize,
+    /// The number of rows covered by the cell.
+    pub row_span: usize,
+    /// The number of columns covered by the cell.
+    pub column_span: usize,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new(Vec::<Constraint>::new(), Vec::<Constraint>::new())
+    }
+}
+
+impl Grid {
+    /// Creates a new grid with the given row and column constraints.
+    ///
+    /// Both parameters accept any type that implements `IntoIterator<Item = Into<Constraint>>`,
+    /// like [`Layout::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Constraint::{Length, Percentage};
+    /// use ratatui_core::layout::Grid;
+    ///
+    /// let grid = Grid::new([Length(3), Length(3)], [Percentage(50), Percentage(50)]);
+    /// ```
+    pub fn new<R, C>(rows: R, columns: C) -> Self
+    where
+        R: IntoIterator,
+        R::Item: Into<Constraint>,
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
+    {
+        Self {
+            rows: Layout::vertical(rows),
+            columns: Layout::horizontal(columns),
+        }
+    }
+
+    /// Sets the constraints of the rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn rows<I>(mut self, constraints: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        self.rows = self.rows.constraints(constraints);
+        self
+    }
+
+    /// Sets the constraints of the columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn columns<I>(mut self, constraints: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        self.columns = self.columns.constraints(constraints);
+        self
+    }
+
+    /// Sets the margin on all sides of the grid.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn margin(mut self, margin: u16) -> Self {
+        self.rows = self.rows.margin(margin);
+        self.columns = self.columns.margin(margin);
+        self
+    }
+
+    /// Sets the gap between both the rows and the columns.
+    ///
+    /// See [`Layout::spacing`] for the meaning of positive and negative values.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn spacing<T: Into<Spacing>>(self, spacing: T) -> Self {
+        let spacing = spacing.into();
+        self.row_spacing(spacing.clone()).column_spacing(spacing)
+    }
+
+    /// Sets the gap between the rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_spacing<T: Into<Spacing>>(mut self, spacing: T) -> Self {
+        self.rows = self.rows.spacing(spacing);
+        self
+    }
+
+    /// Sets the gap between the columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_spacing<T: Into<Spacing>>(mut self, spacing: T) -> Self {
+        self.columns = self.columns.spacing(spacing);
+        self
+    }
+
+    /// Sets how the excess space is distributed along both axes.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn flex(self, flex: Flex) -> Self {
+        self.row_flex(flex).column_flex(flex)
+    }
+
+    /// Sets how the excess vertical space is distributed between the rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_flex(mut self, flex: Flex) -> Self {
+        self.rows = self.rows.flex(flex);
+        self
+    }
+
+    /// Sets how the excess horizontal space is distributed between the columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_flex(mut self, flex: Flex) -> Self {
+        self.columns = self.columns.flex(flex);
+        self
+    }
+
+    /// Splits the area into one rect per cell, without any spanning.
+    ///
+    /// The result contains one `Vec` per row, each with one rect per column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Grid, Rect};
+    ///
+    /// let grid = Grid::new([1, 1], [2, 2, 2]);
+    /// let cells = grid.split(Rect::new(0, 0, 6, 2));
+    /// assert_eq!(cells[1][2], Rect::new(4, 1, 2, 1));
+    /// ```
+    pub fn split(&self, area: Rect) -> Vec<Vec<Rect>> {
+        let rows = self.rows.split(area);
+        let columns = self.columns.split(area);
+        rows.iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| Rect::new(column.x, row.y, column.width, row.height))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the area of each of the given cells.
+    ///
+    /// Spans that extend beyond the last row or column are truncated to the grid, and cells that
+    /// start outside of the grid get an empty area.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Grid, GridCell, Rect};
+    ///
+    /// let grid = Grid::new([1, 1], [2, 2]);
+    /// let areas = grid.place(Rect::new(0, 0, 4, 2), [(0, 0), (0, 1), (1, 0)]);
+    /// assert_eq!(areas.len(), 3);
+    /// ```
+    pub fn place<I>(&self, area: Rect, cells: I) -> Vec<Rect>
+    where
+        I: IntoIterator,
+        I::Item: Into<GridCell>,
+    {
+        let rows = self.rows.split(area);
+        let columns = self.columns.split(area);
+        cells
+            .into_iter()
+            .map(|cell| cell.into().area(&rows, &columns))
+            .collect()
+    }
+
+    /// Returns the area of each of the given cells as an array.
+    ///
+    /// An ergonomic wrapper around [`Grid::place`] for when the number of cells is known at compile
+    /// time.
+    pub fn areas<const N: usize>(&self, area: Rect, cells: [GridCell; N]) -> [Rect; N] {
+        let rows = self.rows.split(area);
+        let columns = self.columns.split(area);
+        cells.map(|cell| cell.area(&rows, &columns))
+    }
+}
+
+impl GridCell {
+    /// Creates a cell at the given row and column that covers a single track in each direction.
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+
+    /// Sets the number of rows and columns covered by the cell.
+    ///
+    /// Spans of 0 are treated as 1.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.row_span = rows;
+        self.column_span = columns;
+        self
+    }
+
+    /// Returns the area covered by the cell given the areas of the row and column tracks
+    fn area(self, rows: &[Rect], columns: &[Rect]) -> Rect {
+        let Some((top, bottom)) = Self::tracks(rows, self.row, self.row_span) else {
+            return Rect::ZERO;
+        };
+        let Some((left, right)) = Self::tracks(columns, self.column, self.column_span) else {
+            return Rect::ZERO;
+        };
+        let x = left.x;
+        let y = top.y;
+        let width = right.right().saturating_sub(x);
+        let height = bottom.bottom().saturating_sub(y);
+        Rect::new(x, y, width, height)
+    }
+
+    /// Returns the first and last track covered by a span, truncated to the available tracks
+    fn tracks(tracks: &[Rect], start: usize, span: usize) -> Option<(Rect, Rect)> {
+        let first = *tracks.get(start)?;
+        let end = start.saturating_add(span.max(1)).min(tracks.len());
+        Some((first, tracks[end - 1]))
+    }
+}
+
+impl From<(usize, usize)> for GridCell {
+    /// Converts a `(row, column)` tuple into a cell that covers a single track in each direction.
+    fn from((row, column): (usize, usize)) -> Self {
+        Self::new(row, column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::layout::Constraint::{Fill, Length, Percentage};
+
+    #[test]
+    fn split() {
+        let grid = Grid::new([Length(1), Fill(1)], [Percentage(25), Fill(1)]);
+        let cells = grid.split(Rect::new(1, 1, 8, 4));
+        assert_eq!(
+            cells,
+            vec![
+                vec![Rect::new(1, 1, 2, 1), Rect::new(3, 1, 6, 1)],
+                vec![Rect::new(1, 2, 2, 3), Rect::new(3, 2, 6, 3)],
+            ]
+        );
+    }
+
+    #[test]
+    fn spacing() {
+        let grid = Grid::new([1, 1], [2, 2]).row_spacing(1).column_spacing(2);
+        let cells = grid.split(Rect::new(0, 0, 10, 10));
+        assert_eq!(
+            cells,
+            vec![
+                vec![Rect::new(0, 0, 2, 1), Rect::new(4, 0, 2, 1)],
+                vec![Rect::new(0, 2, 2, 1), Rect::new(4, 2, 2, 1)],
+            ]
+        );
+    }
+
+    #[test]
+    fn flex() {
+        let grid = Grid::new([2], [2, 2])
+            .row_flex(Flex::End)
+            .column_flex(Flex::Center);
+        let cells = grid.split(Rect::new(0, 0, 8, 4));
+        assert_eq!(
+            cells,
+            vec![vec![Rect::new(2, 2, 2, 2), Rect::new(4, 2, 2, 2)]]
+        );
+    }
+
+    #[test]
+    fn margin() {
+        let grid = Grid::new([Fill(1)], [Fill(1)]).margin(1);
+        let cells = grid.split(Rect::new(0, 0, 6, 4));
+        assert_eq!(cells, vec![vec![Rect::new(1, 1, 4, 2)]]);
+    }
+
+    #[rstest]
+    #[case::single(GridCell::new(1, 1), Rect::new(3, 2, 2, 1))]
+    #[case::row_span(GridCell::new(0, 0).span(3, 1), Rect::new(0, 0, 2, 5))]
+    #[case::column_span(GridCell::new(2, 0).span(1, 3), Rect::new(0, 4, 8, 1))]
+    #[case::both_spans(GridCell::new(1, 1).span(2, 2), Rect::new(3, 2, 5, 3))]
+    #[case::zero_span(GridCell::new(1, 1).span(0, 0), Rect::new(3, 2, 2, 1))]
+    #[case::truncated_span(GridCell::new(2, 2).span(5, 5), Rect::new(6, 4, 2, 1))]
+    #[case::outside(GridCell::new(3, 0), Rect::ZERO)]
+    fn place(#[case] cell: GridCell, #[case] expected: Rect) {
+        let grid = Grid::new([1, 1, 1], [2, 2, 2]).spacing(1);
+        let area = Rect::new(0, 0, 8, 5);
+        assert_eq!(grid.place(area, [cell]), vec![expected]);
+        assert_eq!(grid.areas(area, [cell]), [expected]);
+    }
+
+    #[test]
+    fn place_tuples() {
+        let grid = Grid::new([1, 1], [1, 1]);
+        let areas = grid.place(Rect::new(0, 0, 2, 2), [(0, 1), (1, 0)]);
+        assert_eq!(areas, vec![Rect::new(1, 0, 1, 1), Rect::new(0, 1, 1, 1)]);
+    }
+
+    #[test]
+    fn setters() {
+        let grid = Grid::default().rows([1, 2]).columns([3]);
+        assert_eq!(grid, Grid::new([1, 2], [3]));
+    }
+}
@@ -72,8 +72,10 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod animation;
 pub mod backend;
 pub mod buffer;
+pub mod event;
 pub mod layout;
 pub mod style;
 pub mod symbols;
@@ -66,29 +66,51 @@
 //! );
 //! ```
 //!
+//! # Themes
+//!
+//! A [`Theme`] maps semantic roles such as borders, titles and the selected item to styles, so
+//! that the look of an application can be changed in one place. Themes can be built from the
+//! [`palette`] colors or loaded from a file with the `serde` feature.
+//!
 //! [`Span`]: crate::text::Span
 
 use core::fmt;
 
 use bitflags::bitflags;
 pub use color::{Color, ParseColorError};
+pub use color_depth::ColorDepth;
 use stylize::ColorDebugKind;
 pub use stylize::{Styled, Stylize};
+pub use theme::Theme;
 
 #[cfg(feature = "anstyle")]
 mod anstyle;
 mod color;
+mod color_depth;
 pub mod palette;
 #[cfg(feature = "palette")]
 mod palette_conversion;
 #[macro_use]
 mod stylize;
+mod theme;
 
 bitflags! {
     /// Modifier changes the way a piece of text is displayed.
     ///
     /// They are bitflags so they can easily be composed.
     ///
+    /// Terminals display a single underline style at a time, so when several of the underline
+    /// modifiers are set, the one displayed is the last of [`UNDERLINED`], [`DOUBLE_UNDERLINED`],
+    /// [`CURLY_UNDERLINED`], [`DOTTED_UNDERLINED`] and [`DASHED_UNDERLINED`] (see
+    /// [`Modifier::underline_style`]). Backends and terminals that do not support the extended
+    /// underline styles display them as a plain underline.
+    ///
+    /// [`UNDERLINED`]: Modifier::UNDERLINED
+    /// [`DOUBLE_UNDERLINED`]: Modifier::DOUBLE_UNDERLINED
+    /// [`CURLY_UNDERLINED`]: Modifier::CURLY_UNDERLINED
+    /// [`DOTTED_UNDERLINED`]: Modifier::DOTTED_UNDERLINED
+    /// [`DASHED_UNDERLINED`]: Modifier::DASHED_UNDERLINED
+    ///
     /// `From<Modifier> for Style` is implemented so you can use `Modifier` anywhere that accepts
     /// `Into<Style>`.
     ///
@@ -111,6 +133,44 @@ bitflags! {
         const REVERSED          = 0b0000_0100_0000;
         const HIDDEN            = 0b0000_1000_0000;
         const CROSSED_OUT       = 0b0001_0000_0000;
+        const DOUBLE_UNDERLINED = 0b0010_0000_0000;
+        const CURLY_UNDERLINED  = 0b0100_0000_0000;
+        const DOTTED_UNDERLINED = 0b1000_0000_0000;
+        const DASHED_UNDERLINED = 0b0001_0000_0000_0000;
+        const OVERLINED         = 0b0010_0000_0000_0000;
+    }
+}
+
+impl Modifier {
+    /// All the underline modifiers.
+    pub const ANY_UNDERLINE: Self = Self::UNDERLINED
+        .union(Self::DOUBLE_UNDERLINED)
+        .union(Self::CURLY_UNDERLINED)
+        .union(Self::DOTTED_UNDERLINED)
+        .union(Self::DASHED_UNDERLINED);
+
+    /// Returns the underline modifier that terminals display, or an empty modifier if the text is
+    /// not underlined.
+    ///
+    /// When several underline modifiers are set, the last one of [`Modifier::UNDERLINED`],
+    /// [`Modifier::DOUBLE_UNDERLINED`], [`Modifier::CURLY_UNDERLINED`],
+    /// [`Modifier::DOTTED_UNDERLINED`] and [`Modifier::DASHED_UNDERLINED`] is displayed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Modifier;
+    ///
+    /// let modifier = Modifier::BOLD | Modifier::UNDERLINED | Modifier::CURLY_UNDERLINED;
+    /// assert_eq!(modifier.underline_style(), Modifier::CURLY_UNDERLINED);
+    /// assert_eq!(Modifier::BOLD.underline_style(), Modifier::empty());
+    /// ```
+    #[must_use]
+    pub fn underline_style(self) -> Self {
+        self.intersection(Self::ANY_UNDERLINE)
+            .iter()
+            .last()
+            .unwrap_or_else(Self::empty)
     }
 }
 
@@ -474,6 +534,11 @@ impl Style {
                 Modifier::REVERSED => f.write_str(".reversed()")?,
                 Modifier::HIDDEN => f.write_str(".hidden()")?,
                 Modifier::CROSSED_OUT => f.write_str(".crossed_out()")?,
+                Modifier::DOUBLE_UNDERLINED => f.write_str(".double_underlined()")?,
+                Modifier::CURLY_UNDERLINED => f.write_str(".curly_underlined()")?,
+                Modifier::DOTTED_UNDERLINED => f.write_str(".dotted_underlined()")?,
+                Modifier::DASHED_UNDERLINED => f.write_str(".dashed_underlined()")?,
+                Modifier::OVERLINED => f.write_str(".overlined()")?,
                 _ => f.write_fmt(format_args!(".add_modifier(Modifier::{modifier:?})"))?,
             }
         }
@@ -488,6 +553,11 @@ impl Style {
                 Modifier::REVERSED => f.write_str(".not_reversed()")?,
                 Modifier::HIDDEN => f.write_str(".not_hidden()")?,
                 Modifier::CROSSED_OUT => f.write_str(".not_crossed_out()")?,
+                Modifier::DOUBLE_UNDERLINED => f.write_str(".not_double_underlined()")?,
+                Modifier::CURLY_UNDERLINED => f.write_str(".not_curly_underlined()")?,
+                Modifier::DOTTED_UNDERLINED => f.write_str(".not_dotted_underlined()")?,
+                Modifier::DASHED_UNDERLINED => f.write_str(".not_dashed_underlined()")?,
+                Modifier::OVERLINED => f.write_str(".not_overlined()")?,
                 _ => f.write_fmt(format_args!(".remove_modifier(Modifier::{modifier:?})"))?,
             }
         }
@@ -520,6 +590,11 @@ impl Style {
     modifier!(pub const Modifier::REVERSED, reversed(), not_reversed() -> Self);
     modifier!(pub const Modifier::HIDDEN, hidden(), not_hidden() -> Self);
     modifier!(pub const Modifier::CROSSED_OUT, crossed_out(), not_crossed_out() -> Self);
+    modifier!(pub const Modifier::DOUBLE_UNDERLINED, double_underlined(), not_double_underlined() -> Self);
+    modifier!(pub const Modifier::CURLY_UNDERLINED, curly_underlined(), not_curly_underlined() -> Self);
+    modifier!(pub const Modifier::DOTTED_UNDERLINED, dotted_underlined(), not_dotted_underlined() -> Self);
+    modifier!(pub const Modifier::DASHED_UNDERLINED, dashed_underlined(), not_dashed_underlined() -> Self);
+    modifier!(pub const Modifier::OVERLINED, overlined(), not_overlined() -> Self);
 }
 
 impl From<Color> for Style {
@@ -722,6 +797,11 @@ mod tests {
             Modifier::REVERSED,
             Modifier::HIDDEN,
             Modifier::CROSSED_OUT,
+            Modifier::DOUBLE_UNDERLINED,
+            Modifier::CURLY_UNDERLINED,
+            Modifier::DOTTED_UNDERLINED,
+            Modifier::DASHED_UNDERLINED,
+            Modifier::OVERLINED,
         ];
 
         let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
@@ -746,15 +826,34 @@ mod tests {
     #[case(Modifier::REVERSED, "REVERSED")]
     #[case(Modifier::HIDDEN, "HIDDEN")]
     #[case(Modifier::CROSSED_OUT, "CROSSED_OUT")]
+    #[case(Modifier::DOUBLE_UNDERLINED, "DOUBLE_UNDERLINED")]
+    #[case(Modifier::CURLY_UNDERLINED, "CURLY_UNDERLINED")]
+    #[case(Modifier::DOTTED_UNDERLINED, "DOTTED_UNDERLINED")]
+    #[case(Modifier::DASHED_UNDERLINED, "DASHED_UNDERLINED")]
+    #[case(Modifier::OVERLINED, "OVERLINED")]
     #[case(Modifier::BOLD | Modifier::DIM, "BOLD | DIM")]
     #[case(
         Modifier::all(),
-        "BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT"
+        "BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT | DOUBLE_UNDERLINED | CURLY_UNDERLINED | DOTTED_UNDERLINED | DASHED_UNDERLINED | OVERLINED"
     )]
     fn modifier_debug(#[case] modifier: Modifier, #[case] expected: &str) {
         assert_eq!(format!("{modifier:?}"), expected);
     }
 
+    #[rstest]
+    #[case(Modifier::empty(), Modifier::empty())]
+    #[case(Modifier::BOLD | Modifier::OVERLINED, Modifier::empty())]
+    #[case(Modifier::UNDERLINED, Modifier::UNDERLINED)]
+    #[case(Modifier::DASHED_UNDERLINED | Modifier::UNDERLINED, Modifier::DASHED_UNDERLINED)]
+    #[case(Modifier::ANY_UNDERLINE, Modifier::DASHED_UNDERLINED)]
+    #[case(
+        Modifier::DOUBLE_UNDERLINED | Modifier::DOTTED_UNDERLINED,
+        Modifier::DOTTED_UNDERLINED
+    )]
+    fn underline_style(#[case] modifier: Modifier, #[case] expected: Modifier) {
+        assert_eq!(modifier.underline_style(), expected);
+    }
+
     #[test]
     fn style_can_be_const() {
         const RED: Color = Color::Red;
@@ -842,6 +941,11 @@ mod tests {
     #[case(Style::new().reversed(), Modifier::REVERSED)]
     #[case(Style::new().hidden(), Modifier::HIDDEN)]
     #[case(Style::new().crossed_out(), Modifier::CROSSED_OUT)]
+    #[case(Style::new().double_underlined(), Modifier::DOUBLE_UNDERLINED)]
+    #[case(Style::new().curly_underlined(), Modifier::CURLY_UNDERLINED)]
+    #[case(Style::new().dotted_underlined(), Modifier::DOTTED_UNDERLINED)]
+    #[case(Style::new().dashed_underlined(), Modifier::DASHED_UNDERLINED)]
+    #[case(Style::new().overlined(), Modifier::OVERLINED)]
     fn add_modifier_can_be_stylized(#[case] stylized: Style, #[case] expected: Modifier) {
         assert_eq!(stylized, Style::new().add_modifier(expected));
     }
@@ -856,6 +960,11 @@ mod tests {
     #[case(Style::new().not_reversed(), Modifier::REVERSED)]
     #[case(Style::new().not_hidden(), Modifier::HIDDEN)]
     #[case(Style::new().not_crossed_out(), Modifier::CROSSED_OUT)]
+    #[case(Style::new().not_double_underlined(), Modifier::DOUBLE_UNDERLINED)]
+    #[case(Style::new().not_curly_underlined(), Modifier::CURLY_UNDERLINED)]
+    #[case(Style::new().not_dotted_underlined(), Modifier::DOTTED_UNDERLINED)]
+    #[case(Style::new().not_dashed_underlined(), Modifier::DASHED_UNDERLINED)]
+    #[case(Style::new().not_overlined(), Modifier::OVERLINED)]
     fn remove_modifier_can_be_stylized(#[case] stylized: Style, #[case] expected: Modifier) {
         assert_eq!(stylized, Style::new().remove_modifier(expected));
     }
@@ -130,14 +130,21 @@ impl From<Effects> for Modifier {
         if effect.contains(Effects::ITALIC) {
             modifier |= Self::ITALIC;
         }
-        if effect.contains(Effects::UNDERLINE)
-            || effect.contains(Effects::DOUBLE_UNDERLINE)
-            || effect.contains(Effects::CURLY_UNDERLINE)
-            || effect.contains(Effects::DOTTED_UNDERLINE)
-            || effect.contains(Effects::DASHED_UNDERLINE)
-        {
+        if effect.contains(Effects::UNDERLINE) {
             modifier |= Self::UNDERLINED;
         }
+        if effect.contains(Effects::DOUBLE_UNDERLINE) {
+            modifier |= Self::DOUBLE_UNDERLINED;
+        }
+        if effect.contains(Effects::CURLY_UNDERLINE) {
+            modifier |= Self::CURLY_UNDERLINED;
+        }
+        if effect.contains(Effects::DOTTED_UNDERLINE) {
+            modifier |= Self::DOTTED_UNDERLINED;
+        }
+        if effect.contains(Effects::DASHED_UNDERLINE) {
+            modifier |= Self::DASHED_UNDERLINED;
+        }
         if effect.contains(Effects::BLINK) {
             modifier |= Self::SLOW_BLINK;
         }
@@ -155,6 +162,8 @@ impl From<Effects> for Modifier {
 }
 
 impl From<Modifier> for Effects {
+    /// Converts the modifiers to effects. `anstyle` has no overline effect, so
+    /// [`Modifier::OVERLINED`] is dropped.
     fn from(modifier: Modifier) -> Self {
         let mut effects = Self::new();
         if modifier.contains(Modifier::BOLD) {
@@ -169,6 +178,18 @@ impl From<Modifier> for Effects {
         if modifier.contains(Modifier::UNDERLINED) {
             effects |= Self::UNDERLINE;
         }
+        if modifier.contains(Modifier::DOUBLE_UNDERLINED) {
+            effects |= Self::DOUBLE_UNDERLINE;
+        }
+        if modifier.contains(Modifier::CURLY_UNDERLINED) {
+            effects |= Self::CURLY_UNDERLINE;
+        }
+        if modifier.contains(Modifier::DOTTED_UNDERLINED) {
+            effects |= Self::DOTTED_UNDERLINE;
+        }
+        if modifier.contains(Modifier::DASHED_UNDERLINED) {
+            effects |= Self::DASHED_UNDERLINE;
+        }
         if modifier.contains(Modifier::SLOW_BLINK) || modifier.contains(Modifier::RAPID_BLINK) {
             effects |= Self::BLINK;
         }
@@ -294,6 +315,20 @@ mod tests {
         assert!(modifier.contains(Modifier::ITALIC));
     }
 
+    #[test]
+    fn underline_styles_round_trip() {
+        let effects = Effects::DOUBLE_UNDERLINE
+            | Effects::CURLY_UNDERLINE
+            | Effects::DOTTED_UNDERLINE
+            | Effects::DASHED_UNDERLINE;
+        let modifier = Modifier::from(effects);
+        assert_eq!(
+            modifier,
+            Modifier::ANY_UNDERLINE.difference(Modifier::UNDERLINED)
+        );
+        assert_eq!(Effects::from(modifier | Modifier::OVERLINED), effects);
+    }
+
     #[test]
     fn modifier_to_effects() {
         let modifier = Modifier::BOLD | Modifier::ITALIC;
@@ -369,10 +369,98 @@ impl fmt::Display for Color {
 }
 
 impl Color {
+    /// The named colors in the order of their ANSI color index
+    pub(crate) const ANSI: [Self; 16] = [
+        Self::Black,
+        Self::Red,
+        Self::Green,
+        Self::Yellow,
+        Self::Blue,
+        Self::Magenta,
+        Self::Cyan,
+        Self::Gray,
+        Self::DarkGray,
+        Self::LightRed,
+        Self::LightGreen,
+        Self::LightYellow,
+        Self::LightBlue,
+        Self::LightMagenta,
+        Self::LightCyan,
+        Self::White,
+    ];
+
     pub(crate) const fn stylize_debug(self, kind: ColorDebugKind) -> ColorDebug {
         ColorDebug { kind, color: self }
     }
 
+    /// Returns the RGB components of the color, or `None` for [`Color::Reset`].
+    ///
+    /// Named and indexed colors are converted using the default xterm palette. Terminals may be
+    /// configured with a different palette, so this is only an approximation of how these colors
+    /// are displayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    /// assert_eq!(Color::Red.to_rgb(), Some((205, 0, 0)));
+    /// assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        let rgb = match self {
+            Self::Reset => return None,
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Black => (0, 0, 0),
+            Self::Red => (205, 0, 0),
+            Self::Green => (0, 205, 0),
+            Self::Yellow => (205, 205, 0),
+            Self::Blue => (0, 0, 238),
+            Self::Magenta => (205, 0, 205),
+            Self::Cyan => (0, 205, 205),
+            Self::Gray => (229, 229, 229),
+            Self::DarkGray => (127, 127, 127),
+            Self::LightRed => (255, 0, 0),
+            Self::LightGreen => (0, 255, 0),
+            Self::LightYellow => (255, 255, 0),
+            Self::LightBlue => (92, 92, 255),
+            Self::LightMagenta => (255, 0, 255),
+            Self::LightCyan => (0, 255, 255),
+            Self::White => (255, 255, 255),
+            Self::Indexed(index) => return Self::indexed_rgb(index),
+        };
+        Some(rgb)
+    }
+
+    /// Returns the perceived brightness of the color, scaled by 1000, or `None` for
+    /// [`Color::Reset`].
+    pub(crate) const fn luma(self) -> Option<u32> {
+        let Some((r, g, b)) = self.to_rgb() else {
+            return None;
+        };
+        Some(299 * r as u32 + 587 * g as u32 + 114 * b as u32)
+    }
+
+    /// Returns the RGB components of an indexed color in the default xterm palette
+    const fn indexed_rgb(index: u8) -> Option<(u8, u8, u8)> {
+        const fn level(value: u8) -> u8 {
+            if value == 0 { 0 } else { 55 + value * 40 }
+        }
+        match index {
+            0..16 => Self::ANSI[index as usize].to_rgb(),
+            16..232 => {
+                let index = index - 16;
+                Some((level(index / 36), level(index / 6 % 6), level(index % 6)))
+            }
+            232.. => {
+                let gray = 8 + (index - 232) * 10;
+                Some((gray, gray, gray))
+            }
+        }
+    }
+
     /// Converts a HSL representation to a `Color::Rgb` instance.
     ///
     /// The `from_hsl` function converts the Hue, Saturation and Lightness values to a corresponding
@@ -0,0 +1,328 @@
+use strum::{Display, EnumString};
+
+use crate::style::Color;
+
+/// The number of colors that a terminal can display.
+///
+/// Backends quantize the colors of the cells they draw to the nearest color that the terminal can
+/// display, so that [`Color::Rgb`] does not come out garbled in terminals that only support 256 or
+/// 16 colors. In [`Monochrome`](ColorDepth::Monochrome), which is used when the `NO_COLOR`
+/// environment variable is set, colors are dropped and highlighted cells are rendered with
+/// [`Modifier`]s instead (see [`Cell::quantize`]).
+///
+/// The depths are ordered from the least to the most colors, so they can be compared:
+///
+/// ```rust
+/// use ratatui_core::style::ColorDepth;
+///
+/// assert!(ColorDepth::Ansi256 < ColorDepth::TrueColor);
+/// ```
+///
+/// See [`ColorDepth::from_env`] to detect the color depth of the current terminal.
+///
+/// [`Modifier`]: crate::style::Modifier
+/// [`Cell::quantize`]: crate::buffer::Cell::quantize
+#[derive(
+    Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorDepth {
+    /// No colors, only the default foreground and background colors of the terminal.
+    Monochrome,
+    /// The 16 named ANSI colors.
+    Ansi16,
+    /// The 256 indexed colors of the xterm palette.
+    Ansi256,
+    /// 24-bit RGB colors.
+    #[default]
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Guesses the color depth of the current terminal from the environment.
+    ///
+    /// The following environment variables are checked, in order:
+    ///
+    /// - `NO_COLOR`: [`Monochrome`](Self::Monochrome) if set to a non-empty value (see
+    ///   <https://no-color.org>)
+    /// - `COLORTERM`: [`TrueColor`](Self::TrueColor) if set to `truecolor` or `24bit`
+    /// - `TERM`: [`Monochrome`](Self::Monochrome) for `dumb`, [`TrueColor`](Self::TrueColor) for
+    ///   terminals with `direct` or `truecolor` in their name, [`Ansi256`](Self::Ansi256) for
+    ///   terminals with `256` in their name, and [`Ansi16`](Self::Ansi16) otherwise
+    /// - `WT_SESSION`: [`TrueColor`](Self::TrueColor) if set, as Windows Terminal does not set
+    ///   `TERM`
+    ///
+    /// This is a heuristic: terminals that support more colors than they advertise get fewer
+    /// colors, and multiplexers such as tmux may advertise more colors than the outer terminal
+    /// supports. Backends that implement [`QueryBackend`] can ask the terminal instead, see
+    /// [`TerminalCapabilities::color_depth`].
+    ///
+    /// [`QueryBackend`]: crate::backend::QueryBackend
+    /// [`TerminalCapabilities::color_depth`]: crate::backend::TerminalCapabilities::color_depth
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Self {
+        Self::detect(|name| std::env::var(name).ok())
+    }
+
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    fn detect<F>(var: F) -> Self
+    where
+        F: Fn(&str) -> Option<alloc::string::String>,
+    {
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Self::Monochrome;
+        }
+        if var("COLORTERM").is_some_and(|value| value == "truecolor" || value == "24bit") {
+            return Self::TrueColor;
+        }
+        match var("TERM") {
+            Some(term) if term == "dumb" => Self::Monochrome,
+            Some(term) if term.contains("direct") || term.contains("truecolor") => Self::TrueColor,
+            Some(term) if term.contains("256") => Self::Ansi256,
+            _ if var("WT_SESSION").is_some() => Self::TrueColor,
+            _ => Self::Ansi16,
+        }
+    }
+
+    /// Returns the color depth that the environment explicitly limits the terminal to, or
+    /// [`TrueColor`](Self::TrueColor) if it does not.
+    ///
+    /// Unlike [`ColorDepth::from_env`], which guesses the color depth when the terminal does not
+    /// advertise it, this only limits the colors when there is positive evidence that the terminal
+    /// cannot display them:
+    ///
+    /// - `NO_COLOR`: [`Monochrome`](Self::Monochrome) if set to a non-empty value (see
+    ///   <https://no-color.org>)
+    /// - `TERM`: [`Monochrome`](Self::Monochrome) for `dumb`, and [`Ansi16`](Self::Ansi16) for
+    ///   terminals whose name ends with `-16color` or `-8color`
+    ///
+    /// This is what [`ratatui::init`] uses, so that the colors are not downgraded when `COLORTERM`
+    /// is missing, e.g. over SSH, which does not forward it by default.
+    ///
+    /// [`ratatui::init`]: https://docs.rs/ratatui/latest/ratatui/fn.init.html
+    #[cfg(feature = "std")]
+    pub fn from_env_limits() -> Self {
+        Self::detect_limits(|name| std::env::var(name).ok())
+    }
+
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    fn detect_limits<F>(var: F) -> Self
+    where
+        F: Fn(&str) -> Option<alloc::string::String>,
+    {
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Self::Monochrome;
+        }
+        match var("TERM") {
+            Some(term) if term == "dumb" => Self::Monochrome,
+            Some(term) if term.ends_with("-16color") || term.ends_with("-8color") => Self::Ansi16,
+            _ => Self::TrueColor,
+        }
+    }
+}
+
+impl Color {
+    /// Returns the nearest color that can be displayed with the given color depth.
+    ///
+    /// Colors that can already be displayed are returned unchanged, and [`Color::Reset`] is always
+    /// returned unchanged. Otherwise the nearest color is chosen with a perceptual ("redmean")
+    /// distance between the RGB components of the colors, using the default xterm palette:
+    ///
+    /// - [`Ansi256`](ColorDepth::Ansi256): [`Color::Rgb`] becomes the nearest [`Color::Indexed`]
+    ///   color of the 6x6x6 color cube or the gray ramp. The first 16 indexed colors are not used,
+    ///   as they depend on the palette of the terminal.
+    /// - [`Ansi16`](ColorDepth::Ansi16): [`Color::Rgb`] and [`Color::Indexed`] become the nearest
+    ///   named color.
+    /// - [`Monochrome`](ColorDepth::Monochrome): every color becomes [`Color::Reset`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, ColorDepth};
+    ///
+    /// assert_eq!(Color::Rgb(250, 5, 5).quantize(ColorDepth::Ansi256), Color::Indexed(196));
+    /// assert_eq!(Color::Rgb(250, 5, 5).quantize(ColorDepth::Ansi16), Color::LightRed);
+    /// assert_eq!(Color::Indexed(22).quantize(ColorDepth::Ansi16), Color::Black);
+    /// assert_eq!(Color::Red.quantize(ColorDepth::Monochrome), Color::Reset);
+    /// ```
+    #[must_use = "method returns the quantized color and does not modify self"]
+    pub fn quantize(self, depth: ColorDepth) -> Self {
+        match (depth, self) {
+            (ColorDepth::Monochrome, _) => Self::Reset,
+            (ColorDepth::Ansi256, Self::Rgb(r, g, b)) => nearest_indexed((r, g, b)),
+            (ColorDepth::Ansi16, Self::Indexed(index)) if index < 16 => Self::ANSI[index as usize],
+            (ColorDepth::Ansi16, Self::Rgb(..) | Self::Indexed(_)) => {
+                self.to_rgb().map_or(self, nearest_named)
+            }
+            // named colors can be displayed with any depth that has colors
+            _ => self,
+        }
+    }
+}
+
+/// The levels of each component in the 6x6x6 color cube of the xterm palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Returns the indexed color of the color cube or gray ramp that is nearest to the given color
+fn nearest_indexed(rgb: (u8, u8, u8)) -> Color {
+    let (r, g, b) = rgb;
+    let cube = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube_rgb = (
+        CUBE_LEVELS[cube.0],
+        CUBE_LEVELS[cube.1],
+        CUBE_LEVELS[cube.2],
+    );
+    // the gray ramp goes from 8 to 238 in steps of 10
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_level = 8 + gray * 10;
+    if distance(rgb, (gray_level, gray_level, gray_level)) < distance(rgb, cube_rgb) {
+        Color::Indexed(232 + gray)
+    } else {
+        Color::Indexed(16 + 36 * cube.0 as u8 + 6 * cube.1 as u8 + cube.2 as u8)
+    }
+}
+
+/// Returns the index of the level of the color cube that is nearest to the given component
+fn nearest_level(value: u8) -> usize {
+    CUBE_LEVELS
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, level)| level.abs_diff(value))
+        .map_or(0, |(index, _)| index)
+}
+
+/// Returns the named color that is nearest to the given color
+fn nearest_named(rgb: (u8, u8, u8)) -> Color {
+    Color::ANSI
+        .into_iter()
+        .min_by_key(|color| {
+            color
+                .to_rgb()
+                .map_or(u32::MAX, |other| distance(rgb, other))
+        })
+        .unwrap_or(Color::Reset)
+}
+
+/// Returns the squared "redmean" distance between two colors.
+///
+/// This weighs the components by how sensitive the eye is to them, depending on how red the
+/// colors are, which is a cheap approximation of a perceptual color distance.
+/// See <https://www.compuphase.com/cmetric.htm>.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let red_mean = u32::from(a.0).midpoint(u32::from(b.0));
+    let dr = u32::from(a.0.abs_diff(b.0));
+    let dg = u32::from(a.1.abs_diff(b.1));
+    let db = u32::from(a.2.abs_diff(b.2));
+    (((512 + red_mean) * dr * dr) >> 8) + 4 * dg * dg + (((767 - red_mean) * db * db) >> 8)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(&[], ColorDepth::Ansi16)]
+    #[case(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")], ColorDepth::Monochrome)]
+    #[case(&[("NO_COLOR", ""), ("TERM", "xterm")], ColorDepth::Ansi16)]
+    #[case(&[("COLORTERM", "truecolor"), ("TERM", "xterm")], ColorDepth::TrueColor)]
+    #[case(&[("COLORTERM", "24bit")], ColorDepth::TrueColor)]
+    #[case(&[("TERM", "xterm-256color")], ColorDepth::Ansi256)]
+    #[case(&[("TERM", "tmux-256color")], ColorDepth::Ansi256)]
+    #[case(&[("TERM", "xterm-direct")], ColorDepth::TrueColor)]
+    #[case(&[("TERM", "dumb")], ColorDepth::Monochrome)]
+    #[case(&[("TERM", "linux")], ColorDepth::Ansi16)]
+    #[case(&[("WT_SESSION", "0")], ColorDepth::TrueColor)]
+    fn detect(#[case] vars: &[(&str, &str)], #[case] expected: ColorDepth) {
+        let var = |name: &str| -> Option<String> {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        };
+        assert_eq!(ColorDepth::detect(var), expected);
+    }
+
+    #[rstest]
+    #[case(&[], ColorDepth::TrueColor)]
+    #[case(&[("TERM", "xterm")], ColorDepth::TrueColor)]
+    #[case(&[("TERM", "xterm-256color")], ColorDepth::TrueColor)]
+    #[case(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")], ColorDepth::Monochrome)]
+    #[case(&[("NO_COLOR", ""), ("TERM", "xterm")], ColorDepth::TrueColor)]
+    #[case(&[("TERM", "dumb")], ColorDepth::Monochrome)]
+    #[case(&[("TERM", "xterm-16color")], ColorDepth::Ansi16)]
+    #[case(&[("TERM", "rxvt-8color")], ColorDepth::Ansi16)]
+    fn detect_limits(#[case] vars: &[(&str, &str)], #[case] expected: ColorDepth) {
+        let var = |name: &str| -> Option<String> {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        };
+        assert_eq!(ColorDepth::detect_limits(var), expected);
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("Ansi256".parse(), Ok(ColorDepth::Ansi256));
+        assert_eq!(ColorDepth::Monochrome.to_string(), "Monochrome");
+    }
+
+    #[rstest]
+    #[case(Color::Rgb(1, 2, 3), Color::Rgb(1, 2, 3))]
+    #[case(Color::Indexed(42), Color::Indexed(42))]
+    #[case(Color::Red, Color::Red)]
+    fn quantize_true_color(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.quantize(ColorDepth::TrueColor), expected);
+    }
+
+    #[rstest]
+    #[case(Color::Rgb(0, 0, 0), Color::Indexed(16))]
+    #[case(Color::Rgb(255, 255, 255), Color::Indexed(231))]
+    #[case(Color::Rgb(255, 0, 0), Color::Indexed(196))]
+    #[case(Color::Rgb(0, 95, 135), Color::Indexed(24))]
+    #[case(Color::Rgb(128, 128, 128), Color::Indexed(244))]
+    #[case(Color::Rgb(30, 30, 32), Color::Indexed(234))]
+    #[case(Color::Indexed(42), Color::Indexed(42))]
+    #[case(Color::Blue, Color::Blue)]
+    #[case(Color::Reset, Color::Reset)]
+    fn quantize_ansi256(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.quantize(ColorDepth::Ansi256), expected);
+    }
+
+    #[rstest]
+    #[case(Color::Rgb(0, 0, 0), Color::Black)]
+    #[case(Color::Rgb(255, 255, 255), Color::White)]
+    #[case(Color::Rgb(200, 10, 10), Color::Red)]
+    #[case(Color::Rgb(240, 240, 60), Color::LightYellow)]
+    #[case(Color::Rgb(120, 130, 125), Color::DarkGray)]
+    #[case(Color::Indexed(9), Color::LightRed)]
+    #[case(Color::Indexed(46), Color::LightGreen)]
+    #[case(Color::Indexed(252), Color::Gray)]
+    #[case(Color::Cyan, Color::Cyan)]
+    #[case(Color::Reset, Color::Reset)]
+    fn quantize_ansi16(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.quantize(ColorDepth::Ansi16), expected);
+    }
+
+    #[rstest]
+    #[case(Color::Rgb(1, 2, 3))]
+    #[case(Color::Indexed(42))]
+    #[case(Color::Red)]
+    #[case(Color::Reset)]
+    fn quantize_monochrome(#[case] color: Color) {
+        assert_eq!(color.quantize(ColorDepth::Monochrome), Color::Reset);
+    }
+
+    #[test]
+    fn quantize_is_idempotent() {
+        for depth in [ColorDepth::Ansi256, ColorDepth::Ansi16] {
+            for index in 0..=255 {
+                let color = Color::Rgb(index, 255 - index, index / 2).quantize(depth);
+                assert_eq!(color.quantize(depth), color, "{depth} {index}");
+            }
+        }
+    }
+}
@@ -268,6 +268,11 @@ pub trait Stylize<'a, T>: Sized {
     modifier!(Modifier::REVERSED, reversed(), not_reversed() -> T);
     modifier!(Modifier::HIDDEN, hidden(), not_hidden() -> T);
     modifier!(Modifier::CROSSED_OUT, crossed_out(), not_crossed_out() -> T);
+    modifier!(Modifier::DOUBLE_UNDERLINED, double_underlined(), not_double_underlined() -> T);
+    modifier!(Modifier::CURLY_UNDERLINED, curly_underlined(), not_curly_underlined() -> T);
+    modifier!(Modifier::DOTTED_UNDERLINED, dotted_underlined(), not_dotted_underlined() -> T);
+    modifier!(Modifier::DASHED_UNDERLINED, dashed_underlined(), not_dashed_underlined() -> T);
+    modifier!(Modifier::OVERLINED, overlined(), not_overlined() -> T);
 }
 
 impl<T, U> Stylize<'_, T> for U
@@ -462,6 +467,45 @@ mod tests {
         assert_eq!("hello".reset(), Span::from("hello").reset());
     }
 
+    #[test]
+    fn str_styled_decorations() {
+        assert_eq!(
+            "hello".double_underlined(),
+            Span::from("hello").double_underlined()
+        );
+        assert_eq!(
+            "hello".curly_underlined(),
+            Span::from("hello").curly_underlined()
+        );
+        assert_eq!(
+            "hello".dotted_underlined(),
+            Span::from("hello").dotted_underlined()
+        );
+        assert_eq!(
+            "hello".dashed_underlined(),
+            Span::from("hello").dashed_underlined()
+        );
+        assert_eq!("hello".overlined(), Span::from("hello").overlined());
+
+        assert_eq!(
+            "hello".not_double_underlined(),
+            Span::from("hello").not_double_underlined()
+        );
+        assert_eq!(
+            "hello".not_curly_underlined(),
+            Span::from("hello").not_curly_underlined()
+        );
+        assert_eq!(
+            "hello".not_dotted_underlined(),
+            Span::from("hello").not_dotted_underlined()
+        );
+        assert_eq!(
+            "hello".not_dashed_underlined(),
+            Span::from("hello").not_dashed_underlined()
+        );
+        assert_eq!("hello".not_overlined(), Span::from("hello").not_overlined());
+    }
+
     #[test]
     fn string_styled() {
         let s = String::from("hello");
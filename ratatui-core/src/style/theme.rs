@@ -0,0 +1,244 @@
+use crate::style::palette::{material, tailwind};
+use crate::style::{Modifier, Style};
+
+/// A set of [`Style`]s for the semantic roles of a user interface.
+///
+/// A theme maps roles such as borders, titles or the selected item to styles, so that an
+/// application can be restyled in one place rather than by changing the style of each widget.
+/// The theme set with [`Terminal::set_theme`] is stored in the [buffer] that widgets are rendered
+/// to. Built-in widgets such as `Block`, `List`, `Table` and `Tabs` use its roles for their default
+/// styles, and apply the styles set on the widget on top of them. Their `theme` method overrides
+/// the theme for a single widget. Custom widgets can read the theme from the buffer, or with
+/// [`Frame::theme`] while drawing.
+///
+/// [`Theme::new`] (also the default) leaves every role unstyled, which renders the same as not
+/// using a theme at all. [`Theme::tailwind`] and [`Theme::material`] build dark themes from the
+/// [`palette`] colors with a given accent color.
+///
+/// With the `serde` feature enabled, a theme can be loaded from any format supported by serde,
+/// such as TOML or JSON. Roles that are missing from the file are left unstyled.
+///
+/// ```toml
+/// border = { fg = "DarkGray" }
+/// title = { fg = "#FFFFFF", add_modifier = "BOLD" }
+/// selection = { fg = "Black", bg = "Cyan" }
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::palette::tailwind;
+/// use ratatui_core::style::{Color, Style, Theme};
+///
+/// let theme = Theme::tailwind(&tailwind::EMERALD);
+/// assert_eq!(theme.selection, Style::new().fg(tailwind::SLATE.c950).bg(tailwind::EMERALD.c400));
+///
+/// let theme = Theme {
+///     error: Style::new().fg(Color::LightRed),
+///     ..Theme::default()
+/// };
+/// ```
+///
+/// [`Terminal::set_theme`]: crate::terminal::Terminal::set_theme
+/// [buffer]: crate::buffer::Buffer::theme
+/// [`Frame::theme`]: crate::terminal::Frame::theme
+/// [`palette`]: crate::style::palette
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Theme {
+    /// The base style of widgets, usually the text and background colors.
+    pub base: Style,
+    /// The style of borders.
+    pub border: Style,
+    /// The style of titles.
+    pub title: Style,
+    /// The style of the selected item of lists, tables and tabs.
+    pub selection: Style,
+    /// The style of table headers.
+    pub header: Style,
+    /// The style of secondary content such as hints and separators.
+    pub muted: Style,
+    /// The style of error messages.
+    pub error: Style,
+    /// The style of warning messages.
+    pub warning: Style,
+    /// The style of success messages.
+    pub success: Style,
+    /// The style of informational messages.
+    pub info: Style,
+}
+
+impl Theme {
+    /// Creates a theme that leaves every role unstyled.
+    pub const fn new() -> Self {
+        Self {
+            base: Style::new(),
+            border: Style::new(),
+            title: Style::new(),
+            selection: Style::new(),
+            header: Style::new(),
+            muted: Style::new(),
+            error: Style::new(),
+            warning: Style::new(),
+            success: Style::new(),
+            info: Style::new(),
+        }
+    }
+
+    /// Creates a dark theme from the Tailwind [`SLATE`] palette with the given accent palette.
+    ///
+    /// [`SLATE`]: tailwind::SLATE
+    pub const fn tailwind(accent: &tailwind::Palette) -> Self {
+        let neutral = tailwind::SLATE;
+        Self {
+            base: Style::new().fg(neutral.c200).bg(neutral.c950),
+            border: Style::new().fg(neutral.c600),
+            title: Style::new().fg(accent.c300).add_modifier(Modifier::BOLD),
+            selection: Style::new().fg(neutral.c950).bg(accent.c400),
+            header: Style::new().fg(accent.c200).add_modifier(Modifier::BOLD),
+            muted: Style::new().fg(neutral.c500),
+            error: Style::new().fg(tailwind::RED.c400),
+            warning: Style::new().fg(tailwind::AMBER.c400),
+            success: Style::new().fg(tailwind::GREEN.c400),
+            info: Style::new().fg(tailwind::SKY.c400),
+        }
+    }
+
+    /// Creates a dark theme from the Material [`GRAY`] palette with the given accent palette.
+    ///
+    /// [`GRAY`]: material::GRAY
+    pub const fn material(accent: &material::AccentedPalette) -> Self {
+        let neutral = material::GRAY;
+        Self {
+            base: Style::new().fg(neutral.c200).bg(neutral.c900),
+            border: Style::new().fg(neutral.c600),
+            title: Style::new().fg(accent.c200).add_modifier(Modifier::BOLD),
+            selection: Style::new().fg(material::BLACK).bg(accent.a200),
+            header: Style::new().fg(accent.c100).add_modifier(Modifier::BOLD),
+            muted: Style::new().fg(neutral.c500),
+            error: Style::new().fg(material::RED.c300),
+            warning: Style::new().fg(material::AMBER.c300),
+            success: Style::new().fg(material::GREEN.c300),
+            info: Style::new().fg(material::LIGHT_BLUE.c300),
+        }
+    }
+
+    /// Returns the style of a role by name, or `None` if there is no role with that name.
+    ///
+    /// Role names are the names of the fields of [`Theme`], e.g. `"border"` or `"selection"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Style, Theme};
+    ///
+    /// let theme = Theme {
+    ///     error: Style::new().fg(Color::Red),
+    ///     ..Theme::default()
+    /// };
+    /// assert_eq!(theme.role("error"), Some(Style::new().fg(Color::Red)));
+    /// assert_eq!(theme.role("unknown"), None);
+    /// ```
+    pub fn role(&self, name: &str) -> Option<Style> {
+        let style = match name {
+            "base" => self.base,
+            "border" => self.border,
+            "title" => self.title,
+            "selection" => self.selection,
+            "header" => self.header,
+            "muted" => self.muted,
+            "error" => self.error,
+            "warning" => self.warning,
+            "success" => self.success,
+            "info" => self.info,
+            _ => return None,
+        };
+        Some(style)
+    }
+}
+
+impl From<Theme> for Style {
+    /// Returns the base style of the theme.
+    fn from(theme: Theme) -> Self {
+        theme.base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_is_unstyled() {
+        let theme = Theme::new();
+        assert_eq!(theme, Theme::default());
+        assert_eq!(theme.border, Style::new());
+        assert_eq!(Style::from(theme), Style::new());
+    }
+
+    #[test]
+    fn tailwind() {
+        let theme = Theme::tailwind(&tailwind::BLUE);
+        assert_eq!(
+            theme.base,
+            Style::new()
+                .fg(tailwind::SLATE.c200)
+                .bg(tailwind::SLATE.c950)
+        );
+        assert_eq!(
+            theme.selection,
+            Style::new()
+                .fg(tailwind::SLATE.c950)
+                .bg(tailwind::BLUE.c400)
+        );
+    }
+
+    #[test]
+    fn material() {
+        let theme = Theme::material(&material::TEAL);
+        assert_eq!(
+            theme.selection,
+            Style::new().fg(material::BLACK).bg(material::TEAL.a200)
+        );
+    }
+
+    #[test]
+    fn role() {
+        let theme = Theme::tailwind(&tailwind::BLUE);
+        assert_eq!(theme.role("title"), Some(theme.title));
+        assert_eq!(theme.role("info"), Some(theme.info));
+        assert_eq!(theme.role("Title"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        use crate::style::Color;
+
+        let json = r##"{
+            "border": { "fg": "DarkGray" },
+            "selection": { "fg": "#000000", "bg": "Cyan", "add_modifier": "BOLD" }
+        }"##;
+        let theme: Theme = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            theme,
+            Theme {
+                border: Style::new().fg(Color::DarkGray),
+                selection: Style::new()
+                    .fg(Color::Rgb(0, 0, 0))
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+                ..Theme::new()
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_then_deserialize() {
+        let theme = Theme::material(&material::INDIGO);
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+    }
+}
@@ -32,9 +32,13 @@
 //! [`Buffer`]: crate::buffer::Buffer
 
 mod frame;
+#[cfg(feature = "scrolling-regions")]
+mod scroll;
+mod stats;
 mod terminal;
 mod viewport;
 
-pub use frame::{CompletedFrame, Frame};
+pub use frame::{CompletedFrame, Frame, HitRegion};
+pub use stats::FrameStats;
 pub use terminal::{Options as TerminalOptions, Terminal};
 pub use viewport::Viewport;
@@ -1,5 +1,11 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use crate::backend::CursorStyle;
 use crate::buffer::Buffer;
 use crate::layout::{Position, Rect};
+use crate::style::Theme;
+use crate::terminal::FrameStats;
 use crate::widgets::{StatefulWidget, Widget};
 
 /// A consistent view into the terminal state for rendering a single frame.
@@ -21,6 +27,9 @@ pub struct Frame<'a> {
     /// y))`, the cursor is shown and placed at `(x, y)` after the call to `Terminal::draw()`.
     pub(crate) cursor_position: Option<Position>,
 
+    /// The style of the cursor after drawing this frame
+    pub(crate) cursor_style: CursorStyle,
+
     /// The area of the viewport
     pub(crate) viewport_area: Rect,
 
@@ -29,6 +38,23 @@ pub struct Frame<'a> {
 
     /// The frame count indicating the sequence number of this frame.
     pub(crate) count: usize,
+
+    /// The hit regions registered while drawing this frame, in the order they were registered
+    pub(crate) hit_regions: Vec<HitRegion>,
+}
+
+/// A named area of a [`Frame`] used to find out what was rendered under a mouse position.
+///
+/// Hit regions are registered with [`Frame::register_hit_region`] while drawing, and can be
+/// queried after the frame has been drawn with [`Terminal::hit_test`] until the next draw call.
+///
+/// [`Terminal::hit_test`]: crate::terminal::Terminal::hit_test
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct HitRegion {
+    /// The identifier the region was registered with.
+    pub id: Cow<'static, str>,
+    /// The area covered by the region.
+    pub area: Rect,
 }
 
 /// `CompletedFrame` represents the state of the terminal after all changes performed in the last
@@ -44,6 +70,8 @@ pub struct CompletedFrame<'a> {
     pub area: Rect,
     /// The frame count indicating the sequence number of this frame.
     pub count: usize,
+    /// The statistics of how the frame was drawn.
+    pub stats: FrameStats,
 }
 
 impl Frame<'_> {
@@ -102,6 +130,10 @@ impl Frame<'_> {
     /// The last argument should be an instance of the [`StatefulWidget::State`] associated to the
     /// given [`StatefulWidget`].
     ///
+    /// If the widget reports a [cursor position](StatefulWidget::cursor_position) after rendering,
+    /// e.g. a focused text input, the cursor is shown at that position like with
+    /// [`Frame::set_cursor_position`].
+    ///
     /// # Example
     ///
     /// ```rust,ignore
@@ -126,6 +158,9 @@ impl Frame<'_> {
         W: StatefulWidget,
     {
         widget.render(area, self.buffer, state);
+        if let Some(position) = W::cursor_position(state) {
+            self.set_cursor_position(position);
+        }
     }
 
     /// After drawing this frame, make the cursor visible and put it at the specified (x, y)
@@ -157,7 +192,75 @@ impl Frame<'_> {
         self.set_cursor_position(Position { x, y });
     }
 
+    /// After drawing this frame, give the cursor the specified shape and blinking behavior. If this
+    /// method is not called, the cursor has the style configured by the user.
+    ///
+    /// The style is only sent to the terminal when it differs from the style of the previous
+    /// frame. It is restored to [`CursorStyle::Default`] when the [`Terminal`] is dropped and by
+    /// `ratatui::restore`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(10, 10);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui::backend::CursorStyle;
+    ///
+    /// // a bar cursor in insert mode, as in modal editors
+    /// frame.set_cursor_position((2, 0));
+    /// frame.set_cursor_style(CursorStyle::SteadyBar);
+    /// ```
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    pub const fn set_cursor_style(&mut self, style: CursorStyle) {
+        self.cursor_style = style;
+    }
+
+    /// Registers a named area of the frame so that mouse events can be mapped back to it.
+    ///
+    /// After the frame has been drawn, [`Terminal::hit_test`] returns the last registered region
+    /// that contains a given position, so regions registered later (e.g. popups rendered over
+    /// other widgets) take precedence. The regions are replaced on each draw call.
+    ///
+    /// To find the item under the mouse inside a widget, use the areas returned by widgets that
+    /// support hit-testing instead, such as `List::render_with_areas` or
+    /// `Table::render_with_areas`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(10, 10);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// use ratatui::layout::{Position, Rect};
+    ///
+    /// terminal.draw(|frame| {
+    ///     frame.register_hit_region("sidebar", Rect::new(0, 0, 3, 10));
+    /// })?;
+    /// let region = terminal.hit_test(Position::new(1, 5)).unwrap();
+    /// assert_eq!(region.id, "sidebar");
+    /// ```
+    ///
+    /// [`Terminal::hit_test`]: crate::terminal::Terminal::hit_test
+    pub fn register_hit_region<S: Into<Cow<'static, str>>>(&mut self, id: S, area: Rect) {
+        let area = area.intersection(self.viewport_area);
+        self.hit_regions.push(HitRegion {
+            id: id.into(),
+            area,
+        });
+    }
+
+    /// Returns the hit regions registered so far while drawing this frame.
+    pub fn hit_regions(&self) -> &[HitRegion] {
+        &self.hit_regions
+    }
+
     /// Gets the buffer that this `Frame` draws into as a mutable reference.
+    ///
+    /// The buffer can be split into disjoint views with [`Buffer::split_mut`] to render
+    /// independent areas of the frame concurrently.
     pub const fn buffer_mut(&mut self) -> &mut Buffer {
         self.buffer
     }
@@ -189,4 +292,26 @@ impl Frame<'_> {
     pub const fn count(&self) -> usize {
         self.count
     }
+
+    /// Returns the theme of the terminal, as set with [`Terminal::set_theme`].
+    ///
+    /// The theme is the [theme of the buffer](Buffer::theme) of the frame, which the built-in
+    /// widgets use for their default styles. Custom widgets can use its styles too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # use ratatui::widgets::Paragraph;
+    /// # let backend = TestBackend::new(5, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// let warning = Paragraph::new("Low disk space").style(frame.theme().warning);
+    /// frame.render_widget(warning, frame.area());
+    /// ```
+    ///
+    /// [`Terminal::set_theme`]: crate::terminal::Terminal::set_theme
+    pub const fn theme(&self) -> &Theme {
+        &self.buffer.theme
+    }
 }
@@ -0,0 +1,352 @@
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use core::ops::Range;
+
+use hashbrown::DefaultHashBuilder;
+
+use crate::buffer::{Buffer, Cell};
+
+/// Rows of the screen that moved vertically between two frames, which can be scrolled instead of
+/// being drawn again.
+///
+/// Scrolling regions span the full width of the screen, so the rows of the buffers must span it
+/// too.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(super) enum RowShift {
+    /// The rows of the region moved up, and its bottom rows are new.
+    Up {
+        /// The rows of the screen to scroll
+        region: Range<u16>,
+        /// The number of rows to scroll by
+        lines: u16,
+    },
+    /// The rows of the region moved down, and its top rows are new.
+    Down {
+        /// The rows of the screen to scroll
+        region: Range<u16>,
+        /// The number of rows to scroll by
+        lines: u16,
+    },
+}
+
+/// The number of cells that a shift must save from being drawn, which is about the number of bytes
+/// of the escape sequences that scroll a region.
+const MIN_SAVED_CELLS: usize = 16;
+
+/// Returns the cells of a row of the buffer, relative to the top of the buffer.
+fn row(buffer: &Buffer, y: u16) -> &[Cell] {
+    let width = usize::from(buffer.area.width);
+    let start = usize::from(y) * width;
+    &buffer.content[start..start + width]
+}
+
+/// What is known about each row of the previous and next buffers, which is computed once per frame
+/// and updated as the shifts are applied.
+struct Rows {
+    hasher: DefaultHashBuilder,
+    /// The hash of each row of the previous buffer
+    previous: Vec<u64>,
+    /// The hash of each row of the next buffer
+    next: Vec<u64>,
+    /// The number of cells to draw for each row without scrolling
+    changed: Vec<usize>,
+    /// The number of cells to draw for each row after scrolling it in, i.e. its non-empty cells
+    exposed: Vec<usize>,
+}
+
+impl Rows {
+    fn new(previous: &Buffer, next: &Buffer) -> Self {
+        let hasher = DefaultHashBuilder::default();
+        let height = next.area.height;
+        let hash_rows = |buffer| {
+            (0..height)
+                .map(|y| hasher.hash_one(row(buffer, y)))
+                .collect()
+        };
+        let changed = (0..height)
+            .map(|y| {
+                let cells = row(previous, y).iter().zip(row(next, y));
+                cells.filter(|(from, to)| from != to).count()
+            })
+            .collect();
+        let exposed = (0..height)
+            .map(|y| {
+                row(next, y)
+                    .iter()
+                    .filter(|&cell| *cell != Cell::EMPTY)
+                    .count()
+            })
+            .collect();
+        Self {
+            previous: hash_rows(previous),
+            next: hash_rows(next),
+            changed,
+            exposed,
+            hasher,
+        }
+    }
+
+    /// Finds the shift that saves the most cells from being drawn, comparing the hashes of the
+    /// rows. The region of the shift is relative to the top of the buffers.
+    fn best_shift(&self, scroll_first_row: bool, top: u16) -> Option<(RowShift, Range<u16>)> {
+        if self.changed.iter().all(|&count| count == 0) {
+            return None;
+        }
+        let height = u16::try_from(self.next.len()).unwrap_or(u16::MAX);
+        let mut best: Option<(usize, RowShift, Range<u16>)> = None;
+        for lines in 1..height {
+            for up in [true, false] {
+                let rows = if up { 0..height - lines } else { lines..height };
+                let source = |y: u16| if up { y + lines } else { y - lines };
+                let moved =
+                    |y: u16| self.next[usize::from(y)] == self.previous[usize::from(source(y))];
+                let mut y = rows.start;
+                while y < rows.end {
+                    if self.changed[usize::from(y)] == 0 || !moved(y) {
+                        y += 1;
+                        continue;
+                    }
+                    let start = y;
+                    while y < rows.end && moved(y) {
+                        y += 1;
+                    }
+                    let (region, new_rows) = if up {
+                        (start..y + lines, y..y + lines)
+                    } else {
+                        (start - lines..y, start - lines..start)
+                    };
+                    if up && !scroll_first_row && top + region.start == 0 {
+                        continue;
+                    }
+                    let before: usize = region.clone().map(|y| self.changed[usize::from(y)]).sum();
+                    let after: usize = new_rows.map(|y| self.exposed[usize::from(y)]).sum();
+                    let saved = before.saturating_sub(after);
+                    if saved > MIN_SAVED_CELLS
+                        && best.as_ref().is_none_or(|(most, ..)| saved > *most)
+                    {
+                        let screen_region = top + region.start..top + region.end;
+                        let shift = if up {
+                            RowShift::Up {
+                                region: screen_region,
+                                lines,
+                            }
+                        } else {
+                            RowShift::Down {
+                                region: screen_region,
+                                lines,
+                            }
+                        };
+                        best = Some((saved, shift, start..y));
+                    }
+                }
+            }
+        }
+        best.map(|(_, shift, moved)| (shift, moved))
+    }
+
+    /// Updates the rows of the region of the shift after it was applied to the previous buffer, in
+    /// which the moved rows now match the next buffer.
+    fn update(&mut self, previous: &Buffer, region: Range<u16>, moved: &Range<u16>) {
+        for y in region {
+            let index = usize::from(y);
+            if moved.contains(&y) {
+                self.previous[index] = self.next[index];
+                self.changed[index] = 0;
+            } else {
+                self.previous[index] = self.hasher.hash_one(row(previous, y));
+                self.changed[index] = self.exposed[index];
+            }
+        }
+    }
+}
+
+impl RowShift {
+    /// Finds the rows of the previous buffer that moved vertically in the next buffer, and scrolls
+    /// them in the previous buffer like the terminal does, returning the shifts to apply to the
+    /// terminal in order.
+    ///
+    /// Each shift is the one that saves the most cells from being drawn. A shift is only
+    /// worthwhile when it saves drawing more than [`MIN_SAVED_CELLS`] cells, as scrolling writes a
+    /// few escape sequences and erases the new rows. The rows are hashed once, and moved rows are
+    /// found by comparing the hashes, so this takes time proportional to the square of the height
+    /// of the buffer plus its number of cells.
+    ///
+    /// Regions that include the first row of the screen are only scrolled up when
+    /// `scroll_first_row` is set, as the terminal copies the rows that scroll out of the first row
+    /// into its scrollback, unless it is showing the alternate screen.
+    pub(super) fn find_all(
+        previous: &mut Buffer,
+        next: &Buffer,
+        scroll_first_row: bool,
+    ) -> Vec<Self> {
+        let area = next.area;
+        if previous.area != area || area.is_empty() {
+            return Vec::new();
+        }
+        let mut rows = Rows::new(previous, next);
+        let mut shifts = Vec::new();
+        while let Some((shift, moved)) = rows.best_shift(scroll_first_row, area.y) {
+            // the hashes of the rows could collide, so the moved rows are checked once before
+            // scrolling them
+            let source = |y: u16| match shift {
+                Self::Up { lines, .. } => y + lines,
+                Self::Down { lines, .. } => y - lines,
+            };
+            if !moved
+                .clone()
+                .all(|y| row(next, y) == row(previous, source(y)))
+            {
+                break;
+            }
+            shift.apply(previous);
+            let region = shift.region();
+            rows.update(previous, region.start - area.y..region.end - area.y, &moved);
+            shifts.push(shift);
+        }
+        shifts
+    }
+
+    /// Returns the rows of the screen to scroll.
+    const fn region(&self) -> &Range<u16> {
+        match self {
+            Self::Up { region, .. } | Self::Down { region, .. } => region,
+        }
+    }
+
+    /// Scrolls the rows of the buffer like the terminal does, replacing the new rows with empty
+    /// cells.
+    pub(super) fn apply(&self, buffer: &mut Buffer) {
+        let width = usize::from(buffer.area.width);
+        let top = buffer.area.y;
+        let (region, lines, up) = match self {
+            Self::Up { region, lines } => (region, lines, true),
+            Self::Down { region, lines } => (region, lines, false),
+        };
+        let start = usize::from(region.start - top) * width;
+        let end = usize::from(region.end - top) * width;
+        let cells = &mut buffer.content[start..end];
+        let shifted = usize::from(*lines) * width;
+        if up {
+            let kept = cells.len() - shifted;
+            cells.rotate_left(shifted);
+            cells[kept..].fill(Cell::EMPTY);
+        } else {
+            cells.rotate_right(shifted);
+            cells[..shifted].fill(Cell::EMPTY);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use super::*;
+
+    /// Returns a buffer with a header row, followed by rows of repeated letters from the first
+    /// letter.
+    fn log(header: bool, first: u8) -> Buffer {
+        let header = header.then(|| String::from("header"));
+        let rows = (first..first + 5).map(|letter| char::from(letter).to_string().repeat(10));
+        Buffer::with_lines(header.into_iter().chain(rows))
+    }
+
+    /// Returns the buffer with the rows erased, as after scrolling.
+    fn cleared(mut buffer: Buffer, rows: Range<u16>) -> Buffer {
+        for y in rows {
+            for x in 0..buffer.area.width {
+                buffer[(x, y)] = Cell::EMPTY;
+            }
+        }
+        buffer
+    }
+
+    #[test]
+    fn scroll_up() {
+        let mut previous = log(true, b'a');
+        let shifts = RowShift::find_all(&mut previous, &log(true, b'b'), false);
+        assert_eq!(
+            shifts,
+            [RowShift::Up {
+                region: 1..6,
+                lines: 1
+            }]
+        );
+        assert_eq!(previous, cleared(log(true, b'b'), 5..6));
+    }
+
+    #[test]
+    fn scroll_down() {
+        let mut previous = log(false, b'c');
+        let shifts = RowShift::find_all(&mut previous, &log(false, b'a'), false);
+        assert_eq!(
+            shifts,
+            [RowShift::Down {
+                region: 0..5,
+                lines: 2
+            }]
+        );
+        assert_eq!(previous, cleared(log(false, b'a'), 0..2));
+    }
+
+    #[test]
+    fn does_not_scroll_up_first_row() {
+        let mut previous = log(false, b'a');
+        assert_eq!(
+            RowShift::find_all(&mut previous, &log(false, b'b'), false),
+            []
+        );
+        assert_eq!(previous, log(false, b'a'));
+    }
+
+    #[test]
+    fn scroll_up_first_row() {
+        let mut previous = log(false, b'a');
+        let shifts = RowShift::find_all(&mut previous, &log(false, b'b'), true);
+        assert_eq!(
+            shifts,
+            [RowShift::Up {
+                region: 0..5,
+                lines: 1
+            }]
+        );
+        assert_eq!(previous, cleared(log(false, b'b'), 4..5));
+    }
+
+    #[test]
+    fn scroll_several_regions() {
+        // a header, a log that scrolls up and a log that scrolls down
+        let panes = |up: u8, down: u8| {
+            let rows = (up..up + 5).chain(down..down + 5);
+            let rows = rows.map(|letter| char::from(letter).to_string().repeat(10));
+            Buffer::with_lines(core::iter::once(String::from("header")).chain(rows))
+        };
+        let mut previous = panes(b'a', b'p');
+        let shifts = RowShift::find_all(&mut previous, &panes(b'b', b'o'), false);
+        assert_eq!(
+            shifts,
+            [
+                RowShift::Up {
+                    region: 1..6,
+                    lines: 1
+                },
+                RowShift::Down {
+                    region: 6..11,
+                    lines: 1
+                }
+            ]
+        );
+        assert_eq!(previous, cleared(panes(b'b', b'o'), 5..7));
+    }
+
+    #[test]
+    fn does_not_scroll_small_changes() {
+        let previous = Buffer::with_lines(["aaaaaaaaaa", "bb        ", "c         "]);
+        let next = Buffer::with_lines(["aaaaaaaaaa", "c         ", "          "]);
+        assert_eq!(RowShift::find_all(&mut previous.clone(), &next, false), []);
+        assert_eq!(
+            RowShift::find_all(&mut previous.clone(), &previous, false),
+            []
+        );
+    }
+}
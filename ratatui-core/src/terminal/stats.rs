@@ -0,0 +1,120 @@
+use core::time::Duration;
+
+use crate::backend::OutputStats;
+
+/// Statistics about how a frame was drawn by [`Terminal::draw`].
+///
+/// The statistics of each frame are available in [`CompletedFrame::stats`]. They can be used to
+/// find out why some frames are slow or write a lot of output, e.g. by displaying them with the
+/// `FrameStatsOverlay` widget.
+///
+/// The durations are measured with [`std::time::Instant`], and are always zero when the `std`
+/// feature is disabled.
+///
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+/// [`CompletedFrame::stats`]: crate::terminal::CompletedFrame::stats
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FrameStats {
+    /// The time spent in the render callback.
+    pub render_time: Duration,
+    /// The time spent comparing the frame with the previous one in [`Buffer::diff`].
+    ///
+    /// [`Buffer::diff`]: crate::buffer::Buffer::diff
+    pub diff_time: Duration,
+    /// The time spent writing the changes to the backend and flushing it.
+    pub flush_time: Duration,
+    /// The time elapsed since the previous frame started drawing, or zero for the first frame.
+    pub frame_interval: Duration,
+    /// The number of cells that changed since the previous frame and were drawn.
+    pub changed_cells: usize,
+    /// The output written by the backend to draw the frame, or `None` if the backend does not
+    /// count its output (see [`Backend::output_stats`]).
+    ///
+    /// [`Backend::output_stats`]: crate::backend::Backend::output_stats
+    pub output: Option<OutputStats>,
+}
+
+impl FrameStats {
+    /// Returns the time spent drawing the frame, from the start of the render callback to the end
+    /// of the flush.
+    pub const fn total_time(&self) -> Duration {
+        self.render_time
+            .saturating_add(self.diff_time)
+            .saturating_add(self.flush_time)
+    }
+
+    /// Returns the number of frames per second at the rate of the last two frames, or `None` for
+    /// the first frame.
+    pub fn frames_per_second(&self) -> Option<f64> {
+        (!self.frame_interval.is_zero()).then(|| 1.0 / self.frame_interval.as_secs_f64())
+    }
+}
+
+/// Measures the time elapsed since it was started, which is always zero without the `std` feature.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub(super) struct Stopwatch {
+    #[cfg(feature = "std")]
+    started: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl Stopwatch {
+    /// Starts a stopwatch at the current time
+    pub(super) fn start() -> Self {
+        Self {
+            started: std::time::Instant::now(),
+        }
+    }
+
+    /// Returns the time elapsed between the start of an earlier stopwatch and the start of this one
+    pub(super) fn since(self, earlier: Self) -> Duration {
+        self.started.saturating_duration_since(earlier.started)
+    }
+
+    /// Returns the time elapsed since the stopwatch was started
+    pub(super) fn elapsed(self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+#[expect(clippy::unused_self)]
+impl Stopwatch {
+    pub(super) const fn start() -> Self {
+        Self {}
+    }
+
+    pub(super) const fn since(self, _earlier: Self) -> Duration {
+        Duration::ZERO
+    }
+
+    pub(super) const fn elapsed(self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_time() {
+        let stats = FrameStats {
+            render_time: Duration::from_millis(3),
+            diff_time: Duration::from_millis(1),
+            flush_time: Duration::from_millis(2),
+            ..FrameStats::default()
+        };
+        assert_eq!(stats.total_time(), Duration::from_millis(6));
+    }
+
+    #[test]
+    fn frames_per_second() {
+        assert_eq!(FrameStats::default().frames_per_second(), None);
+        let stats = FrameStats {
+            frame_interval: Duration::from_millis(20),
+            ..FrameStats::default()
+        };
+        assert_eq!(stats.frames_per_second(), Some(50.0));
+    }
+}
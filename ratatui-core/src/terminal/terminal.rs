@@ -1,7 +1,14 @@
-use crate::backend::{Backend, ClearType};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::backend::{Backend, ClearType, CursorStyle};
 use crate::buffer::{Buffer, Cell};
 use crate::layout::{Position, Rect, Size};
-use crate::terminal::{CompletedFrame, Frame, TerminalOptions, Viewport};
+use crate::style::Theme;
+#[cfg(feature = "scrolling-regions")]
+use crate::terminal::scroll::RowShift;
+use crate::terminal::stats::Stopwatch;
+use crate::terminal::{CompletedFrame, Frame, FrameStats, HitRegion, TerminalOptions, Viewport};
 
 /// An interface to interact and draw [`Frame`]s on the user's terminal.
 ///
@@ -73,6 +80,7 @@ use crate::terminal::{CompletedFrame, Frame, TerminalOptions, Viewport};
 /// [`Backend`]: crate::backend::Backend
 /// [`Buffer`]: crate::buffer::Buffer
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[expect(clippy::struct_excessive_bools)] // independent options and state of the terminal
 pub struct Terminal<B>
 where
     B: Backend,
@@ -86,6 +94,8 @@ where
     current: usize,
     /// Whether the cursor is currently hidden
     hidden_cursor: bool,
+    /// The style of the cursor set by the last frame
+    cursor_style: CursorStyle,
     /// Viewport
     viewport: Viewport,
     /// Area of the viewport
@@ -97,6 +107,18 @@ where
     last_known_cursor_pos: Position,
     /// Number of frames rendered up until current time.
     frame_count: usize,
+    /// When the last frame started drawing, used to measure the interval between frames
+    last_draw_start: Option<Stopwatch>,
+    /// Hit regions registered while drawing the last frame
+    hit_regions: Vec<HitRegion>,
+    /// The theme of the buffers that widgets are rendered to
+    theme: Theme,
+    /// Whether frames are written as synchronized updates
+    synchronized_output: bool,
+    /// Whether the terminal shows the alternate screen
+    alternate_screen: bool,
+    /// Whether rows that moved between frames are drawn again rather than scrolled
+    redraw_moved_rows: bool,
 }
 
 /// Options to pass to [`Terminal::with_options`]
@@ -104,6 +126,23 @@ where
 pub struct Options {
     /// Viewport used to draw to the terminal
     pub viewport: Viewport,
+    /// Whether each frame is written as a synchronized update to avoid tearing.
+    ///
+    /// This has no effect when the backend does not support synchronized output (see
+    /// [`Backend::supports_synchronized_output`]).
+    pub synchronized_output: bool,
+    /// Whether the terminal shows the alternate screen, which has no scrollback.
+    ///
+    /// With the `scrolling-regions` feature, a fullscreen viewport in the alternate screen can also
+    /// scroll the rows at the top of the screen, which would otherwise be copied into the
+    /// scrollback.
+    pub alternate_screen: bool,
+    /// Whether rows that moved between frames are drawn again rather than scrolled.
+    ///
+    /// This only has an effect with the `scrolling-regions` feature, where rows that moved are
+    /// scrolled by default (see [`Terminal::flush`]). Drawing them again is useful on terminals
+    /// that handle scrolling regions poorly, or to compare the output of both methods.
+    pub redraw_moved_rows: bool,
 }
 
 impl<B> Drop for Terminal<B>
@@ -119,6 +158,13 @@ where
                 std::eprintln!("Failed to show the cursor: {err}");
             }
         }
+        if self.cursor_style != CursorStyle::Default {
+            #[allow(unused_variables)]
+            if let Err(err) = self.backend.set_cursor_style(CursorStyle::Default) {
+                #[cfg(feature = "std")]
+                std::eprintln!("Failed to restore the cursor style: {err}");
+            }
+        }
     }
 }
 
@@ -159,6 +205,9 @@ where
             backend,
             TerminalOptions {
                 viewport: Viewport::Fullscreen,
+                synchronized_output: false,
+                alternate_screen: false,
+                redraw_moved_rows: false,
             },
         )
     }
@@ -174,12 +223,19 @@ where
     ///
     /// let backend = CrosstermBackend::new(stdout());
     /// let viewport = Viewport::Fixed(Rect::new(0, 0, 10, 10));
-    /// let terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+    /// let options = TerminalOptions {
+    ///     viewport,
+    ///     synchronized_output: true,
+    ///     ..Default::default()
+    /// };
+    /// let terminal = Terminal::with_options(backend, options)?;
     /// # std::io::Result::Ok(())
     /// ```
     pub fn with_options(mut backend: B, options: TerminalOptions) -> Result<Self, B::Error> {
         let area = match options.viewport {
-            Viewport::Fullscreen | Viewport::Inline(_) => backend.size()?.into(),
+            Viewport::Fullscreen | Viewport::Inline(_) | Viewport::InlineAuto(_) => {
+                backend.size()?.into()
+            }
             Viewport::Fixed(area) => area,
         };
         let (viewport_area, cursor_pos) = match options.viewport {
@@ -187,6 +243,8 @@ where
             Viewport::Inline(height) => {
                 compute_inline_size(&mut backend, height, area.as_size(), 0)?
             }
+            // the viewport grows to fit the first frame
+            Viewport::InlineAuto(_) => compute_inline_size(&mut backend, 1, area.as_size(), 0)?,
             Viewport::Fixed(area) => (area, area.as_position()),
         };
         Ok(Self {
@@ -194,11 +252,18 @@ where
             buffers: [Buffer::empty(viewport_area), Buffer::empty(viewport_area)],
             current: 0,
             hidden_cursor: false,
+            cursor_style: CursorStyle::Default,
             viewport: options.viewport,
             viewport_area,
             last_known_area: area,
             last_known_cursor_pos: cursor_pos,
             frame_count: 0,
+            last_draw_start: None,
+            hit_regions: Vec::new(),
+            theme: Theme::new(),
+            synchronized_output: options.synchronized_output,
+            alternate_screen: options.alternate_screen,
+            redraw_moved_rows: options.redraw_moved_rows,
         })
     }
 
@@ -241,9 +306,11 @@ where
         let count = self.frame_count;
         Frame {
             cursor_position: None,
+            cursor_style: CursorStyle::Default,
             viewport_area: self.viewport_area,
-            buffer: self.current_buffer_mut(),
+            buffer: &mut self.buffers[self.current],
             count,
+            hit_regions: Vec::new(),
         }
     }
 
@@ -264,14 +331,39 @@ where
 
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
+    ///
+    /// With the `scrolling-regions` feature, rows that moved up or down since the previous buffer
+    /// (e.g. in a log viewer or a chat pane) are first scrolled with
+    /// [`Backend::scroll_region_up`] or [`Backend::scroll_region_down`], so that only the new rows
+    /// are drawn. This is only done for fullscreen and inline viewports, which span the full width
+    /// of the screen like scrolling regions do. Rows at the top of the screen are only scrolled up
+    /// in the [alternate screen](TerminalOptions::alternate_screen), as the terminal would otherwise
+    /// copy them into its scrollback. Set [`TerminalOptions::redraw_moved_rows`] to draw the moved
+    /// rows again instead.
     pub fn flush(&mut self) -> Result<(), B::Error> {
+        self.flush_with_stats(&mut FrameStats::default())
+    }
+
+    /// Flushes the changes of the current buffer like [`Terminal::flush`], recording the time
+    /// spent in [`Buffer::diff`] and the number of changed cells in the statistics.
+    fn flush_with_stats(&mut self, stats: &mut FrameStats) -> Result<(), B::Error> {
+        #[cfg(feature = "scrolling-regions")]
+        self.scroll_moved_rows()?;
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
+        let diffing = Stopwatch::start();
         let updates = previous_buffer.diff(current_buffer);
+        stats.diff_time = diffing.elapsed();
+        stats.changed_cells = updates.len();
         if let Some((col, row, _)) = updates.last() {
             self.last_known_cursor_pos = Position { x: *col, y: *row };
         }
-        self.backend.draw(updates.into_iter())
+        // the screen is only known to be as wide as the last known area for the viewports that are
+        // resized with the screen
+        let screen_width =
+            (!matches!(self.viewport, Viewport::Fixed(_))).then_some(self.last_known_area.width);
+        self.backend
+            .draw_diff(current_buffer, &updates, screen_width)
     }
 
     /// Updates the Terminal so that internal buffers match the requested area.
@@ -280,7 +372,11 @@ where
     /// of the screen.
     pub fn resize(&mut self, area: Rect) -> Result<(), B::Error> {
         let next_area = match self.viewport {
-            Viewport::Inline(height) => {
+            Viewport::Inline(_) | Viewport::InlineAuto(_) => {
+                let height = match self.viewport {
+                    Viewport::Inline(height) => height,
+                    _ => self.viewport_area.height,
+                };
                 let offset_in_previous_viewport = self
                     .last_known_cursor_pos
                     .y
@@ -308,10 +404,111 @@ where
         self.viewport_area = area;
     }
 
+    /// Changes the height of an inline viewport.
+    ///
+    /// When the viewport grows, it takes the rows below it, and the content above it is scrolled
+    /// into the scrollback when there are not enough rows left on the screen. When it shrinks, the
+    /// rows that are no longer part of the viewport are cleared. The rows at the top of the
+    /// viewport are kept in both cases, and the new height is limited to the height of the screen.
+    ///
+    /// For [`Viewport::InlineAuto`], this sets the maximum height of the viewport instead, and
+    /// only shrinks the viewport when it is taller than the new maximum.
+    ///
+    /// This has no effect on fullscreen and fixed viewports.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ratatui::{backend::TestBackend, Terminal, TerminalOptions, Viewport};
+    ///
+    /// let backend = TestBackend::new(10, 10);
+    /// let options = TerminalOptions {
+    ///     viewport: Viewport::Inline(2),
+    ///     ..Default::default()
+    /// };
+    /// let mut terminal = Terminal::with_options(backend, options)?;
+    /// terminal.set_inline_height(10)?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn set_inline_height(&mut self, height: u16) -> Result<(), B::Error> {
+        match self.viewport {
+            Viewport::Inline(_) => {
+                self.viewport = Viewport::Inline(height);
+                self.resize_inline_viewport(height)
+            }
+            Viewport::InlineAuto(_) => {
+                self.viewport = Viewport::InlineAuto(height);
+                if self.viewport_area.height > height {
+                    self.resize_inline_viewport(height)?;
+                }
+                Ok(())
+            }
+            Viewport::Fullscreen | Viewport::Fixed(_) => Ok(()),
+        }
+    }
+
+    /// Resizes the inline viewport to the given height, keeping its top rows.
+    ///
+    /// Both buffers are moved and resized to the new area, so this also truncates a current buffer
+    /// that is taller than the viewport.
+    fn resize_inline_viewport(&mut self, height: u16) -> Result<(), B::Error> {
+        let screen = self.last_known_area;
+        let area = self.viewport_area;
+        let height = height.min(screen.height);
+        let mut next_area = Rect { height, ..area };
+        match height.cmp(&area.height) {
+            Ordering::Greater => {
+                let available = screen.bottom().saturating_sub(area.bottom());
+                let lines_to_scroll = (height - area.height).saturating_sub(available);
+                self.scroll_up(lines_to_scroll)?;
+                next_area.y = area.y.saturating_sub(lines_to_scroll);
+                // the rows taken by the viewport may still hold other output
+                self.clear_rows_from(next_area.y + area.height)?;
+            }
+            Ordering::Less => self.clear_rows_from(next_area.bottom())?,
+            Ordering::Equal => {}
+        }
+        // the rows of the buffers stay at the top of the viewport when it moves
+        for buffer in &mut self.buffers {
+            buffer.resize(next_area);
+        }
+        self.viewport_area = next_area;
+        Ok(())
+    }
+
+    /// Clears the screen from the start of the given row to the end of the screen.
+    fn clear_rows_from(&mut self, y: u16) -> Result<(), B::Error> {
+        self.set_cursor_position(Position::new(0, y))?;
+        self.backend.clear_region(ClearType::CurrentLine)?;
+        self.backend.clear_region(ClearType::AfterCursor)
+    }
+
+    /// Returns the number of rows of the current buffer used by the last rendered frame, which is
+    /// at least one.
+    fn rendered_height(&self, cursor_position: Option<Position>) -> u16 {
+        let buffer = &self.buffers[self.current];
+        let width = usize::from(buffer.area.width).max(1);
+        let content_rows = buffer
+            .content
+            .iter()
+            .rposition(|cell| *cell != Cell::EMPTY)
+            .map_or(0, |index| index / width + 1);
+        let cursor_rows = cursor_position.map_or(0, |position| {
+            position.y.saturating_sub(buffer.area.y).saturating_add(1)
+        });
+        u16::try_from(content_rows)
+            .unwrap_or(u16::MAX)
+            .max(cursor_rows)
+            .clamp(1, buffer.area.height.max(1))
+    }
+
     /// Queries the backend for size and resizes if it doesn't match the previous size.
     pub fn autoresize(&mut self) -> Result<(), B::Error> {
         // fixed viewports do not get autoresized
-        if matches!(self.viewport, Viewport::Fullscreen | Viewport::Inline(_)) {
+        if matches!(
+            self.viewport,
+            Viewport::Fullscreen | Viewport::Inline(_) | Viewport::InlineAuto(_)
+        ) {
             let area = self.size()?.into();
             if area != self.last_known_area {
                 self.resize(area)?;
@@ -335,12 +532,14 @@ where
     ///
     /// - autoresize the terminal if necessary
     /// - call the render callback, passing it a [`Frame`] reference to render to
-    /// - flush the current internal state by copying the current buffer to the backend
+    /// - flush the current internal state by copying the current buffer to the backend, as a
+    ///   synchronized update if enabled in the [`TerminalOptions`]
     /// - move the cursor to the last known position if it was set during the rendering closure
-    /// - return a [`CompletedFrame`] with the current buffer and the area of the terminal
+    /// - return a [`CompletedFrame`] with the current buffer, the area of the terminal and the
+    ///   [`FrameStats`] of the frame
     ///
-    /// The [`CompletedFrame`] returned by this method can be useful for debugging or testing
-    /// purposes, but it is often not used in regular applications.
+    /// The [`CompletedFrame`] returned by this method can be useful for debugging, testing or
+    /// performance monitoring purposes, but it is often not used in regular applications.
     ///
     /// The render callback should fully render the entire frame when called, including areas that
     /// are unchanged from the previous frame. This is because each frame is compared to the
@@ -397,9 +596,11 @@ where
     ///
     /// - autoresize the terminal if necessary
     /// - call the render callback, passing it a [`Frame`] reference to render to
-    /// - flush the current internal state by copying the current buffer to the backend
+    /// - flush the current internal state by copying the current buffer to the backend, as a
+    ///   synchronized update if enabled in the [`TerminalOptions`]
     /// - move the cursor to the last known position if it was set during the rendering closure
-    /// - return a [`CompletedFrame`] with the current buffer and the area of the terminal
+    /// - return a [`CompletedFrame`] with the current buffer, the area of the terminal and the
+    ///   [`FrameStats`] of the frame
     ///
     /// The render callback passed to `try_draw` can return any [`Result`] with an error type that
     /// can be converted into an [`std::io::Error`] using the [`Into`] trait. This makes it possible
@@ -407,8 +608,8 @@ where
     /// callback returns an error, the error will be returned from `try_draw` as an
     /// [`std::io::Error`] and the terminal will not be updated.
     ///
-    /// The [`CompletedFrame`] returned by this method can be useful for debugging or testing
-    /// purposes, but it is often not used in regular applications.
+    /// The [`CompletedFrame`] returned by this method can be useful for debugging, testing or
+    /// performance monitoring purposes, but it is often not used in regular applications.
     ///
     /// The render callback should fully render the entire frame when called, including areas that
     /// are unchanged from the previous frame. This is because each frame is compared to the
@@ -450,39 +651,91 @@ where
         F: FnOnce(&mut Frame) -> Result<(), E>,
         E: Into<B::Error>,
     {
+        let draw_start = Stopwatch::start();
+        let mut stats = FrameStats {
+            frame_interval: self
+                .last_draw_start
+                .replace(draw_start)
+                .map(|last| draw_start.since(last))
+                .unwrap_or_default(),
+            ..FrameStats::default()
+        };
+        let output_before = self.backend.output_stats();
+
         // Autoresize - otherwise we get glitches if shrinking or potential desync between widgets
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
 
+        // Auto-height inline viewports render into an area of their maximum height, which is
+        // fitted to the rendered rows afterwards.
+        let render_area = match self.viewport {
+            Viewport::InlineAuto(max_height) => Rect {
+                height: max_height.min(self.last_known_area.height),
+                ..self.viewport_area
+            },
+            _ => self.viewport_area,
+        };
+        self.buffers[self.current].resize(render_area);
+
         let mut frame = self.get_frame();
+        frame.viewport_area = render_area;
 
+        let rendering = Stopwatch::start();
         render_callback(&mut frame).map_err(Into::into)?;
+        stats.render_time = rendering.elapsed();
+        let flushing = Stopwatch::start();
 
         // We can't change the cursor position right away because we have to flush the frame to
         // stdout first. But we also can't keep the frame around, since it holds a &mut to
         // Buffer. Thus, we're taking the important data out of the Frame and dropping it.
-        let cursor_position = frame.cursor_position;
-
-        // Draw to stdout
-        self.flush()?;
+        let mut cursor_position = frame.cursor_position;
+        let cursor_style = frame.cursor_style;
+        let mut hit_regions = frame.hit_regions;
 
-        match cursor_position {
-            None => self.hide_cursor()?,
-            Some(position) => {
-                self.show_cursor()?;
-                self.set_cursor_position(position)?;
+        if matches!(self.viewport, Viewport::InlineAuto(_)) {
+            let height = self.rendered_height(cursor_position);
+            let top = self.viewport_area.y;
+            self.resize_inline_viewport(height)?;
+            // the viewport moves up when it grows into the scrollback
+            let offset = top - self.viewport_area.y;
+            if let Some(position) = &mut cursor_position {
+                position.y = position.y.saturating_sub(offset);
+            }
+            for region in &mut hit_regions {
+                region.area.y = region.area.y.saturating_sub(offset);
             }
         }
+        self.hit_regions = hit_regions;
 
-        self.swap_buffers();
+        // Keep the terminal from showing a partially drawn frame
+        let synchronized = self.synchronized_output && self.backend.supports_synchronized_output();
+        if synchronized {
+            self.backend.begin_synchronized_update()?;
+        }
+
+        let written = self.write_frame(&mut stats, cursor_position, cursor_style);
+        // the update is also ended when writing the frame failed, so that the terminal does not
+        // keep displaying the previous frame
+        let ended = if synchronized {
+            self.backend.end_synchronized_update()
+        } else {
+            Ok(())
+        };
+        written.and(ended)?;
 
         // Flush
         self.backend.flush()?;
 
+        stats.flush_time = flushing.elapsed().saturating_sub(stats.diff_time);
+        stats.output = output_before
+            .zip(self.backend.output_stats())
+            .map(|(before, after)| after.since(before));
+
         let completed_frame = CompletedFrame {
             buffer: &self.buffers[1 - self.current],
             area: self.last_known_area,
             count: self.frame_count,
+            stats,
         };
 
         // increment frame count before returning from draw
@@ -491,6 +744,93 @@ where
         Ok(completed_frame)
     }
 
+    /// Writes the rendered frame and the cursor to the backend, and swaps the buffers.
+    fn write_frame(
+        &mut self,
+        stats: &mut FrameStats,
+        cursor_position: Option<Position>,
+        cursor_style: CursorStyle,
+    ) -> Result<(), B::Error> {
+        // Draw to stdout
+        self.flush_with_stats(stats)?;
+
+        match cursor_position {
+            None => self.hide_cursor()?,
+            Some(position) => {
+                self.show_cursor()?;
+                self.set_cursor_position(position)?;
+            }
+        }
+        if cursor_style != self.cursor_style {
+            self.backend.set_cursor_style(cursor_style)?;
+            self.cursor_style = cursor_style;
+        }
+
+        self.swap_buffers();
+        Ok(())
+    }
+
+    /// Returns the theme that widgets use when drawing, also available with [`Frame::theme`].
+    pub const fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the theme that widgets use when drawing, from the next draw call.
+    ///
+    /// The theme is set as the [theme of the buffers](Buffer::theme) that widgets are rendered to,
+    /// including the buffers of [`Terminal::insert_before`]. The built-in widgets use its roles
+    /// for their default styles, and the styles set on a widget are applied on top of them. Custom
+    /// widgets can read the theme from the buffer or with [`Frame::theme`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use ratatui::style::Theme;
+    /// use ratatui::style::palette::tailwind;
+    ///
+    /// terminal.set_theme(Theme::tailwind(&tailwind::INDIGO));
+    /// ```
+    pub const fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.buffers[0].theme = theme;
+        self.buffers[1].theme = theme;
+    }
+
+    /// Returns the hit regions registered with [`Frame::register_hit_region`] while drawing the
+    /// last frame, in the order they were registered.
+    pub fn hit_regions(&self) -> &[HitRegion] {
+        &self.hit_regions
+    }
+
+    /// Returns the hit region of the last frame at the given position.
+    ///
+    /// When several regions contain the position, the one that was registered last is returned,
+    /// which matches the widget that was rendered on top. Returns `None` if no region contains the
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use ratatui::crossterm::event::{self, Event, MouseEventKind};
+    /// use ratatui::layout::Position;
+    ///
+    /// if let Event::Mouse(mouse) = event::read()? {
+    ///     if mouse.kind == MouseEventKind::Down(event::MouseButton::Left) {
+    ///         let position = Position::new(mouse.column, mouse.row);
+    ///         if let Some(region) = terminal.hit_test(position) {
+    ///             println!("clicked on {}", region.id);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn hit_test<P: Into<Position>>(&self, position: P) -> Option<&HitRegion> {
+        let position = position.into();
+        self.hit_regions
+            .iter()
+            .rev()
+            .find(|region| region.area.contains(position))
+    }
+
     /// Hides the cursor.
     pub fn hide_cursor(&mut self) -> Result<(), B::Error> {
         self.backend.hide_cursor()?;
@@ -540,7 +880,7 @@ where
     pub fn clear(&mut self) -> Result<(), B::Error> {
         match self.viewport {
             Viewport::Fullscreen => self.backend.clear_region(ClearType::All)?,
-            Viewport::Inline(_) => {
+            Viewport::Inline(_) | Viewport::InlineAuto(_) => {
                 self.backend
                     .set_cursor_position(self.viewport_area.as_position())?;
                 self.backend.clear_region(ClearType::AfterCursor)?;
@@ -558,6 +898,34 @@ where
         Ok(())
     }
 
+    /// Scrolls the rows that moved vertically between the previous and the current buffer, and
+    /// updates the previous buffer to match the screen.
+    #[cfg(feature = "scrolling-regions")]
+    fn scroll_moved_rows(&mut self) -> Result<(), B::Error> {
+        if self.redraw_moved_rows || matches!(self.viewport, Viewport::Fixed(_)) {
+            return Ok(());
+        }
+        let [first, second] = &mut self.buffers;
+        let (previous, current) = if self.current == 0 {
+            (second, &*first)
+        } else {
+            (first, &*second)
+        };
+        // the rows scrolled out of the top of the screen are only discarded in the alternate screen
+        let scroll_first_row = self.alternate_screen && self.viewport == Viewport::Fullscreen;
+        for shift in RowShift::find_all(previous, current, scroll_first_row) {
+            match shift {
+                RowShift::Up { region, lines } => {
+                    self.backend.scroll_region_up(region, lines)?;
+                }
+                RowShift::Down { region, lines } => {
+                    self.backend.scroll_region_down(region, lines)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Clears the inactive buffer and swaps it with the current buffer
     pub fn swap_buffers(&mut self) {
         self.buffers[1 - self.current].reset();
@@ -651,9 +1019,13 @@ where
     {
         match self.viewport {
             #[cfg(feature = "scrolling-regions")]
-            Viewport::Inline(_) => self.insert_before_scrolling_regions(height, draw_fn),
+            Viewport::Inline(_) | Viewport::InlineAuto(_) => {
+                self.insert_before_scrolling_regions(height, draw_fn)
+            }
             #[cfg(not(feature = "scrolling-regions"))]
-            Viewport::Inline(_) => self.insert_before_no_scrolling_regions(height, draw_fn),
+            Viewport::Inline(_) | Viewport::InlineAuto(_) => {
+                self.insert_before_no_scrolling_regions(height, draw_fn)
+            }
             _ => Ok(()),
         }
     }
@@ -675,6 +1047,7 @@ where
             height,
         };
         let mut buffer = Buffer::empty(area);
+        buffer.theme = self.theme;
         draw_fn(&mut buffer);
         let mut buffer = buffer.content.as_slice();
 
@@ -776,6 +1149,7 @@ where
             height,
         };
         let mut buffer = Buffer::empty(area);
+        buffer.theme = self.theme;
         draw_fn(&mut buffer);
         let mut buffer = buffer.content.as_slice();
 
@@ -869,6 +1243,7 @@ where
             let new = Buffer {
                 area,
                 content: to_draw.to_vec(),
+                ..Buffer::default()
             };
             self.backend.draw(old.diff(&new).into_iter())?;
             self.backend.flush()?;
@@ -877,7 +1252,6 @@ where
     }
 
     /// Scroll the whole screen up by the given number of lines.
-    #[cfg(not(feature = "scrolling-regions"))]
     fn scroll_up(&mut self, lines_to_scroll: u16) -> Result<(), B::Error> {
         if lines_to_scroll > 0 {
             self.set_cursor_position(Position::new(
@@ -8,7 +8,9 @@ use crate::layout::Rect;
 /// When the viewport is fullscreen, the whole terminal is used to draw the application.
 ///
 /// When the viewport is inline, it is drawn inline with the rest of the terminal. The height of
-/// the viewport is fixed, but the width is the same as the terminal width.
+/// the viewport is set when the terminal is created and can be changed with
+/// [`Terminal::set_inline_height`], and the width is the same as the terminal width. An auto-height
+/// inline viewport instead fits its height to what was rendered in each frame.
 ///
 /// When the viewport is fixed, it is drawn in a fixed area of the terminal. The area is specified
 /// by a [`Rect`].
@@ -16,6 +18,7 @@ use crate::layout::Rect;
 /// See [`Terminal::with_options`] for more information.
 ///
 /// [`Terminal::with_options`]: crate::terminal::Terminal::with_options
+/// [`Terminal::set_inline_height`]: crate::terminal::Terminal::set_inline_height
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub enum Viewport {
     /// The viewport is fullscreen
@@ -26,6 +29,14 @@ pub enum Viewport {
     /// The viewport's height is fixed and specified in number of lines. The width is the same as
     /// the terminal's width. The viewport is drawn below the cursor position.
     Inline(u16),
+    /// The viewport is inline with the rest of the terminal, and its height fits what was rendered.
+    ///
+    /// The value is the maximum height of the viewport in number of lines. Each frame is rendered
+    /// in an area of the maximum height, then the viewport grows or shrinks to the last row that
+    /// holds any content or the cursor. Growing scrolls the content above the viewport into the
+    /// scrollback when there is no room below it, and shrinking clears the rows that are no longer
+    /// used.
+    InlineAuto(u16),
     /// The viewport is drawn in a fixed area of the terminal. The area is specified by a [`Rect`].
     Fixed(Rect),
 }
@@ -35,6 +46,7 @@ impl fmt::Display for Viewport {
         match self {
             Self::Fullscreen => write!(f, "Fullscreen"),
             Self::Inline(height) => write!(f, "Inline({height})"),
+            Self::InlineAuto(max_height) => write!(f, "InlineAuto({max_height})"),
             Self::Fixed(area) => write!(f, "Fixed({area})"),
         }
     }
@@ -50,6 +62,7 @@ mod tests {
     fn viewport_to_string() {
         assert_eq!(Viewport::Fullscreen.to_string(), "Fullscreen");
         assert_eq!(Viewport::Inline(5).to_string(), "Inline(5)");
+        assert_eq!(Viewport::InlineAuto(5).to_string(), "InlineAuto(5)");
         assert_eq!(
             Viewport::Fixed(Rect::new(0, 0, 5, 5)).to_string(),
             "Fixed(5x5+0+0)"
@@ -12,6 +12,8 @@ const ZWSP: &str = "\u{200b}";
 pub struct StyledGrapheme<'a> {
     pub symbol: &'a str,
     pub style: Style,
+    /// The hyperlink target of the [`Span`](crate::text::Span) this grapheme came from, if any.
+    pub hyperlink: Option<&'a str>,
 }
 
 impl<'a> StyledGrapheme<'a> {
@@ -25,9 +27,19 @@ impl<'a> StyledGrapheme<'a> {
         Self {
             symbol,
             style: style.into(),
+            hyperlink: None,
         }
     }
 
+    /// Sets the hyperlink target of the grapheme.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hyperlink(mut self, hyperlink: Option<&'a str>) -> Self {
+        self.hyperlink = hyperlink;
+        self
+    }
+
     pub fn is_whitespace(&self) -> bool {
         let symbol = self.symbol;
         symbol == ZWSP || symbol.chars().all(char::is_whitespace) && symbol != NBSP
@@ -58,6 +70,13 @@ mod tests {
         let sg = StyledGrapheme::new("a", style);
         assert_eq!(sg.symbol, "a");
         assert_eq!(sg.style, style);
+        assert_eq!(sg.hyperlink, None);
+    }
+
+    #[test]
+    fn hyperlink() {
+        let sg = StyledGrapheme::new("a", Style::new()).hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(sg.hyperlink, Some("https://ratatui.rs"));
     }
 
     #[test]
@@ -792,11 +792,12 @@ fn spans_after_width<'a>(
             // that takes that into account by indenting the start of the area
             let first_grapheme_offset = available_width.saturating_sub(actual_width);
             let first_grapheme_offset = u16::try_from(first_grapheme_offset).unwrap_or(u16::MAX);
-            (
-                Span::styled(content, span.style),
-                actual_width,
-                first_grapheme_offset,
-            )
+            let span = Span {
+                content: Cow::Borrowed(content),
+                style: span.style,
+                hyperlink: span.hyperlink.as_deref().map(Cow::Borrowed),
+            };
+            (span, actual_width, first_grapheme_offset)
         })
 }
 
@@ -1288,6 +1289,20 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_truncated_hyperlink() {
+            let line = Line::from(vec![
+                Span::raw("Hello "),
+                Span::raw("world").hyperlink("https://ratatui.rs"),
+            ])
+            .right_aligned();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+            line.render(buf.area, &mut buf);
+            let expected = Buffer::with_lines([Span::raw("rld").hyperlink("https://ratatui.rs")]);
+            assert_eq!(buf, expected);
+            assert_eq!(buf[(0, 0)].hyperlink(), Some("https://ratatui.rs"));
+        }
+
         #[test]
         fn render_truncates_left() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
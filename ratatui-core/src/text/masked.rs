@@ -1,12 +1,16 @@
 use alloc::borrow::Cow;
 use core::fmt;
 
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::text::Text;
 
 /// A wrapper around a string that is masked when displayed.
 ///
-/// The masked string is displayed as a series of the same character. This might be used to display
-/// a password field or similar secure data.
+/// The masked string is displayed as a series of the same character, one for each grapheme, so
+/// that characters made of several code points (e.g. an accented letter written with a combining
+/// accent) are masked as a single character. This might be used to display a password field or
+/// similar secure data.
 ///
 /// # Examples
 ///
@@ -41,9 +45,9 @@ impl<'a> Masked<'a> {
         self.mask_char
     }
 
-    /// The underlying string, with all characters masked.
+    /// The underlying string, with each grapheme masked.
     pub fn value(&self) -> Cow<'a, str> {
-        self.inner.chars().map(|_| self.mask_char).collect()
+        self.inner.graphemes(true).map(|_| self.mask_char).collect()
     }
 }
 
@@ -106,6 +110,13 @@ mod tests {
         assert_eq!(masked.value(), "xxxxx");
     }
 
+    #[test]
+    fn value_masks_graphemes() {
+        // an accented letter written with a combining accent, and a thumbs up with a skin tone
+        let masked = Masked::new("e\u{301}👍🏽", 'x');
+        assert_eq!(masked.value(), "xx");
+    }
+
     #[test]
     fn mask_char() {
         let masked = Masked::new("12345", 'x');
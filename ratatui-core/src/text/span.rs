@@ -28,6 +28,7 @@ use crate::widgets::Widget;
 ///
 /// - [`Span::content`] sets the content of the span.
 /// - [`Span::style`] sets the style of the span.
+/// - [`Span::hyperlink`] sets the hyperlink target of the span.
 ///
 /// # Other Methods
 ///
@@ -81,6 +82,17 @@ use crate::widgets::Widget;
 ///     .italic();
 /// ```
 ///
+/// A `Span` can be turned into a clickable [OSC 8] hyperlink by setting its target with
+/// [`Span::hyperlink`]. The target is stored on each [`Cell`] the span is rendered to, and backends
+/// that support hyperlinks emit the appropriate escape sequences around the content.
+///
+/// ```rust
+/// use ratatui_core::style::Stylize;
+/// use ratatui_core::text::Span;
+///
+/// let span = Span::raw("ratatui.rs").blue().hyperlink("https://ratatui.rs");
+/// ```
+///
 /// `Span` implements the [`Widget`] trait, which allows it to be rendered to a [`Buffer`]. Often
 /// apps will use the `Paragraph` widget instead of rendering `Span` directly, as it handles text
 /// wrapping and alignment for you.
@@ -95,12 +107,16 @@ use crate::widgets::Widget;
 /// [`Line`]: crate::text::Line
 /// [`Stylize`]: crate::style::Stylize
 /// [`Cow<str>`]: std::borrow::Cow
+/// [`Cell`]: crate::buffer::Cell
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
 #[derive(Default, Clone, Eq, PartialEq, Hash)]
 pub struct Span<'a> {
     /// The style of the span.
     pub style: Style,
     /// The content of the span as a Clone-on-write string.
     pub content: Cow<'a, str>,
+    /// The target of the hyperlink the span points to, if any.
+    pub hyperlink: Option<Cow<'a, str>>,
 }
 
 impl fmt::Debug for Span<'_> {
@@ -113,6 +129,9 @@ impl fmt::Debug for Span<'_> {
         if self.style != Style::default() {
             self.style.fmt_stylize(f)?;
         }
+        if let Some(hyperlink) = &self.hyperlink {
+            write!(f, ".hyperlink({hyperlink:?})")?;
+        }
         Ok(())
     }
 }
@@ -135,6 +154,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            hyperlink: None,
         }
     }
 
@@ -166,6 +186,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: style.into(),
+            hyperlink: None,
         }
     }
 
@@ -218,6 +239,37 @@ impl<'a> Span<'a> {
         self
     }
 
+    /// Sets the hyperlink target of the span.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// When rendered, the target is stored on every [`Cell`] covered by the span. Backends that
+    /// support [OSC 8] hyperlinks emit the escape sequences that make the content clickable, and
+    /// terminals that do not support them display the content as plain text.
+    ///
+    /// Accepts any type that can be converted to [`Cow<str>`] (e.g. `&str`, `String`, `&String`,
+    /// etc.).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Span;
+    ///
+    /// let span = Span::raw("ratatui").hyperlink("https://ratatui.rs");
+    /// assert_eq!(span.hyperlink.as_deref(), Some("https://ratatui.rs"));
+    /// ```
+    ///
+    /// [`Cell`]: crate::buffer::Cell
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hyperlink<T>(mut self, hyperlink: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.hyperlink = Some(hyperlink.into());
+        self
+    }
+
     /// Patches the style of the Span, adding modifiers from the given style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -308,11 +360,16 @@ impl<'a> Span<'a> {
         base_style: S,
     ) -> impl Iterator<Item = StyledGrapheme<'a>> {
         let style = base_style.into().patch(self.style);
+        let hyperlink = self.hyperlink.as_deref();
         self.content
             .as_ref()
             .graphemes(true)
             .filter(|g| !g.contains(char::is_control))
-            .map(move |g| StyledGrapheme { symbol: g, style })
+            .map(move |g| StyledGrapheme {
+                symbol: g,
+                style,
+                hyperlink,
+            })
     }
 
     /// Converts this Span into a left-aligned [`Line`]
@@ -439,7 +496,8 @@ impl Widget for &Span<'_> {
                 // the first grapheme is always set on the cell
                 buf[(x, y)]
                     .set_symbol(grapheme.symbol)
-                    .set_style(grapheme.style);
+                    .set_style(grapheme.style)
+                    .set_hyperlink(grapheme.hyperlink);
             } else if x == area.x {
                 // there is one or more zero-width graphemes in the first cell, so the first cell
                 // must be appended to.
@@ -455,7 +513,8 @@ impl Widget for &Span<'_> {
                 // just a normal grapheme (not first, not zero-width, not overflowing the area)
                 buf[(x, y)]
                     .set_symbol(grapheme.symbol)
-                    .set_style(grapheme.style);
+                    .set_style(grapheme.style)
+                    .set_hyperlink(grapheme.hyperlink);
             }
 
             // multi-width graphemes must clear the cells of characters that are hidden by the
@@ -506,6 +565,7 @@ impl fmt::Display for Span<'_> {
 #[cfg(test)]
 mod tests {
     use alloc::string::String;
+    use alloc::vec::Vec;
     use alloc::{format, vec};
 
     use rstest::{fixture, rstest};
@@ -622,6 +682,25 @@ mod tests {
         assert_eq!(span.style, Style::new().red().on_yellow().bold());
     }
 
+    #[test]
+    fn hyperlink() {
+        let span = Span::raw("test content").hyperlink("https://ratatui.rs");
+        assert_eq!(span.content, Cow::Borrowed("test content"));
+        assert_eq!(span.hyperlink, Some(Cow::Borrowed("https://ratatui.rs")));
+    }
+
+    #[test]
+    fn styled_graphemes_hyperlink() {
+        let span = Span::raw("ab").hyperlink("https://ratatui.rs");
+        assert_eq!(
+            span.styled_graphemes(Style::new()).collect::<Vec<_>>(),
+            vec![
+                StyledGrapheme::new("a", Style::new()).hyperlink(Some("https://ratatui.rs")),
+                StyledGrapheme::new("b", Style::new()).hyperlink(Some("https://ratatui.rs")),
+            ]
+        );
+    }
+
     #[test]
     fn width() {
         assert_eq!(Span::raw("").width(), 0);
@@ -702,6 +781,18 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_hyperlink() {
+            let span = Span::raw("test").hyperlink("https://ratatui.rs");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            span.render(buf.area, &mut buf);
+            for x in 0..4 {
+                assert_eq!(buf[(x, 0)].hyperlink(), Some("https://ratatui.rs"));
+            }
+            assert_eq!(buf[(4, 0)].hyperlink(), None);
+            assert_eq!(buf[(5, 0)].hyperlink(), None);
+        }
+
         #[rstest]
         #[case::x(20, 0)]
         #[case::y(0, 20)]
@@ -898,6 +989,10 @@ mod tests {
         Span::styled("test", Style::new().green().italic()),
         r#"Span::from("test").green().italic()"#
     )]
+    #[case::hyperlink(
+        Span::raw("test").hyperlink("https://ratatui.rs"),
+        r#"Span::from("test").hyperlink("https://ratatui.rs")"#
+    )]
     fn debug(#[case] span: Span, #[case] expected: &str) {
         assert_eq!(format!("{span:?}"), expected);
     }
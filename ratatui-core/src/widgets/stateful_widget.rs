@@ -1,5 +1,5 @@
 use crate::buffer::Buffer;
-use crate::layout::Rect;
+use crate::layout::{Position, Rect};
 
 /// A `StatefulWidget` is a widget that can take advantage of some local state to remember things
 /// between two draw calls.
@@ -131,6 +131,21 @@ pub trait StatefulWidget {
     /// Draws the current state of the widget in the given buffer. That is the only method required
     /// to implement a custom stateful widget.
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
+
+    /// Returns where the terminal cursor should be shown after the widget was rendered with the
+    /// given state, if anywhere.
+    ///
+    /// [`Frame::render_stateful_widget`] shows the cursor at this position, so that widgets such as
+    /// text inputs can place the cursor while they are focused. The default implementation returns
+    /// `None`, which leaves the cursor as it is.
+    ///
+    /// [`Frame::render_stateful_widget`]: crate::terminal::Frame::render_stateful_widget
+    fn cursor_position(_state: &Self::State) -> Option<Position>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -183,6 +198,28 @@ mod tests {
         }
     }
 
+    /// A widget that shows the cursor after the greeting.
+    struct Prompt;
+
+    impl StatefulWidget for Prompt {
+        type State = String;
+        fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+            Line::from(format!("{state}> ")).render(area, buf);
+        }
+
+        fn cursor_position(state: &Self::State) -> Option<Position> {
+            u16::try_from(state.len() + 2)
+                .ok()
+                .map(|x| Position::new(x, 0))
+        }
+    }
+
+    #[rstest]
+    fn cursor_position(state: String) {
+        assert_eq!(PersonalGreeting::cursor_position(&state), None);
+        assert_eq!(Prompt::cursor_position(&state), Some(Position::new(7, 0)));
+    }
+
     #[rstest]
     fn render_unsized_state_type(mut buf: Buffer) {
         let widget = Bytes;
@@ -64,17 +64,24 @@
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
 
+use core::time::Duration;
 use std::io::{self, Write};
 
-use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::cursor::{Hide, MoveTo, SetCursorStyle, Show};
+use crossterm::event::{
+    Event as CrosstermEvent, KeyCode as CrosstermKeyCode, KeyEvent as CrosstermKeyEvent,
+    KeyEventKind as CrosstermKeyEventKind, KeyModifiers as CrosstermKeyModifiers,
+    MediaKeyCode as CrosstermMediaKeyCode, ModifierKeyCode as CrosstermModifierKeyCode,
+    MouseButton as CrosstermMouseButton, MouseEvent as CrosstermMouseEvent,
+    MouseEventKind as CrosstermMouseEventKind,
+};
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
 use crossterm::style::{
     Attribute as CrosstermAttribute, Attributes as CrosstermAttributes, Color as CrosstermColor,
-    Colors as CrosstermColors, ContentStyle, Print, SetAttribute, SetBackgroundColor, SetColors,
-    SetForegroundColor,
+    ContentStyle, Print, SetAttribute, SetBackgroundColor, SetForegroundColor,
 };
-use crossterm::terminal::{self, Clear};
+use crossterm::terminal::{self, BeginSynchronizedUpdate, Clear, EndSynchronizedUpdate};
 use crossterm::{execute, queue};
 cfg_if::cfg_if! {
     // Re-export the selected Crossterm crate making sure to choose the latest version. We do this
@@ -89,10 +96,18 @@ cfg_if::cfg_if! {
         );
     }
 }
-use ratatui_core::backend::{Backend, ClearType, WindowSize};
-use ratatui_core::buffer::Cell;
+use ratatui_core::backend::{
+    Backend, ClearType, CommandBackend, CursorStyle, DrawCommand, DrawPlanner, GraphicsBackend,
+    GraphicsProtocol, OutputStats, QueryBackend, StyleChange, TerminalCapabilities,
+    TerminalCommand, WindowSize,
+};
+use ratatui_core::buffer::{Buffer, Cell};
+use ratatui_core::event::{
+    Event, EventReader, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MediaKeyCode,
+    ModifierKeyCode, MouseButton, MouseEvent, MouseEventKind,
+};
 use ratatui_core::layout::{Position, Size};
-use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::style::{Color, ColorDepth, Modifier, Style};
 
 /// A [`Backend`] implementation that uses [Crossterm] to render to the terminal.
 ///
@@ -149,10 +164,25 @@ use ratatui_core::style::{Color, Modifier, Style};
 /// [`backend`]: ratatui_core::backend
 /// [Crossterm]: https://crates.io/crates/crossterm
 /// [Examples]: https://github.com/ratatui/ratatui/tree/main/ratatui/examples/README.md
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct CrosstermBackend<W: Write> {
     /// The writer used to send commands to the terminal.
     writer: W,
+    /// The graphics protocol reported by [`GraphicsBackend::graphics_protocol`].
+    graphics_protocol: Option<GraphicsProtocol>,
+    /// The colors that the terminal can display.
+    color_depth: ColorDepth,
+    /// The size of the terminal, when supplied by the application rather than queried from the
+    /// terminal of the process.
+    window_size: Option<WindowSize>,
+    /// Whether runs of the same character are drawn with repeat sequences.
+    repeat_sequences: bool,
+    /// Whether the terminal erases cells with the current background color.
+    background_color_erase: bool,
+    /// The counters of the output written by the backend.
+    output_stats: OutputStats,
+    /// Whether the terminal supports synchronized output.
+    synchronized_output: bool,
 }
 
 impl<W> CrosstermBackend<W>
@@ -176,7 +206,175 @@ where
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            graphics_protocol: None,
+            color_depth: ColorDepth::TrueColor,
+            window_size: None,
+            repeat_sequences: false,
+            background_color_erase: true,
+            output_stats: OutputStats {
+                bytes_written: 0,
+                cursor_moves: 0,
+            },
+            synchronized_output: false,
+        }
+    }
+
+    /// Sets the graphics protocol used to display images.
+    ///
+    /// Crossterm has no way to query the terminal for graphics support, so by default the backend
+    /// reports no support and image widgets fall back to rendering with text cells. Use
+    /// [`GraphicsProtocol::from_env`] to guess the protocol of the current terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::{CrosstermBackend, GraphicsProtocol};
+    ///
+    /// let backend =
+    ///     CrosstermBackend::new(stdout()).with_graphics_protocol(GraphicsProtocol::from_env());
+    /// ```
+    #[must_use]
+    pub const fn with_graphics_protocol(mut self, protocol: Option<GraphicsProtocol>) -> Self {
+        self.graphics_protocol = protocol;
+        self
+    }
+
+    /// Sets the colors that the terminal can display.
+    ///
+    /// Colors that the terminal cannot display are replaced with the nearest color it can display
+    /// when drawing (see [`Cell::quantize`]). By default, all colors are drawn as is. Use
+    /// [`ColorDepth::from_env`] to guess the color depth of the current terminal, which also
+    /// honors the `NO_COLOR` environment variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::CrosstermBackend;
+    /// use ratatui::style::ColorDepth;
+    ///
+    /// let backend = CrosstermBackend::new(stdout()).with_color_depth(ColorDepth::from_env());
+    /// ```
+    #[must_use]
+    pub const fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Returns the colors that the terminal can display.
+    pub const fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Sets whether runs of the same character are drawn with the REP escape sequence.
+    ///
+    /// This reduces the output of frames with long runs of the same character, such as borders,
+    /// but REP is not supported by all terminals (e.g. the Linux console), so it is disabled by
+    /// default. See [`DrawPlanner`] for the other ways the backend reduces its output.
+    #[must_use]
+    pub const fn with_repeat_sequences(mut self, repeat_sequences: bool) -> Self {
+        self.repeat_sequences = repeat_sequences;
+        self
+    }
+
+    /// Sets whether the terminal erases cells with the current background color.
+    ///
+    /// Blank cells are drawn by erasing them, which leaves them with the default background color
+    /// on terminals without background color erase (BCE), such as `screen` or `tmux` with some
+    /// `TERM` settings. Disable it on these terminals so that only the cells with the default
+    /// background color are erased. It is enabled by default, as most terminals support it.
+    #[must_use]
+    pub const fn with_background_color_erase(mut self, background_color_erase: bool) -> Self {
+        self.background_color_erase = background_color_erase;
+        self
+    }
+
+    /// Sets whether the terminal supports synchronized output.
+    ///
+    /// The [`Terminal`] only writes frames as synchronized updates when the backend supports them
+    /// and they are enabled in its options. The support is unknown by default, so the backend
+    /// reports no support and frames are written as usual. It is detected from the answer of the
+    /// terminal to a DECRQM request for mode 2026 when the capabilities are queried with
+    /// [`QueryBackend::query_capabilities`], or can be set with this method.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::io::stdout;
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::backend::{CrosstermBackend, QueryBackend};
+    ///
+    /// let mut backend = CrosstermBackend::new(stdout());
+    /// backend.query_capabilities(Duration::from_millis(100))?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// [`Terminal`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html
+    #[must_use]
+    pub const fn with_synchronized_output(mut self, supported: bool) -> Self {
+        self.synchronized_output = supported;
+        self
+    }
+
+    /// Sets the size of the terminal instead of querying it from the terminal of the process.
+    ///
+    /// By default, the size is queried from the controlling terminal of the process, which is
+    /// not the terminal that displays the output when the writer is a network connection, such as
+    /// an SSH channel or a Unix socket. With a size set, the backend can write to any byte stream,
+    /// and each backend can have a different size. Call [`set_window_size`] when the remote
+    /// terminal is resized, and the next [`Terminal::draw`] resizes the viewport.
+    ///
+    /// The cursor position cannot be queried from a byte stream, so [`get_cursor_position`] and
+    /// [`QueryBackend::query`] return an [`io::ErrorKind::Unsupported`] error when a size is set,
+    /// and inline viewports cannot be used. Crossterm reads input from the terminal of the process
+    /// rather than from the stream, so the [`EventReader`] of the backend returns an
+    /// [`io::ErrorKind::Unsupported`] error too. Read the input of the remote terminal from the
+    /// stream instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// use ratatui::Terminal;
+    /// use ratatui::backend::{CrosstermBackend, WindowSize};
+    /// use ratatui::layout::Size;
+    ///
+    /// let stream = UnixStream::connect("/tmp/app.sock")?;
+    /// let backend = CrosstermBackend::new(stream).with_window_size(WindowSize {
+    ///     columns_rows: Size::new(80, 24),
+    ///     pixels: Size::new(640, 480),
+    /// });
+    /// let mut terminal = Terminal::new(backend)?;
+    ///
+    /// // when the client reports that its terminal was resized
+    /// terminal.backend_mut().set_window_size(WindowSize {
+    ///     columns_rows: Size::new(100, 30),
+    ///     pixels: Size::new(800, 600),
+    /// });
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// [`set_window_size`]: Self::set_window_size
+    /// [`get_cursor_position`]: Backend::get_cursor_position
+    /// [`Terminal::draw`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html#method.draw
+    #[must_use]
+    pub const fn with_window_size(mut self, window_size: WindowSize) -> Self {
+        self.window_size = Some(window_size);
+        self
+    }
+
+    /// Sets the size of the terminal, e.g. when the remote terminal is resized.
+    ///
+    /// See [`with_window_size`](Self::with_window_size) for details.
+    pub const fn set_window_size(&mut self, window_size: WindowSize) {
+        self.window_size = Some(window_size);
     }
 
     /// Gets the writer.
@@ -199,6 +397,41 @@ where
     pub const fn writer_mut(&mut self) -> &mut W {
         &mut self.writer
     }
+
+    /// Returns the writer, counting the bytes written to it in the output statistics.
+    const fn counted_writer(&mut self) -> CountingWriter<'_, W> {
+        CountingWriter {
+            writer: &mut self.writer,
+            bytes_written: &mut self.output_stats.bytes_written,
+        }
+    }
+}
+
+impl<W> Default for CrosstermBackend<W>
+where
+    W: Write + Default,
+{
+    fn default() -> Self {
+        Self::new(W::default())
+    }
+}
+
+/// A writer that counts the bytes written to the writer of a backend.
+struct CountingWriter<'a, W> {
+    writer: &'a mut W,
+    bytes_written: &'a mut usize,
+}
+
+impl<W: Write> Write for CountingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let count = self.writer.write(buf)?;
+        *self.bytes_written = self.bytes_written.wrapping_add(count);
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
 }
 
 impl<W> Write for CrosstermBackend<W>
@@ -207,7 +440,7 @@ where
 {
     /// Writes a buffer of bytes to the underlying buffer.
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.writer.write(buf)
+        self.counted_writer().write(buf)
     }
 
     /// Flushes the underlying buffer.
@@ -231,45 +464,56 @@ where
         #[cfg(feature = "underline-color")]
         let mut underline_color = Color::Reset;
         let mut modifier = Modifier::empty();
+        let mut hyperlink: Option<&str> = None;
         let mut last_pos: Option<Position> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
             if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
-                queue!(self.writer, MoveTo(x, y))?;
+                queue!(self.counted_writer(), MoveTo(x, y))?;
+                self.output_stats.cursor_moves = self.output_stats.cursor_moves.wrapping_add(1);
             }
             last_pos = Some(Position { x, y });
-            if cell.modifier != modifier {
+            let colors = cell.quantize(self.color_depth);
+            if colors.modifier != modifier {
                 let diff = ModifierDiff {
                     from: modifier,
-                    to: cell.modifier,
+                    to: colors.modifier,
                 };
-                diff.queue(&mut self.writer)?;
-                modifier = cell.modifier;
+                diff.queue(&mut self.counted_writer())?;
+                modifier = colors.modifier;
             }
-            if cell.fg != fg || cell.bg != bg {
+            if colors.fg != fg || colors.bg != bg {
                 queue!(
-                    self.writer,
-                    SetColors(CrosstermColors::new(
-                        cell.fg.into_crossterm(),
-                        cell.bg.into_crossterm(),
-                    ))
+                    self.counted_writer(),
+                    SetCellColors {
+                        fg: colors.fg,
+                        bg: colors.bg,
+                    }
                 )?;
-                fg = cell.fg;
-                bg = cell.bg;
+                fg = colors.fg;
+                bg = colors.bg;
             }
             #[cfg(feature = "underline-color")]
-            if cell.underline_color != underline_color {
-                let color = cell.underline_color.into_crossterm();
-                queue!(self.writer, SetUnderlineColor(color))?;
-                underline_color = cell.underline_color;
+            if colors.underline_color != underline_color {
+                let color = colors.underline_color.into_crossterm();
+                queue!(self.counted_writer(), SetUnderlineColor(color))?;
+                underline_color = colors.underline_color;
             }
+            if cell.hyperlink() != hyperlink {
+                queue!(self.counted_writer(), SetHyperlink(cell.hyperlink()))?;
+                hyperlink = cell.hyperlink();
+            }
+
+            queue!(self.counted_writer(), Print(cell.symbol()))?;
+        }
 
-            queue!(self.writer, Print(cell.symbol()))?;
+        if hyperlink.is_some() {
+            queue!(self.counted_writer(), SetHyperlink(None))?;
         }
 
         #[cfg(feature = "underline-color")]
         return queue!(
-            self.writer,
+            self.counted_writer(),
             SetForegroundColor(CrosstermColor::Reset),
             SetBackgroundColor(CrosstermColor::Reset),
             SetUnderlineColor(CrosstermColor::Reset),
@@ -277,22 +521,59 @@ where
         );
         #[cfg(not(feature = "underline-color"))]
         return queue!(
-            self.writer,
+            self.counted_writer(),
             SetForegroundColor(CrosstermColor::Reset),
             SetBackgroundColor(CrosstermColor::Reset),
             SetAttribute(CrosstermAttribute::Reset),
         );
     }
 
+    fn draw_diff(
+        &mut self,
+        next: &Buffer,
+        updates: &[(u16, u16, &Cell)],
+        screen_width: Option<u16>,
+    ) -> io::Result<()> {
+        #[cfg(windows)]
+        if !crossterm::ansi_support::supports_ansi() {
+            // the legacy Windows console cannot interpret the planned escape sequences
+            return self.draw(updates.iter().copied());
+        }
+        let mut planner = DrawPlanner::new()
+            .with_color_depth(self.color_depth)
+            .with_repeat_sequences(self.repeat_sequences)
+            .with_background_color_erase(self.background_color_erase);
+        if let Some(width) = screen_width {
+            planner = planner.with_screen_width(width);
+        }
+        for command in planner.plan(next, updates) {
+            if command.is_cursor_move() {
+                self.output_stats.cursor_moves = self.output_stats.cursor_moves.wrapping_add(1);
+            }
+            queue!(self.counted_writer(), PlannedDraw(command))?;
+        }
+        Ok(())
+    }
+
     fn hide_cursor(&mut self) -> io::Result<()> {
-        execute!(self.writer, Hide)
+        execute!(self.counted_writer(), Hide)
     }
 
     fn show_cursor(&mut self) -> io::Result<()> {
-        execute!(self.writer, Show)
+        execute!(self.counted_writer(), Show)
+    }
+
+    fn set_cursor_style(&mut self, style: CursorStyle) -> io::Result<()> {
+        execute!(self.counted_writer(), style.into_crossterm())
     }
 
     fn get_cursor_position(&mut self) -> io::Result<Position> {
+        if self.window_size.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the cursor position cannot be queried when the window size is set",
+            ));
+        }
         crossterm::cursor::position()
             .map(|(x, y)| Position { x, y })
             .map_err(io::Error::other)
@@ -300,7 +581,8 @@ where
 
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
         let Position { x, y } = position.into();
-        execute!(self.writer, MoveTo(x, y))
+        self.output_stats.cursor_moves = self.output_stats.cursor_moves.wrapping_add(1);
+        execute!(self.counted_writer(), MoveTo(x, y))
     }
 
     fn clear(&mut self) -> io::Result<()> {
@@ -309,7 +591,7 @@ where
 
     fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
         execute!(
-            self.writer,
+            self.counted_writer(),
             Clear(match clear_type {
                 ClearType::All => crossterm::terminal::ClearType::All,
                 ClearType::AfterCursor => crossterm::terminal::ClearType::FromCursorDown,
@@ -322,17 +604,23 @@ where
 
     fn append_lines(&mut self, n: u16) -> io::Result<()> {
         for _ in 0..n {
-            queue!(self.writer, Print("\n"))?;
+            queue!(self.counted_writer(), Print("\n"))?;
         }
         self.writer.flush()
     }
 
     fn size(&self) -> io::Result<Size> {
+        if let Some(window_size) = self.window_size {
+            return Ok(window_size.columns_rows);
+        }
         let (width, height) = terminal::size()?;
         Ok(Size { width, height })
     }
 
     fn window_size(&mut self) -> io::Result<WindowSize> {
+        if let Some(window_size) = self.window_size {
+            return Ok(window_size);
+        }
         let crossterm::terminal::WindowSize {
             columns,
             rows,
@@ -352,10 +640,26 @@ where
         self.writer.flush()
     }
 
+    fn output_stats(&self) -> Option<OutputStats> {
+        Some(self.output_stats)
+    }
+
+    fn supports_synchronized_output(&self) -> bool {
+        self.synchronized_output
+    }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        queue!(self.counted_writer(), BeginSynchronizedUpdate)
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        queue!(self.counted_writer(), EndSynchronizedUpdate)
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         queue!(
-            self.writer,
+            self.counted_writer(),
             ScrollUpInRegion {
                 first_row: region.start,
                 last_row: region.end.saturating_sub(1),
@@ -368,7 +672,7 @@ where
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_down(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         queue!(
-            self.writer,
+            self.counted_writer(),
             ScrollDownInRegion {
                 first_row: region.start,
                 last_row: region.end.saturating_sub(1),
@@ -379,6 +683,158 @@ where
     }
 }
 
+impl<W> GraphicsBackend for CrosstermBackend<W>
+where
+    W: Write,
+{
+    fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        self.graphics_protocol
+    }
+
+    fn draw_graphics(&mut self, position: Position, payload: &[u8]) -> io::Result<()> {
+        queue!(self.counted_writer(), MoveTo(position.x, position.y))?;
+        self.counted_writer().write_all(payload)
+    }
+}
+
+impl<W> CommandBackend for CrosstermBackend<W>
+where
+    W: Write,
+{
+    fn execute_command(&mut self, command: &TerminalCommand) -> io::Result<()> {
+        write!(self.counted_writer(), "{command}")?;
+        self.writer.flush()
+    }
+}
+
+impl<W> QueryBackend for CrosstermBackend<W>
+where
+    W: Write,
+{
+    /// Writes the request to the writer and reads the responses from the controlling terminal of
+    /// the process.
+    ///
+    /// Crossterm's event reader discards the responses to most queries, so they are read directly
+    /// from `/dev/tty`, which is only supported on Unix. The responses are not read by the event
+    /// reader of the application, so events should not be read while querying.
+    fn query(
+        &mut self,
+        request: &[u8],
+        timeout: Duration,
+        is_complete: fn(&[u8]) -> bool,
+    ) -> io::Result<Vec<u8>> {
+        if self.window_size.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the terminal cannot be queried when the window size is set",
+            ));
+        }
+        #[cfg(unix)]
+        {
+            query_tty(&mut self.counted_writer(), request, timeout, is_complete)
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = (request, timeout, is_complete);
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the terminal can only be queried on Unix",
+            ))
+        }
+    }
+
+    /// Queries the capabilities of the terminal, and records whether it supports synchronized
+    /// output (see [`with_synchronized_output`]).
+    ///
+    /// [`with_synchronized_output`]: Self::with_synchronized_output
+    fn query_capabilities(&mut self, timeout: Duration) -> io::Result<TerminalCapabilities> {
+        let response = self.query(
+            TerminalCapabilities::REQUEST.as_bytes(),
+            timeout,
+            TerminalCapabilities::is_complete,
+        )?;
+        let capabilities = TerminalCapabilities::parse(&response);
+        if let Some(supported) = capabilities.synchronized_output {
+            self.synchronized_output = supported;
+        }
+        Ok(capabilities)
+    }
+}
+
+/// Writes the request and reads the responses from `/dev/tty` until they are complete or the
+/// timeout elapses.
+///
+/// The terminal is switched to non-canonical mode without echo while reading, so that the
+/// responses are neither line buffered nor displayed, and reads return after a tenth of a second
+/// when no input arrives.
+#[cfg(unix)]
+fn query_tty<W: Write>(
+    writer: &mut W,
+    request: &[u8],
+    timeout: Duration,
+    is_complete: fn(&[u8]) -> bool,
+) -> io::Result<Vec<u8>> {
+    use std::fs::File;
+    use std::io::Read;
+    use std::time::Instant;
+
+    use rustix::termios::{self, LocalModes, OptionalActions, SpecialCodeIndex};
+
+    let mut tty = File::open("/dev/tty")?;
+    let original = termios::tcgetattr(&tty)?;
+    let mut mode = original.clone();
+    mode.local_modes
+        .remove(LocalModes::ICANON | LocalModes::ECHO);
+    mode.special_codes[SpecialCodeIndex::VMIN] = 0;
+    mode.special_codes[SpecialCodeIndex::VTIME] = 1;
+    termios::tcsetattr(&tty, OptionalActions::Now, &mode)?;
+
+    let mut read_responses = || {
+        writer.write_all(request)?;
+        writer.flush()?;
+        let deadline = Instant::now() + timeout;
+        let mut response = Vec::new();
+        let mut buffer = [0; 1024];
+        while !is_complete(&response) && Instant::now() < deadline {
+            let count = tty.read(&mut buffer)?;
+            response.extend_from_slice(&buffer[..count]);
+        }
+        Ok(response)
+    };
+    let response = read_responses();
+    termios::tcsetattr(&tty, OptionalActions::Now, &original)?;
+    response
+}
+
+/// Reads events from the terminal with [`crossterm::event::poll`] and [`crossterm::event::read`].
+///
+/// Crossterm reads input from the terminal of the process rather than from the writer of the
+/// backend, so events can be read from any `CrosstermBackend` that writes to that terminal. When
+/// a [window size](CrosstermBackend::with_window_size) is set, the backend writes to a remote
+/// terminal whose input crossterm cannot read, so an [`io::ErrorKind::Unsupported`] error is
+/// returned instead.
+impl<W> EventReader for CrosstermBackend<W>
+where
+    W: Write,
+{
+    type Error = io::Error;
+
+    fn poll_event(&mut self, timeout: Option<Duration>) -> io::Result<Option<Event>> {
+        if self.window_size.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "events cannot be read from the terminal when the window size is set",
+            ));
+        }
+        if let Some(timeout) = timeout {
+            if !crossterm::event::poll(timeout)? {
+                return Ok(None);
+            }
+        }
+        crossterm::event::read().map(|event| Some(Event::from_crossterm(event)))
+    }
+}
+
 /// A trait for converting a Ratatui type to a Crossterm type.
 ///
 /// This trait is needed for avoiding the orphan rule when implementing `From` for crossterm types
@@ -423,6 +879,20 @@ impl IntoCrossterm<CrosstermColor> for Color {
     }
 }
 
+impl IntoCrossterm<SetCursorStyle> for CursorStyle {
+    fn into_crossterm(self) -> SetCursorStyle {
+        match self {
+            Self::Default => SetCursorStyle::DefaultUserShape,
+            Self::BlinkingBlock => SetCursorStyle::BlinkingBlock,
+            Self::SteadyBlock => SetCursorStyle::SteadyBlock,
+            Self::BlinkingUnderline => SetCursorStyle::BlinkingUnderScore,
+            Self::SteadyUnderline => SetCursorStyle::SteadyUnderScore,
+            Self::BlinkingBar => SetCursorStyle::BlinkingBar,
+            Self::SteadyBar => SetCursorStyle::SteadyBar,
+        }
+    }
+}
+
 impl FromCrossterm<CrosstermColor> for Color {
     fn from_crossterm(value: CrosstermColor) -> Self {
         match value {
@@ -484,17 +954,29 @@ impl ModifierDiff {
         if removed.contains(Modifier::ITALIC) {
             queue!(w, SetAttribute(CrosstermAttribute::NoItalic))?;
         }
-        if removed.contains(Modifier::UNDERLINED) {
+        let mut added = self.to - self.from;
+        if removed.intersects(Modifier::ANY_UNDERLINE) {
             queue!(w, SetAttribute(CrosstermAttribute::NoUnderline))?;
+
+            // All the underline styles are reset, so the remaining one must be reapplied
+            added |= self.to & Modifier::ANY_UNDERLINE;
+        }
+        if removed.contains(Modifier::OVERLINED) {
+            queue!(w, SetAttribute(CrosstermAttribute::NotOverLined))?;
         }
         if removed.contains(Modifier::CROSSED_OUT) {
             queue!(w, SetAttribute(CrosstermAttribute::NotCrossedOut))?;
         }
+        if removed.contains(Modifier::HIDDEN) {
+            queue!(w, SetAttribute(CrosstermAttribute::NoHidden))?;
+        }
         if removed.contains(Modifier::SLOW_BLINK) || removed.contains(Modifier::RAPID_BLINK) {
             queue!(w, SetAttribute(CrosstermAttribute::NoBlink))?;
+
+            // Both blinks are reset, so the remaining one must be reapplied
+            added |= self.to & (Modifier::SLOW_BLINK | Modifier::RAPID_BLINK);
         }
 
-        let added = self.to - self.from;
         if added.contains(Modifier::REVERSED) {
             queue!(w, SetAttribute(CrosstermAttribute::Reverse))?;
         }
@@ -504,8 +986,19 @@ impl ModifierDiff {
         if added.contains(Modifier::ITALIC) {
             queue!(w, SetAttribute(CrosstermAttribute::Italic))?;
         }
-        if added.contains(Modifier::UNDERLINED) {
-            queue!(w, SetAttribute(CrosstermAttribute::Underlined))?;
+        if added.intersects(Modifier::ANY_UNDERLINE) {
+            // only one underline style is displayed, which depends on all the underline modifiers
+            let underline = match self.to.underline_style() {
+                Modifier::DOUBLE_UNDERLINED => CrosstermAttribute::DoubleUnderlined,
+                Modifier::CURLY_UNDERLINED => CrosstermAttribute::Undercurled,
+                Modifier::DOTTED_UNDERLINED => CrosstermAttribute::Underdotted,
+                Modifier::DASHED_UNDERLINED => CrosstermAttribute::Underdashed,
+                _ => CrosstermAttribute::Underlined,
+            };
+            queue!(w, SetAttribute(underline))?;
+        }
+        if added.contains(Modifier::OVERLINED) {
+            queue!(w, SetAttribute(CrosstermAttribute::OverLined))?;
         }
         if added.contains(Modifier::DIM) {
             queue!(w, SetAttribute(CrosstermAttribute::Dim))?;
@@ -519,6 +1012,9 @@ impl ModifierDiff {
         if added.contains(Modifier::RAPID_BLINK) {
             queue!(w, SetAttribute(CrosstermAttribute::RapidBlink))?;
         }
+        if added.contains(Modifier::HIDDEN) {
+            queue!(w, SetAttribute(CrosstermAttribute::Hidden))?;
+        }
 
         Ok(())
     }
@@ -544,14 +1040,24 @@ impl FromCrossterm<CrosstermAttributes> for Modifier {
         if value.has(CrosstermAttribute::Italic) {
             res |= Self::ITALIC;
         }
-        if value.has(CrosstermAttribute::Underlined)
-            || value.has(CrosstermAttribute::DoubleUnderlined)
-            || value.has(CrosstermAttribute::Undercurled)
-            || value.has(CrosstermAttribute::Underdotted)
-            || value.has(CrosstermAttribute::Underdashed)
-        {
+        if value.has(CrosstermAttribute::Underlined) {
             res |= Self::UNDERLINED;
         }
+        if value.has(CrosstermAttribute::DoubleUnderlined) {
+            res |= Self::DOUBLE_UNDERLINED;
+        }
+        if value.has(CrosstermAttribute::Undercurled) {
+            res |= Self::CURLY_UNDERLINED;
+        }
+        if value.has(CrosstermAttribute::Underdotted) {
+            res |= Self::DOTTED_UNDERLINED;
+        }
+        if value.has(CrosstermAttribute::Underdashed) {
+            res |= Self::DASHED_UNDERLINED;
+        }
+        if value.has(CrosstermAttribute::OverLined) {
+            res |= Self::OVERLINED;
+        }
         if value.has(CrosstermAttribute::SlowBlink) {
             res |= Self::SLOW_BLINK;
         }
@@ -584,7 +1090,10 @@ impl FromCrossterm<ContentStyle> for Style {
             sub_modifier |= Modifier::CROSSED_OUT;
         }
         if value.attributes.has(CrosstermAttribute::NoUnderline) {
-            sub_modifier |= Modifier::UNDERLINED;
+            sub_modifier |= Modifier::ANY_UNDERLINE;
+        }
+        if value.attributes.has(CrosstermAttribute::NotOverLined) {
+            sub_modifier |= Modifier::OVERLINED;
         }
         if value.attributes.has(CrosstermAttribute::NoHidden) {
             sub_modifier |= Modifier::HIDDEN;
@@ -607,6 +1116,251 @@ impl FromCrossterm<ContentStyle> for Style {
     }
 }
 
+impl FromCrossterm<CrosstermEvent> for Event {
+    fn from_crossterm(value: CrosstermEvent) -> Self {
+        match value {
+            CrosstermEvent::FocusGained => Self::FocusGained,
+            CrosstermEvent::FocusLost => Self::FocusLost,
+            CrosstermEvent::Key(key) => Self::Key(KeyEvent::from_crossterm(key)),
+            CrosstermEvent::Mouse(mouse) => Self::Mouse(MouseEvent::from_crossterm(mouse)),
+            CrosstermEvent::Paste(text) => Self::Paste(text),
+            CrosstermEvent::Resize(width, height) => Self::Resize(Size::new(width, height)),
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermKeyEvent> for KeyEvent {
+    fn from_crossterm(value: CrosstermKeyEvent) -> Self {
+        Self {
+            code: KeyCode::from_crossterm(value.code),
+            modifiers: KeyModifiers::from_crossterm(value.modifiers),
+            kind: KeyEventKind::from_crossterm(value.kind),
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermKeyCode> for KeyCode {
+    fn from_crossterm(value: CrosstermKeyCode) -> Self {
+        match value {
+            CrosstermKeyCode::Backspace => Self::Backspace,
+            CrosstermKeyCode::Enter => Self::Enter,
+            CrosstermKeyCode::Left => Self::Left,
+            CrosstermKeyCode::Right => Self::Right,
+            CrosstermKeyCode::Up => Self::Up,
+            CrosstermKeyCode::Down => Self::Down,
+            CrosstermKeyCode::Home => Self::Home,
+            CrosstermKeyCode::End => Self::End,
+            CrosstermKeyCode::PageUp => Self::PageUp,
+            CrosstermKeyCode::PageDown => Self::PageDown,
+            CrosstermKeyCode::Tab => Self::Tab,
+            CrosstermKeyCode::BackTab => Self::BackTab,
+            CrosstermKeyCode::Delete => Self::Delete,
+            CrosstermKeyCode::Insert => Self::Insert,
+            CrosstermKeyCode::F(n) => Self::F(n),
+            CrosstermKeyCode::Char(c) => Self::Char(c),
+            CrosstermKeyCode::Null => Self::Null,
+            CrosstermKeyCode::Esc => Self::Esc,
+            CrosstermKeyCode::CapsLock => Self::CapsLock,
+            CrosstermKeyCode::ScrollLock => Self::ScrollLock,
+            CrosstermKeyCode::NumLock => Self::NumLock,
+            CrosstermKeyCode::PrintScreen => Self::PrintScreen,
+            CrosstermKeyCode::Pause => Self::Pause,
+            CrosstermKeyCode::Menu => Self::Menu,
+            CrosstermKeyCode::KeypadBegin => Self::KeypadBegin,
+            CrosstermKeyCode::Media(media) => Self::Media(MediaKeyCode::from_crossterm(media)),
+            CrosstermKeyCode::Modifier(modifier) => {
+                Self::Modifier(ModifierKeyCode::from_crossterm(modifier))
+            }
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermMediaKeyCode> for MediaKeyCode {
+    fn from_crossterm(value: CrosstermMediaKeyCode) -> Self {
+        match value {
+            CrosstermMediaKeyCode::Play => Self::Play,
+            CrosstermMediaKeyCode::Pause => Self::Pause,
+            CrosstermMediaKeyCode::PlayPause => Self::PlayPause,
+            CrosstermMediaKeyCode::Reverse => Self::Reverse,
+            CrosstermMediaKeyCode::Stop => Self::Stop,
+            CrosstermMediaKeyCode::FastForward => Self::FastForward,
+            CrosstermMediaKeyCode::Rewind => Self::Rewind,
+            CrosstermMediaKeyCode::TrackNext => Self::TrackNext,
+            CrosstermMediaKeyCode::TrackPrevious => Self::TrackPrevious,
+            CrosstermMediaKeyCode::Record => Self::Record,
+            CrosstermMediaKeyCode::LowerVolume => Self::LowerVolume,
+            CrosstermMediaKeyCode::RaiseVolume => Self::RaiseVolume,
+            CrosstermMediaKeyCode::MuteVolume => Self::MuteVolume,
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermModifierKeyCode> for ModifierKeyCode {
+    fn from_crossterm(value: CrosstermModifierKeyCode) -> Self {
+        match value {
+            CrosstermModifierKeyCode::LeftShift => Self::LeftShift,
+            CrosstermModifierKeyCode::LeftControl => Self::LeftControl,
+            CrosstermModifierKeyCode::LeftAlt => Self::LeftAlt,
+            CrosstermModifierKeyCode::LeftSuper => Self::LeftSuper,
+            CrosstermModifierKeyCode::LeftHyper => Self::LeftHyper,
+            CrosstermModifierKeyCode::LeftMeta => Self::LeftMeta,
+            CrosstermModifierKeyCode::RightShift => Self::RightShift,
+            CrosstermModifierKeyCode::RightControl => Self::RightControl,
+            CrosstermModifierKeyCode::RightAlt => Self::RightAlt,
+            CrosstermModifierKeyCode::RightSuper => Self::RightSuper,
+            CrosstermModifierKeyCode::RightHyper => Self::RightHyper,
+            CrosstermModifierKeyCode::RightMeta => Self::RightMeta,
+            CrosstermModifierKeyCode::IsoLevel3Shift => Self::IsoLevel3Shift,
+            CrosstermModifierKeyCode::IsoLevel5Shift => Self::IsoLevel5Shift,
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermKeyModifiers> for KeyModifiers {
+    fn from_crossterm(value: CrosstermKeyModifiers) -> Self {
+        let mut res = Self::empty();
+        if value.contains(CrosstermKeyModifiers::SHIFT) {
+            res |= Self::SHIFT;
+        }
+        if value.contains(CrosstermKeyModifiers::CONTROL) {
+            res |= Self::CONTROL;
+        }
+        if value.contains(CrosstermKeyModifiers::ALT) {
+            res |= Self::ALT;
+        }
+        if value.contains(CrosstermKeyModifiers::SUPER) {
+            res |= Self::SUPER;
+        }
+        if value.contains(CrosstermKeyModifiers::HYPER) {
+            res |= Self::HYPER;
+        }
+        if value.contains(CrosstermKeyModifiers::META) {
+            res |= Self::META;
+        }
+        res
+    }
+}
+
+impl FromCrossterm<CrosstermKeyEventKind> for KeyEventKind {
+    fn from_crossterm(value: CrosstermKeyEventKind) -> Self {
+        match value {
+            CrosstermKeyEventKind::Press => Self::Press,
+            CrosstermKeyEventKind::Repeat => Self::Repeat,
+            CrosstermKeyEventKind::Release => Self::Release,
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermMouseEvent> for MouseEvent {
+    fn from_crossterm(value: CrosstermMouseEvent) -> Self {
+        Self {
+            kind: MouseEventKind::from_crossterm(value.kind),
+            position: Position::new(value.column, value.row),
+            modifiers: KeyModifiers::from_crossterm(value.modifiers),
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermMouseEventKind> for MouseEventKind {
+    fn from_crossterm(value: CrosstermMouseEventKind) -> Self {
+        match value {
+            CrosstermMouseEventKind::Down(button) => {
+                Self::Down(MouseButton::from_crossterm(button))
+            }
+            CrosstermMouseEventKind::Up(button) => Self::Up(MouseButton::from_crossterm(button)),
+            CrosstermMouseEventKind::Drag(button) => {
+                Self::Drag(MouseButton::from_crossterm(button))
+            }
+            CrosstermMouseEventKind::Moved => Self::Moved,
+            CrosstermMouseEventKind::ScrollDown => Self::ScrollDown,
+            CrosstermMouseEventKind::ScrollUp => Self::ScrollUp,
+            CrosstermMouseEventKind::ScrollLeft => Self::ScrollLeft,
+            CrosstermMouseEventKind::ScrollRight => Self::ScrollRight,
+        }
+    }
+}
+
+impl FromCrossterm<CrosstermMouseButton> for MouseButton {
+    fn from_crossterm(value: CrosstermMouseButton) -> Self {
+        match value {
+            CrosstermMouseButton::Left => Self::Left,
+            CrosstermMouseButton::Right => Self::Right,
+            CrosstermMouseButton::Middle => Self::Middle,
+        }
+    }
+}
+
+/// A command that starts or ends an [OSC 8] hyperlink.
+///
+/// `Some(url)` opens a hyperlink to `url` that applies to all the text printed afterwards, and
+/// `None` closes the currently open hyperlink.
+///
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SetHyperlink<'a>(Option<&'a str>);
+
+impl crate::crossterm::Command for SetHyperlink<'_> {
+    fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        write!(f, "\x1b]8;;{}\x1b\\", self.0.unwrap_or_default())
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        // The legacy Windows console has no notion of hyperlinks, so the text is printed as is.
+        Ok(())
+    }
+}
+
+/// A command that writes a [`DrawCommand`] planned by a [`DrawPlanner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PlannedDraw<'a>(DrawCommand<'a>);
+
+impl crate::crossterm::Command for PlannedDraw<'_> {
+    fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "PlannedDraw command not supported for winapi",
+        ))
+    }
+}
+
+/// A command that sets the foreground and background colors of the following cells.
+///
+/// Unlike crossterm's [`SetColors`](crossterm::style::SetColors), the named colors are written with the short `30–37`/`90–97`
+/// and `40–47`/`100–107` codes, as [`DrawPlanner`] does, so that they also work in terminals that
+/// only support 16 colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SetCellColors {
+    fg: Color,
+    bg: Color,
+}
+
+impl crate::crossterm::Command for SetCellColors {
+    fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        let change = StyleChange {
+            fg: Some(self.fg),
+            bg: Some(self.bg),
+            ..StyleChange::default()
+        };
+        write!(f, "{}", DrawCommand::SetStyle(change))
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        use crossterm::style::{Colors, SetColors};
+        SetColors(Colors::new(
+            self.fg.into_crossterm(),
+            self.bg.into_crossterm(),
+        ))
+        .execute_winapi()
+    }
+}
+
 /// A command that scrolls the terminal screen a given number of rows up in a specific scrolling
 /// region.
 ///
@@ -705,6 +1459,9 @@ impl crate::crossterm::Command for ScrollDownInRegion {
 
 #[cfg(test)]
 mod tests {
+    use ratatui_core::backend::VirtualTerminal;
+    use ratatui_core::layout::Rect;
+    use ratatui_core::terminal::{Terminal, TerminalOptions};
     use rstest::rstest;
 
     use super::*;
@@ -746,12 +1503,12 @@ mod tests {
         #[case(CrosstermAttribute::NoItalic, Modifier::empty())]
         #[case(CrosstermAttribute::Underlined, Modifier::UNDERLINED)]
         #[case(CrosstermAttribute::NoUnderline, Modifier::empty())]
-        #[case(CrosstermAttribute::OverLined, Modifier::empty())]
+        #[case(CrosstermAttribute::OverLined, Modifier::OVERLINED)]
         #[case(CrosstermAttribute::NotOverLined, Modifier::empty())]
-        #[case(CrosstermAttribute::DoubleUnderlined, Modifier::UNDERLINED)]
-        #[case(CrosstermAttribute::Undercurled, Modifier::UNDERLINED)]
-        #[case(CrosstermAttribute::Underdotted, Modifier::UNDERLINED)]
-        #[case(CrosstermAttribute::Underdashed, Modifier::UNDERLINED)]
+        #[case(CrosstermAttribute::DoubleUnderlined, Modifier::DOUBLE_UNDERLINED)]
+        #[case(CrosstermAttribute::Undercurled, Modifier::CURLY_UNDERLINED)]
+        #[case(CrosstermAttribute::Underdotted, Modifier::DOTTED_UNDERLINED)]
+        #[case(CrosstermAttribute::Underdashed, Modifier::DASHED_UNDERLINED)]
         #[case(CrosstermAttribute::Dim, Modifier::DIM)]
         #[case(CrosstermAttribute::NormalIntensity, Modifier::empty())]
         #[case(CrosstermAttribute::CrossedOut, Modifier::CROSSED_OUT)]
@@ -777,7 +1534,7 @@ mod tests {
         #[case(&[CrosstermAttribute::Bold], Modifier::BOLD)]
         #[case(&[CrosstermAttribute::Bold, CrosstermAttribute::Italic], Modifier::BOLD | Modifier::ITALIC)]
         #[case(&[CrosstermAttribute::Bold, CrosstermAttribute::NotCrossedOut], Modifier::BOLD)]
-        #[case(&[CrosstermAttribute::Dim, CrosstermAttribute::Underdotted], Modifier::DIM | Modifier::UNDERLINED)]
+        #[case(&[CrosstermAttribute::Dim, CrosstermAttribute::Underdotted], Modifier::DIM | Modifier::DOTTED_UNDERLINED)]
         #[case(&[CrosstermAttribute::Dim, CrosstermAttribute::SlowBlink, CrosstermAttribute::Italic], Modifier::DIM | Modifier::SLOW_BLINK | Modifier::ITALIC)]
         #[case(&[CrosstermAttribute::Hidden, CrosstermAttribute::NoUnderline, CrosstermAttribute::NotCrossedOut], Modifier::HIDDEN)]
         #[case(&[CrosstermAttribute::Reverse], Modifier::REVERSED)]
@@ -864,6 +1621,334 @@ mod tests {
         assert_eq!(Style::from_crossterm(content_style), style);
     }
 
+    #[test]
+    fn draw_hyperlink() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let plain = Cell::new("a");
+        let mut link = Cell::new("b");
+        link.set_hyperlink(Some("https://ratatui.rs"));
+        backend
+            .draw([(0, 0, &plain), (1, 0, &link), (2, 0, &link), (3, 0, &plain)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        let open = "\x1b]8;;https://ratatui.rs\x1b\\";
+        let close = "\x1b]8;;\x1b\\";
+        assert!(
+            output.contains(&format!("a{open}bb{close}a")),
+            "unexpected output: {output:?}"
+        );
+    }
+
+    #[test]
+    fn draw_closes_hyperlink() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut link = Cell::new("a");
+        link.set_hyperlink(Some("https://ratatui.rs"));
+        backend.draw([(0, 0, &link)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(
+            output.contains("a\x1b]8;;\x1b\\"),
+            "unexpected output: {output:?}"
+        );
+    }
+
+    #[rstest]
+    #[case(ColorDepth::TrueColor, "\x1b[1;1H\x1b[38;2;250;5;5;48;5;22ma")]
+    #[case(ColorDepth::Ansi256, "\x1b[1;1H\x1b[38;5;196;48;5;22ma")]
+    #[case(ColorDepth::Ansi16, "\x1b[1;1H\x1b[91;40ma")]
+    // light text on a dark background needs no reversal
+    #[case(ColorDepth::Monochrome, "\x1b[1;1Ha")]
+    fn draw_quantizes_colors(#[case] color_depth: ColorDepth, #[case] expected: &str) {
+        let mut backend = CrosstermBackend::new(Vec::new()).with_color_depth(color_depth);
+        assert_eq!(backend.color_depth(), color_depth);
+        let mut cell = Cell::new("a");
+        cell.set_fg(Color::Rgb(250, 5, 5))
+            .set_bg(Color::Indexed(22));
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(
+            output.starts_with(expected),
+            "unexpected output: {output:?}"
+        );
+    }
+
+    #[rstest]
+    #[case::bold_to_dim(Style::new().bold(), Style::new().dim())]
+    #[case::bold_dim_to_dim(Style::new().bold().dim(), Style::new().dim())]
+    #[case::dim_to_bold(Style::new().dim().italic(), Style::new().bold())]
+    #[case::blink(Style::new().slow_blink().rapid_blink(), Style::new().rapid_blink())]
+    #[case::hidden(Style::new().hidden(), Style::new().reversed())]
+    #[case::underlined(Style::new().underlined().crossed_out(), Style::new().italic())]
+    #[case::colors(Style::new().red().on_blue(), Style::new().fg(Color::Indexed(42)))]
+    #[case::rgb(Style::new().fg(Color::Rgb(1, 2, 3)), Style::new().on_light_yellow())]
+    fn draw_matches_virtual_terminal(#[case] first: Style, #[case] second: Style) {
+        let area = Rect::new(0, 0, 8, 2);
+        let mut expected = Buffer::empty(area);
+        expected.set_string(0, 0, "ab", first);
+        expected.set_string(2, 0, "cd", second);
+        expected.set_string(1, 1, "界e", second);
+        expected.set_string(6, 1, "f", first);
+        expected[(7, 1)].set_hyperlink(Some("https://ratatui.rs"));
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend
+            .draw(Buffer::empty(area).diff(&expected).into_iter())
+            .unwrap();
+        let mut terminal = VirtualTerminal::new(area.width, area.height);
+        terminal.process(&backend.writer);
+        assert_eq!(terminal.buffer(), &expected);
+
+        let mut backend = CrosstermBackend::new(Vec::new()).with_repeat_sequences(true);
+        let previous = Buffer::with_lines(["abcdefgh", "========"]);
+        backend
+            .draw_diff(
+                &previous,
+                &Buffer::empty(area).diff(&previous),
+                Some(area.width),
+            )
+            .unwrap();
+        backend
+            .draw_diff(&expected, &previous.diff(&expected), Some(area.width))
+            .unwrap();
+        let mut terminal = VirtualTerminal::new(area.width, area.height);
+        terminal.process(&backend.writer);
+        assert_eq!(terminal.buffer(), &expected);
+    }
+
+    #[rstest]
+    #[case::default(CursorStyle::Default)]
+    #[case::blinking_block(CursorStyle::BlinkingBlock)]
+    #[case::steady_block(CursorStyle::SteadyBlock)]
+    #[case::blinking_underline(CursorStyle::BlinkingUnderline)]
+    #[case::steady_underline(CursorStyle::SteadyUnderline)]
+    #[case::blinking_bar(CursorStyle::BlinkingBar)]
+    #[case::steady_bar(CursorStyle::SteadyBar)]
+    fn set_cursor_style(#[case] style: CursorStyle) {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.set_cursor_style(CursorStyle::SteadyBlock).unwrap();
+        backend.set_cursor_style(style).unwrap();
+        let mut terminal = VirtualTerminal::new(1, 1);
+        terminal.process(&backend.writer);
+        assert_eq!(terminal.cursor_style(), style);
+    }
+
+    #[test]
+    fn draw_diff_writes_fewer_bytes() {
+        let area = Rect::new(0, 0, 40, 2);
+        let previous = Buffer::empty(area);
+        let mut next = Buffer::empty(area);
+        next.set_string(0, 0, "─".repeat(40), Style::new().blue());
+        next.set_string(0, 1, "hello", Style::new().bold());
+        next.set_string(20, 1, "world", Style::new().bold());
+        let updates = previous.diff(&next);
+
+        let mut naive = CrosstermBackend::new(Vec::new());
+        naive.draw(updates.iter().copied()).unwrap();
+        let mut planned = CrosstermBackend::new(Vec::new()).with_repeat_sequences(true);
+        planned.draw_diff(&next, &updates, Some(40)).unwrap();
+        assert!(planned.writer.len() < naive.writer.len() / 2);
+        assert_eq!(
+            String::from_utf8(planned.writer).unwrap(),
+            "\x1b[1;1H\x1b[34m─\x1b[39b\x1b[2;1H\x1b[0;1mhello\x1b[21Gworld\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn draw_diff_without_background_color_erase() {
+        let previous = Buffer::with_lines(["hello world"]);
+        let mut next = Buffer::empty(previous.area);
+        next.set_style(next.area, Style::new().on_blue());
+        let updates = previous.diff(&next);
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw_diff(&next, &updates, Some(11)).unwrap();
+        assert_eq!(
+            String::from_utf8(backend.writer).unwrap(),
+            "\x1b[1;1H\x1b[44m\x1b[K\x1b[0m"
+        );
+
+        let mut backend = CrosstermBackend::new(Vec::new()).with_background_color_erase(false);
+        backend.draw_diff(&next, &updates, Some(11)).unwrap();
+        assert_eq!(
+            String::from_utf8(backend.writer).unwrap(),
+            "\x1b[1;1H\x1b[44m           \x1b[0m"
+        );
+    }
+
+    #[test]
+    fn window_size() {
+        let window_size = WindowSize {
+            columns_rows: Size::new(80, 24),
+            pixels: Size::new(640, 480),
+        };
+        let mut backend = CrosstermBackend::new(Vec::new()).with_window_size(window_size);
+        assert_eq!(backend.size().unwrap(), Size::new(80, 24));
+        assert_eq!(backend.window_size().unwrap(), window_size);
+        assert_eq!(
+            backend.get_cursor_position().unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+        assert_eq!(
+            backend
+                .query_capabilities(Duration::from_millis(100))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::Unsupported
+        );
+        assert_eq!(
+            backend.poll_event(Some(Duration::ZERO)).unwrap_err().kind(),
+            io::ErrorKind::Unsupported
+        );
+
+        let window_size = WindowSize {
+            columns_rows: Size::new(100, 30),
+            pixels: Size::new(800, 600),
+        };
+        backend.set_window_size(window_size);
+        assert_eq!(backend.size().unwrap(), Size::new(100, 30));
+        assert_eq!(backend.window_size().unwrap(), window_size);
+    }
+
+    #[test]
+    fn synchronized_update() {
+        let backend = CrosstermBackend::new(Vec::new());
+        assert!(!backend.supports_synchronized_output());
+        let mut backend = backend.with_synchronized_output(true);
+        assert!(backend.supports_synchronized_output());
+        backend.begin_synchronized_update().unwrap();
+        backend.end_synchronized_update().unwrap();
+        assert_eq!(backend.writer, b"\x1b[?2026h\x1b[?2026l");
+    }
+
+    #[test]
+    fn synchronized_update_ends_when_draw_fails() {
+        /// A writer that fails to write the frame, which contains an exclamation mark
+        struct FailingWriter(Vec<u8>);
+
+        impl Write for FailingWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if buf.contains(&b'!') {
+                    return Err(io::Error::other("failed to write"));
+                }
+                self.0.write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let window_size = WindowSize {
+            columns_rows: Size::new(4, 1),
+            pixels: Size::new(0, 0),
+        };
+        let backend = CrosstermBackend::new(FailingWriter(Vec::new()))
+            .with_window_size(window_size)
+            .with_synchronized_output(true);
+        let options = TerminalOptions {
+            synchronized_output: true,
+            ..TerminalOptions::default()
+        };
+        let mut terminal = Terminal::with_options(backend, options).unwrap();
+        let result = terminal.draw(|frame| frame.render_widget("!", frame.area()));
+        assert!(result.is_err());
+        assert!(terminal.backend().writer.0.ends_with(b"\x1b[?2026l"));
+    }
+
+    #[test]
+    fn draw_graphics() {
+        let mut backend =
+            CrosstermBackend::new(Vec::new()).with_graphics_protocol(Some(GraphicsProtocol::Sixel));
+        assert_eq!(backend.graphics_protocol(), Some(GraphicsProtocol::Sixel));
+        backend
+            .draw_graphics(Position::new(2, 3), b"\x1bPq#0~\x1b\\")
+            .unwrap();
+        assert_eq!(backend.writer, b"\x1b[4;3H\x1bPq#0~\x1b\\");
+    }
+
+    #[test]
+    fn draw_underline_styles() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let mut curly = Cell::new("a");
+        curly.set_style(Style::new().underlined().curly_underlined().overlined());
+        let mut plain = Cell::new("b");
+        plain.set_style(Style::new().underlined());
+        backend
+            .draw([(0, 0, &curly), (1, 0, &plain)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains("\x1b[4:3m\x1b[53ma"), "{output:?}");
+        assert!(output.contains("\x1b[24m\x1b[55m\x1b[4m"), "{output:?}");
+    }
+
+    #[test]
+    fn output_stats() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        let cell = Cell::new("a");
+        backend
+            .draw([(0, 0, &cell), (1, 0, &cell), (0, 1, &cell)].into_iter())
+            .unwrap();
+        backend.set_cursor_position(Position::new(3, 3)).unwrap();
+        assert_eq!(
+            backend.output_stats(),
+            Some(OutputStats {
+                bytes_written: backend.writer.len(),
+                cursor_moves: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn frame_stats() {
+        let window_size = WindowSize {
+            columns_rows: Size::new(10, 2),
+            pixels: Size::new(0, 0),
+        };
+        let backend = CrosstermBackend::new(Vec::new()).with_window_size(window_size);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let start = terminal.backend().writer.len();
+        let stats = terminal
+            .draw(|frame| frame.buffer_mut().set_string(0, 1, "hello", Style::new()))
+            .unwrap()
+            .stats;
+        assert_eq!(stats.changed_cells, 5);
+        let output = stats.output.unwrap();
+        assert_eq!(
+            output.bytes_written,
+            terminal.backend().writer.len() - start
+        );
+        assert_eq!(output.cursor_moves, 1);
+    }
+
+    #[test]
+    fn draw_diff_uses_screen_width_of_terminal() {
+        let window_size = WindowSize {
+            columns_rows: Size::new(10, 1),
+            pixels: Size::new(0, 0),
+        };
+        let backend = CrosstermBackend::new(Vec::new()).with_window_size(window_size);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| frame.buffer_mut().set_string(0, 0, "hello", Style::new()))
+            .unwrap();
+        let start = terminal.backend().writer.len();
+        terminal.draw(|_| {}).unwrap();
+        // the rest of the row is erased, as the buffer ends at the right edge of the screen
+        assert!(
+            terminal.backend().writer[start..]
+                .windows(3)
+                .any(|bytes| bytes == b"\x1b[K")
+        );
+    }
+
+    #[test]
+    fn execute_command() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.set_title("title").unwrap();
+        backend.notify(None, "done").unwrap();
+        assert_eq!(backend.writer, b"\x1b]0;title\x1b\\\x1b]9;done\x1b\\");
+    }
+
     #[test]
     #[cfg(feature = "underline-color")]
     fn from_crossterm_content_style_underline() {
@@ -876,4 +1961,55 @@ mod tests {
             Style::default().underline_color(Color::Red)
         );
     }
+
+    #[rstest]
+    #[case(CrosstermEvent::FocusGained, Event::FocusGained)]
+    #[case(CrosstermEvent::FocusLost, Event::FocusLost)]
+    #[case(CrosstermEvent::Resize(80, 24), Event::Resize(Size::new(80, 24)))]
+    #[case(CrosstermEvent::Paste("text".into()), Event::Paste("text".into()))]
+    #[case(
+        CrosstermEvent::Key(CrosstermKeyEvent::new(
+            CrosstermKeyCode::Char('c'),
+            CrosstermKeyModifiers::CONTROL | CrosstermKeyModifiers::SHIFT,
+        )),
+        Event::Key(KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )),
+    )]
+    #[case(
+        CrosstermEvent::Key(CrosstermKeyEvent::new_with_kind(
+            CrosstermKeyCode::Media(CrosstermMediaKeyCode::PlayPause),
+            CrosstermKeyModifiers::NONE,
+            CrosstermKeyEventKind::Release,
+        )),
+        Event::Key(KeyEvent::from(KeyCode::Media(MediaKeyCode::PlayPause)).kind(KeyEventKind::Release)),
+    )]
+    #[case(
+        CrosstermEvent::Mouse(CrosstermMouseEvent {
+            kind: CrosstermMouseEventKind::Drag(CrosstermMouseButton::Right),
+            column: 3,
+            row: 7,
+            modifiers: CrosstermKeyModifiers::ALT,
+        }),
+        Event::Mouse(
+            MouseEvent::new(MouseEventKind::Drag(MouseButton::Right), Position::new(3, 7))
+                .modifiers(KeyModifiers::ALT),
+        ),
+    )]
+    fn from_crossterm_event(#[case] crossterm: CrosstermEvent, #[case] expected: Event) {
+        assert_eq!(Event::from_crossterm(crossterm), expected);
+    }
+
+    #[rstest]
+    #[case(CrosstermKeyCode::BackTab, KeyCode::BackTab)]
+    #[case(CrosstermKeyCode::F(12), KeyCode::F(12))]
+    #[case(CrosstermKeyCode::KeypadBegin, KeyCode::KeypadBegin)]
+    #[case(
+        CrosstermKeyCode::Modifier(CrosstermModifierKeyCode::RightSuper),
+        KeyCode::Modifier(ModifierKeyCode::RightSuper)
+    )]
+    fn from_crossterm_key_code(#[case] crossterm: CrosstermKeyCode, #[case] expected: KeyCode) {
+        assert_eq!(KeyCode::from_crossterm(crossterm), expected);
+    }
 }
@@ -37,14 +37,22 @@
 
 use std::fmt;
 use std::io::{self, Write};
+use std::time::{Duration, Instant};
 
-use ratatui_core::backend::{Backend, ClearType, WindowSize};
-use ratatui_core::buffer::Cell;
+use ratatui_core::backend::{
+    Backend, ClearType, CommandBackend, CursorStyle, DrawCommand, DrawPlanner, GraphicsBackend,
+    GraphicsProtocol, OutputStats, QueryBackend, StyleChange, TerminalCapabilities,
+    TerminalCommand, WindowSize,
+};
+use ratatui_core::buffer::{Buffer, Cell};
+use ratatui_core::event::{
+    Event, EventReader, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 use ratatui_core::layout::{Position, Size};
-use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::style::{Color, ColorDepth, Modifier, Style};
 pub use termion;
-use termion::color::Color as _;
-use termion::{color as tcolor, style as tstyle};
+use termion::input::TermRead;
+use termion::{color as tcolor, event as tevent, style as tstyle};
 
 /// A [`Backend`] implementation that uses [Termion] to render to the terminal.
 ///
@@ -91,12 +99,18 @@ use termion::{color as tcolor, style as tstyle};
 /// [`IntoAlternateScreen::into_alternate_screen()`]: termion::screen::IntoAlternateScreen
 /// [`Terminal`]: ratatui_core::terminal::Terminal
 /// [Termion]: https://docs.rs/termion
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct TermionBackend<W>
 where
     W: Write,
 {
     writer: W,
+    graphics_protocol: Option<GraphicsProtocol>,
+    color_depth: ColorDepth,
+    repeat_sequences: bool,
+    background_color_erase: bool,
+    output_stats: OutputStats,
+    synchronized_output: bool,
 }
 
 impl<W> TermionBackend<W>
@@ -120,7 +134,108 @@ where
     /// let backend = TermionBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            graphics_protocol: None,
+            color_depth: ColorDepth::TrueColor,
+            repeat_sequences: false,
+            background_color_erase: true,
+            output_stats: OutputStats {
+                bytes_written: 0,
+                cursor_moves: 0,
+            },
+            synchronized_output: false,
+        }
+    }
+
+    /// Sets the graphics protocol used to display images.
+    ///
+    /// By default the backend reports no graphics support and image widgets fall back to rendering
+    /// with text cells. Use [`GraphicsProtocol::from_env`] to guess the protocol of the current
+    /// terminal.
+    #[must_use]
+    pub const fn with_graphics_protocol(mut self, protocol: Option<GraphicsProtocol>) -> Self {
+        self.graphics_protocol = protocol;
+        self
+    }
+
+    /// Sets the colors that the terminal can display.
+    ///
+    /// Colors that the terminal cannot display are replaced with the nearest color it can display
+    /// when drawing (see [`Cell::quantize`]). By default, all colors are drawn as is. Use
+    /// [`ColorDepth::from_env`] to guess the color depth of the current terminal, which also
+    /// honors the `NO_COLOR` environment variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::TermionBackend;
+    /// use ratatui::style::ColorDepth;
+    ///
+    /// let backend = TermionBackend::new(stdout()).with_color_depth(ColorDepth::from_env());
+    /// ```
+    #[must_use]
+    pub const fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Returns the colors that the terminal can display.
+    pub const fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Sets whether runs of the same character are drawn with the REP escape sequence.
+    ///
+    /// This reduces the output of frames with long runs of the same character, such as borders,
+    /// but REP is not supported by all terminals (e.g. the Linux console), so it is disabled by
+    /// default. See [`DrawPlanner`] for the other ways the backend reduces its output.
+    #[must_use]
+    pub const fn with_repeat_sequences(mut self, repeat_sequences: bool) -> Self {
+        self.repeat_sequences = repeat_sequences;
+        self
+    }
+
+    /// Sets whether the terminal erases cells with the current background color.
+    ///
+    /// Blank cells are drawn by erasing them, which leaves them with the default background color
+    /// on terminals without background color erase (BCE), such as `screen` or `tmux` with some
+    /// `TERM` settings. Disable it on these terminals so that only the cells with the default
+    /// background color are erased. It is enabled by default, as most terminals support it.
+    #[must_use]
+    pub const fn with_background_color_erase(mut self, background_color_erase: bool) -> Self {
+        self.background_color_erase = background_color_erase;
+        self
+    }
+
+    /// Sets whether the terminal supports synchronized output.
+    ///
+    /// The [`Terminal`] only writes frames as synchronized updates when the backend supports them
+    /// and they are enabled in its options. The support is unknown by default, so the backend
+    /// reports no support and frames are written as usual. It is detected from the answer of the
+    /// terminal to a DECRQM request for mode 2026 when the capabilities are queried with
+    /// [`QueryBackend::query_capabilities`], or can be set with this method.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::io::stdout;
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::backend::{QueryBackend, TermionBackend};
+    ///
+    /// let mut backend = TermionBackend::new(stdout());
+    /// backend.query_capabilities(Duration::from_millis(100))?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// [`Terminal`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html
+    #[must_use]
+    pub const fn with_synchronized_output(mut self, supported: bool) -> Self {
+        self.synchronized_output = supported;
+        self
     }
 
     /// Gets the writer.
@@ -142,6 +257,41 @@ where
     pub const fn writer_mut(&mut self) -> &mut W {
         &mut self.writer
     }
+
+    /// Returns the writer, counting the bytes written to it in the output statistics.
+    const fn counted_writer(&mut self) -> CountingWriter<'_, W> {
+        CountingWriter {
+            writer: &mut self.writer,
+            bytes_written: &mut self.output_stats.bytes_written,
+        }
+    }
+}
+
+impl<W> Default for TermionBackend<W>
+where
+    W: Write + Default,
+{
+    fn default() -> Self {
+        Self::new(W::default())
+    }
+}
+
+/// A writer that counts the bytes written to the writer of a backend.
+struct CountingWriter<'a, W> {
+    writer: &'a mut W,
+    bytes_written: &'a mut usize,
+}
+
+impl<W: Write> Write for CountingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let count = self.writer.write(buf)?;
+        *self.bytes_written = self.bytes_written.wrapping_add(count);
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
 }
 
 impl<W> Write for TermionBackend<W>
@@ -149,7 +299,7 @@ where
     W: Write,
 {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.writer.write(buf)
+        self.counted_writer().write(buf)
     }
 
     fn flush(&mut self) -> io::Result<()> {
@@ -168,30 +318,37 @@ where
     }
 
     fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        let mut writer = self.counted_writer();
         match clear_type {
-            ClearType::All => write!(self.writer, "{}", termion::clear::All)?,
-            ClearType::AfterCursor => write!(self.writer, "{}", termion::clear::AfterCursor)?,
-            ClearType::BeforeCursor => write!(self.writer, "{}", termion::clear::BeforeCursor)?,
-            ClearType::CurrentLine => write!(self.writer, "{}", termion::clear::CurrentLine)?,
-            ClearType::UntilNewLine => write!(self.writer, "{}", termion::clear::UntilNewline)?,
+            ClearType::All => write!(writer, "{}", termion::clear::All)?,
+            ClearType::AfterCursor => write!(writer, "{}", termion::clear::AfterCursor)?,
+            ClearType::BeforeCursor => write!(writer, "{}", termion::clear::BeforeCursor)?,
+            ClearType::CurrentLine => write!(writer, "{}", termion::clear::CurrentLine)?,
+            ClearType::UntilNewLine => write!(writer, "{}", termion::clear::UntilNewline)?,
         }
-        self.writer.flush()
+        writer.flush()
     }
 
     fn append_lines(&mut self, n: u16) -> io::Result<()> {
         for _ in 0..n {
-            writeln!(self.writer)?;
+            writeln!(self.counted_writer())?;
         }
         self.writer.flush()
     }
 
     fn hide_cursor(&mut self) -> io::Result<()> {
-        write!(self.writer, "{}", termion::cursor::Hide)?;
+        write!(self.counted_writer(), "{}", termion::cursor::Hide)?;
         self.writer.flush()
     }
 
     fn show_cursor(&mut self) -> io::Result<()> {
-        write!(self.writer, "{}", termion::cursor::Show)?;
+        write!(self.counted_writer(), "{}", termion::cursor::Show)?;
+        self.writer.flush()
+    }
+
+    fn set_cursor_style(&mut self, style: CursorStyle) -> io::Result<()> {
+        // termion has no sequence for the default style, so all styles are written directly
+        write!(self.counted_writer(), "\x1b[{} q", style.decscusr())?;
         self.writer.flush()
     }
 
@@ -202,7 +359,12 @@ where
 
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
         let Position { x, y } = position.into();
-        write!(self.writer, "{}", termion::cursor::Goto(x + 1, y + 1))?;
+        self.output_stats.cursor_moves = self.output_stats.cursor_moves.wrapping_add(1);
+        write!(
+            self.counted_writer(),
+            "{}",
+            termion::cursor::Goto(x + 1, y + 1)
+        )?;
         self.writer.flush()
     }
 
@@ -216,37 +378,47 @@ where
         let mut fg = Color::Reset;
         let mut bg = Color::Reset;
         let mut modifier = Modifier::empty();
+        let mut hyperlink: Option<&str> = None;
         let mut last_pos: Option<Position> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
             if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
                 write!(string, "{}", termion::cursor::Goto(x + 1, y + 1)).unwrap();
+                self.output_stats.cursor_moves = self.output_stats.cursor_moves.wrapping_add(1);
             }
             last_pos = Some(Position { x, y });
-            if cell.modifier != modifier {
+            let colors = cell.quantize(self.color_depth);
+            if colors.modifier != modifier {
                 write!(
                     string,
                     "{}",
                     ModifierDiff {
                         from: modifier,
-                        to: cell.modifier
+                        to: colors.modifier
                     }
                 )
                 .unwrap();
-                modifier = cell.modifier;
+                modifier = colors.modifier;
             }
-            if cell.fg != fg {
-                write!(string, "{}", Fg(cell.fg)).unwrap();
-                fg = cell.fg;
+            if colors.fg != fg {
+                write!(string, "{}", Fg(colors.fg)).unwrap();
+                fg = colors.fg;
             }
-            if cell.bg != bg {
-                write!(string, "{}", Bg(cell.bg)).unwrap();
-                bg = cell.bg;
+            if colors.bg != bg {
+                write!(string, "{}", Bg(colors.bg)).unwrap();
+                bg = colors.bg;
+            }
+            if cell.hyperlink() != hyperlink {
+                write!(string, "{}", Hyperlink(cell.hyperlink())).unwrap();
+                hyperlink = cell.hyperlink();
             }
             string.push_str(cell.symbol());
         }
+        if hyperlink.is_some() {
+            write!(string, "{}", Hyperlink(None)).unwrap();
+        }
         write!(
-            self.writer,
+            self.counted_writer(),
             "{string}{}{}{}",
             Fg(Color::Reset),
             Bg(Color::Reset),
@@ -254,6 +426,31 @@ where
         )
     }
 
+    fn draw_diff(
+        &mut self,
+        next: &Buffer,
+        updates: &[(u16, u16, &Cell)],
+        screen_width: Option<u16>,
+    ) -> io::Result<()> {
+        use std::fmt::Write;
+
+        let mut planner = DrawPlanner::new()
+            .with_color_depth(self.color_depth)
+            .with_repeat_sequences(self.repeat_sequences)
+            .with_background_color_erase(self.background_color_erase);
+        if let Some(width) = screen_width {
+            planner = planner.with_screen_width(width);
+        }
+        let mut string = String::with_capacity(updates.len() * 3);
+        for command in planner.plan(next, updates) {
+            if command.is_cursor_move() {
+                self.output_stats.cursor_moves = self.output_stats.cursor_moves.wrapping_add(1);
+            }
+            write!(string, "{command}").unwrap();
+        }
+        self.counted_writer().write_all(string.as_bytes())
+    }
+
     fn size(&self) -> io::Result<Size> {
         let terminal = termion::terminal_size()?;
         Ok(Size::new(terminal.0, terminal.1))
@@ -270,10 +467,26 @@ where
         self.writer.flush()
     }
 
+    fn output_stats(&self) -> Option<OutputStats> {
+        Some(self.output_stats)
+    }
+
+    fn supports_synchronized_output(&self) -> bool {
+        self.synchronized_output
+    }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.counted_writer(), "\x1b[?2026h")
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.counted_writer(), "\x1b[?2026l")
+    }
+
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         write!(
-            self.writer,
+            self.counted_writer(),
             "{}{}{}",
             SetRegion(region.start.saturating_add(1), region.end),
             termion::scroll::Up(amount),
@@ -285,7 +498,7 @@ where
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_down(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         write!(
-            self.writer,
+            self.counted_writer(),
             "{}{}{}",
             SetRegion(region.start.saturating_add(1), region.end),
             termion::scroll::Down(amount),
@@ -294,10 +507,137 @@ where
         self.writer.flush()
     }
 }
+
+impl<W> GraphicsBackend for TermionBackend<W>
+where
+    W: Write,
+{
+    fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        self.graphics_protocol
+    }
+
+    fn draw_graphics(&mut self, position: Position, payload: &[u8]) -> io::Result<()> {
+        write!(
+            self.counted_writer(),
+            "{}",
+            termion::cursor::Goto(position.x + 1, position.y + 1)
+        )?;
+        self.counted_writer().write_all(payload)
+    }
+}
+
+impl<W> CommandBackend for TermionBackend<W>
+where
+    W: Write,
+{
+    fn execute_command(&mut self, command: &TerminalCommand) -> io::Result<()> {
+        write!(self.counted_writer(), "{command}")?;
+        self.writer.flush()
+    }
+}
+
+impl<W> QueryBackend for TermionBackend<W>
+where
+    W: Write,
+{
+    /// Writes the request to the writer and reads the responses from the controlling terminal of
+    /// the process.
+    ///
+    /// The responses are read directly from `/dev/tty`, so events should not be read while
+    /// querying.
+    fn query(
+        &mut self,
+        request: &[u8],
+        timeout: Duration,
+        is_complete: fn(&[u8]) -> bool,
+    ) -> io::Result<Vec<u8>> {
+        #[cfg(unix)]
+        {
+            query_tty(&mut self.counted_writer(), request, timeout, is_complete)
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = (request, timeout, is_complete);
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the terminal can only be queried on Unix",
+            ))
+        }
+    }
+
+    /// Queries the capabilities of the terminal, and records whether it supports synchronized
+    /// output (see [`with_synchronized_output`]).
+    ///
+    /// [`with_synchronized_output`]: Self::with_synchronized_output
+    fn query_capabilities(&mut self, timeout: Duration) -> io::Result<TerminalCapabilities> {
+        let response = self.query(
+            TerminalCapabilities::REQUEST.as_bytes(),
+            timeout,
+            TerminalCapabilities::is_complete,
+        )?;
+        let capabilities = TerminalCapabilities::parse(&response);
+        if let Some(supported) = capabilities.synchronized_output {
+            self.synchronized_output = supported;
+        }
+        Ok(capabilities)
+    }
+}
+
+/// Writes the request and reads the responses from `/dev/tty` until they are complete or the
+/// timeout elapses.
+///
+/// The terminal is switched to non-canonical mode without echo while reading, so that the
+/// responses are neither line buffered nor displayed, and reads return after a tenth of a second
+/// when no input arrives.
+#[cfg(unix)]
+fn query_tty<W: Write>(
+    writer: &mut W,
+    request: &[u8],
+    timeout: Duration,
+    is_complete: fn(&[u8]) -> bool,
+) -> io::Result<Vec<u8>> {
+    use std::fs::File;
+    use std::io::Read;
+
+    use rustix::termios::{self, LocalModes, OptionalActions, SpecialCodeIndex};
+
+    let mut tty = File::open("/dev/tty")?;
+    let original = termios::tcgetattr(&tty)?;
+    let mut mode = original.clone();
+    mode.local_modes
+        .remove(LocalModes::ICANON | LocalModes::ECHO);
+    mode.special_codes[SpecialCodeIndex::VMIN] = 0;
+    mode.special_codes[SpecialCodeIndex::VTIME] = 1;
+    termios::tcsetattr(&tty, OptionalActions::Now, &mode)?;
+
+    let mut read_responses = || {
+        writer.write_all(request)?;
+        writer.flush()?;
+        let deadline = Instant::now() + timeout;
+        let mut response = Vec::new();
+        let mut buffer = [0; 1024];
+        while !is_complete(&response) && Instant::now() < deadline {
+            let count = tty.read(&mut buffer)?;
+            response.extend_from_slice(&buffer[..count]);
+        }
+        Ok(response)
+    };
+    let response = read_responses();
+    termios::tcsetattr(&tty, OptionalActions::Now, &original)?;
+    response
+}
+
+/// Sets the foreground color, writing the named colors with the short `30–37`/`90–97` codes.
 struct Fg(Color);
 
+/// Sets the background color, writing the named colors with the short `40–47`/`100–107` codes.
 struct Bg(Color);
 
+/// Starts an [OSC 8] hyperlink to the given target, or ends the current one when `None`.
+///
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+struct Hyperlink<'a>(Option<&'a str>);
+
 /// The `ModifierDiff` struct is used to calculate the difference between two `Modifier`
 /// values. This is useful when updating the terminal display, as it allows for more
 /// efficient updates by only sending the necessary changes.
@@ -308,52 +648,27 @@ struct ModifierDiff {
 
 impl fmt::Display for Fg {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.0 {
-            Color::Reset => termion::color::Reset.write_fg(f),
-            Color::Black => termion::color::Black.write_fg(f),
-            Color::Red => termion::color::Red.write_fg(f),
-            Color::Green => termion::color::Green.write_fg(f),
-            Color::Yellow => termion::color::Yellow.write_fg(f),
-            Color::Blue => termion::color::Blue.write_fg(f),
-            Color::Magenta => termion::color::Magenta.write_fg(f),
-            Color::Cyan => termion::color::Cyan.write_fg(f),
-            Color::Gray => termion::color::White.write_fg(f),
-            Color::DarkGray => termion::color::LightBlack.write_fg(f),
-            Color::LightRed => termion::color::LightRed.write_fg(f),
-            Color::LightGreen => termion::color::LightGreen.write_fg(f),
-            Color::LightBlue => termion::color::LightBlue.write_fg(f),
-            Color::LightYellow => termion::color::LightYellow.write_fg(f),
-            Color::LightMagenta => termion::color::LightMagenta.write_fg(f),
-            Color::LightCyan => termion::color::LightCyan.write_fg(f),
-            Color::White => termion::color::LightWhite.write_fg(f),
-            Color::Indexed(i) => termion::color::AnsiValue(i).write_fg(f),
-            Color::Rgb(r, g, b) => termion::color::Rgb(r, g, b).write_fg(f),
-        }
+        let change = StyleChange {
+            fg: Some(self.0),
+            ..StyleChange::default()
+        };
+        write!(f, "{}", DrawCommand::SetStyle(change))
     }
 }
+
 impl fmt::Display for Bg {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.0 {
-            Color::Reset => termion::color::Reset.write_bg(f),
-            Color::Black => termion::color::Black.write_bg(f),
-            Color::Red => termion::color::Red.write_bg(f),
-            Color::Green => termion::color::Green.write_bg(f),
-            Color::Yellow => termion::color::Yellow.write_bg(f),
-            Color::Blue => termion::color::Blue.write_bg(f),
-            Color::Magenta => termion::color::Magenta.write_bg(f),
-            Color::Cyan => termion::color::Cyan.write_bg(f),
-            Color::Gray => termion::color::White.write_bg(f),
-            Color::DarkGray => termion::color::LightBlack.write_bg(f),
-            Color::LightRed => termion::color::LightRed.write_bg(f),
-            Color::LightGreen => termion::color::LightGreen.write_bg(f),
-            Color::LightBlue => termion::color::LightBlue.write_bg(f),
-            Color::LightYellow => termion::color::LightYellow.write_bg(f),
-            Color::LightMagenta => termion::color::LightMagenta.write_bg(f),
-            Color::LightCyan => termion::color::LightCyan.write_bg(f),
-            Color::White => termion::color::LightWhite.write_bg(f),
-            Color::Indexed(i) => termion::color::AnsiValue(i).write_bg(f),
-            Color::Rgb(r, g, b) => termion::color::Rgb(r, g, b).write_bg(f),
-        }
+        let change = StyleChange {
+            bg: Some(self.0),
+            ..StyleChange::default()
+        };
+        write!(f, "{}", DrawCommand::SetStyle(change))
+    }
+}
+
+impl fmt::Display for Hyperlink<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\x1b]8;;{}\x1b\\", self.0.unwrap_or_default())
     }
 }
 
@@ -470,8 +785,16 @@ impl fmt::Display for ModifierDiff {
         if remove.contains(Modifier::ITALIC) {
             write!(f, "{}", termion::style::NoItalic)?;
         }
-        if remove.contains(Modifier::UNDERLINED) {
+        let mut add = self.to - self.from;
+        if remove.intersects(Modifier::ANY_UNDERLINE) {
             write!(f, "{}", termion::style::NoUnderline)?;
+
+            // All the underline styles are reset, so the remaining one must be reapplied
+            add |= self.to & Modifier::ANY_UNDERLINE;
+        }
+        if remove.contains(Modifier::OVERLINED) {
+            // termion has no sequences for overlined text
+            write!(f, "\x1b[55m")?;
         }
         if remove.contains(Modifier::DIM) {
             write!(f, "{}", termion::style::NoFaint)?;
@@ -485,11 +808,17 @@ impl fmt::Display for ModifierDiff {
         if remove.contains(Modifier::CROSSED_OUT) {
             write!(f, "{}", termion::style::NoCrossedOut)?;
         }
+        if remove.contains(Modifier::HIDDEN) {
+            // termion has no sequence to reveal hidden text
+            write!(f, "\x1b[28m")?;
+        }
         if remove.contains(Modifier::SLOW_BLINK) || remove.contains(Modifier::RAPID_BLINK) {
             write!(f, "{}", termion::style::NoBlink)?;
+
+            // Both blinks are reset, so the remaining one must be reapplied
+            add |= self.to & (Modifier::SLOW_BLINK | Modifier::RAPID_BLINK);
         }
 
-        let add = self.to - self.from;
         if add.contains(Modifier::REVERSED) {
             write!(f, "{}", termion::style::Invert)?;
         }
@@ -499,8 +828,19 @@ impl fmt::Display for ModifierDiff {
         if add.contains(Modifier::ITALIC) {
             write!(f, "{}", termion::style::Italic)?;
         }
-        if add.contains(Modifier::UNDERLINED) {
-            write!(f, "{}", termion::style::Underline)?;
+        if add.intersects(Modifier::ANY_UNDERLINE) {
+            // only one underline style is displayed, which depends on all the underline
+            // modifiers, and termion has no sequences for the extended underline styles
+            match self.to.underline_style() {
+                Modifier::DOUBLE_UNDERLINED => write!(f, "\x1b[4:2m")?,
+                Modifier::CURLY_UNDERLINED => write!(f, "\x1b[4:3m")?,
+                Modifier::DOTTED_UNDERLINED => write!(f, "\x1b[4:4m")?,
+                Modifier::DASHED_UNDERLINED => write!(f, "\x1b[4:5m")?,
+                _ => write!(f, "{}", termion::style::Underline)?,
+            }
+        }
+        if add.contains(Modifier::OVERLINED) {
+            write!(f, "\x1b[53m")?;
         }
         if add.contains(Modifier::DIM) {
             write!(f, "{}", termion::style::Faint)?;
@@ -508,9 +848,16 @@ impl fmt::Display for ModifierDiff {
         if add.contains(Modifier::CROSSED_OUT) {
             write!(f, "{}", termion::style::CrossedOut)?;
         }
-        if add.contains(Modifier::SLOW_BLINK) || add.contains(Modifier::RAPID_BLINK) {
+        if add.contains(Modifier::SLOW_BLINK) {
             write!(f, "{}", termion::style::Blink)?;
         }
+        if add.contains(Modifier::RAPID_BLINK) {
+            // termion has no sequences for rapid blinking or hidden text
+            write!(f, "\x1b[6m")?;
+        }
+        if add.contains(Modifier::HIDDEN) {
+            write!(f, "\x1b[8m")?;
+        }
 
         Ok(())
     }
@@ -540,6 +887,175 @@ impl FromTermion<termion::style::Reset> for Modifier {
     }
 }
 
+/// Converts a termion event, or returns `None` for unsupported input.
+impl FromTermion<tevent::Event> for Option<Event> {
+    fn from_termion(value: tevent::Event) -> Self {
+        match value {
+            tevent::Event::Key(key) => Option::<KeyEvent>::from_termion(key).map(Event::Key),
+            tevent::Event::Mouse(mouse) => Some(Event::Mouse(MouseEvent::from_termion(mouse))),
+            tevent::Event::Unsupported(_) => None,
+        }
+    }
+}
+
+/// Converts a termion key, or returns `None` for keys that termion does not fully parse.
+///
+/// Termion reports Enter and Tab as the `'\n'` and `'\t'` characters, and only reports the
+/// modifiers of arrow keys, Home, End and characters.
+impl FromTermion<tevent::Key> for Option<KeyEvent> {
+    fn from_termion(value: tevent::Key) -> Self {
+        let (code, modifiers) = match value {
+            tevent::Key::Backspace => (KeyCode::Backspace, KeyModifiers::empty()),
+            tevent::Key::Left => (KeyCode::Left, KeyModifiers::empty()),
+            tevent::Key::ShiftLeft => (KeyCode::Left, KeyModifiers::SHIFT),
+            tevent::Key::AltLeft => (KeyCode::Left, KeyModifiers::ALT),
+            tevent::Key::CtrlLeft => (KeyCode::Left, KeyModifiers::CONTROL),
+            tevent::Key::Right => (KeyCode::Right, KeyModifiers::empty()),
+            tevent::Key::ShiftRight => (KeyCode::Right, KeyModifiers::SHIFT),
+            tevent::Key::AltRight => (KeyCode::Right, KeyModifiers::ALT),
+            tevent::Key::CtrlRight => (KeyCode::Right, KeyModifiers::CONTROL),
+            tevent::Key::Up => (KeyCode::Up, KeyModifiers::empty()),
+            tevent::Key::ShiftUp => (KeyCode::Up, KeyModifiers::SHIFT),
+            tevent::Key::AltUp => (KeyCode::Up, KeyModifiers::ALT),
+            tevent::Key::CtrlUp => (KeyCode::Up, KeyModifiers::CONTROL),
+            tevent::Key::Down => (KeyCode::Down, KeyModifiers::empty()),
+            tevent::Key::ShiftDown => (KeyCode::Down, KeyModifiers::SHIFT),
+            tevent::Key::AltDown => (KeyCode::Down, KeyModifiers::ALT),
+            tevent::Key::CtrlDown => (KeyCode::Down, KeyModifiers::CONTROL),
+            tevent::Key::Home => (KeyCode::Home, KeyModifiers::empty()),
+            tevent::Key::CtrlHome => (KeyCode::Home, KeyModifiers::CONTROL),
+            tevent::Key::End => (KeyCode::End, KeyModifiers::empty()),
+            tevent::Key::CtrlEnd => (KeyCode::End, KeyModifiers::CONTROL),
+            tevent::Key::PageUp => (KeyCode::PageUp, KeyModifiers::empty()),
+            tevent::Key::PageDown => (KeyCode::PageDown, KeyModifiers::empty()),
+            tevent::Key::BackTab => (KeyCode::BackTab, KeyModifiers::SHIFT),
+            tevent::Key::Delete => (KeyCode::Delete, KeyModifiers::empty()),
+            tevent::Key::Insert => (KeyCode::Insert, KeyModifiers::empty()),
+            tevent::Key::F(n) => (KeyCode::F(n), KeyModifiers::empty()),
+            tevent::Key::Char(c) => (char_key_code(c), KeyModifiers::empty()),
+            tevent::Key::Alt(c) => (char_key_code(c), KeyModifiers::ALT),
+            tevent::Key::Ctrl(c) => (char_key_code(c), KeyModifiers::CONTROL),
+            tevent::Key::Null => (KeyCode::Null, KeyModifiers::empty()),
+            tevent::Key::Esc => (KeyCode::Esc, KeyModifiers::empty()),
+            _ => return None,
+        };
+        Some(KeyEvent::new(code, modifiers))
+    }
+}
+
+/// Converts a termion mouse event.
+///
+/// Termion does not report which button was released or held while the mouse moved, so releases
+/// and drags are reported for the left button.
+impl FromTermion<tevent::MouseEvent> for MouseEvent {
+    fn from_termion(value: tevent::MouseEvent) -> Self {
+        let (kind, x, y) = match value {
+            tevent::MouseEvent::Press(button, x, y) => {
+                let kind = match button {
+                    tevent::MouseButton::Left => MouseEventKind::Down(MouseButton::Left),
+                    tevent::MouseButton::Right => MouseEventKind::Down(MouseButton::Right),
+                    tevent::MouseButton::Middle => MouseEventKind::Down(MouseButton::Middle),
+                    tevent::MouseButton::WheelUp => MouseEventKind::ScrollUp,
+                    tevent::MouseButton::WheelDown => MouseEventKind::ScrollDown,
+                    tevent::MouseButton::WheelLeft => MouseEventKind::ScrollLeft,
+                    tevent::MouseButton::WheelRight => MouseEventKind::ScrollRight,
+                };
+                (kind, x, y)
+            }
+            tevent::MouseEvent::Release(x, y) => (MouseEventKind::Up(MouseButton::Left), x, y),
+            tevent::MouseEvent::Hold(x, y) => (MouseEventKind::Drag(MouseButton::Left), x, y),
+        };
+        // termion positions are 1-based
+        Self::new(
+            kind,
+            Position::new(x.saturating_sub(1), y.saturating_sub(1)),
+        )
+    }
+}
+
+/// Returns the key code of a character, which is a special key for some control characters
+const fn char_key_code(c: char) -> KeyCode {
+    match c {
+        '\n' | '\r' => KeyCode::Enter,
+        '\t' => KeyCode::Tab,
+        c => KeyCode::Char(c),
+    }
+}
+
+/// Reads [`Event`]s from stdin.
+///
+/// [`TermionBackend`] only owns the writer used for output, so events are read by this separate
+/// reader. Termion has no way to wait for input with a timeout, so stdin is read by the thread of
+/// [`termion::async_stdin`] and polled every few milliseconds. That thread keeps running until the
+/// end of the process.
+///
+/// Termion does not report resizes, pastes, focus changes or key releases.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui_core::event::{EventReader, KeyCode};
+/// use ratatui_termion::TermionEventReader;
+///
+/// let mut events = TermionEventReader::new();
+/// let event = events.read_event()?;
+/// if event.as_key_press_event().is_some_and(|key| key.code == KeyCode::Char('q')) {
+///     // quit
+/// }
+/// # std::io::Result::Ok(())
+/// ```
+pub struct TermionEventReader {
+    events: termion::input::Events<termion::AsyncReader>,
+}
+
+impl TermionEventReader {
+    /// How often stdin is checked for new input
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Creates a new reader of the events of stdin.
+    pub fn new() -> Self {
+        Self {
+            events: termion::async_stdin().events(),
+        }
+    }
+}
+
+impl Default for TermionEventReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for TermionEventReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TermionEventReader").finish_non_exhaustive()
+    }
+}
+
+impl EventReader for TermionEventReader {
+    type Error = io::Error;
+
+    fn poll_event(&mut self, timeout: Option<Duration>) -> io::Result<Option<Event>> {
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        loop {
+            // unsupported input is skipped rather than ending the wait
+            if let Some(event) = self.events.next().transpose()? {
+                if let Some(event) = Option::<Event>::from_termion(event) {
+                    return Ok(Some(event));
+                }
+                continue;
+            }
+            let remaining = deadline.map_or(Self::POLL_INTERVAL, |deadline| {
+                deadline.saturating_duration_since(Instant::now())
+            });
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            std::thread::sleep(remaining.min(Self::POLL_INTERVAL));
+        }
+    }
+}
+
 /// Set scrolling region.
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct SetRegion(pub u16, pub u16);
@@ -562,6 +1078,10 @@ impl fmt::Display for ResetRegion {
 
 #[cfg(test)]
 mod tests {
+    use ratatui_core::backend::VirtualTerminal;
+    use ratatui_core::layout::Rect;
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -723,6 +1243,157 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(ColorDepth::TrueColor, "\x1b[1;1H\x1b[38;2;250;5;5m\x1b[48;5;22ma")]
+    #[case(ColorDepth::Ansi256, "\x1b[1;1H\x1b[38;5;196m\x1b[48;5;22ma")]
+    #[case(ColorDepth::Ansi16, "\x1b[1;1H\x1b[91m\x1b[40ma")]
+    // light text on a dark background needs no reversal
+    #[case(ColorDepth::Monochrome, "\x1b[1;1Ha")]
+    fn draw_quantizes_colors(#[case] color_depth: ColorDepth, #[case] expected: &str) {
+        let mut backend = TermionBackend::new(Vec::new()).with_color_depth(color_depth);
+        assert_eq!(backend.color_depth(), color_depth);
+        let mut cell = Cell::new("a");
+        cell.set_fg(Color::Rgb(250, 5, 5))
+            .set_bg(Color::Indexed(22));
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(
+            output.starts_with(expected),
+            "unexpected output: {output:?}"
+        );
+    }
+
+    #[rstest]
+    #[case::bold_to_dim(Style::new().bold(), Style::new().dim())]
+    #[case::bold_dim_to_dim(Style::new().bold().dim(), Style::new().dim())]
+    #[case::dim_to_bold(Style::new().dim().italic(), Style::new().bold())]
+    #[case::blink(Style::new().slow_blink().rapid_blink(), Style::new().rapid_blink())]
+    #[case::hidden(Style::new().hidden(), Style::new().reversed())]
+    #[case::underlined(Style::new().underlined().crossed_out(), Style::new().italic())]
+    #[case::colors(Style::new().red().on_blue(), Style::new().fg(Color::Indexed(42)))]
+    #[case::rgb(Style::new().fg(Color::Rgb(1, 2, 3)), Style::new().on_light_yellow())]
+    fn draw_matches_virtual_terminal(#[case] first: Style, #[case] second: Style) {
+        let area = Rect::new(0, 0, 8, 2);
+        let mut expected = Buffer::empty(area);
+        expected.set_string(0, 0, "ab", first);
+        expected.set_string(2, 0, "cd", second);
+        expected.set_string(1, 1, "界e", second);
+        expected.set_string(6, 1, "f", first);
+        expected[(7, 1)].set_hyperlink(Some("https://ratatui.rs"));
+
+        let mut backend = TermionBackend::new(Vec::new());
+        backend
+            .draw(Buffer::empty(area).diff(&expected).into_iter())
+            .unwrap();
+        let mut terminal = VirtualTerminal::new(area.width, area.height);
+        terminal.process(&backend.writer);
+        assert_eq!(terminal.buffer(), &expected);
+
+        let mut backend = TermionBackend::new(Vec::new()).with_repeat_sequences(true);
+        let previous = Buffer::with_lines(["abcdefgh", "========"]);
+        backend
+            .draw_diff(
+                &previous,
+                &Buffer::empty(area).diff(&previous),
+                Some(area.width),
+            )
+            .unwrap();
+        backend
+            .draw_diff(&expected, &previous.diff(&expected), Some(area.width))
+            .unwrap();
+        let mut terminal = VirtualTerminal::new(area.width, area.height);
+        terminal.process(&backend.writer);
+        assert_eq!(terminal.buffer(), &expected);
+    }
+
+    #[test]
+    fn synchronized_update() {
+        let backend = TermionBackend::new(Vec::new());
+        assert!(!backend.supports_synchronized_output());
+        let mut backend = backend.with_synchronized_output(true);
+        assert!(backend.supports_synchronized_output());
+        backend.begin_synchronized_update().unwrap();
+        backend.end_synchronized_update().unwrap();
+        assert_eq!(backend.writer, b"\x1b[?2026h\x1b[?2026l");
+    }
+
+    #[test]
+    fn set_cursor_style() {
+        let mut backend = TermionBackend::new(Vec::new());
+        backend.set_cursor_style(CursorStyle::SteadyBar).unwrap();
+        backend.set_cursor_style(CursorStyle::Default).unwrap();
+        assert_eq!(backend.writer, b"\x1b[6 q\x1b[0 q");
+    }
+
+    #[test]
+    fn draw_graphics() {
+        let mut backend =
+            TermionBackend::new(Vec::new()).with_graphics_protocol(Some(GraphicsProtocol::Iterm2));
+        assert_eq!(backend.graphics_protocol(), Some(GraphicsProtocol::Iterm2));
+        backend
+            .draw_graphics(Position::new(2, 3), b"\x1b]1337;File=:\x07")
+            .unwrap();
+        assert_eq!(backend.writer, b"\x1b[4;3H\x1b]1337;File=:\x07");
+    }
+
+    #[test]
+    fn draw_underline_styles() {
+        let mut backend = TermionBackend::new(Vec::new());
+        let mut curly = Cell::new("a");
+        curly.set_style(Style::new().underlined().curly_underlined().overlined());
+        let mut plain = Cell::new("b");
+        plain.set_style(Style::new().underlined());
+        backend
+            .draw([(0, 0, &curly), (1, 0, &plain)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains("\x1b[4:3m\x1b[53m"), "{output:?}");
+        assert!(output.contains("\x1b[24m\x1b[55m\x1b[4m"), "{output:?}");
+    }
+
+    #[test]
+    fn output_stats() {
+        let mut backend = TermionBackend::new(Vec::new());
+        let cell = Cell::new("a");
+        backend
+            .draw([(0, 0, &cell), (1, 0, &cell), (0, 1, &cell)].into_iter())
+            .unwrap();
+        backend.set_cursor_position(Position::new(3, 3)).unwrap();
+        assert_eq!(
+            backend.output_stats(),
+            Some(OutputStats {
+                bytes_written: backend.writer.len(),
+                cursor_moves: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn execute_command() {
+        let mut backend = TermionBackend::new(Vec::new());
+        backend.set_title("title").unwrap();
+        backend.notify(None, "done").unwrap();
+        assert_eq!(backend.writer, b"\x1b]0;title\x1b\\\x1b]9;done\x1b\\");
+    }
+
+    #[test]
+    fn draw_hyperlink() {
+        let mut backend = TermionBackend::new(Vec::new());
+        let plain = Cell::new("a");
+        let mut link = Cell::new("b");
+        link.set_hyperlink(Some("https://ratatui.rs"));
+        backend
+            .draw([(0, 0, &plain), (1, 0, &link), (2, 0, &link), (3, 0, &plain)].into_iter())
+            .unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        let open = "\x1b]8;;https://ratatui.rs\x1b\\";
+        let close = "\x1b]8;;\x1b\\";
+        assert!(
+            output.contains(&format!("a{open}bb{close}a")),
+            "unexpected output: {output:?}"
+        );
+    }
+
     #[test]
     fn from_termion_style() {
         assert_eq!(Modifier::from_termion(tstyle::Invert), Modifier::REVERSED);
@@ -740,4 +1411,66 @@ mod tests {
         assert_eq!(Modifier::from_termion(tstyle::Blink), Modifier::SLOW_BLINK);
         assert_eq!(Modifier::from_termion(tstyle::Reset), Modifier::empty());
     }
+
+    #[rstest]
+    #[case(tevent::Key::Char('a'), KeyEvent::from(KeyCode::Char('a')))]
+    #[case(tevent::Key::Char('\n'), KeyEvent::from(KeyCode::Enter))]
+    #[case(tevent::Key::Char('\t'), KeyEvent::from(KeyCode::Tab))]
+    #[case(
+        tevent::Key::Ctrl('c'),
+        KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)
+    )]
+    #[case(tevent::Key::AltUp, KeyEvent::new(KeyCode::Up, KeyModifiers::ALT))]
+    #[case(
+        tevent::Key::BackTab,
+        KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)
+    )]
+    #[case(tevent::Key::F(3), KeyEvent::from(KeyCode::F(3)))]
+    fn from_termion_key(#[case] key: tevent::Key, #[case] expected: KeyEvent) {
+        assert_eq!(Option::<KeyEvent>::from_termion(key), Some(expected));
+    }
+
+    #[rstest]
+    #[case(
+        tevent::MouseEvent::Press(tevent::MouseButton::Right, 1, 1),
+        MouseEventKind::Down(MouseButton::Right),
+        Position::new(0, 0)
+    )]
+    #[case(
+        tevent::MouseEvent::Press(tevent::MouseButton::WheelUp, 5, 2),
+        MouseEventKind::ScrollUp,
+        Position::new(4, 1)
+    )]
+    #[case(
+        tevent::MouseEvent::Release(3, 4),
+        MouseEventKind::Up(MouseButton::Left),
+        Position::new(2, 3)
+    )]
+    #[case(
+        tevent::MouseEvent::Hold(3, 4),
+        MouseEventKind::Drag(MouseButton::Left),
+        Position::new(2, 3)
+    )]
+    fn from_termion_mouse(
+        #[case] mouse: tevent::MouseEvent,
+        #[case] kind: MouseEventKind,
+        #[case] position: Position,
+    ) {
+        assert_eq!(
+            MouseEvent::from_termion(mouse),
+            MouseEvent::new(kind, position)
+        );
+    }
+
+    #[test]
+    fn from_termion_event() {
+        assert_eq!(
+            Option::<Event>::from_termion(tevent::Event::Key(tevent::Key::Esc)),
+            Some(Event::Key(KeyEvent::from(KeyCode::Esc)))
+        );
+        assert_eq!(
+            Option::<Event>::from_termion(tevent::Event::Unsupported(vec![0x1b])),
+            None
+        );
+    }
 }
@@ -37,16 +37,30 @@
 
 use std::error::Error;
 use std::io;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use ratatui_core::backend::{Backend, ClearType, WindowSize};
+use ratatui_core::backend::{
+    Backend, ClearType, CommandBackend, CursorStyle, DrawCommand, GraphicsBackend,
+    GraphicsProtocol, TerminalCommand, WindowSize,
+};
 use ratatui_core::buffer::Cell;
+use ratatui_core::event::{
+    Event, EventReader, KeyCode, KeyEvent, KeyModifiers, MediaKeyCode, ModifierKeyCode,
+    MouseButton, MouseEvent, MouseEventKind,
+};
 use ratatui_core::layout::{Position, Size};
-use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::style::{Color, ColorDepth, Modifier, Style};
 pub use termwiz;
 use termwiz::caps::Capabilities;
 use termwiz::cell::{AttributeChange, Blink, CellAttributes, Intensity, Underline};
 use termwiz::color::{AnsiColor, ColorAttribute, ColorSpec, LinearRgba, RgbColor, SrgbaTuple};
-use termwiz::surface::{Change, CursorVisibility, Position as TermwizPosition};
+use termwiz::hyperlink::Hyperlink;
+use termwiz::input::{
+    InputEvent, KeyCode as TermwizKeyCode, KeyEvent as TermwizKeyEvent, Modifiers, MouseButtons,
+    MouseEvent as TermwizMouseEvent,
+};
+use termwiz::surface::{Change, CursorShape, CursorVisibility, Position as TermwizPosition};
 use termwiz::terminal::buffered::BufferedTerminal;
 use termwiz::terminal::{ScreenSize, SystemTerminal, Terminal};
 
@@ -64,6 +78,11 @@ use termwiz::terminal::{ScreenSize, SystemTerminal, Terminal};
 /// screen when dropped). Use the [`TermwizBackend::with_buffered_terminal`] to create a new
 /// instance with a custom [`BufferedTerminal`] if this is not desired.
 ///
+/// Unlike the other backends, this backend does not implement [`QueryBackend`]: termwiz parses
+/// everything the terminal sends into input events and only reads the raw responses of the
+/// terminal without a timeout, so its capabilities cannot be queried. Use
+/// [`ColorDepth::from_env`] and [`GraphicsProtocol::from_env`] to guess them instead.
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -84,12 +103,22 @@ use termwiz::terminal::{ScreenSize, SystemTerminal, Terminal};
 /// for more details on raw mode and alternate screen.
 ///
 /// [`backend`]: ratatui_core::backend
+/// [`QueryBackend`]: ratatui_core::backend::QueryBackend
 /// [`Terminal`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html
 /// [`BufferedTerminal`]: termwiz::terminal::buffered::BufferedTerminal
 /// [Termwiz]: https://crates.io/crates/termwiz
 /// [Examples]: https://github.com/ratatui/ratatui/tree/main/ratatui/examples/README.md
 pub struct TermwizBackend {
     buffered_terminal: BufferedTerminal<SystemTerminal>,
+    /// The mouse buttons held at the last mouse event, to tell presses, releases and drags apart
+    mouse_buttons: MouseButtons,
+    color_depth: ColorDepth,
+    /// The graphics protocol reported by [`GraphicsBackend::graphics_protocol`].
+    graphics_protocol: Option<GraphicsProtocol>,
+    /// Whether the terminal supports synchronized output
+    synchronized_output: bool,
+    /// Whether a synchronized update ends once the buffered changes are flushed
+    ending_synchronized_update: bool,
 }
 
 impl TermwizBackend {
@@ -118,16 +147,82 @@ impl TermwizBackend {
             BufferedTerminal::new(SystemTerminal::new(Capabilities::new_from_env()?)?)?;
         buffered_terminal.terminal().set_raw_mode()?;
         buffered_terminal.terminal().enter_alternate_screen()?;
-        Ok(Self { buffered_terminal })
+        Ok(Self::with_buffered_terminal(buffered_terminal))
     }
 
     /// Creates a new Termwiz backend instance with the given buffered terminal.
     pub const fn with_buffered_terminal(instance: BufferedTerminal<SystemTerminal>) -> Self {
         Self {
             buffered_terminal: instance,
+            mouse_buttons: MouseButtons::NONE,
+            color_depth: ColorDepth::TrueColor,
+            graphics_protocol: None,
+            synchronized_output: false,
+            ending_synchronized_update: false,
         }
     }
 
+    /// Sets the graphics protocol used to display images.
+    ///
+    /// By default the backend reports no graphics support and image widgets fall back to rendering
+    /// with text cells. Use [`GraphicsProtocol::from_env`] to guess the protocol of the current
+    /// terminal.
+    #[must_use]
+    pub const fn with_graphics_protocol(mut self, protocol: Option<GraphicsProtocol>) -> Self {
+        self.graphics_protocol = protocol;
+        self
+    }
+
+    /// Sets the colors that the terminal can display.
+    ///
+    /// Colors that the terminal cannot display are replaced with the nearest color it can display
+    /// when drawing (see [`Cell::quantize`]). By default, all colors are drawn as is. Use
+    /// [`ColorDepth::from_env`] to guess the color depth of the current terminal, which also
+    /// honors the `NO_COLOR` environment variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui::backend::TermwizBackend;
+    /// use ratatui::style::ColorDepth;
+    ///
+    /// let backend = TermwizBackend::new()?.with_color_depth(ColorDepth::from_env());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub const fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.color_depth = color_depth;
+        self
+    }
+
+    /// Returns the colors that the terminal can display.
+    pub const fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Sets whether the terminal supports synchronized output.
+    ///
+    /// The [`Terminal`] only writes frames as synchronized updates when the backend supports them
+    /// and they are enabled in its options. Termwiz does not report whether the terminal supports
+    /// synchronized output, so the backend reports no support unless it is set with this method.
+    ///
+    /// [`Terminal`]: https://docs.rs/ratatui/latest/ratatui/struct.Terminal.html
+    #[must_use]
+    pub const fn with_synchronized_output(mut self, supported: bool) -> Self {
+        self.synchronized_output = supported;
+        self
+    }
+
+    /// Writes an escape sequence directly to the terminal, bypassing the buffered changes, as
+    /// termwiz has no change for most escape sequences.
+    fn write_directly(&mut self, sequence: &str) -> io::Result<()> {
+        let terminal = self.buffered_terminal.terminal();
+        terminal
+            .render(&[Change::Text(sequence.to_string())])
+            .map_err(io::Error::other)?;
+        terminal.flush().map_err(io::Error::other)
+    }
+
     /// Returns a reference to the buffered terminal used by the backend.
     pub const fn buffered_terminal(&self) -> &BufferedTerminal<SystemTerminal> {
         &self.buffered_terminal
@@ -146,21 +241,25 @@ impl Backend for TermwizBackend {
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
+        // Consecutive cells usually share the same hyperlink, so the last one is kept around to
+        // avoid allocating a new `Hyperlink` for every cell.
+        let mut last_hyperlink: Option<(&str, Arc<Hyperlink>)> = None;
         for (x, y, cell) in content {
+            let colors = cell.quantize(self.color_depth);
             self.buffered_terminal.add_changes(vec![
                 Change::CursorPosition {
                     x: TermwizPosition::Absolute(x as usize),
                     y: TermwizPosition::Absolute(y as usize),
                 },
-                Change::Attribute(AttributeChange::Foreground(cell.fg.into_termwiz())),
-                Change::Attribute(AttributeChange::Background(cell.bg.into_termwiz())),
+                Change::Attribute(AttributeChange::Foreground(colors.fg.into_termwiz())),
+                Change::Attribute(AttributeChange::Background(colors.bg.into_termwiz())),
             ]);
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::Intensity(
-                    if cell.modifier.contains(Modifier::BOLD) {
+                    if colors.modifier.contains(Modifier::BOLD) {
                         Intensity::Bold
-                    } else if cell.modifier.contains(Modifier::DIM) {
+                    } else if colors.modifier.contains(Modifier::DIM) {
                         Intensity::Half
                     } else {
                         Intensity::Normal
@@ -169,44 +268,54 @@ impl Backend for TermwizBackend {
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::Italic(
-                    cell.modifier.contains(Modifier::ITALIC),
+                    colors.modifier.contains(Modifier::ITALIC),
                 )));
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::Underline(
-                    if cell.modifier.contains(Modifier::UNDERLINED) {
-                        Underline::Single
-                    } else {
-                        Underline::None
-                    },
+                    colors.modifier.into_termwiz(),
                 )));
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::Reverse(
-                    cell.modifier.contains(Modifier::REVERSED),
+                    colors.modifier.contains(Modifier::REVERSED),
                 )));
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::Invisible(
-                    cell.modifier.contains(Modifier::HIDDEN),
+                    colors.modifier.contains(Modifier::HIDDEN),
                 )));
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::StrikeThrough(
-                    cell.modifier.contains(Modifier::CROSSED_OUT),
+                    colors.modifier.contains(Modifier::CROSSED_OUT),
                 )));
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::Blink(
-                    if cell.modifier.contains(Modifier::SLOW_BLINK) {
+                    if colors.modifier.contains(Modifier::SLOW_BLINK) {
                         Blink::Slow
-                    } else if cell.modifier.contains(Modifier::RAPID_BLINK) {
+                    } else if colors.modifier.contains(Modifier::RAPID_BLINK) {
                         Blink::Rapid
                     } else {
                         Blink::None
                     },
                 )));
 
+            let hyperlink = match (cell.hyperlink(), &last_hyperlink) {
+                (Some(url), Some((last_url, hyperlink))) if url == *last_url => {
+                    Some(Arc::clone(hyperlink))
+                }
+                (Some(url), _) => {
+                    let hyperlink = Arc::new(Hyperlink::new(url));
+                    last_hyperlink = Some((url, Arc::clone(&hyperlink)));
+                    Some(hyperlink)
+                }
+                (None, _) => None,
+            };
+            self.buffered_terminal
+                .add_change(Change::Attribute(AttributeChange::Hyperlink(hyperlink)));
+
             self.buffered_terminal.add_change(cell.symbol());
         }
         Ok(())
@@ -224,6 +333,12 @@ impl Backend for TermwizBackend {
         Ok(())
     }
 
+    fn set_cursor_style(&mut self, style: CursorStyle) -> io::Result<()> {
+        self.buffered_terminal
+            .add_change(Change::CursorShape(style.into_termwiz()));
+        Ok(())
+    }
+
     fn get_cursor_position(&mut self) -> io::Result<Position> {
         let (x, y) = self.buffered_terminal.cursor_position();
         Ok(Position::new(x as u16, y as u16))
@@ -287,6 +402,25 @@ impl Backend for TermwizBackend {
 
     fn flush(&mut self) -> io::Result<()> {
         self.buffered_terminal.flush().map_err(io::Error::other)?;
+        if self.ending_synchronized_update {
+            self.ending_synchronized_update = false;
+            self.write_directly("\x1b[?2026l")?;
+        }
+        Ok(())
+    }
+
+    fn supports_synchronized_output(&self) -> bool {
+        self.synchronized_output
+    }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        self.write_directly("\x1b[?2026h")
+    }
+
+    /// Ends the synchronized update once the buffered changes are written by the next flush, as
+    /// termwiz only writes the changes to the terminal when it is flushed.
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        self.ending_synchronized_update = true;
         Ok(())
     }
 
@@ -337,6 +471,73 @@ impl Backend for TermwizBackend {
     }
 }
 
+/// Writes the image directly to the terminal once the buffered changes are written, as termwiz has
+/// no change for the escape sequences of the graphics protocols.
+impl GraphicsBackend for TermwizBackend {
+    fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        self.graphics_protocol
+    }
+
+    fn draw_graphics(&mut self, position: Position, payload: &[u8]) -> io::Result<()> {
+        let payload = core::str::from_utf8(payload)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.buffered_terminal.flush().map_err(io::Error::other)?;
+        self.write_directly(&format!("{}{payload}", DrawCommand::MoveTo(position)))
+    }
+}
+
+impl CommandBackend for TermwizBackend {
+    /// Writes the command directly to the terminal, bypassing the buffered changes, as termwiz
+    /// has no change for most commands.
+    fn execute_command(&mut self, command: &TerminalCommand) -> io::Result<()> {
+        self.write_directly(&command.to_string())
+    }
+}
+
+/// Reads events from the input of the terminal of the backend.
+///
+/// Termwiz reports the mouse buttons that are held rather than presses and releases, so the
+/// backend keeps track of the held buttons to report presses, releases and drags.
+impl EventReader for TermwizBackend {
+    type Error = io::Error;
+
+    fn poll_event(&mut self, timeout: Option<Duration>) -> io::Result<Option<Event>> {
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        loop {
+            let remaining =
+                deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            let Some(input) = self
+                .buffered_terminal
+                .terminal()
+                .poll_input(remaining)
+                .map_err(io::Error::other)?
+            else {
+                return Ok(None);
+            };
+            let event = match input {
+                InputEvent::Mouse(mouse) => {
+                    let kind = mouse_event_kind(&self.mouse_buttons, &mouse.mouse_buttons);
+                    if !mouse
+                        .mouse_buttons
+                        .intersects(MouseButtons::VERT_WHEEL | MouseButtons::HORZ_WHEEL)
+                    {
+                        self.mouse_buttons = mouse.mouse_buttons.clone();
+                    }
+                    Some(Event::Mouse(MouseEvent {
+                        kind,
+                        ..MouseEvent::from_termwiz(mouse)
+                    }))
+                }
+                input => Option::<Event>::from_termwiz(input),
+            };
+            // events that have no equivalent are skipped rather than ending the wait
+            if event.is_some() || remaining.is_some_and(|remaining| remaining.is_zero()) {
+                return Ok(event);
+            }
+        }
+    }
+}
+
 /// A trait for converting types from Termwiz to Ratatui.
 ///
 /// This trait replaces the `From` trait for converting types from Termwiz to Ratatui. It is
@@ -393,6 +594,9 @@ impl FromTermwiz<CellAttributes> for Style {
         if value.invisible() {
             style.add_modifier |= Modifier::HIDDEN;
         }
+        if value.overline() {
+            style.add_modifier |= Modifier::OVERLINED;
+        }
 
         style.fg = Some(value.foreground().into_ratatui());
         style.bg = Some(value.background().into_ratatui());
@@ -419,7 +623,28 @@ impl FromTermwiz<Underline> for Modifier {
     fn from_termwiz(value: Underline) -> Self {
         match value {
             Underline::None => Self::empty(),
-            _ => Self::UNDERLINED,
+            Underline::Single => Self::UNDERLINED,
+            Underline::Double => Self::DOUBLE_UNDERLINED,
+            Underline::Curly => Self::CURLY_UNDERLINED,
+            Underline::Dotted => Self::DOTTED_UNDERLINED,
+            Underline::Dashed => Self::DASHED_UNDERLINED,
+        }
+    }
+}
+
+/// Converts the underline style that the modifiers display (see [`Modifier::underline_style`]).
+///
+/// Termwiz has no attribute change for overlined text, so [`Modifier::OVERLINED`] is not drawn by
+/// the [`TermwizBackend`].
+impl IntoTermwiz<Underline> for Modifier {
+    fn into_termwiz(self) -> Underline {
+        match self.underline_style() {
+            Self::UNDERLINED => Underline::Single,
+            Self::DOUBLE_UNDERLINED => Underline::Double,
+            Self::CURLY_UNDERLINED => Underline::Curly,
+            Self::DOTTED_UNDERLINED => Underline::Dotted,
+            Self::DASHED_UNDERLINED => Underline::Dashed,
+            _ => Underline::None,
         }
     }
 }
@@ -462,6 +687,20 @@ impl IntoTermwiz<ColorAttribute> for Color {
     }
 }
 
+impl IntoTermwiz<CursorShape> for CursorStyle {
+    fn into_termwiz(self) -> CursorShape {
+        match self {
+            Self::Default => CursorShape::Default,
+            Self::BlinkingBlock => CursorShape::BlinkingBlock,
+            Self::SteadyBlock => CursorShape::SteadyBlock,
+            Self::BlinkingUnderline => CursorShape::BlinkingUnderline,
+            Self::SteadyUnderline => CursorShape::SteadyUnderline,
+            Self::BlinkingBar => CursorShape::BlinkingBar,
+            Self::SteadyBar => CursorShape::SteadyBar,
+        }
+    }
+}
+
 impl FromTermwiz<AnsiColor> for Color {
     fn from_termwiz(value: AnsiColor) -> Self {
         match value {
@@ -531,6 +770,195 @@ fn u16_max(i: usize) -> u16 {
     u16::try_from(i).unwrap_or(u16::MAX)
 }
 
+/// Converts a termwiz event, or returns `None` for events that have no equivalent, such as
+/// pixel mouse reports, wake-ups and some keys.
+impl FromTermwiz<InputEvent> for Option<Event> {
+    fn from_termwiz(termwiz: InputEvent) -> Self {
+        match termwiz {
+            InputEvent::Key(key) => Option::<KeyEvent>::from_termwiz(key).map(Event::Key),
+            InputEvent::Mouse(mouse) => Some(Event::Mouse(MouseEvent::from_termwiz(mouse))),
+            InputEvent::Resized { cols, rows } => Some(Event::Resize(Size::new(
+                u16::try_from(cols).unwrap_or(u16::MAX),
+                u16::try_from(rows).unwrap_or(u16::MAX),
+            ))),
+            InputEvent::Paste(text) => Some(Event::Paste(text)),
+            InputEvent::PixelMouse(_) | InputEvent::Wake => None,
+        }
+    }
+}
+
+/// Converts a termwiz key event, or returns `None` for keys that have no equivalent.
+///
+/// Termwiz reports Shift + Tab as Tab with the Shift modifier, which is converted to
+/// [`KeyCode::BackTab`] like the other backends do. Keypad keys are converted to the keys they
+/// type.
+impl FromTermwiz<TermwizKeyEvent> for Option<KeyEvent> {
+    fn from_termwiz(termwiz: TermwizKeyEvent) -> Self {
+        let modifiers = KeyModifiers::from_termwiz(termwiz.modifiers);
+        let code = match termwiz.key {
+            TermwizKeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            key => Option::<KeyCode>::from_termwiz(key)?,
+        };
+        Some(KeyEvent::new(code, modifiers))
+    }
+}
+
+/// Converts a termwiz key code, or returns `None` for keys that have no equivalent.
+impl FromTermwiz<TermwizKeyCode> for Option<KeyCode> {
+    fn from_termwiz(termwiz: TermwizKeyCode) -> Self {
+        let code = match termwiz {
+            TermwizKeyCode::Char(c) => KeyCode::Char(c),
+            TermwizKeyCode::Backspace => KeyCode::Backspace,
+            TermwizKeyCode::Tab => KeyCode::Tab,
+            TermwizKeyCode::Enter => KeyCode::Enter,
+            TermwizKeyCode::Escape => KeyCode::Esc,
+            TermwizKeyCode::Menu | TermwizKeyCode::LeftMenu | TermwizKeyCode::RightMenu => {
+                KeyCode::Menu
+            }
+            TermwizKeyCode::Pause => KeyCode::Pause,
+            TermwizKeyCode::CapsLock => KeyCode::CapsLock,
+            TermwizKeyCode::NumLock => KeyCode::NumLock,
+            TermwizKeyCode::ScrollLock => KeyCode::ScrollLock,
+            TermwizKeyCode::PrintScreen => KeyCode::PrintScreen,
+            TermwizKeyCode::PageUp | TermwizKeyCode::KeyPadPageUp => KeyCode::PageUp,
+            TermwizKeyCode::PageDown | TermwizKeyCode::KeyPadPageDown => KeyCode::PageDown,
+            TermwizKeyCode::End | TermwizKeyCode::KeyPadEnd => KeyCode::End,
+            TermwizKeyCode::Home | TermwizKeyCode::KeyPadHome => KeyCode::Home,
+            TermwizKeyCode::LeftArrow | TermwizKeyCode::ApplicationLeftArrow => KeyCode::Left,
+            TermwizKeyCode::RightArrow | TermwizKeyCode::ApplicationRightArrow => KeyCode::Right,
+            TermwizKeyCode::UpArrow | TermwizKeyCode::ApplicationUpArrow => KeyCode::Up,
+            TermwizKeyCode::DownArrow | TermwizKeyCode::ApplicationDownArrow => KeyCode::Down,
+            TermwizKeyCode::KeyPadBegin => KeyCode::KeypadBegin,
+            TermwizKeyCode::Insert => KeyCode::Insert,
+            TermwizKeyCode::Delete => KeyCode::Delete,
+            TermwizKeyCode::Function(n) => KeyCode::F(n),
+            TermwizKeyCode::Numpad0 => KeyCode::Char('0'),
+            TermwizKeyCode::Numpad1 => KeyCode::Char('1'),
+            TermwizKeyCode::Numpad2 => KeyCode::Char('2'),
+            TermwizKeyCode::Numpad3 => KeyCode::Char('3'),
+            TermwizKeyCode::Numpad4 => KeyCode::Char('4'),
+            TermwizKeyCode::Numpad5 => KeyCode::Char('5'),
+            TermwizKeyCode::Numpad6 => KeyCode::Char('6'),
+            TermwizKeyCode::Numpad7 => KeyCode::Char('7'),
+            TermwizKeyCode::Numpad8 => KeyCode::Char('8'),
+            TermwizKeyCode::Numpad9 => KeyCode::Char('9'),
+            TermwizKeyCode::Multiply => KeyCode::Char('*'),
+            TermwizKeyCode::Add => KeyCode::Char('+'),
+            TermwizKeyCode::Separator => KeyCode::Char(','),
+            TermwizKeyCode::Subtract => KeyCode::Char('-'),
+            TermwizKeyCode::Decimal => KeyCode::Char('.'),
+            TermwizKeyCode::Divide => KeyCode::Char('/'),
+            TermwizKeyCode::MediaNextTrack => KeyCode::Media(MediaKeyCode::TrackNext),
+            TermwizKeyCode::MediaPrevTrack => KeyCode::Media(MediaKeyCode::TrackPrevious),
+            TermwizKeyCode::MediaStop => KeyCode::Media(MediaKeyCode::Stop),
+            TermwizKeyCode::MediaPlayPause => KeyCode::Media(MediaKeyCode::PlayPause),
+            TermwizKeyCode::VolumeMute => KeyCode::Media(MediaKeyCode::MuteVolume),
+            TermwizKeyCode::VolumeDown => KeyCode::Media(MediaKeyCode::LowerVolume),
+            TermwizKeyCode::VolumeUp => KeyCode::Media(MediaKeyCode::RaiseVolume),
+            TermwizKeyCode::Shift | TermwizKeyCode::LeftShift => {
+                KeyCode::Modifier(ModifierKeyCode::LeftShift)
+            }
+            TermwizKeyCode::RightShift => KeyCode::Modifier(ModifierKeyCode::RightShift),
+            TermwizKeyCode::Control | TermwizKeyCode::LeftControl => {
+                KeyCode::Modifier(ModifierKeyCode::LeftControl)
+            }
+            TermwizKeyCode::RightControl => KeyCode::Modifier(ModifierKeyCode::RightControl),
+            TermwizKeyCode::Alt | TermwizKeyCode::LeftAlt => {
+                KeyCode::Modifier(ModifierKeyCode::LeftAlt)
+            }
+            TermwizKeyCode::RightAlt => KeyCode::Modifier(ModifierKeyCode::RightAlt),
+            TermwizKeyCode::Super | TermwizKeyCode::LeftWindows => {
+                KeyCode::Modifier(ModifierKeyCode::LeftSuper)
+            }
+            TermwizKeyCode::RightWindows => KeyCode::Modifier(ModifierKeyCode::RightSuper),
+            TermwizKeyCode::Hyper => KeyCode::Modifier(ModifierKeyCode::LeftHyper),
+            TermwizKeyCode::Meta => KeyCode::Modifier(ModifierKeyCode::LeftMeta),
+            _ => return None,
+        };
+        Some(code)
+    }
+}
+
+impl FromTermwiz<Modifiers> for KeyModifiers {
+    fn from_termwiz(termwiz: Modifiers) -> Self {
+        let mut res = Self::empty();
+        if termwiz.intersects(Modifiers::SHIFT | Modifiers::LEFT_SHIFT | Modifiers::RIGHT_SHIFT) {
+            res |= Self::SHIFT;
+        }
+        if termwiz.intersects(Modifiers::CTRL | Modifiers::LEFT_CTRL | Modifiers::RIGHT_CTRL) {
+            res |= Self::CONTROL;
+        }
+        if termwiz.intersects(Modifiers::ALT | Modifiers::LEFT_ALT | Modifiers::RIGHT_ALT) {
+            res |= Self::ALT;
+        }
+        if termwiz.contains(Modifiers::SUPER) {
+            res |= Self::SUPER;
+        }
+        res
+    }
+}
+
+/// Converts a termwiz mouse event.
+///
+/// Termwiz reports the mouse buttons that are held rather than presses and releases, so a held
+/// button is converted to [`MouseEventKind::Down`] and no held button to [`MouseEventKind::Moved`].
+/// The [`EventReader`] implementation of [`TermwizBackend`] keeps track of the held buttons to
+/// report releases and drags as well.
+impl FromTermwiz<TermwizMouseEvent> for MouseEvent {
+    fn from_termwiz(termwiz: TermwizMouseEvent) -> Self {
+        // termwiz reports the 1-based positions of the terminal, except on Windows
+        let offset = u16::from(cfg!(not(windows)));
+        Self {
+            kind: mouse_event_kind(&MouseButtons::NONE, &termwiz.mouse_buttons),
+            position: Position::new(
+                termwiz.x.saturating_sub(offset),
+                termwiz.y.saturating_sub(offset),
+            ),
+            modifiers: KeyModifiers::from_termwiz(termwiz.modifiers),
+        }
+    }
+}
+
+/// Returns the kind of a mouse event from the buttons held before and at the event
+fn mouse_event_kind(previous: &MouseButtons, current: &MouseButtons) -> MouseEventKind {
+    const BUTTONS: [(MouseButtons, MouseButton); 3] = [
+        (MouseButtons::LEFT, MouseButton::Left),
+        (MouseButtons::RIGHT, MouseButton::Right),
+        (MouseButtons::MIDDLE, MouseButton::Middle),
+    ];
+    let positive = current.contains(MouseButtons::WHEEL_POSITIVE);
+    if current.contains(MouseButtons::VERT_WHEEL) {
+        return if positive {
+            MouseEventKind::ScrollUp
+        } else {
+            MouseEventKind::ScrollDown
+        };
+    }
+    if current.contains(MouseButtons::HORZ_WHEEL) {
+        return if positive {
+            MouseEventKind::ScrollLeft
+        } else {
+            MouseEventKind::ScrollRight
+        };
+    }
+    if let Some((flag, button)) = BUTTONS
+        .iter()
+        .find(|(flag, _)| current.contains(flag.clone()))
+    {
+        return if previous.contains(flag.clone()) {
+            MouseEventKind::Drag(*button)
+        } else {
+            MouseEventKind::Down(*button)
+        };
+    }
+    BUTTONS
+        .iter()
+        .find(|(flag, _)| previous.contains(flag.clone()))
+        .map_or(MouseEventKind::Moved, |(_, button)| {
+            MouseEventKind::Up(*button)
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -775,19 +1203,33 @@ mod tests {
             );
             assert_eq!(
                 Modifier::from_termwiz(Underline::Double),
-                Modifier::UNDERLINED
+                Modifier::DOUBLE_UNDERLINED
             );
             assert_eq!(
                 Modifier::from_termwiz(Underline::Curly),
-                Modifier::UNDERLINED
+                Modifier::CURLY_UNDERLINED
             );
             assert_eq!(
                 Modifier::from_termwiz(Underline::Dashed),
-                Modifier::UNDERLINED
+                Modifier::DASHED_UNDERLINED
             );
             assert_eq!(
                 Modifier::from_termwiz(Underline::Dotted),
-                Modifier::UNDERLINED
+                Modifier::DOTTED_UNDERLINED
+            );
+        }
+
+        #[test]
+        fn into_underline() {
+            assert_eq!(Modifier::BOLD.into_termwiz(), Underline::None);
+            assert_eq!(Modifier::UNDERLINED.into_termwiz(), Underline::Single);
+            assert_eq!(
+                (Modifier::UNDERLINED | Modifier::CURLY_UNDERLINED).into_termwiz(),
+                Underline::Curly
+            );
+            assert_eq!(
+                Modifier::DOTTED_UNDERLINED.into_termwiz(),
+                Underline::Dotted
             );
         }
 
@@ -799,6 +1241,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn into_cursor_shape() {
+        assert_eq!(CursorStyle::Default.into_termwiz(), CursorShape::Default);
+        assert_eq!(
+            CursorStyle::BlinkingUnderline.into_termwiz(),
+            CursorShape::BlinkingUnderline
+        );
+        assert_eq!(
+            CursorStyle::SteadyBar.into_termwiz(),
+            CursorShape::SteadyBar
+        );
+    }
+
     #[test]
     fn from_cell_attribute_for_style() {
         #[cfg(feature = "underline-color")]
@@ -885,4 +1340,117 @@ mod tests {
             STYLE.underline_color(Color::Indexed(9))
         );
     }
+
+    mod from_termwiz_event {
+        use super::*;
+
+        #[test]
+        fn key() {
+            let key = TermwizKeyEvent {
+                key: TermwizKeyCode::Char('x'),
+                modifiers: Modifiers::CTRL | Modifiers::LEFT_ALT,
+            };
+            assert_eq!(
+                Option::<Event>::from_termwiz(InputEvent::Key(key)),
+                Some(Event::Key(KeyEvent::new(
+                    KeyCode::Char('x'),
+                    KeyModifiers::CONTROL | KeyModifiers::ALT
+                )))
+            );
+        }
+
+        #[test]
+        fn back_tab() {
+            let key = TermwizKeyEvent {
+                key: TermwizKeyCode::Tab,
+                modifiers: Modifiers::SHIFT,
+            };
+            assert_eq!(
+                Option::<KeyEvent>::from_termwiz(key),
+                Some(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT))
+            );
+        }
+
+        #[test]
+        fn key_codes() {
+            assert_eq!(
+                Option::<KeyCode>::from_termwiz(TermwizKeyCode::ApplicationUpArrow),
+                Some(KeyCode::Up)
+            );
+            assert_eq!(
+                Option::<KeyCode>::from_termwiz(TermwizKeyCode::Numpad7),
+                Some(KeyCode::Char('7'))
+            );
+            assert_eq!(
+                Option::<KeyCode>::from_termwiz(TermwizKeyCode::VolumeUp),
+                Some(KeyCode::Media(MediaKeyCode::RaiseVolume))
+            );
+            assert_eq!(
+                Option::<KeyCode>::from_termwiz(TermwizKeyCode::BrowserBack),
+                None
+            );
+        }
+
+        #[test]
+        fn resize_and_paste() {
+            assert_eq!(
+                Option::<Event>::from_termwiz(InputEvent::Resized { cols: 80, rows: 24 }),
+                Some(Event::Resize(Size::new(80, 24)))
+            );
+            assert_eq!(
+                Option::<Event>::from_termwiz(InputEvent::Paste("text".into())),
+                Some(Event::Paste("text".into()))
+            );
+            assert_eq!(Option::<Event>::from_termwiz(InputEvent::Wake), None);
+        }
+
+        #[cfg(not(windows))]
+        #[test]
+        fn mouse() {
+            let mouse = TermwizMouseEvent {
+                x: 3,
+                y: 5,
+                mouse_buttons: MouseButtons::RIGHT,
+                modifiers: Modifiers::SHIFT,
+            };
+            assert_eq!(
+                MouseEvent::from_termwiz(mouse),
+                MouseEvent::new(
+                    MouseEventKind::Down(MouseButton::Right),
+                    Position::new(2, 4)
+                )
+                .modifiers(KeyModifiers::SHIFT)
+            );
+        }
+
+        #[test]
+        fn mouse_kind() {
+            use MouseButtons as B;
+            use MouseEventKind as K;
+
+            assert_eq!(
+                mouse_event_kind(&B::NONE, &B::LEFT),
+                K::Down(MouseButton::Left)
+            );
+            assert_eq!(
+                mouse_event_kind(&B::LEFT, &B::LEFT),
+                K::Drag(MouseButton::Left)
+            );
+            assert_eq!(
+                mouse_event_kind(&B::MIDDLE, &B::NONE),
+                K::Up(MouseButton::Middle)
+            );
+            assert_eq!(mouse_event_kind(&B::NONE, &B::NONE), K::Moved);
+            assert_eq!(
+                mouse_event_kind(&B::LEFT, &(B::VERT_WHEEL | B::WHEEL_POSITIVE)),
+                K::ScrollUp
+            );
+            assert_eq!(mouse_event_kind(&B::NONE, &B::VERT_WHEEL), K::ScrollDown);
+            assert_eq!(
+                mouse_event_kind(&B::NONE, &(B::HORZ_WHEEL | B::WHEEL_POSITIVE)),
+                K::ScrollLeft
+            );
+            assert_eq!(mouse_event_kind(&B::NONE, &B::HORZ_WHEEL), K::ScrollRight);
+        }
+    }
 }
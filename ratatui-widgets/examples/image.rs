@@ -0,0 +1,84 @@
+//! # [Ratatui] `Image` example
+//!
+//! The latest version of this example is available in the [widget examples] folder in the
+//! repository.
+//!
+//! Please note that the examples are designed to be run against the `main` branch of the Github
+//! repository. This means that you may not be able to compile with the latest release version on
+//! crates.io, or the one that you have installed locally.
+//!
+//! See the [examples readme] for more information on finding examples that match the version of the
+//! library you are using.
+//!
+//! [Ratatui]: https://github.com/ratatui/ratatui
+//! [widget examples]: https://github.com/ratatui/ratatui/blob/main/ratatui-widgets/examples
+//! [examples readme]: https://github.com/ratatui/ratatui/blob/main/examples/README.md
+
+use color_eyre::Result;
+use crossterm::event;
+use ratatui::backend::GraphicsProtocol;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Image, ImageState};
+use ratatui::{DefaultTerminal, Frame};
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    ratatui::run(run)
+}
+
+fn run(terminal: &mut DefaultTerminal) -> Result<()> {
+    let pixels = gradient(64, 64);
+    let image = Image::new(&pixels, 64, 64);
+    // crossterm can't query the terminal for graphics support, so guess from the environment
+    let mut graphics = ImageState::new(GraphicsProtocol::from_env());
+    let mut half_blocks = ImageState::new(None);
+    loop {
+        terminal.draw(|frame| render(frame, &image, &mut graphics, &mut half_blocks))?;
+        graphics.draw(terminal.backend_mut())?;
+        if event::read()?.is_key_press() {
+            break Ok(());
+        }
+    }
+}
+
+/// Render the same image using a graphics protocol and using half blocks.
+fn render(
+    frame: &mut Frame,
+    image: &Image,
+    graphics: &mut ImageState,
+    half_blocks: &mut ImageState,
+) {
+    let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).spacing(1);
+    let [top, main] = frame.area().layout(&layout);
+    let [left, right] = main.layout(&Layout::horizontal([Constraint::Fill(1); 2]).spacing(1));
+
+    let title = Line::from_iter([
+        Span::from("Image Widget").bold(),
+        Span::from(" (Press 'q' to quit)"),
+    ]);
+    frame.render_widget(title.centered(), top);
+
+    let protocol = graphics
+        .protocol()
+        .map_or_else(|| "None".to_string(), |protocol| protocol.to_string());
+    let block = Block::bordered().title(format!("Protocol: {protocol}"));
+    frame.render_stateful_widget(image.clone().block(block), left, graphics);
+
+    let block = Block::bordered().title("Half blocks");
+    frame.render_stateful_widget(image.clone().block(block), right, half_blocks);
+}
+
+/// Create a `width` by `height` RGBA image with a color gradient.
+fn gradient(width: u32, height: u32) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let red = (x * 255 / width) as u8;
+            let green = (y * 255 / height) as u8;
+            pixels.extend_from_slice(&[red, green, 255 - red, 255]);
+        }
+    }
+    pixels
+}
@@ -26,6 +26,7 @@ fn main() -> Result<()> {
     color_eyre::install()?;
     let terminal = ratatui::init_with_options(TerminalOptions {
         viewport: Viewport::Inline(3),
+        ..Default::default()
     });
     let size = match args().nth(1).as_deref() {
         Some("small") => RatatuiLogoSize::Small,
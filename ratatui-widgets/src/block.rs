@@ -10,7 +10,7 @@ use alloc::vec::Vec;
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Alignment, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::symbols::border;
 use ratatui_core::symbols::merge::MergeStrategy;
 use ratatui_core::text::Line;
@@ -19,6 +19,7 @@ use strum::{Display, EnumString};
 
 pub use self::padding::Padding;
 use crate::borders::{BorderType, Borders};
+use crate::themed::with_theme;
 
 mod padding;
 
@@ -233,6 +234,8 @@ pub struct Block<'a> {
     padding: Padding,
     /// Border merging strategy
     merge_borders: MergeStrategy,
+    /// The theme set with [`Block::theme`], used instead of the theme of the buffer
+    theme: Option<Theme>,
 }
 
 /// Defines the position of the title.
@@ -274,6 +277,7 @@ impl<'a> Block<'a> {
             style: Style::new(),
             padding: Padding::ZERO,
             merge_borders: MergeStrategy::Replace,
+            theme: None,
         }
     }
 
@@ -558,6 +562,38 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Sets the theme used for the default styles of the block, instead of the theme of the
+    /// buffer it is rendered to.
+    ///
+    /// By default, a block uses the theme of the buffer, which is the theme of the [`Terminal`]
+    /// when rendered to a frame. The block is filled with [`Theme::base`], its borders use
+    /// [`Theme::border`] and its titles [`Theme::title`]. The styles set with [`Block::style`],
+    /// [`Block::border_style`] and [`Block::title_style`] are applied on top of these when the
+    /// block is rendered, so the order of the calls does not matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::palette::tailwind;
+    /// use ratatui::style::{Style, Stylize, Theme};
+    /// use ratatui::widgets::Block;
+    ///
+    /// let block = Block::bordered()
+    ///     .title("Themed")
+    ///     .border_style(Style::new().italic())
+    ///     .theme(&Theme::tailwind(&tailwind::BLUE));
+    /// ```
+    ///
+    /// [`Terminal`]: ratatui_core::terminal::Terminal
+    /// [`Theme::base`]: Theme#structfield.base
+    /// [`Theme::border`]: Theme#structfield.border
+    /// [`Theme::title`]: Theme#structfield.title
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = Some(*theme);
+        self
+    }
+
     /// Defines which borders to display.
     ///
     /// [`Borders`] can also be styled with [`Block::border_style`] and [`Block::border_type`].
@@ -780,9 +816,11 @@ impl Widget for &Block<'_> {
         if area.is_empty() {
             return;
         }
-        buf.set_style(area, self.style);
-        self.render_borders(area, buf);
-        self.render_titles(area, buf);
+        with_theme(buf, self.theme.as_ref(), |buf| {
+            buf.set_style(area, buf.theme.base.patch(self.style));
+            self.render_borders(area, buf);
+            self.render_titles(area, buf);
+        });
     }
 }
 
@@ -808,6 +846,7 @@ impl Block<'_> {
         let top_inset = top + u16::from(is_replace && self.borders.contains(Borders::TOP));
         let right_inset = right - u16::from(is_replace && self.borders.contains(Borders::RIGHT));
         let bottom_inset = bottom - u16::from(is_replace && self.borders.contains(Borders::BOTTOM));
+        let border_style = buf.theme.border.patch(self.border_style);
 
         let sides = [
             (
@@ -841,7 +880,7 @@ impl Block<'_> {
                     for y in y_range.clone() {
                         buf[(x, y)]
                             .merge_symbol(symbol, self.merge_borders)
-                            .set_style(self.border_style);
+                            .set_style(border_style);
                     }
                 }
             }
@@ -876,11 +915,12 @@ impl Block<'_> {
             ),
         ];
 
+        let border_style = buf.theme.border.patch(self.border_style);
         for (border, x, y, symbol) in corners {
             if self.borders.contains(border) {
                 buf[(x, y)]
                     .merge_symbol(symbol, self.merge_borders)
-                    .set_style(self.border_style);
+                    .set_style(border_style);
             }
         }
     }
@@ -921,7 +961,7 @@ impl Block<'_> {
                 width: title_width.min(titles_area.width),
                 ..titles_area
             };
-            buf.set_style(title_area, self.titles_style);
+            buf.set_style(title_area, buf.theme.title.patch(self.titles_style));
             title.render(title_area, buf);
 
             // bump the width of the titles area to the left
@@ -965,7 +1005,7 @@ impl Block<'_> {
         for title in titles {
             let width = title.width() as u16;
             let title_area = Rect { width, ..area };
-            buf.set_style(title_area, self.titles_style);
+            buf.set_style(title_area, buf.theme.title.patch(self.titles_style));
             title.render(title_area, buf);
             // Move the rendering cursor to the right, leaving 1 column space.
             area.x = area.x.saturating_add(width + 1);
@@ -989,7 +1029,7 @@ impl Block<'_> {
             }
             let width = area.width.min(title.width() as u16).saturating_sub(offset);
             let title_area = Rect { width, ..area };
-            buf.set_style(title_area, self.titles_style);
+            buf.set_style(title_area, buf.theme.title.patch(self.titles_style));
             if offset > 0 {
                 // truncate the left side of the title to fit the area
                 title.clone().right_aligned().render(title_area, buf);
@@ -1018,7 +1058,7 @@ impl Block<'_> {
                 width: title_width.min(titles_area.width),
                 ..titles_area
             };
-            buf.set_style(title_area, self.titles_style);
+            buf.set_style(title_area, buf.theme.title.patch(self.titles_style));
             title.render(title_area, buf);
 
             // bump the titles area to the right and reduce its width
@@ -1340,6 +1380,7 @@ mod tests {
                 style: Style::new(),
                 padding: Padding::ZERO,
                 merge_borders: MergeStrategy::Replace,
+                theme: None,
             }
         );
     }
@@ -2155,4 +2196,87 @@ mod tests {
             .padding(Padding::uniform(10))
             .render(buffer.area, &mut buffer);
     }
+
+    #[test]
+    fn theme() {
+        let theme = Theme {
+            base: Style::new().on_black(),
+            border: Style::new().blue(),
+            title: Style::new().bold(),
+            ..Theme::new()
+        };
+        let block = Block::bordered().title("test").theme(&theme);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        block.render(buf.area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["┌test┐", "│    │", "└────┘"]);
+        expected.set_style(expected.area, theme.base);
+        for border in [
+            Rect::new(0, 0, 6, 1),
+            Rect::new(0, 1, 1, 1),
+            Rect::new(5, 1, 1, 1),
+            Rect::new(0, 2, 6, 1),
+        ] {
+            expected.set_style(border, theme.border);
+        }
+        expected.set_style(Rect::new(1, 0, 4, 1), theme.title);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn theme_of_buffer() {
+        let theme = Theme {
+            base: Style::new().on_black(),
+            border: Style::new().blue(),
+            ..Theme::new()
+        };
+        let area = Rect::new(0, 0, 6, 3);
+        let mut expected = Buffer::empty(area);
+        Block::bordered().theme(&theme).render(area, &mut expected);
+
+        let mut buf = Buffer::empty(area);
+        buf.theme = theme;
+        Block::bordered().render(area, &mut buf);
+        assert_eq!(buf.content, expected.content);
+
+        // the theme of the block replaces the theme of the buffer while rendering only
+        let mut buf = Buffer::empty(area);
+        buf.theme = Theme {
+            border: Style::new().red(),
+            ..Theme::new()
+        };
+        Block::bordered().theme(&theme).render(area, &mut buf);
+        assert_eq!(buf.content, expected.content);
+        assert_eq!(buf.theme.border, Style::new().red());
+    }
+
+    #[test]
+    fn theme_is_applied_under_styles_in_any_order() {
+        let theme = Theme {
+            border: Style::new().blue().on_black(),
+            title: Style::new().bold(),
+            ..Theme::new()
+        };
+        let area = Rect::new(0, 0, 6, 3);
+        let mut before = Buffer::empty(area);
+        Block::bordered()
+            .title("test")
+            .theme(&theme)
+            .border_style(Style::new().red())
+            .title_style(Style::new().italic())
+            .render(area, &mut before);
+        let mut after = Buffer::empty(area);
+        Block::bordered()
+            .title("test")
+            .border_style(Style::new().red())
+            .title_style(Style::new().italic())
+            .theme(&theme)
+            .render(area, &mut after);
+        assert_eq!(before, after);
+        let corner = &after[(0, 0)];
+        assert_eq!((corner.fg, corner.bg), (Color::Red, Color::Black));
+        let title = &after[(1, 0)];
+        assert_eq!((title.fg, title.bg), (Color::Red, Color::Black));
+        assert_eq!(title.modifier, Modifier::BOLD | Modifier::ITALIC);
+    }
 }
@@ -0,0 +1,280 @@
+//! The [`FrameStatsOverlay`] widget displays the render statistics of a frame in a corner of the
+//! screen.
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{HorizontalAlignment, Rect, VerticalAlignment};
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::terminal::FrameStats;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+use crate::clear::Clear;
+
+/// A widget that displays the [`FrameStats`] of a frame as a small overlay, e.g. a heads-up
+/// display of the frame rate and latency in the corner of the screen.
+///
+/// The overlay displays three lines:
+///
+/// - the frames per second and the total time spent drawing the frame
+/// - the time spent in the render callback, in the diff and in the flush
+/// - the number of changed cells, and the bytes written and cursor moves if the backend counts
+///   its output
+///
+/// The statistics of a frame are only known once it has been drawn, so the overlay usually
+/// displays the statistics of the previous frame, as returned in the `CompletedFrame` of the
+/// previous draw call. The overlay is placed in the top right corner of the area by default, and
+/// clears the cells that it covers.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::FrameStatsOverlay;
+/// use ratatui::{DefaultTerminal, FrameStats};
+///
+/// # fn run(terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+/// let mut stats = FrameStats::default();
+/// loop {
+///     let overlay = FrameStatsOverlay::new(stats).style(Style::new().black().on_yellow());
+///     stats = terminal
+///         .draw(|frame| {
+///             // render the application, then the overlay on top of it
+///             frame.render_widget(overlay, frame.area());
+///         })?
+///         .stats;
+/// #   break Ok(());
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FrameStatsOverlay {
+    /// The statistics to display
+    stats: FrameStats,
+    /// Horizontal position of the overlay in the area
+    horizontal_alignment: HorizontalAlignment,
+    /// Vertical position of the overlay in the area
+    vertical_alignment: VerticalAlignment,
+    /// Style of the overlay
+    style: Style,
+}
+
+impl Default for FrameStatsOverlay {
+    fn default() -> Self {
+        Self::new(FrameStats::default())
+    }
+}
+
+impl FrameStatsOverlay {
+    /// Creates a new overlay that displays the given statistics in the top right corner.
+    pub const fn new(stats: FrameStats) -> Self {
+        Self {
+            stats,
+            horizontal_alignment: HorizontalAlignment::Right,
+            vertical_alignment: VerticalAlignment::Top,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the statistics to display.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn stats(mut self, stats: FrameStats) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// Sets the horizontal position of the overlay in the area.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn horizontal_alignment(mut self, alignment: HorizontalAlignment) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical position of the overlay in the area.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the style of the overlay.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the lines of text displayed by the overlay
+    fn lines(&self) -> Vec<Line<'static>> {
+        let stats = &self.stats;
+        let fps = stats
+            .frames_per_second()
+            .map_or_else(|| String::from("--"), |fps| format!("{fps:.1}"));
+        let output = stats.output.map_or_else(String::new, |output| {
+            format!(
+                " | {} bytes | {} moves",
+                output.bytes_written, output.cursor_moves
+            )
+        });
+        [
+            format!(" {fps} fps | {} ms ", millis(stats.total_time())),
+            format!(
+                " render {} | diff {} | flush {} ms ",
+                millis(stats.render_time),
+                millis(stats.diff_time),
+                millis(stats.flush_time),
+            ),
+            format!(" {} cells{output} ", stats.changed_cells),
+        ]
+        .into_iter()
+        .map(Line::from)
+        .collect()
+    }
+}
+
+/// Formats the duration in milliseconds
+fn millis(duration: Duration) -> String {
+    format!("{:.2}", duration.as_secs_f64() * 1000.0)
+}
+
+impl Styled for FrameStatsOverlay {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for FrameStatsOverlay {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &FrameStatsOverlay {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        let lines = self.lines();
+        let width = lines.iter().map(Line::width).max().unwrap_or_default();
+        let width = u16::try_from(width).unwrap_or(u16::MAX).min(area.width);
+        let height = u16::try_from(lines.len())
+            .unwrap_or(u16::MAX)
+            .min(area.height);
+        let x = match self.horizontal_alignment {
+            HorizontalAlignment::Left => area.left(),
+            HorizontalAlignment::Center => area.left() + (area.width - width) / 2,
+            HorizontalAlignment::Right => area.right() - width,
+        };
+        let y = match self.vertical_alignment {
+            VerticalAlignment::Top => area.top(),
+            VerticalAlignment::Center => area.top() + (area.height - height) / 2,
+            VerticalAlignment::Bottom => area.bottom() - height,
+        };
+        let overlay = Rect::new(x, y, width, height);
+        Clear.render(overlay, buf);
+        buf.set_style(overlay, self.style);
+        for (line, row) in lines.iter().zip(overlay.rows()) {
+            line.render(row, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::backend::OutputStats;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn stats() -> FrameStats {
+        FrameStats {
+            render_time: Duration::from_micros(1500),
+            diff_time: Duration::from_micros(250),
+            flush_time: Duration::from_micros(750),
+            frame_interval: Duration::from_millis(20),
+            changed_cells: 42,
+            output: Some(OutputStats {
+                bytes_written: 512,
+                cursor_moves: 3,
+            }),
+        }
+    }
+
+    #[test]
+    fn render() {
+        let row = "x".repeat(50);
+        let mut buffer = Buffer::with_lines([row.as_str(); 4]);
+        FrameStatsOverlay::new(stats()).render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "xxxxxxxxx 50.0 fps | 2.50 ms                      ",
+            "xxxxxxxxx render 1.50 | diff 0.25 | flush 0.75 ms ",
+            "xxxxxxxxx 42 cells | 512 bytes | 3 moves          ",
+            "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_without_output() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 40, 3));
+        let stats = FrameStats {
+            changed_cells: 7,
+            ..FrameStats::default()
+        };
+        FrameStatsOverlay::new(stats)
+            .horizontal_alignment(HorizontalAlignment::Left)
+            .render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            " -- fps | 0.00 ms                       ",
+            " render 0.00 | diff 0.00 | flush 0.00 ms",
+            " 7 cells                                ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::bottom_left(
+        HorizontalAlignment::Left,
+        VerticalAlignment::Bottom,
+        Rect::new(0, 3, 41, 3)
+    )]
+    #[case::center(
+        HorizontalAlignment::Center,
+        VerticalAlignment::Center,
+        Rect::new(4, 1, 41, 3)
+    )]
+    #[case::top_right(
+        HorizontalAlignment::Right,
+        VerticalAlignment::Top,
+        Rect::new(9, 0, 41, 3)
+    )]
+    fn alignment(
+        #[case] horizontal: HorizontalAlignment,
+        #[case] vertical: VerticalAlignment,
+        #[case] expected: Rect,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 50, 6));
+        FrameStatsOverlay::new(stats())
+            .horizontal_alignment(horizontal)
+            .vertical_alignment(vertical)
+            .style(Style::new().on_blue())
+            .render(buffer.area, &mut buffer);
+        let mut styled = Buffer::empty(buffer.area);
+        styled.set_style(expected, Style::new().on_blue());
+        for (cell, styled) in buffer.content.iter().zip(&styled.content) {
+            assert_eq!(cell.bg, styled.bg);
+        }
+    }
+}
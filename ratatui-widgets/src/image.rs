@@ -0,0 +1,412 @@
+//! The [`Image`] widget displays RGBA pixel data using a terminal graphics protocol, or half block
+//! characters when the terminal does not support one.
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use ratatui_core::backend::GraphicsProtocol;
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Rect, Size};
+use ratatui_core::style::Color;
+use ratatui_core::symbols::half_block;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+
+use self::state::EncodedImage;
+pub use self::state::ImageState;
+use crate::block::{Block, BlockExt};
+
+pub mod encode;
+mod state;
+
+/// A widget to display an image made of RGBA pixels.
+///
+/// The image is stretched to fill the area it is rendered in. How it is displayed depends on the
+/// [`ImageState`] it is rendered with:
+///
+/// - When the state has a [`GraphicsProtocol`], the image is encoded with the [Kitty graphics
+///   protocol], as [Sixel] data or as an [iTerm2 inline image]. The cells covered by the image are
+///   reset and marked as skipped (see [`Cell::set_skip`]) so that [`Buffer::diff`] never
+///   overwrites the image, and the encoded image is written by calling [`ImageState::draw`] after
+///   the frame is drawn.
+/// - Otherwise, the image is rendered using half block characters, with two pixels per cell, in
+///   the same way as [`Marker::HalfBlock`] in the [`Canvas`] widget. Rendering an `Image` as a
+///   regular [`Widget`] always uses half blocks.
+///
+/// Pixels with an alpha value below 128 are treated as transparent. Missing pixels, when the data
+/// is shorter than `width * height * 4` bytes, are also transparent.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{Block, Image};
+///
+/// let pixels = [[255, 0, 0, 255], [0, 0, 255, 255]].concat();
+/// let image = Image::new(&pixels, 2, 1).block(Block::bordered().title("Image"));
+/// ```
+///
+/// [Kitty graphics protocol]: https://sw.kovidgoyal.net/kitty/graphics-protocol/
+/// [Sixel]: https://vt100.net/docs/vt3xx-gp/chapter14.html
+/// [iTerm2 inline image]: https://iterm2.com/documentation-images.html
+/// [`Cell::set_skip`]: ratatui_core::buffer::Cell::set_skip
+/// [`Marker::HalfBlock`]: ratatui_core::symbols::Marker::HalfBlock
+/// [`Canvas`]: crate::canvas::Canvas
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Image<'a> {
+    /// Row-major RGBA pixel data
+    pixels: &'a [u8],
+    /// Width of the image in pixels
+    width: u32,
+    /// Height of the image in pixels
+    height: u32,
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+}
+
+impl<'a> Image<'a> {
+    /// Creates a new image from row-major RGBA pixel data with 4 bytes per pixel.
+    pub const fn new(pixels: &'a [u8], width: u32, height: u32) -> Self {
+        Self {
+            pixels,
+            width,
+            height,
+            block: None,
+        }
+    }
+
+    /// Surrounds the `Image` with a [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Returns the pixel data padded or truncated to exactly `width * height` pixels.
+    fn rgba(&self) -> Cow<'a, [u8]> {
+        let len = self.width as usize * self.height as usize * 4;
+        if let Some(pixels) = self.pixels.get(..len) {
+            Cow::Borrowed(pixels)
+        } else {
+            let mut pixels = self.pixels.to_vec();
+            pixels.resize(len, 0);
+            Cow::Owned(pixels)
+        }
+    }
+
+    /// Returns the pixel at `(x, y)` of the image stretched to `width` by `height` pixels, or
+    /// `None` if it is transparent.
+    fn sample(&self, x: u32, y: u32, width: u32, height: u32) -> Option<[u8; 3]> {
+        let source_x = u64::from(x) * u64::from(self.width) / u64::from(width);
+        let source_y = u64::from(y) * u64::from(self.height) / u64::from(height);
+        let index = (source_y * u64::from(self.width) + source_x) as usize * 4;
+        let pixel = self.pixels.get(index..index + 4)?;
+        (pixel[3] >= encode::ALPHA_THRESHOLD).then(|| [pixel[0], pixel[1], pixel[2]])
+    }
+
+    /// Returns the image resampled to `width` by `height` pixels.
+    fn resize(&self, width: u32, height: u32) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        for y in 0..height {
+            for x in 0..width {
+                match self.sample(x, y, width, height) {
+                    Some([r, g, b]) => pixels.extend_from_slice(&[r, g, b, 255]),
+                    None => pixels.extend_from_slice(&[0; 4]),
+                }
+            }
+        }
+        pixels
+    }
+
+    fn encode(&self, protocol: GraphicsProtocol, area: Rect, state: &ImageState) -> Vec<u8> {
+        let cells = area.as_size();
+        match protocol {
+            GraphicsProtocol::Kitty => {
+                encode::kitty(self.width, self.height, &self.rgba(), cells, state.id)
+            }
+            GraphicsProtocol::Iterm2 => {
+                encode::iterm2(self.width, self.height, &self.rgba(), cells)
+            }
+            GraphicsProtocol::Sixel => {
+                let Size { width, height } = state.cell_size;
+                let width = u32::from(cells.width) * u32::from(width);
+                let height = u32::from(cells.height) * u32::from(height);
+                encode::sixel(width, height, &self.resize(width, height))
+            }
+        }
+    }
+
+    fn render_half_blocks(&self, area: Rect, buf: &mut Buffer) {
+        let width = u32::from(area.width);
+        let height = u32::from(area.height) * 2;
+        for (y, row) in (0..).zip(area.rows()) {
+            for (x, position) in (0..).zip(row.positions()) {
+                let top = self.sample(x, y * 2, width, height).map(rgb);
+                let bottom = self.sample(x, y * 2 + 1, width, height).map(rgb);
+                let cell = &mut buf[position];
+                match (top, bottom) {
+                    (Some(top), Some(bottom)) => {
+                        cell.set_char(half_block::UPPER).set_fg(top).set_bg(bottom);
+                    }
+                    (Some(top), None) => {
+                        cell.set_char(half_block::UPPER).set_fg(top);
+                    }
+                    (None, Some(bottom)) => {
+                        cell.set_char(half_block::LOWER).set_fg(bottom);
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+    }
+}
+
+const fn rgb([r, g, b]: [u8; 3]) -> Color {
+    Color::Rgb(r, g, b)
+}
+
+impl Widget for Image<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Image<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ImageState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Image<'_> {
+    type State = ImageState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Image<'_> {
+    type State = ImageState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area).intersection(buf.area);
+        if area.is_empty() || self.width == 0 || self.height == 0 {
+            state.pending = None;
+            state.encoded = None;
+            return;
+        }
+        let Some(protocol) = state.protocol else {
+            self.render_half_blocks(area, buf);
+            return;
+        };
+        for position in area.positions() {
+            buf[position].reset();
+            buf[position].set_skip(true);
+        }
+        // an unchanged image is still displayed, or still pending if it was not drawn yet
+        if state.encoded.as_ref().is_some_and(|encoded| {
+            encoded.area == area
+                && (encoded.width, encoded.height) == (self.width, self.height)
+                && encoded.pixels == *self.pixels
+        }) {
+            return;
+        }
+        let payload = self.encode(protocol, area, state);
+        state.pending = Some((area.as_position(), payload));
+        state.encoded = Some(EncodedImage {
+            pixels: self.pixels.to_vec(),
+            width: self.width,
+            height: self.height,
+            area,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::backend::TestBackend;
+    use ratatui_core::buffer::Cell;
+    use ratatui_core::layout::Position;
+    use ratatui_core::style::Style;
+
+    use super::*;
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+    const CLEAR: [u8; 4] = [0, 0, 0, 0];
+
+    #[test]
+    fn render_half_blocks() {
+        // 2x2 image rendered in a 2x1 area: each cell shows the top and bottom pixel
+        let pixels = [RED, BLUE, BLUE, CLEAR].concat();
+        let image = Image::new(&pixels, 2, 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Widget::render(image, Rect::new(0, 0, 2, 1), &mut buf);
+        let mut expected = Buffer::with_lines(["▀▀ "]);
+        expected[(0, 0)].set_style(
+            Style::new()
+                .fg(Color::Rgb(255, 0, 0))
+                .bg(Color::Rgb(0, 0, 255)),
+        );
+        expected[(1, 0)].set_fg(Color::Rgb(0, 0, 255));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_half_blocks_transparent_top() {
+        let pixels = [CLEAR, RED].concat();
+        let image = Image::new(&pixels, 1, 2);
+        let mut buf = Buffer::with_lines(["x"]);
+        Widget::render(image, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["▄"]);
+        expected[(0, 0)].set_fg(Color::Rgb(255, 0, 0));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_half_blocks_stretched() {
+        // a single pixel fills the whole area
+        let image = Image::new(&RED, 1, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        Widget::render(image, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["▀▀", "▀▀"]);
+        expected.set_style(
+            buf.area,
+            Style::new()
+                .fg(Color::Rgb(255, 0, 0))
+                .bg(Color::Rgb(255, 0, 0)),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_with_block() {
+        let image = Image::new(&RED, 1, 1).block(Block::bordered());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(image, buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "┌");
+        assert_eq!(buf[(1, 1)].symbol(), "▀");
+    }
+
+    #[test]
+    fn render_missing_pixels_are_transparent() {
+        let image = Image::new(&RED, 2, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Widget::render(image, buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "▀");
+        assert_eq!(buf[(1, 0)], Cell::EMPTY);
+    }
+
+    #[test]
+    fn render_with_protocol_reserves_cells() {
+        let image = Image::new(&RED, 1, 1);
+        let mut state = ImageState::new(Some(GraphicsProtocol::Kitty)).id(3);
+        let mut buf = Buffer::with_lines(["xxx", "xxx"]);
+        StatefulWidget::render(&image, Rect::new(1, 0, 2, 2), &mut buf, &mut state);
+
+        let mut expected = Buffer::with_lines(["x  ", "x  "]);
+        for position in Rect::new(1, 0, 2, 2).positions() {
+            expected[position].set_skip(true);
+        }
+        assert_eq!(buf, expected);
+        let payload = encode::kitty(1, 1, &RED, Size::new(2, 2), 3);
+        assert_eq!(state.pending, Some((Position::new(1, 0), payload)));
+
+        // skipped cells are never part of the diff, so the image is not overwritten
+        let previous = Buffer::empty(buf.area);
+        let updates = previous.diff(&buf);
+        assert!(updates.iter().all(|(x, _, _)| *x == 0));
+    }
+
+    #[test]
+    fn render_sixel_uses_cell_size() {
+        let image = Image::new(&BLUE, 1, 1);
+        let mut state = ImageState::new(Some(GraphicsProtocol::Sixel)).cell_size(Size::new(2, 3));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        StatefulWidget::render(&image, buf.area, &mut buf, &mut state);
+        let payload = encode::sixel(4, 6, &BLUE.repeat(24));
+        assert_eq!(state.pending, Some((Position::ORIGIN, payload)));
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let image = Image::new(&RED, 1, 1);
+        let mut state = ImageState::new(Some(GraphicsProtocol::Iterm2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        StatefulWidget::render(&image, Rect::new(0, 0, 0, 2), &mut buf, &mut state);
+        assert_eq!(state.pending, None);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 2, 2)));
+    }
+
+    #[test]
+    fn state_draw() {
+        let mut backend =
+            TestBackend::new(4, 4).with_graphics_protocol(Some(GraphicsProtocol::Iterm2));
+        let mut state = ImageState::from_backend(&mut backend);
+        assert_eq!(state.protocol(), Some(GraphicsProtocol::Iterm2));
+        // TestBackend reports a 640x480 window
+        assert_eq!(state.cell_size, Size::new(160, 120));
+
+        let image = Image::new(&RED, 1, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        StatefulWidget::render(&image, Rect::new(1, 1, 2, 2), &mut buf, &mut state);
+        state.draw(&mut backend).unwrap();
+        let payload = encode::iterm2(1, 1, &RED, Size::new(2, 2));
+        assert_eq!(backend.graphics(), [(Position::new(1, 1), payload)]);
+
+        // the pending image is only drawn once per render
+        state.draw(&mut backend).unwrap();
+        assert_eq!(backend.graphics().len(), 1);
+    }
+
+    #[test]
+    fn state_draw_only_changed_images() {
+        let mut backend =
+            TestBackend::new(4, 4).with_graphics_protocol(Some(GraphicsProtocol::Kitty));
+        let mut state = ImageState::from_backend(&mut backend);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        let mut render_and_draw = |image: &Image, area: Rect, state: &mut ImageState| {
+            StatefulWidget::render(image, area, &mut buf, state);
+            state.draw(&mut backend).unwrap();
+        };
+
+        let red = Image::new(&RED, 1, 1);
+        render_and_draw(&red, Rect::new(0, 0, 2, 2), &mut state);
+        render_and_draw(&red, Rect::new(0, 0, 2, 2), &mut state);
+        // the area changed
+        render_and_draw(&red, Rect::new(1, 1, 2, 2), &mut state);
+        // the pixels changed
+        render_and_draw(&Image::new(&BLUE, 1, 1), Rect::new(1, 1, 2, 2), &mut state);
+        render_and_draw(&Image::new(&BLUE, 1, 1), Rect::new(1, 1, 2, 2), &mut state);
+        // the image is drawn again after the screen was cleared
+        state.redraw();
+        render_and_draw(&Image::new(&BLUE, 1, 1), Rect::new(1, 1, 2, 2), &mut state);
+
+        let kitty = |pixels: &[u8], position| {
+            let payload = encode::kitty(1, 1, pixels, Size::new(2, 2), 1);
+            (position, payload)
+        };
+        assert_eq!(
+            backend.graphics(),
+            [
+                kitty(&RED, Position::new(0, 0)),
+                kitty(&RED, Position::new(1, 1)),
+                kitty(&BLUE, Position::new(1, 1)),
+                kitty(&BLUE, Position::new(1, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn unchanged_image_stays_pending_until_drawn() {
+        let image = Image::new(&RED, 1, 1);
+        let mut state = ImageState::new(Some(GraphicsProtocol::Iterm2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        StatefulWidget::render(&image, buf.area, &mut buf, &mut state);
+        StatefulWidget::render(&image, buf.area, &mut buf, &mut state);
+        let payload = encode::iterm2(1, 1, &RED, Size::new(2, 2));
+        assert_eq!(state.pending, Some((Position::ORIGIN, payload)));
+        assert!(buf.content.iter().all(|cell| cell.skip));
+    }
+}
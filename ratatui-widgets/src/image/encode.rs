@@ -0,0 +1,331 @@
+//! Encoders for the terminal graphics protocols supported by the [`Image`](super::Image) widget.
+//!
+//! All encoders take tightly packed, row-major RGBA pixel data with 4 bytes per pixel and return
+//! a complete escape sequence ready to be written with
+//! [`GraphicsBackend::draw_graphics`](ratatui_core::backend::GraphicsBackend::draw_graphics).
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use ratatui_core::layout::Size;
+
+/// The maximum number of base64 bytes in a single Kitty graphics escape sequence.
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+/// Pixels with an alpha value below this threshold are treated as fully transparent.
+pub(super) const ALPHA_THRESHOLD: u8 = 128;
+
+/// Encodes an image using the Kitty graphics protocol.
+///
+/// The image is transmitted as raw RGBA data and displayed scaled to `cells`. Any previous
+/// placement of the image with the same `id` is deleted first so that redrawing the image every
+/// frame replaces it instead of stacking copies.
+pub fn kitty(width: u32, height: u32, pixels: &[u8], cells: Size, id: u32) -> Vec<u8> {
+    let data = base64(pixels);
+    let mut output = format!("\x1b_Ga=d,d=i,i={id},q=2\x1b\\");
+    // an empty image still needs a single (empty) chunk
+    let chunks: Vec<&[u8]> = if data.is_empty() {
+        alloc::vec![&[]]
+    } else {
+        data.chunks(KITTY_CHUNK_SIZE).collect()
+    };
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        if i == 0 {
+            let _ = write!(
+                output,
+                "\x1b_Ga=T,f=32,s={width},v={height},c={},r={},i={id},C=1,q=2,m={more};",
+                cells.width, cells.height
+            );
+        } else {
+            let _ = write!(output, "\x1b_Gm={more};");
+        }
+        // base64 output is always ASCII
+        output.extend(chunk.iter().map(|&b| char::from(b)));
+        output.push_str("\x1b\\");
+    }
+    output.into_bytes()
+}
+
+/// Encodes an image using the iTerm2 inline images protocol.
+///
+/// The image is sent as a PNG file and displayed stretched to `cells`.
+pub fn iterm2(width: u32, height: u32, pixels: &[u8], cells: Size) -> Vec<u8> {
+    let png = png(width, height, pixels);
+    let data = base64(&png);
+    let mut output = format!(
+        "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=0:",
+        png.len(),
+        cells.width,
+        cells.height
+    )
+    .into_bytes();
+    output.extend_from_slice(&data);
+    output.push(0x07);
+    output
+}
+
+/// Encodes an image as Sixel data.
+///
+/// Sixel images are not scaled by the terminal, so `pixels` should already be sized to the area
+/// the image covers. Colors are quantized to a 6×6×6 color cube and transparent pixels are left
+/// untouched.
+pub fn sixel(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    const LEVELS: u32 = 6;
+    let (width, height) = (width as usize, height as usize);
+    let quantize = |value: u8| (u32::from(value) * (LEVELS - 1) + 127) / 255;
+    let indexes: Vec<Option<u16>> = pixels
+        .chunks_exact(4)
+        .map(|p| {
+            (p[3] >= ALPHA_THRESHOLD).then(|| {
+                let index = quantize(p[0]) * LEVELS * LEVELS + quantize(p[1]) * LEVELS;
+                (index + quantize(p[2])) as u16
+            })
+        })
+        .collect();
+
+    let mut output = format!("\x1bP0;1;0q\"1;1;{width};{height}");
+    let palette_size = (LEVELS * LEVELS * LEVELS) as usize;
+    let mut used = alloc::vec![false; palette_size];
+    for index in indexes.iter().flatten() {
+        used[*index as usize] = true;
+    }
+    let percent = |level: u32| level * 100 / (LEVELS - 1);
+    for (index, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+        let index = index as u32;
+        let (r, g, b) = (index / 36, index / 6 % 6, index % 6);
+        let _ = write!(
+            output,
+            "#{index};2;{};{};{}",
+            percent(r),
+            percent(g),
+            percent(b)
+        );
+    }
+
+    for band in 0..height.div_ceil(6) {
+        let rows = band * 6..height.min(band * 6 + 6);
+        let mut band_colors = alloc::vec![false; palette_size];
+        for y in rows.clone() {
+            for index in indexes[y * width..(y + 1) * width].iter().flatten() {
+                band_colors[*index as usize] = true;
+            }
+        }
+        for (color, _) in band_colors.iter().enumerate().filter(|(_, used)| **used) {
+            let _ = write!(output, "#{color}");
+            let mut run: Option<(char, usize)> = None;
+            for x in 0..width {
+                let bits = rows.clone().fold(0u8, |bits, y| {
+                    let matches = indexes[y * width + x] == Some(color as u16);
+                    bits | (u8::from(matches) << (y - band * 6))
+                });
+                let sixel = char::from(0x3f + bits);
+                run = match run {
+                    Some((c, n)) if c == sixel => Some((c, n + 1)),
+                    Some((c, n)) => {
+                        push_run(&mut output, c, n);
+                        Some((sixel, 1))
+                    }
+                    None => Some((sixel, 1)),
+                };
+            }
+            if let Some((c, n)) = run {
+                push_run(&mut output, c, n);
+            }
+            output.push('$');
+        }
+        output.push('-');
+    }
+    output.push_str("\x1b\\");
+    output.into_bytes()
+}
+
+/// Appends a run of `count` sixel characters, using the repeat introducer for longer runs.
+fn push_run(output: &mut alloc::string::String, sixel: char, count: usize) {
+    if count > 3 {
+        let _ = write!(output, "!{count}{sixel}");
+    } else {
+        output.extend(core::iter::repeat_n(sixel, count));
+    }
+}
+
+/// Encodes bytes as standard base64 with padding.
+fn base64(input: &[u8]) -> Vec<u8> {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut output = Vec::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize]);
+            } else {
+                output.push(b'=');
+            }
+        }
+    }
+    output
+}
+
+/// Encodes RGBA pixels as an uncompressed PNG file.
+///
+/// The image data is stored using uncompressed deflate blocks, which is larger than necessary but
+/// avoids pulling in a compression library.
+fn png(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK_SIZE: usize = 0xffff;
+
+    let mut raw = Vec::with_capacity(pixels.len() + height as usize);
+    let stride = width as usize * 4;
+    if stride > 0 {
+        for row in pixels.chunks_exact(stride) {
+            raw.push(0); // filter type: none
+            raw.extend_from_slice(row);
+        }
+    } else {
+        raw.resize(height as usize, 0);
+    }
+
+    let mut zlib = alloc::vec![0x78, 0x01];
+    let mut blocks = raw.chunks(MAX_BLOCK_SIZE).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        zlib.push(u8::from(blocks.peek().is_none()));
+        let len = block.len() as u16;
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    // bit depth 8, color type RGBA, default compression, filter and interlace methods
+    ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut output = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut output, *b"IHDR", &ihdr);
+    png_chunk(&mut output, *b"IDAT", &zlib);
+    png_chunk(&mut output, *b"IEND", &[]);
+    output
+}
+
+fn png_chunk(output: &mut Vec<u8>, kind: [u8; 4], data: &[u8]) {
+    output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = output.len();
+    output.extend_from_slice(&kind);
+    output.extend_from_slice(data);
+    let crc = crc32(&output[start..]);
+    output.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % MOD;
+        b = (b + a) % MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty("", "")]
+    #[case::one("f", "Zg==")]
+    #[case::two("fo", "Zm8=")]
+    #[case::three("foo", "Zm9v")]
+    #[case::six("foobar", "Zm9vYmFy")]
+    fn base64_encodes(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(base64(input.as_bytes()), expected.as_bytes());
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn kitty_single_chunk() {
+        let output = kitty(1, 1, &[255, 0, 0, 255], Size::new(2, 1), 7);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\x1b_Ga=d,d=i,i=7,q=2\x1b\\\
+             \x1b_Ga=T,f=32,s=1,v=1,c=2,r=1,i=7,C=1,q=2,m=0;/wAA/w==\x1b\\"
+        );
+    }
+
+    #[test]
+    fn kitty_chunks() {
+        // 4096 bytes of base64 encode 3072 bytes of pixel data
+        let pixels = [0; 3072 + 4];
+        let output = String::from_utf8(kitty(769, 1, &pixels, Size::new(1, 1), 1)).unwrap();
+        assert_eq!(output.matches("m=1;").count(), 1);
+        assert_eq!(output.matches("\x1b_Gm=0;").count(), 1);
+        assert!(output.ends_with("\x1b_Gm=0;AAAAAA==\x1b\\"));
+    }
+
+    #[test]
+    fn iterm2_png() {
+        let output = iterm2(1, 1, &[0, 0, 255, 255], Size::new(3, 2));
+        let output = String::from_utf8(output).unwrap();
+        let png = png(1, 1, &[0, 0, 255, 255]);
+        let data = String::from_utf8(base64(&png)).unwrap();
+        assert_eq!(
+            output,
+            format!(
+                "\x1b]1337;File=inline=1;size={};width=3;height=2;preserveAspectRatio=0:{data}\x07",
+                png.len()
+            )
+        );
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x01\0\0\0\x01\x08\x06"));
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+    }
+
+    #[test]
+    fn sixel_two_colors() {
+        // a 2x2 image: red on the left, blue on the right, with a transparent bottom right pixel
+        let red = [255, 0, 0, 255];
+        let blue = [0, 0, 255, 255];
+        let pixels = [red, blue, red, [0; 4]].concat();
+        let output = String::from_utf8(sixel(2, 2, &pixels)).unwrap();
+        assert_eq!(
+            output,
+            "\x1bP0;1;0q\"1;1;2;2#5;2;0;0;100#180;2;100;0;0#5?@$#180B?$-\x1b\\"
+        );
+    }
+
+    #[test]
+    fn sixel_run_length() {
+        let pixels = [[0, 255, 0, 255]; 5].concat();
+        let output = String::from_utf8(sixel(5, 1, &pixels)).unwrap();
+        assert_eq!(output, "\x1bP0;1;0q\"1;1;5;1#30;2;0;100;0#30!5@$-\x1b\\");
+    }
+}
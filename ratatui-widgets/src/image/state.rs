@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+
+use ratatui_core::backend::{GraphicsBackend, GraphicsProtocol};
+use ratatui_core::layout::{Position, Rect, Size};
+
+/// State of the [`Image`] widget
+///
+/// This state determines how the image is displayed. When a [`GraphicsProtocol`] is set, rendering
+/// the [`Image`] reserves the cells it covers and stores the encoded image in the state, which must
+/// then be written to the terminal with [`ImageState::draw`] once the frame has been drawn. When
+/// no protocol is set, the image is rendered with half block characters instead.
+///
+/// The terminal keeps displaying an image until the cells under it are drawn over, so the image is
+/// only encoded and drawn again when its pixels or its area change. Call [`ImageState::redraw`]
+/// when the screen was cleared (e.g. with [`Terminal::clear`]) to draw it again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui::backend::{CrosstermBackend, GraphicsProtocol};
+/// use ratatui::widgets::{Image, ImageState};
+/// use ratatui::Terminal;
+///
+/// let protocol = GraphicsProtocol::from_env();
+/// let backend = CrosstermBackend::new(std::io::stdout()).with_graphics_protocol(protocol);
+/// let mut terminal = Terminal::new(backend)?;
+/// let mut state = ImageState::from_backend(terminal.backend_mut());
+///
+/// let pixels = [255, 0, 0, 255].repeat(16 * 16);
+/// let image = Image::new(&pixels, 16, 16);
+/// terminal.draw(|frame| frame.render_stateful_widget(&image, frame.area(), &mut state))?;
+/// state.draw(terminal.backend_mut())?;
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// [`Image`]: super::Image
+/// [`Terminal::clear`]: ratatui_core::terminal::Terminal::clear
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ImageState {
+    pub(crate) protocol: Option<GraphicsProtocol>,
+    pub(crate) cell_size: Size,
+    pub(crate) id: u32,
+    pub(crate) pending: Option<(Position, Vec<u8>)>,
+    /// The image that was encoded last, which is not encoded again while it does not change
+    pub(crate) encoded: Option<EncodedImage>,
+}
+
+/// The pixels and area of an encoded image
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct EncodedImage {
+    pub(crate) pixels: Vec<u8>,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) area: Rect,
+}
+
+impl Default for ImageState {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl ImageState {
+    /// The cell size in pixels used when the terminal does not report its pixel size.
+    pub const DEFAULT_CELL_SIZE: Size = Size::new(10, 20);
+
+    /// Creates a new `ImageState` that displays images using the given protocol.
+    ///
+    /// Passing `None` renders images with half block characters.
+    pub const fn new(protocol: Option<GraphicsProtocol>) -> Self {
+        Self {
+            protocol,
+            cell_size: Self::DEFAULT_CELL_SIZE,
+            id: 1,
+            pending: None,
+            encoded: None,
+        }
+    }
+
+    /// Creates a new `ImageState` using the graphics protocol and cell size reported by the
+    /// backend.
+    ///
+    /// If the backend fails to report its window size, or reports a pixel size of zero, the
+    /// [`DEFAULT_CELL_SIZE`](Self::DEFAULT_CELL_SIZE) is used.
+    pub fn from_backend<B: GraphicsBackend>(backend: &mut B) -> Self {
+        let mut state = Self::new(backend.graphics_protocol());
+        if let Ok(window) = backend.window_size() {
+            let (pixels, cells) = (window.pixels, window.columns_rows);
+            if pixels.width > 0 && pixels.height > 0 && cells.width > 0 && cells.height > 0 {
+                state.cell_size = Size::new(
+                    (pixels.width / cells.width).max(1),
+                    (pixels.height / cells.height).max(1),
+                );
+            }
+        }
+        state
+    }
+
+    /// Sets the size of a single terminal cell in pixels.
+    ///
+    /// This is used to scale Sixel images, which the terminal displays at their native size.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn cell_size(mut self, cell_size: Size) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the image id used by the Kitty graphics protocol.
+    ///
+    /// Each image displayed at the same time needs its own state with a distinct id, otherwise
+    /// drawing one image replaces the others. Defaults to `1`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn id(mut self, id: u32) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Returns the graphics protocol used to display images.
+    pub const fn protocol(&self) -> Option<GraphicsProtocol> {
+        self.protocol
+    }
+
+    /// Makes the next render encode the image again, so that the next [`ImageState::draw`] draws
+    /// it even if it did not change.
+    ///
+    /// Call this when the image was removed from the screen, e.g. when the screen was cleared.
+    pub fn redraw(&mut self) {
+        self.encoded = None;
+    }
+
+    /// Writes the image encoded by the last render to the backend and flushes it.
+    ///
+    /// This must be called after [`Terminal::draw`] so that the image is drawn on top of the
+    /// cells reserved for it. Does nothing if no image is pending, which is the case when the
+    /// image did not change since it was last drawn.
+    ///
+    /// [`Terminal::draw`]: ratatui_core::terminal::Terminal::draw
+    pub fn draw<B: GraphicsBackend>(&mut self, backend: &mut B) -> Result<(), B::Error> {
+        if let Some((position, payload)) = self.pending.take() {
+            backend.draw_graphics(position, &payload)?;
+            backend.flush()?;
+        }
+        Ok(())
+    }
+}
@@ -39,7 +39,9 @@
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as lines or scatter graphs.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`FrameStatsOverlay`]: displays the frame rate and render statistics of the terminal.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Image`]: displays an image using a terminal graphics protocol or half block characters.
 //! - [`LineGauge`]: displays progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
@@ -49,6 +51,9 @@
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TextArea`]: edits multiple lines of text.
+//! - [`TextInput`]: edits a single line of text.
+//! - [`Tree`]: displays hierarchical data with expandable nodes.
 //!
 //! [`BarChart`]: crate::barchart::BarChart
 //! [`Block`]: crate::block::Block
@@ -56,7 +61,9 @@
 //! [`Canvas`]: crate::canvas::Canvas
 //! [`Chart`]: crate::chart::Chart
 //! [`Clear`]: crate::clear::Clear
+//! [`FrameStatsOverlay`]: crate::frame_stats::FrameStatsOverlay
 //! [`Gauge`]: crate::gauge::Gauge
+//! [`Image`]: crate::image::Image
 //! [`LineGauge`]: crate::gauge::LineGauge
 //! [`List`]: crate::list::List
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
@@ -66,6 +73,9 @@
 //! [`Sparkline`]: crate::sparkline::Sparkline
 //! [`Table`]: crate::table::Table
 //! [`Tabs`]: crate::tabs::Tabs
+//! [`TextArea`]: crate::text_input::TextArea
+//! [`TextInput`]: crate::text_input::TextInput
+//! [`Tree`]: crate::tree::Tree
 //!
 //! All these widgets are re-exported directly under `ratatui::widgets` in the `ratatui` crate.
 //!
@@ -116,7 +126,9 @@ pub mod borders;
 pub mod canvas;
 pub mod chart;
 pub mod clear;
+pub mod frame_stats;
 pub mod gauge;
+pub mod image;
 pub mod list;
 pub mod logo;
 pub mod mascot;
@@ -125,10 +137,13 @@ pub mod scrollbar;
 pub mod sparkline;
 pub mod table;
 pub mod tabs;
+pub mod text_input;
+pub mod tree;
 
 #[cfg(not(feature = "std"))]
 mod polyfills;
 mod reflow;
+mod themed;
 
 #[cfg(feature = "calendar")]
 pub mod calendar;
@@ -3,15 +3,17 @@
 
 use alloc::vec::Vec;
 
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::text::Line;
 use strum::{Display, EnumString};
 
+pub use self::areas::ListAreas;
 pub use self::item::ListItem;
 pub use self::state::ListState;
 use crate::block::Block;
 use crate::table::HighlightSpacing;
 
+mod areas;
 mod item;
 mod rendering;
 mod state;
@@ -125,6 +127,8 @@ pub struct List<'a> {
     pub(crate) highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
     pub(crate) scroll_padding: usize,
+    /// The theme set with [`List::theme`], used instead of the theme of the buffer
+    pub(crate) theme: Option<Theme>,
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -331,6 +335,40 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets the theme used for the default styles of the list, instead of the theme of the
+    /// buffer it is rendered to.
+    ///
+    /// By default, a list uses the theme of the buffer, which is the theme of the [`Terminal`]
+    /// when rendered to a frame. The list is filled with [`Theme::base`] and the selected item
+    /// uses [`Theme::selection`]. The styles set with [`List::style`] and
+    /// [`List::highlight_style`] are applied on top of these when the list is rendered, so the
+    /// order of the calls does not matter. The theme is also used by the block of the list, unless
+    /// the block has a theme of its own.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::Theme;
+    /// use ratatui::style::palette::tailwind;
+    /// use ratatui::widgets::{Block, List};
+    ///
+    /// let theme = Theme::tailwind(&tailwind::BLUE);
+    /// let list = List::new(["Item 1", "Item 2"])
+    ///     .theme(&theme)
+    ///     .block(Block::bordered().title("List"));
+    /// ```
+    ///
+    /// [`Terminal`]: ratatui_core::terminal::Terminal
+    /// [`Theme::base`]: Theme#structfield.base
+    /// [`Theme::selection`]: Theme#structfield.selection
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = Some(*theme);
+        self
+    }
+
     /// Set whether to repeat the highlight symbol and style over selected multi-line items
     ///
     /// This is `false` by default.
@@ -654,4 +692,58 @@ mod tests {
         // This should not panic, even if the buffer has zero size.
         list.render(buffer.area, &mut buffer, &mut state);
     }
+
+    #[test]
+    fn theme() {
+        let theme = Theme {
+            base: Style::new().white(),
+            border: Style::new().blue(),
+            selection: Style::new().on_blue(),
+            ..Theme::new()
+        };
+        let area = Rect::new(0, 0, 10, 4);
+        let mut state = ListState::default().with_selected(Some(1));
+        let mut buf = Buffer::empty(area);
+        List::new(["Item 0", "Item 1"])
+            .theme(&theme)
+            .block(Block::bordered())
+            .render(area, &mut buf, &mut state);
+
+        let mut expected =
+            Buffer::with_lines(["┌────────┐", "│Item 0  │", "│Item 1  │", "└────────┘"]);
+        expected.set_style(area, theme.base);
+        expected.set_style(area, theme.border);
+        expected.set_style(Rect::new(1, 1, 8, 2), theme.base);
+        expected.set_style(Rect::new(1, 2, 8, 1), theme.selection);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn theme_is_applied_under_styles_in_any_order() {
+        let theme = Theme {
+            base: Style::new().white().on_black(),
+            selection: Style::new().on_blue(),
+            ..Theme::new()
+        };
+        let area = Rect::new(0, 0, 6, 2);
+        let mut state = ListState::default().with_selected(Some(1));
+        let mut before = Buffer::empty(area);
+        List::new(["Item 0", "Item 1"])
+            .theme(&theme)
+            .style(Style::new().red())
+            .highlight_style(Style::new().bold())
+            .render(area, &mut before, &mut state);
+        let mut after = Buffer::empty(area);
+        List::new(["Item 0", "Item 1"])
+            .style(Style::new().red())
+            .highlight_style(Style::new().bold())
+            .theme(&theme)
+            .render(area, &mut after, &mut state);
+        assert_eq!(before, after);
+
+        let mut expected = Buffer::with_lines(["Item 0", "Item 1"]);
+        expected.set_style(area, Style::new().red().on_black());
+        expected.set_style(Rect::new(0, 1, 6, 1), Style::new().on_blue().bold());
+        assert_eq!(after, expected);
+    }
 }
@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+
+use ratatui_core::layout::{Position, Rect};
+
+/// The areas of the items displayed by a [`List`], used for hit-testing
+///
+/// This is returned by [`List::render_with_areas`], and maps a position (e.g. of a mouse event)
+/// back to the item displayed there.
+///
+/// [`List`]: super::List
+/// [`List::render_with_areas`]: super::List::render_with_areas
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ListAreas {
+    pub(crate) items: Vec<(usize, Rect)>,
+}
+
+impl ListAreas {
+    /// The index and area of each displayed item, in the order they were rendered
+    pub fn items(&self) -> &[(usize, Rect)] {
+        &self.items
+    }
+
+    /// Returns the index of the item displayed at the given position
+    ///
+    /// Returns `None` if no item was displayed at the position.
+    pub fn item_at<P: Into<Position>>(&self, position: P) -> Option<usize> {
+        let position = position.into();
+        self.items
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(index, _)| *index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn item_at() {
+        let areas = ListAreas {
+            items: vec![(2, Rect::new(0, 0, 5, 1)), (3, Rect::new(0, 1, 5, 2))],
+        };
+        assert_eq!(areas.item_at((0, 0)), Some(2));
+        assert_eq!(areas.item_at((4, 2)), Some(3));
+        assert_eq!(areas.item_at((5, 0)), None);
+        assert_eq!(areas.item_at((0, 3)), None);
+    }
+}
@@ -1,10 +1,13 @@
+use alloc::vec::Vec;
+
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
 use ratatui_core::text::{Line, ToLine};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
 use crate::block::BlockExt;
-use crate::list::{List, ListDirection, ListState};
+use crate::list::{List, ListAreas, ListDirection, ListState};
+use crate::themed::with_theme;
 
 impl Widget for List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -31,7 +34,69 @@ impl StatefulWidget for &List<'_> {
     type State = ListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        buf.set_style(area, self.style);
+        self.render_list(area, buf, state, None);
+    }
+}
+
+impl List<'_> {
+    /// Renders the list like [`StatefulWidget::render`], and returns the areas of the items that
+    /// were displayed.
+    ///
+    /// The areas can be used to map a mouse event back to an item with [`ListAreas::item_at`],
+    /// without repeating the layout of the list. They are not stored in the [`ListState`], so
+    /// they have to be kept until the next render.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Position, Rect};
+    /// use ratatui::widgets::{List, ListState};
+    ///
+    /// let list = List::new(["Item 0", "Item 1", "Item 2"]);
+    /// let mut state = ListState::default();
+    /// let area = Rect::new(0, 0, 10, 3);
+    /// let areas = list.render_with_areas(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert_eq!(areas.item_at(Position::new(2, 1)), Some(1));
+    /// assert_eq!(areas.item_at(Position::new(2, 5)), None);
+    /// ```
+    pub fn render_with_areas(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+    ) -> ListAreas {
+        let mut items = Vec::new();
+        self.render_list(area, buf, state, Some(&mut items));
+        ListAreas { items }
+    }
+
+    /// Renders the list, recording the index and area of each displayed item if `item_areas` is
+    /// given
+    fn render_list(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+        item_areas: Option<&mut Vec<(usize, Rect)>>,
+    ) {
+        with_theme(buf, self.theme.as_ref(), |buf| {
+            self.render_items(area, buf, state, item_areas);
+        });
+    }
+
+    /// Renders the list with the styles of the theme of the buffer
+    fn render_items(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+        mut item_areas: Option<&mut Vec<(usize, Rect)>>,
+    ) {
+        let style = buf.theme.base.patch(self.style);
+        let highlight_style = buf.theme.selection.patch(self.highlight_style);
+        buf.set_style(area, style);
         self.block.as_ref().render(area, buf);
         let list_area = self.block.inner_if_some(area);
 
@@ -86,8 +151,11 @@ impl StatefulWidget for &List<'_> {
             };
 
             let row_area = Rect::new(x, y, list_area.width, item.height() as u16);
+            if let Some(item_areas) = item_areas.as_mut() {
+                item_areas.push((i, row_area.intersection(list_area)));
+            }
 
-            let item_style = self.style.patch(item.style);
+            let item_style = style.patch(item.style);
             buf.set_style(row_area, item_style);
 
             let is_selected = state.selected == Some(i);
@@ -104,7 +172,7 @@ impl StatefulWidget for &List<'_> {
             Widget::render(&item.content, item_area, buf);
 
             if is_selected {
-                buf.set_style(row_area, self.highlight_style);
+                buf.set_style(row_area, highlight_style);
             }
             if selection_spacing {
                 for j in 0..item.content.height() {
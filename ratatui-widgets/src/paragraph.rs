@@ -459,7 +459,12 @@ fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut
 
 fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u16) {
     let mut x = get_line_offset(wrapped.width, area.width, wrapped.alignment);
-    for StyledGrapheme { symbol, style } in wrapped.graphemes {
+    for StyledGrapheme {
+        symbol,
+        style,
+        hyperlink,
+    } in wrapped.graphemes
+    {
         let width = symbol.width();
         if width == 0 {
             continue;
@@ -467,7 +472,10 @@ fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u
         // Make sure to overwrite any previous character with a space (rather than a zero-width)
         let symbol = if symbol.is_empty() { " " } else { symbol };
         let position = Position::new(area.left() + x, area.top() + y);
-        buf[position].set_symbol(symbol).set_style(*style);
+        buf[position]
+            .set_symbol(symbol)
+            .set_style(*style)
+            .set_hyperlink(*hyperlink);
         x += u16::try_from(width).unwrap_or(u16::MAX);
     }
 }
@@ -681,6 +689,34 @@ mod tests {
         test_case(&paragraph, &expected);
     }
 
+    #[test]
+    fn test_render_hyperlink() {
+        let line = Line::from(vec![
+            Span::raw("see "),
+            Span::raw("ratatui").hyperlink("https://ratatui.rs"),
+        ]);
+        let truncated_paragraph = Paragraph::new(line);
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+
+        let expected = Buffer::with_lines([Line::from(vec![
+            Span::raw("see "),
+            Span::raw("ratatui").hyperlink("https://ratatui.rs"),
+            Span::raw(" "),
+        ])]);
+        for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
+            test_case(paragraph, &expected);
+        }
+
+        let expected = Buffer::with_lines([
+            Line::from("see "),
+            Line::from(Span::raw("ratatui").hyperlink("https://ratatui.rs")),
+        ]);
+        for paragraph in [&wrapped_paragraph, &trimmed_paragraph] {
+            test_case(paragraph, &expected);
+        }
+    }
+
     #[test]
     fn test_render_line_spans_styled() {
         let l0 = Line::default().spans([
@@ -7,10 +7,11 @@
     clippy::module_name_repetitions
 )]
 
+use core::hash::{Hash, Hasher};
 use core::iter;
 
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::Rect;
+use ratatui_core::layout::{Position, Rect};
 use ratatui_core::style::Style;
 use ratatui_core::symbols::scrollbar::{DOUBLE_HORIZONTAL, DOUBLE_VERTICAL, Set};
 use ratatui_core::widgets::StatefulWidget;
@@ -144,7 +145,7 @@ pub enum ScrollbarOrientation {
 ///
 /// If you don't have multi-line content, you can leave the `viewport_content_length` set to the
 /// default and it'll use the track size as a `viewport_content_length`.
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScrollbarState {
     /// The total length of the scrollable content.
@@ -155,6 +156,13 @@ pub struct ScrollbarState {
     ///
     /// FIXME: this should be `Option<usize>`, but it will break serialization to change it.
     viewport_content_length: usize,
+    /// The area of the track (excluding the begin and end symbols) from the last render, used for
+    /// hit-testing.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    track_area: Rect,
+    /// Whether the scrollbar was vertical in the last render.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    track_vertical: bool,
 }
 
 /// An enum representing a scrolling direction.
@@ -425,6 +433,8 @@ impl ScrollbarState {
             content_length,
             position: 0,
             viewport_content_length: 0,
+            track_area: Rect::ZERO,
+            track_vertical: false,
         }
     }
 
@@ -500,15 +510,83 @@ impl ScrollbarState {
     pub const fn get_position(&self) -> usize {
         self.position
     }
+
+    /// Returns the content position that corresponds to the given position on the track
+    ///
+    /// This uses the area of the track from the last time the scrollbar was rendered, so it can be
+    /// used to scroll to the position of a mouse click or drag. The first cell of the track maps to
+    /// the start of the content and the last cell to the end of the content. Returns `None` if the
+    /// position is outside of the track (including the begin and end symbols), or if the
+    /// scrollbar has not been rendered yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Position, Rect};
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget};
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+    /// let mut state = ScrollbarState::new(101);
+    /// let area = Rect::new(0, 0, 10, 13);
+    /// scrollbar.render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// // the begin symbol is at y = 0, so the track starts at y = 1
+    /// assert_eq!(state.position_at(Position::new(9, 1)), Some(0));
+    /// assert_eq!(state.position_at(Position::new(9, 6)), Some(50));
+    /// assert_eq!(state.position_at(Position::new(9, 11)), Some(100));
+    /// assert_eq!(state.position_at(Position::new(9, 12)), None);
+    /// ```
+    #[must_use = "returns the content position under the given position"]
+    pub fn position_at<P: Into<Position>>(&self, position: P) -> Option<usize> {
+        let position = position.into();
+        if !self.track_area.contains(position) {
+            return None;
+        }
+        let (offset, length) = if self.track_vertical {
+            (position.y - self.track_area.y, self.track_area.height)
+        } else {
+            (position.x - self.track_area.x, self.track_area.width)
+        };
+        let last = usize::from(length.saturating_sub(1));
+        if last == 0 {
+            return Some(0);
+        }
+        let max_position = self.content_length.saturating_sub(1);
+        Some((usize::from(offset) * max_position + last / 2) / last)
+    }
+}
+
+/// Compares the content length, position and viewport content length, but not the track area
+/// recorded by the last render.
+impl PartialEq for ScrollbarState {
+    fn eq(&self, other: &Self) -> bool {
+        self.content_length == other.content_length
+            && self.position == other.position
+            && self.viewport_content_length == other.viewport_content_length
+    }
+}
+
+impl Eq for ScrollbarState {}
+
+impl Hash for ScrollbarState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.content_length.hash(state);
+        self.position.hash(state);
+        self.viewport_content_length.hash(state);
+    }
 }
 
 impl StatefulWidget for Scrollbar<'_> {
     type State = ScrollbarState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.track_area = Rect::ZERO;
         if state.content_length == 0 || self.track_length_excluding_arrow_heads(area) == 0 {
             return;
         }
+        state.track_area = self.track_area(area);
+        state.track_vertical = self.orientation.is_vertical();
 
         if let Some(area) = self.scrollbar_area(area) {
             let areas = area.columns().flat_map(Rect::rows);
@@ -596,6 +674,28 @@ impl Scrollbar<'_> {
         }
     }
 
+    /// Returns the area of the track excluding the arrow heads
+    fn track_area(&self, area: Rect) -> Rect {
+        let Some(area) = self.scrollbar_area(area) else {
+            return Rect::ZERO;
+        };
+        let start_len = self.begin_symbol.map_or(0, |s| s.width() as u16);
+        let track_length = self.track_length_excluding_arrow_heads(area);
+        if self.orientation.is_vertical() {
+            Rect {
+                y: area.y.saturating_add(start_len),
+                height: track_length,
+                ..area
+            }
+        } else {
+            Rect {
+                x: area.x.saturating_add(start_len),
+                width: track_length,
+                ..area
+            }
+        }
+    }
+
     /// Calculates length of the track excluding the arrow heads
     ///
     /// ```plain
@@ -1120,4 +1220,22 @@ mod tests {
         // This should not panic, even if the buffer has zero size.
         scrollbar.render(buffer.area, &mut buffer, &mut state);
     }
+
+    #[rstest]
+    #[case::start(2, Some(0))]
+    #[case::middle(5, Some(10))]
+    #[case::end(8, Some(20))]
+    #[case::begin_symbol(1, None)]
+    #[case::end_symbol(9, None)]
+    fn position_at_horizontal(#[case] x: u16, #[case] expected: Option<usize>) {
+        let mut buffer = Buffer::empty(Rect::new(1, 0, 9, 2));
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalBottom);
+        let mut state = ScrollbarState::new(21);
+        assert_eq!(state.position_at((x, 1)), None);
+        scrollbar.render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.position_at((x, 1)), expected);
+        assert_eq!(state.position_at((x, 0)), None);
+        // the track area of the last render is not compared
+        assert_eq!(state, ScrollbarState::new(21));
+    }
 }
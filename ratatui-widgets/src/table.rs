@@ -7,16 +7,19 @@ use alloc::vec::Vec;
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Constraint, Flex, Layout, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::text::Text;
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
+pub use self::areas::TableAreas;
 pub use self::cell::Cell;
 pub use self::highlight_spacing::HighlightSpacing;
 pub use self::row::Row;
 pub use self::state::TableState;
 use crate::block::{Block, BlockExt};
+use crate::themed::with_theme;
 
+mod areas;
 mod cell;
 mod highlight_spacing;
 mod row;
@@ -269,6 +272,9 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// The theme set with [`Table::theme`], used instead of the theme of the buffer
+    theme: Option<Theme>,
 }
 
 impl Default for Table<'_> {
@@ -287,6 +293,7 @@ impl Default for Table<'_> {
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            theme: None,
         }
     }
 }
@@ -588,6 +595,43 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Sets the theme used for the default styles of the table, instead of the theme of the
+    /// buffer it is rendered to.
+    ///
+    /// By default, a table uses the theme of the buffer, which is the theme of the [`Terminal`]
+    /// when rendered to a frame. The table is filled with [`Theme::base`], the header row uses
+    /// [`Theme::header`] and the selected row [`Theme::selection`]. The styles set with
+    /// [`Table::style`], [`Table::row_highlight_style`] and the style of the header row are
+    /// applied on top of these when the table is rendered, so the order of the calls does not
+    /// matter. The theme is also used by the block of the table, unless the block has a theme of
+    /// its own.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, widgets::{Row, Table}};
+    /// use ratatui::style::Theme;
+    /// use ratatui::style::palette::tailwind;
+    ///
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .theme(&Theme::tailwind(&tailwind::BLUE))
+    ///     .header(Row::new(vec!["Col1", "Col2"]));
+    /// ```
+    ///
+    /// [`Terminal`]: ratatui_core::terminal::Terminal
+    /// [`Theme::base`]: Theme#structfield.base
+    /// [`Theme::selection`]: Theme#structfield.selection
+    /// [`Theme::header`]: Theme#structfield.header
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = Some(*theme);
+        self
+    }
+
     /// Set the style of the selected column
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -747,7 +791,72 @@ impl StatefulWidget for &Table<'_> {
     type State = TableState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        buf.set_style(area, self.style);
+        self.render_table(area, buf, state, None);
+    }
+}
+
+impl Table<'_> {
+    /// Renders the table like [`StatefulWidget::render`], and returns the areas of the rows and
+    /// columns that were displayed.
+    ///
+    /// The areas can be used to map a mouse event back to a row or cell with
+    /// [`TableAreas::row_at`] and [`TableAreas::cell_at`], without repeating the layout of the
+    /// table. They are not stored in the [`TableState`], so they have to be kept until the next
+    /// render.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Constraint, Position, Rect};
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// let rows = [Row::new(["a", "b"]), Row::new(["c", "d"])];
+    /// let table = Table::new(rows, [Constraint::Length(3); 2]);
+    /// let mut state = TableState::default();
+    /// let area = Rect::new(0, 0, 7, 2);
+    /// let areas = table.render_with_areas(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert_eq!(areas.cell_at(Position::new(5, 1)), Some((1, 1)));
+    /// assert_eq!(areas.cell_at(Position::new(3, 1)), None);
+    /// ```
+    pub fn render_with_areas(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+    ) -> TableAreas {
+        let mut areas = TableAreas::default();
+        self.render_table(area, buf, state, Some(&mut areas));
+        areas
+    }
+}
+
+// private methods for rendering
+impl Table<'_> {
+    /// Renders the table, recording the areas of the displayed rows and columns if `areas` is
+    /// given
+    fn render_table(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+        areas: Option<&mut TableAreas>,
+    ) {
+        with_theme(buf, self.theme.as_ref(), |buf| {
+            self.render_themed_table(area, buf, state, areas);
+        });
+    }
+
+    /// Renders the table with the styles of the theme of the buffer
+    fn render_themed_table(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+        areas: Option<&mut TableAreas>,
+    ) {
+        buf.set_style(area, buf.theme.base.patch(self.style));
         self.block.as_ref().render(area, buf);
         let table_area = self.block.inner_if_some(area);
         if table_area.is_empty() {
@@ -776,14 +885,18 @@ impl StatefulWidget for &Table<'_> {
 
         self.render_header(header_area, buf, &column_widths);
 
-        self.render_rows(rows_area, buf, state, selection_width, &column_widths);
+        self.render_rows(
+            rows_area,
+            buf,
+            state,
+            selection_width,
+            &column_widths,
+            areas,
+        );
 
         self.render_footer(footer_area, buf, &column_widths);
     }
-}
 
-// private methods for rendering
-impl Table<'_> {
     /// Splits the table area into a header, rows area and a footer
     fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
         let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
@@ -808,7 +921,7 @@ impl Table<'_> {
 
     fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
         if let Some(ref header) = self.header {
-            buf.set_style(area, header.style);
+            buf.set_style(area, buf.theme.header.patch(header.style));
             for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
                 cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
             }
@@ -831,6 +944,7 @@ impl Table<'_> {
         state: &mut TableState,
         selection_width: u16,
         columns_widths: &[(u16, u16)],
+        mut areas: Option<&mut TableAreas>,
     ) {
         if self.rows.is_empty() {
             return;
@@ -839,6 +953,13 @@ impl Table<'_> {
         let (start_index, end_index) = self.visible_rows(state, area);
         state.offset = start_index;
 
+        if let Some(areas) = areas.as_mut() {
+            areas.columns = columns_widths
+                .iter()
+                .map(|(x, width)| Rect::new(area.x + x, area.y, *width, area.height))
+                .collect();
+        }
+
         let mut y_offset = 0;
 
         let mut selected_row_area = None;
@@ -852,6 +973,9 @@ impl Table<'_> {
             let y = area.y + y_offset + row.top_margin;
             let height = (y + row.height).min(area.bottom()).saturating_sub(y);
             let row_area = Rect { y, height, ..area };
+            if let Some(areas) = areas.as_mut() {
+                areas.rows.push((i, row_area));
+            }
             buf.set_style(row_area, row.style);
 
             let is_selected = state.selected.is_some_and(|index| index == i);
@@ -885,15 +1009,16 @@ impl Table<'_> {
             })
         });
 
+        let row_highlight_style = buf.theme.selection.patch(self.row_highlight_style);
         match (selected_row_area, selected_column_area) {
             (Some(row_area), Some(col_area)) => {
-                buf.set_style(row_area, self.row_highlight_style);
+                buf.set_style(row_area, row_highlight_style);
                 buf.set_style(col_area, self.column_highlight_style);
                 let cell_area = row_area.intersection(col_area);
                 buf.set_style(cell_area, self.cell_highlight_style);
             }
             (Some(row_area), None) => {
-                buf.set_style(row_area, self.row_highlight_style);
+                buf.set_style(row_area, row_highlight_style);
             }
             (None, Some(col_area)) => {
                 buf.set_style(col_area, self.column_highlight_style);
@@ -2302,4 +2427,65 @@ mod tests {
         // This should not panic, even if the buffer has zero size.
         Widget::render(table, buffer.area, &mut buffer);
     }
+
+    #[test]
+    fn theme() {
+        let theme = Theme {
+            base: Style::new().white(),
+            border: Style::new().blue(),
+            selection: Style::new().on_blue(),
+            header: Style::new().bold(),
+            ..Theme::new()
+        };
+        let area = Rect::new(0, 0, 8, 4);
+        let mut state = TableState::default().with_selected(Some(0));
+        let mut buf = Buffer::empty(area);
+        let table = Table::new([Row::new(["Cell"])], [Length(4)])
+            .theme(&theme)
+            .header(Row::new(["Col1"]))
+            .block(Block::bordered());
+        StatefulWidget::render(table, area, &mut buf, &mut state);
+
+        let mut expected = Buffer::with_lines(["┌──────┐", "│Col1  │", "│Cell  │", "└──────┘"]);
+        expected.set_style(area, theme.base);
+        expected.set_style(area, theme.border);
+        expected.set_style(Rect::new(1, 1, 6, 2), theme.base);
+        expected.set_style(Rect::new(1, 1, 6, 1), theme.header);
+        expected.set_style(Rect::new(1, 2, 6, 1), theme.selection);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn theme_is_applied_under_styles_in_any_order() {
+        let theme = Theme {
+            base: Style::new().white().on_black(),
+            selection: Style::new().on_blue(),
+            header: Style::new().bold(),
+            ..Theme::new()
+        };
+        let area = Rect::new(0, 0, 4, 2);
+        let mut state = TableState::default().with_selected(Some(0));
+        let rows = [Row::new(["Cell"])];
+        let mut before = Buffer::empty(area);
+        let table = Table::new(rows.clone(), [Length(4)])
+            .theme(&theme)
+            .header(Row::new(["Col1"]).italic())
+            .style(Style::new().red())
+            .row_highlight_style(Style::new().underlined());
+        StatefulWidget::render(table, area, &mut before, &mut state);
+        let mut after = Buffer::empty(area);
+        let table = Table::new(rows, [Length(4)])
+            .header(Row::new(["Col1"]).italic())
+            .style(Style::new().red())
+            .row_highlight_style(Style::new().underlined())
+            .theme(&theme);
+        StatefulWidget::render(table, area, &mut after, &mut state);
+        assert_eq!(before, after);
+
+        let mut expected = Buffer::with_lines(["Col1", "Cell"]);
+        expected.set_style(area, Style::new().red().on_black());
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().bold().italic());
+        expected.set_style(Rect::new(0, 1, 4, 1), Style::new().on_blue().underlined());
+        assert_eq!(after, expected);
+    }
 }
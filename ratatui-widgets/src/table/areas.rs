@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+use ratatui_core::layout::{Position, Rect};
+
+/// The areas of the rows and columns displayed by a [`Table`], used for hit-testing
+///
+/// This is returned by [`Table::render_with_areas`], and maps a position (e.g. of a mouse event)
+/// back to the row or cell displayed there.
+///
+/// [`Table`]: super::Table
+/// [`Table::render_with_areas`]: super::Table::render_with_areas
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TableAreas {
+    pub(crate) rows: Vec<(usize, Rect)>,
+    pub(crate) columns: Vec<Rect>,
+}
+
+impl TableAreas {
+    /// The index and area of each displayed row, in the order they were rendered
+    pub fn rows(&self) -> &[(usize, Rect)] {
+        &self.rows
+    }
+
+    /// The area of each column, spanning the displayed rows
+    pub fn columns(&self) -> &[Rect] {
+        &self.columns
+    }
+
+    /// Returns the index of the row displayed at the given position
+    ///
+    /// Returns `None` if no row was displayed at the position (e.g. on the header, the footer or
+    /// the margins between rows).
+    pub fn row_at<P: Into<Position>>(&self, position: P) -> Option<usize> {
+        let position = position.into();
+        self.rows
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(index, _)| *index)
+    }
+
+    /// Returns the row and column indexes of the cell displayed at the given position
+    ///
+    /// Returns `None` if the position is not on a row, or if it is on the selection symbol or the
+    /// spacing between columns.
+    pub fn cell_at<P: Into<Position>>(&self, position: P) -> Option<(usize, usize)> {
+        let position = position.into();
+        let row = self.row_at(position)?;
+        let column = self
+            .columns
+            .iter()
+            .position(|area| (area.left()..area.right()).contains(&position.x))?;
+        Some((row, column))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn row_at_cell_at() {
+        let areas = TableAreas {
+            rows: vec![(4, Rect::new(0, 1, 9, 1)), (5, Rect::new(0, 3, 9, 2))],
+            columns: vec![Rect::new(2, 1, 3, 4), Rect::new(6, 1, 3, 4)],
+        };
+        assert_eq!(areas.row_at((0, 1)), Some(4));
+        assert_eq!(areas.row_at((0, 4)), Some(5));
+        assert_eq!(areas.row_at((0, 2)), None);
+        assert_eq!(areas.row_at((0, 0)), None);
+
+        assert_eq!(areas.cell_at((2, 1)), Some((4, 0)));
+        assert_eq!(areas.cell_at((8, 3)), Some((5, 1)));
+        assert_eq!(areas.cell_at((0, 1)), None);
+        assert_eq!(areas.cell_at((5, 1)), None);
+        assert_eq!(areas.cell_at((2, 2)), None);
+    }
+}
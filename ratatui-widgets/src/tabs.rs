@@ -3,14 +3,15 @@ use alloc::vec::Vec;
 
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::Rect;
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::{Style, Styled, Theme};
 use ratatui_core::symbols;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
 use unicode_width::UnicodeWidthStr;
 
 use crate::block::{Block, BlockExt};
+use crate::themed::with_theme;
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().reversed();
 
@@ -57,14 +58,17 @@ pub struct Tabs<'a> {
     selected: Option<usize>,
     /// The style used to draw the text
     style: Style,
-    /// Style to apply to the selected item
-    highlight_style: Style,
+    /// Style to apply to the selected item, or `None` for the selection style of the theme (or
+    /// [`DEFAULT_HIGHLIGHT_STYLE`] if the theme has none)
+    highlight_style: Option<Style>,
     /// Tab divider
     divider: Span<'a>,
     /// Tab Left Padding
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// The theme set with [`Tabs::theme`], used instead of the theme of the buffer
+    theme: Option<Theme>,
 }
 
 impl Default for Tabs<'_> {
@@ -139,10 +143,11 @@ impl<'a> Tabs<'a> {
             titles,
             selected,
             style: Style::default(),
-            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+            highlight_style: None,
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            theme: None,
         }
     }
 
@@ -250,7 +255,38 @@ impl<'a> Tabs<'a> {
     ///
     /// [`Color`]: ratatui_core::style::Color
     pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
-        self.highlight_style = style.into();
+        self.highlight_style = Some(style.into());
+        self
+    }
+
+    /// Sets the theme used for the default styles of the tabs, instead of the theme of the buffer
+    /// they are rendered to.
+    ///
+    /// By default, tabs use the theme of the buffer, which is the theme of the [`Terminal`] when
+    /// rendered to a frame. The tabs are filled with [`Theme::base`], the selected tab uses
+    /// [`Theme::selection`] (instead of the default reversed style, unless the theme has no
+    /// selection style) and the divider uses [`Theme::muted`]. The styles set with [`Tabs::style`],
+    /// [`Tabs::highlight_style`] and the style of the divider are applied on top of these when the
+    /// tabs are rendered, so the order of the calls does not matter. The theme is also used by the
+    /// block of the tabs, unless the block has a theme of its own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::Theme;
+    /// use ratatui::style::palette::material;
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).theme(&Theme::material(&material::TEAL));
+    /// ```
+    ///
+    /// [`Terminal`]: ratatui_core::terminal::Terminal
+    /// [`Theme::base`]: Theme#structfield.base
+    /// [`Theme::selection`]: Theme#structfield.selection
+    /// [`Theme::muted`]: Theme#structfield.muted
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn theme(mut self, theme: &Theme) -> Self {
+        self.theme = Some(*theme);
         self
     }
 
@@ -374,19 +410,67 @@ impl Widget for Tabs<'_> {
 
 impl Widget for &Tabs<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        self.block.as_ref().render(area, buf);
-        let inner = self.block.inner_if_some(area);
-        self.render_tabs(inner, buf);
+        with_theme(buf, self.theme.as_ref(), |buf| {
+            self.render_tabs(area, buf, None);
+        });
     }
 }
 
 impl Tabs<'_> {
-    fn render_tabs(&self, tabs_area: Rect, buf: &mut Buffer) {
+    /// Renders the tabs like [`Widget::render`], and returns the areas of the tabs that were
+    /// displayed.
+    ///
+    /// The areas can be used to map a mouse event back to a tab with [`TabAreas::tab_at`],
+    /// without repeating the layout of the tabs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::{Position, Rect};
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// // renders as " Tab1 │ Tab2 "
+    /// let tabs = Tabs::new(["Tab1", "Tab2"]);
+    /// let area = Rect::new(0, 0, 20, 1);
+    /// let areas = tabs.render_with_areas(area, &mut Buffer::empty(area));
+    /// assert_eq!(areas.tab_at(Position::new(1, 0)), Some(0));
+    /// assert_eq!(areas.tab_at(Position::new(6, 0)), None);
+    /// assert_eq!(areas.tab_at(Position::new(8, 0)), Some(1));
+    /// ```
+    pub fn render_with_areas(&self, area: Rect, buf: &mut Buffer) -> TabAreas {
+        let mut tabs = Vec::new();
+        with_theme(buf, self.theme.as_ref(), |buf| {
+            self.render_tabs(area, buf, Some(&mut tabs));
+        });
+        TabAreas { tabs }
+    }
+
+    /// Renders the tabs with the styles of the theme of the buffer, recording the area of each
+    /// displayed tab if `tab_areas` is given
+    fn render_tabs(&self, area: Rect, buf: &mut Buffer, mut tab_areas: Option<&mut Vec<Rect>>) {
+        buf.set_style(area, buf.theme.base.patch(self.style));
+        self.block.as_ref().render(area, buf);
+        let tabs_area = self.block.inner_if_some(area);
         if tabs_area.is_empty() {
             return;
         }
 
+        let highlight_style = self.highlight_style.map_or_else(
+            || {
+                if buf.theme.selection == Style::new() {
+                    DEFAULT_HIGHLIGHT_STYLE
+                } else {
+                    buf.theme.selection
+                }
+            },
+            |style| buf.theme.selection.patch(style),
+        );
+        let divider = Span {
+            style: buf.theme.muted.patch(self.divider.style),
+            ..self.divider.clone()
+        };
+
         let mut x = tabs_area.left();
         let titles_length = self.titles.len();
         for (i, title) in self.titles.iter().enumerate() {
@@ -397,47 +481,78 @@ impl Tabs<'_> {
                 break;
             }
 
-            // Left Padding
-            let pos = buf.set_line(x, tabs_area.top(), &self.padding_left, remaining_width);
-            x = pos.0;
-            let remaining_width = tabs_area.right().saturating_sub(x);
-            if remaining_width == 0 {
-                break;
+            // A tab is made of its padding and title, and is cut at the right of the area
+            let start = x;
+            'tab: {
+                // Left Padding
+                let pos = buf.set_line(x, tabs_area.top(), &self.padding_left, remaining_width);
+                x = pos.0;
+                let remaining_width = tabs_area.right().saturating_sub(x);
+                if remaining_width == 0 {
+                    break 'tab;
+                }
+
+                // Title
+                let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
+                if Some(i) == self.selected {
+                    buf.set_style(
+                        Rect {
+                            x,
+                            y: tabs_area.top(),
+                            width: pos.0.saturating_sub(x),
+                            height: 1,
+                        },
+                        highlight_style,
+                    );
+                }
+                x = pos.0;
+                let remaining_width = tabs_area.right().saturating_sub(x);
+                if remaining_width == 0 {
+                    break 'tab;
+                }
+
+                // Right Padding
+                let pos = buf.set_line(x, tabs_area.top(), &self.padding_right, remaining_width);
+                x = pos.0;
             }
-
-            // Title
-            let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
-            if Some(i) == self.selected {
-                buf.set_style(
-                    Rect {
-                        x,
-                        y: tabs_area.top(),
-                        width: pos.0.saturating_sub(x),
-                        height: 1,
-                    },
-                    self.highlight_style,
-                );
+            if let Some(tab_areas) = tab_areas.as_mut() {
+                tab_areas.push(Rect::new(start, tabs_area.top(), x - start, 1));
             }
-            x = pos.0;
-            let remaining_width = tabs_area.right().saturating_sub(x);
-            if remaining_width == 0 {
-                break;
-            }
-
-            // Right Padding
-            let pos = buf.set_line(x, tabs_area.top(), &self.padding_right, remaining_width);
-            x = pos.0;
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 || last_title {
                 break;
             }
 
-            let pos = buf.set_span(x, tabs_area.top(), &self.divider, remaining_width);
+            let pos = buf.set_span(x, tabs_area.top(), &divider, remaining_width);
             x = pos.0;
         }
     }
 }
 
+/// The areas of the tabs displayed by [`Tabs`], used for hit-testing
+///
+/// This is returned by [`Tabs::render_with_areas`], and maps a position (e.g. of a mouse event)
+/// back to the tab displayed there. A tab includes its padding but not the divider.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TabAreas {
+    tabs: Vec<Rect>,
+}
+
+impl TabAreas {
+    /// The area of each displayed tab, in the order of the titles
+    pub fn tabs(&self) -> &[Rect] {
+        &self.tabs
+    }
+
+    /// Returns the index of the tab displayed at the given position
+    ///
+    /// Returns `None` if no tab was displayed at the position.
+    pub fn tab_at<P: Into<Position>>(&self, position: P) -> Option<usize> {
+        let position = position.into();
+        self.tabs.iter().position(|area| area.contains(position))
+    }
+}
+
 impl<'a, Item> FromIterator<Item> for Tabs<'a>
 where
     Item: Into<Line<'a>>,
@@ -529,10 +644,11 @@ mod tests {
                 ],
                 selected: Some(0),
                 style: Style::default(),
-                highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+                highlight_style: None,
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                theme: None,
             }
         );
     }
@@ -546,10 +662,11 @@ mod tests {
                 titles: vec![],
                 selected: None,
                 style: Style::default(),
-                highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+                highlight_style: None,
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                theme: None,
             }
         );
     }
@@ -802,4 +919,88 @@ mod tests {
         let rendered = "左你右分左好右分左世界右";
         assert_eq!(tabs.width_cjk(), UnicodeWidthStr::width_cjk(rendered));
     }
+
+    #[test]
+    fn tab_at() {
+        // renders as "│[a]-[bb]-[c│" inside the block
+        let tabs = Tabs::new(["a", "bb", "ccc"])
+            .block(Block::bordered())
+            .padding("[", "]")
+            .divider("-");
+        let area = Rect::new(0, 0, 13, 3);
+        let areas = tabs.render_with_areas(area, &mut Buffer::empty(area));
+        let tab_at = |x, y| areas.tab_at((x, y));
+        assert_eq!(tab_at(0, 1), None);
+        assert_eq!(tab_at(1, 1), Some(0));
+        assert_eq!(tab_at(3, 1), Some(0));
+        assert_eq!(tab_at(4, 1), None);
+        assert_eq!(tab_at(5, 1), Some(1));
+        assert_eq!(tab_at(8, 1), Some(1));
+        assert_eq!(tab_at(10, 1), Some(2));
+        assert_eq!(tab_at(11, 1), Some(2));
+        assert_eq!(tab_at(12, 1), None);
+        assert_eq!(tab_at(5, 0), None);
+        assert_eq!(tab_at(5, 2), None);
+    }
+
+    #[test]
+    fn theme() {
+        let theme = Theme {
+            base: Style::new().white(),
+            selection: Style::new().yellow(),
+            muted: Style::new().dark_gray(),
+            ..Theme::new()
+        };
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]).theme(&theme);
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 "]);
+        expected.set_style(expected.area, theme.base);
+        expected.set_style(Rect::new(1, 0, 4, 1), theme.selection);
+        expected.set_style(Rect::new(6, 0, 1, 1), theme.muted);
+        test_case(tabs, Rect::new(0, 0, 13, 1), &expected);
+
+        // the theme of the buffer is used when the tabs have no theme
+        let mut buf = Buffer::empty(Rect::new(0, 0, 13, 1));
+        buf.theme = theme;
+        Tabs::new(vec!["Tab1", "Tab2"]).render(buf.area, &mut buf);
+        assert_eq!(buf.content, expected.content);
+    }
+
+    #[test]
+    fn theme_is_applied_under_styles_in_any_order() {
+        let theme = Theme {
+            base: Style::new().white().on_black(),
+            selection: Style::new().yellow(),
+            muted: Style::new().dark_gray(),
+            ..Theme::new()
+        };
+        let before = Tabs::new(vec!["Tab1", "Tab2"])
+            .theme(&theme)
+            .style(Style::new().red())
+            .highlight_style(Style::new().bold())
+            .divider(Span::raw("|").italic());
+        let after = Tabs::new(vec!["Tab1", "Tab2"])
+            .style(Style::new().red())
+            .highlight_style(Style::new().bold())
+            .divider(Span::raw("|").italic())
+            .theme(&theme);
+        let mut expected = Buffer::with_lines([" Tab1 | Tab2 "]);
+        expected.set_style(expected.area, Style::new().red().on_black());
+        expected.set_style(Rect::new(1, 0, 4, 1), Style::new().yellow().bold());
+        expected.set_style(Rect::new(6, 0, 1, 1), Style::new().dark_gray().italic());
+        test_case(before, Rect::new(0, 0, 13, 1), &expected);
+        test_case(after, Rect::new(0, 0, 13, 1), &expected);
+    }
+
+    #[test]
+    fn theme_without_selection_style() {
+        let theme = Theme {
+            base: Style::new().white(),
+            ..Theme::new()
+        };
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"]).theme(&theme);
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 "]);
+        expected.set_style(expected.area, theme.base);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 13, 1), &expected);
+    }
 }
@@ -0,0 +1,576 @@
+//! The [`TextInput`] and [`TextArea`] widgets are used to edit a single line or multiple lines of
+//! text.
+use alloc::borrow::Cow;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::{Line, Masked};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub use self::state::{TextInputState, TextPosition};
+use crate::block::{Block, BlockExt};
+
+mod state;
+
+/// A widget to edit a single line of text.
+///
+/// The text, cursor and selection are stored in a [`TextInputState`], which is updated by the
+/// application in response to key events (for example by calling
+/// [`TextInputState::insert_char`] or [`TextInputState::move_left`]). When rendered, the line is
+/// scrolled horizontally so that the cursor is always visible. If the state is focused,
+/// `Frame::render_stateful_widget` shows the terminal cursor at the cursor of the text, whose
+/// screen position is also available from [`TextInputState::cursor_position`].
+///
+/// Only the line containing the cursor is displayed. Use [`TextArea`] to edit multiple lines.
+///
+/// The text can be masked, for example for password fields, using [`TextInput::mask`]. Each
+/// grapheme is then displayed as the mask character.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::{Block, TextInput, TextInputState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let mut state = TextInputState::default().focused(true);
+/// let input = TextInput::new()
+///     .block(Block::bordered().title("Password"))
+///     .placeholder("Enter a password")
+///     .mask('*');
+/// // shows the cursor, as the input is focused
+/// frame.render_stateful_widget(input, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TextInput<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// Style of the selected text
+    selection_style: Style,
+    /// Text displayed when the input is empty
+    placeholder: Option<Line<'a>>,
+    /// Character used to mask the text
+    mask: Option<char>,
+}
+
+/// A widget to edit multiple lines of text.
+///
+/// This works like [`TextInput`] but displays all lines, scrolling vertically as well as
+/// horizontally to keep the cursor visible. Lines are not wrapped.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Block, TextArea, TextInputState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let mut state = TextInputState::new("first line\nsecond line").focused(true);
+/// let text_area = TextArea::new().block(Block::bordered().title("Notes"));
+/// // shows the cursor, as the text area is focused
+/// frame.render_stateful_widget(text_area, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TextArea<'a> {
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// Style of the selected text
+    selection_style: Style,
+    /// Text displayed when the input is empty
+    placeholder: Option<Line<'a>>,
+}
+
+impl<'a> TextInput<'a> {
+    /// Creates a new `TextInput` with default settings.
+    ///
+    /// The selection is displayed reversed by default.
+    pub fn new() -> Self {
+        Self {
+            selection_style: Style::new().reversed(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the `TextInput` with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Sets the text displayed when the input is empty.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Line<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Masks the text, displaying each grapheme as `mask`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+}
+
+impl<'a> TextArea<'a> {
+    /// Creates a new `TextArea` with default settings.
+    ///
+    /// The selection is displayed reversed by default.
+    pub fn new() -> Self {
+        Self {
+            selection_style: Style::new().reversed(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the `TextArea` with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Sets the text displayed when the input is empty.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Line<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+}
+
+impl StatefulWidget for TextInput<'_> {
+    type State = TextInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+
+    fn cursor_position(state: &Self::State) -> Option<Position> {
+        state.cursor_position()
+    }
+}
+
+impl StatefulWidget for &TextInput<'_> {
+    type State = TextInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        let text = TextRenderer {
+            selection_style: self.selection_style,
+            placeholder: self.placeholder.as_ref(),
+            mask: self.mask,
+            single_line: true,
+        };
+        text.render(inner, buf, state);
+    }
+
+    fn cursor_position(state: &Self::State) -> Option<Position> {
+        state.cursor_position()
+    }
+}
+
+impl StatefulWidget for TextArea<'_> {
+    type State = TextInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+
+    fn cursor_position(state: &Self::State) -> Option<Position> {
+        state.cursor_position()
+    }
+}
+
+impl StatefulWidget for &TextArea<'_> {
+    type State = TextInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        let text = TextRenderer {
+            selection_style: self.selection_style,
+            placeholder: self.placeholder.as_ref(),
+            mask: None,
+            single_line: false,
+        };
+        text.render(inner, buf, state);
+    }
+
+    fn cursor_position(state: &Self::State) -> Option<Position> {
+        state.cursor_position()
+    }
+}
+
+/// Renders the text of a [`TextInputState`], shared by [`TextInput`] and [`TextArea`].
+struct TextRenderer<'a> {
+    selection_style: Style,
+    placeholder: Option<&'a Line<'a>>,
+    mask: Option<char>,
+    single_line: bool,
+}
+
+impl TextRenderer<'_> {
+    fn render(&self, area: Rect, buf: &mut Buffer, state: &mut TextInputState) {
+        state.cursor_position = None;
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let cursor = state.cursor();
+        let (width, height) = (usize::from(area.width), usize::from(area.height));
+
+        let first_row = if self.single_line {
+            cursor.row
+        } else {
+            state.vertical_offset = scroll_to(state.vertical_offset, cursor.row, height);
+            state.vertical_offset
+        };
+        let cursor_x = self.width_before(&state.lines()[cursor.row], cursor.column);
+        state.horizontal_offset = scroll_to(state.horizontal_offset, cursor_x, width);
+        let offset = state.horizontal_offset;
+
+        if state.is_empty() {
+            if let Some(placeholder) = self.placeholder {
+                placeholder.render(area, buf);
+            }
+        } else {
+            let rows = if self.single_line { 1 } else { height };
+            let selection = state.selection();
+            for (y, row) in (area.top()..)
+                .zip(first_row..state.lines().len())
+                .take(rows)
+            {
+                let mut x = 0;
+                for (column, grapheme) in state.lines()[row].graphemes(true).enumerate() {
+                    let symbol = self.display(grapheme);
+                    let symbol_width = symbol.width();
+                    if x >= offset + width {
+                        break;
+                    }
+                    if x >= offset && x + symbol_width <= offset + width {
+                        let position = TextPosition::new(row, column);
+                        let selected = selection
+                            .is_some_and(|(start, end)| start <= position && position < end);
+                        let style = if selected {
+                            self.selection_style
+                        } else {
+                            Style::new()
+                        };
+                        let screen_x = area.x + (x - offset) as u16;
+                        buf.set_stringn(screen_x, y, &symbol, symbol_width, style);
+                    }
+                    x += symbol_width;
+                }
+            }
+        }
+
+        if state.is_focused() {
+            state.cursor_position = Some(Position::new(
+                area.x + (cursor_x - offset) as u16,
+                area.y + (cursor.row - first_row) as u16,
+            ));
+        }
+    }
+
+    /// Returns how the grapheme is displayed, taking the mask into account.
+    fn display<'a>(&self, grapheme: &'a str) -> Cow<'a, str> {
+        self.mask.map_or(Cow::Borrowed(grapheme), |mask| {
+            Masked::new(grapheme, mask).value()
+        })
+    }
+
+    /// Returns the display width of the first `column` graphemes of the line.
+    fn width_before(&self, line: &str, column: usize) -> usize {
+        line.graphemes(true)
+            .take(column)
+            .map(|grapheme| self.display(grapheme).width())
+            .sum()
+    }
+}
+
+/// Returns the scroll offset closest to `offset` that keeps `position` in a view of `size`.
+const fn scroll_to(offset: usize, position: usize, size: usize) -> usize {
+    if position < offset {
+        position
+    } else if position >= offset + size {
+        position + 1 - size
+    } else {
+        offset
+    }
+}
+
+impl Styled for TextInput<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Styled for TextArea<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::backend::TestBackend;
+    use ratatui_core::style::{Color, Stylize};
+    use ratatui_core::terminal::Terminal;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn render_input(input: &TextInput, state: &mut TextInputState, width: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn render_text_input() {
+        let mut state = TextInputState::new("hello").focused(true);
+        let buf = render_input(&TextInput::new(), &mut state, 8);
+        assert_eq!(buf, Buffer::with_lines(["hello   "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 0)));
+    }
+
+    #[test]
+    fn render_unfocused_has_no_cursor() {
+        let mut state = TextInputState::new("hello");
+        render_input(&TextInput::new(), &mut state, 8);
+        assert_eq!(state.cursor_position(), None);
+    }
+
+    #[rstest]
+    #[case::end(5, "llo ", 2, 3)]
+    #[case::start(0, "hell", 0, 0)]
+    fn render_horizontal_scroll(
+        #[case] column: usize,
+        #[case] expected: &str,
+        #[case] offset: usize,
+        #[case] cursor_x: u16,
+    ) {
+        let mut state = TextInputState::new("hello").focused(true);
+        state.set_cursor(TextPosition::new(0, column));
+        let buf = render_input(&TextInput::new(), &mut state, 4);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+        assert_eq!(state.horizontal_offset(), offset);
+        assert_eq!(state.cursor_position(), Some(Position::new(cursor_x, 0)));
+    }
+
+    #[test]
+    fn render_keeps_scroll_offset_while_cursor_visible() {
+        let mut state = TextInputState::new("hello").focused(true);
+        render_input(&TextInput::new(), &mut state, 4);
+        assert_eq!(state.horizontal_offset(), 2);
+        state.move_left();
+        state.move_left();
+        let buf = render_input(&TextInput::new(), &mut state, 4);
+        assert_eq!(buf, Buffer::with_lines(["llo "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(1, 0)));
+    }
+
+    #[test]
+    fn render_wide_graphemes() {
+        let mut state = TextInputState::new("a😀b").focused(true);
+        let buf = render_input(&TextInput::new(), &mut state, 5);
+        assert_eq!(buf, Buffer::with_lines(["a😀b "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(4, 0)));
+    }
+
+    #[test]
+    fn render_masked() {
+        let mut state = TextInputState::new("secret").focused(true);
+        let buf = render_input(&TextInput::new().mask('*'), &mut state, 8);
+        assert_eq!(buf, Buffer::with_lines(["******  "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(6, 0)));
+    }
+
+    #[test]
+    fn render_masked_graphemes() {
+        // an accented letter written with a combining accent, and a thumbs up with a skin tone
+        let mut state = TextInputState::new("e\u{301}👍🏽").focused(true);
+        let buf = render_input(&TextInput::new().mask('*'), &mut state, 4);
+        assert_eq!(buf, Buffer::with_lines(["**  "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(2, 0)));
+    }
+
+    #[test]
+    fn render_in_frame_shows_cursor() {
+        let mut terminal = Terminal::new(TestBackend::new(8, 1)).unwrap();
+        let mut state = TextInputState::new("hello").focused(true);
+        state.set_cursor(TextPosition::new(0, 2));
+        terminal
+            .draw(|frame| frame.render_stateful_widget(TextInput::new(), frame.area(), &mut state))
+            .unwrap();
+        terminal.backend().assert_buffer_lines(["hello   "]);
+        terminal.backend_mut().assert_cursor_position((2, 0));
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let mut state = TextInputState::default().focused(true);
+        let input = TextInput::new().placeholder("Name".dark_gray());
+        let buf = render_input(&input, &mut state, 6);
+        let mut expected = Buffer::with_lines(["Name  "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().dark_gray());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn render_selection() {
+        let mut state = TextInputState::new("hello");
+        state.start_selection();
+        state.move_left();
+        state.move_left();
+        let input = TextInput::new().selection_style(Color::Red);
+        let buf = render_input(&input, &mut state, 5);
+        let mut expected = Buffer::with_lines(["hello"]);
+        expected.set_style(Rect::new(3, 0, 2, 1), Color::Red);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_with_block() {
+        let mut state = TextInputState::new("hi").focused(true);
+        let input = TextInput::new().block(Block::bordered());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        StatefulWidget::render(&input, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["┌────┐", "│hi  │", "└────┘"]));
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 1)));
+    }
+
+    #[test]
+    fn render_text_input_shows_cursor_line() {
+        let mut state = TextInputState::new("one\ntwo").focused(true);
+        let buf = render_input(&TextInput::new(), &mut state, 4);
+        assert_eq!(buf, Buffer::with_lines(["two "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 0)));
+    }
+
+    #[test]
+    fn render_text_area_vertical_scroll() {
+        let mut state = TextInputState::new("1\n2\n3\n4").focused(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        StatefulWidget::render(&TextArea::new(), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["3 ", "4 "]));
+        assert_eq!(state.vertical_offset(), 2);
+        assert_eq!(state.cursor_position(), Some(Position::new(1, 1)));
+
+        state.move_to_start();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        StatefulWidget::render(&TextArea::new(), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["1 ", "2 "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn render_text_area_multiline_selection() {
+        let mut state = TextInputState::new("ab\ncd");
+        state.set_cursor(TextPosition::new(0, 1));
+        state.start_selection();
+        state.move_down();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        let text_area = TextArea::new().selection_style(Color::Red);
+        StatefulWidget::render(&text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["ab", "cd"]);
+        expected[(1, 0)].set_fg(Color::Red);
+        expected[(0, 1)].set_fg(Color::Red);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let mut state = TextInputState::new("hello").focused(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(&TextInput::new(), Rect::ZERO, &mut buf, &mut state);
+        assert_eq!(state.cursor_position(), None);
+    }
+
+    #[test]
+    fn styled() {
+        let input = TextInput::new().red();
+        assert_eq!(input.style, Style::new().red());
+        let text_area = TextArea::new().on_blue();
+        assert_eq!(text_area.style, Style::new().on_blue());
+    }
+}
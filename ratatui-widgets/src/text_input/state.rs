@@ -0,0 +1,750 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ratatui_core::layout::Position;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A position in the text of a [`TextInputState`].
+///
+/// The `row` is the index of the line and the `column` is the index of the grapheme cluster within
+/// that line, so a `column` of `1` in the line `"e\u{301}x"` is the position before the `x`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextPosition {
+    /// The index of the line
+    pub row: usize,
+    /// The index of the grapheme cluster within the line
+    pub column: usize,
+}
+
+impl TextPosition {
+    /// Creates a new `TextPosition` from a row and column.
+    pub const fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+}
+
+/// State of the [`TextInput`] and [`TextArea`] widgets
+///
+/// This state holds the text being edited along with the cursor, the selection, the scroll offset
+/// and the undo/redo history. All positions are measured in grapheme clusters, so moving the
+/// cursor or deleting text never splits a user-perceived character.
+///
+/// When the widget is rendered with a focused state, the screen position of the cursor is stored in
+/// the state, and `Frame::render_stateful_widget` shows the terminal cursor there. The position can
+/// also be read with [`TextInputState::cursor_position`], e.g. when rendering to a [`Buffer`]
+/// directly. The scroll offset is adjusted when rendering so that the cursor is always visible.
+///
+/// A selection is started with [`TextInputState::start_selection`]. While a selection is active,
+/// moving the cursor extends it, and inserting or deleting text replaces the selected text.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{TextInput, TextInputState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextInputState::new("Hello").focused(true);
+/// state.insert_str(", world!");
+///
+/// frame.render_stateful_widget(TextInput::new(), area, &mut state);
+/// # }
+/// ```
+///
+/// [`Buffer`]: ratatui_core::buffer::Buffer
+/// [`TextInput`]: super::TextInput
+/// [`TextArea`]: super::TextArea
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextInputState {
+    lines: Vec<String>,
+    cursor: TextPosition,
+    anchor: Option<TextPosition>,
+    focused: bool,
+    pub(crate) horizontal_offset: usize,
+    pub(crate) vertical_offset: usize,
+    pub(crate) cursor_position: Option<Position>,
+    history: History,
+}
+
+/// A snapshot of the text and cursor used to undo and redo edits.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: TextPosition,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct History {
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// Whether the last edit was typing a word character, so that typing a whole word can be
+    /// undone in one step.
+    typing: bool,
+}
+
+/// The maximum number of edits that can be undone.
+const HISTORY_LIMIT: usize = 100;
+
+impl Default for TextInputState {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl From<&str> for TextInputState {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for TextInputState {
+    fn from(text: String) -> Self {
+        Self::new(&text)
+    }
+}
+
+impl TextInputState {
+    /// Creates a new `TextInputState` containing the given text, with the cursor at the end.
+    ///
+    /// Lines are separated by `\n`. A trailing `\r` on each line is removed.
+    pub fn new(text: &str) -> Self {
+        let mut state = Self {
+            lines: split_lines(text),
+            cursor: TextPosition::default(),
+            anchor: None,
+            focused: false,
+            horizontal_offset: 0,
+            vertical_offset: 0,
+            cursor_position: None,
+            history: History::default(),
+        };
+        state.move_to_end();
+        state
+    }
+
+    /// Sets whether the input is focused.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets whether the input is focused.
+    ///
+    /// Only a focused input reports a [`cursor_position`](Self::cursor_position) when rendered.
+    pub const fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Returns whether the input is focused.
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Returns the lines of text.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns the text, with lines joined by `\n`.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns whether the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    /// Replaces the text, moving the cursor to the end and clearing the selection.
+    ///
+    /// This can be undone with [`TextInputState::undo`].
+    pub fn set_text(&mut self, text: &str) {
+        self.save_history(false);
+        self.lines = split_lines(text);
+        self.anchor = None;
+        self.move_to_end();
+    }
+
+    /// Returns the cursor position in the text.
+    pub const fn cursor(&self) -> TextPosition {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given position in the text.
+    ///
+    /// The position is clamped to the text.
+    pub fn set_cursor(&mut self, position: TextPosition) {
+        let row = position.row.min(self.lines.len() - 1);
+        let column = position.column.min(grapheme_count(&self.lines[row]));
+        self.cursor = TextPosition { row, column };
+        self.history.typing = false;
+    }
+
+    /// Returns the position of the terminal cursor computed by the last render.
+    ///
+    /// This is `None` if the input is not focused or has not been rendered yet.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// Returns the horizontal scroll offset in columns.
+    pub const fn horizontal_offset(&self) -> usize {
+        self.horizontal_offset
+    }
+
+    /// Returns the vertical scroll offset in lines.
+    pub const fn vertical_offset(&self) -> usize {
+        self.vertical_offset
+    }
+
+    /// Starts a selection at the cursor position, if no selection is active.
+    ///
+    /// Moving the cursor afterwards extends the selection.
+    pub const fn start_selection(&mut self) {
+        if self.anchor.is_none() {
+            self.anchor = Some(self.cursor);
+        }
+    }
+
+    /// Clears the selection without changing the text.
+    pub const fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Selects all the text.
+    pub fn select_all(&mut self) {
+        self.anchor = Some(TextPosition::default());
+        self.move_to_end();
+    }
+
+    /// Returns the start (inclusive) and end (exclusive) of the selection, or `None` if nothing is
+    /// selected.
+    pub fn selection(&self) -> Option<(TextPosition, TextPosition)> {
+        let anchor = self.anchor?;
+        match anchor.cmp(&self.cursor) {
+            core::cmp::Ordering::Less => Some((anchor, self.cursor)),
+            core::cmp::Ordering::Greater => Some((self.cursor, anchor)),
+            core::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Returns the selected text, or `None` if nothing is selected.
+    #[expect(clippy::string_slice)] // byte indexes come from UnicodeSegmentation
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        if start.row == end.row {
+            let line = &self.lines[start.row];
+            return Some(line[byte_index(line, start.column)..byte_index(line, end.column)].into());
+        }
+        let first = &self.lines[start.row];
+        let last = &self.lines[end.row];
+        let mut text = first[byte_index(first, start.column)..].to_string();
+        for line in &self.lines[start.row + 1..end.row] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(&last[..byte_index(last, end.column)]);
+        Some(text)
+    }
+
+    /// Inserts a character at the cursor, replacing the selection if there is one.
+    ///
+    /// Inserting `'\n'` splits the line.
+    #[expect(clippy::string_slice)] // byte indexes come from UnicodeSegmentation
+    pub fn insert_char(&mut self, c: char) {
+        if c == '\n' {
+            self.insert_newline();
+            return;
+        }
+        let typing = !c.is_whitespace() && self.anchor.is_none();
+        self.save_history(typing);
+        self.delete_selection();
+        let line = &mut self.lines[self.cursor.row];
+        let index = byte_index(line, self.cursor.column);
+        line.insert(index, c);
+        self.cursor.column = grapheme_count(&line[..index + c.len_utf8()]);
+    }
+
+    /// Inserts a string at the cursor, replacing the selection if there is one.
+    ///
+    /// Lines in the string are separated by `\n`.
+    pub fn insert_str(&mut self, text: &str) {
+        self.save_history(false);
+        self.delete_selection();
+        let line = &mut self.lines[self.cursor.row];
+        let index = byte_index(line, self.cursor.column);
+        let tail = line.split_off(index);
+        let mut new_lines = split_lines(text).into_iter();
+        line.push_str(&new_lines.next().unwrap_or_default());
+        let mut row = self.cursor.row;
+        for new_line in new_lines {
+            row += 1;
+            self.lines.insert(row, new_line);
+        }
+        let line = &mut self.lines[row];
+        let column = grapheme_count(line);
+        line.push_str(&tail);
+        self.cursor = TextPosition { row, column };
+    }
+
+    /// Splits the line at the cursor, replacing the selection if there is one.
+    pub fn insert_newline(&mut self) {
+        self.save_history(false);
+        self.delete_selection();
+        let line = &mut self.lines[self.cursor.row];
+        let tail = line.split_off(byte_index(line, self.cursor.column));
+        self.cursor = TextPosition::new(self.cursor.row + 1, 0);
+        self.lines.insert(self.cursor.row, tail);
+    }
+
+    /// Deletes the selection, or the grapheme before the cursor if nothing is selected.
+    ///
+    /// At the start of a line, the line is joined with the previous line. Returns whether any
+    /// text was deleted.
+    pub fn delete_backward(&mut self) -> bool {
+        if self.selection().is_none() && self.cursor == TextPosition::default() {
+            return false;
+        }
+        self.save_history(false);
+        if self.delete_selection() {
+            return true;
+        }
+        if self.cursor.column == 0 {
+            let line = self.lines.remove(self.cursor.row);
+            self.cursor.row -= 1;
+            let previous = &mut self.lines[self.cursor.row];
+            self.cursor.column = grapheme_count(previous);
+            previous.push_str(&line);
+        } else {
+            let line = &mut self.lines[self.cursor.row];
+            let start = byte_index(line, self.cursor.column - 1);
+            let end = byte_index(line, self.cursor.column);
+            line.replace_range(start..end, "");
+            self.cursor.column -= 1;
+        }
+        true
+    }
+
+    /// Deletes the selection, or the grapheme after the cursor if nothing is selected.
+    ///
+    /// At the end of a line, the next line is joined with the current line. Returns whether any
+    /// text was deleted.
+    pub fn delete_forward(&mut self) -> bool {
+        let line_len = grapheme_count(&self.lines[self.cursor.row]);
+        let at_end = self.cursor.row + 1 == self.lines.len() && self.cursor.column == line_len;
+        if self.selection().is_none() && at_end {
+            return false;
+        }
+        self.save_history(false);
+        if self.delete_selection() {
+            return true;
+        }
+        if self.cursor.column == line_len {
+            let next = self.lines.remove(self.cursor.row + 1);
+            self.lines[self.cursor.row].push_str(&next);
+        } else {
+            let line = &mut self.lines[self.cursor.row];
+            let start = byte_index(line, self.cursor.column);
+            let end = byte_index(line, self.cursor.column + 1);
+            line.replace_range(start..end, "");
+        }
+        true
+    }
+
+    /// Moves the cursor one grapheme to the left, wrapping to the end of the previous line.
+    #[expect(clippy::else_if_without_else)]
+    pub fn move_left(&mut self) {
+        if self.cursor.column > 0 {
+            self.cursor.column -= 1;
+        } else if self.cursor.row > 0 {
+            self.cursor.row -= 1;
+            self.cursor.column = grapheme_count(&self.lines[self.cursor.row]);
+        }
+        self.history.typing = false;
+    }
+
+    /// Moves the cursor one grapheme to the right, wrapping to the start of the next line.
+    #[expect(clippy::else_if_without_else)]
+    pub fn move_right(&mut self) {
+        if self.cursor.column < grapheme_count(&self.lines[self.cursor.row]) {
+            self.cursor.column += 1;
+        } else if self.cursor.row + 1 < self.lines.len() {
+            self.cursor = TextPosition::new(self.cursor.row + 1, 0);
+        }
+        self.history.typing = false;
+    }
+
+    /// Moves the cursor to the previous line, or to the start of the text on the first line.
+    pub fn move_up(&mut self) {
+        if self.cursor.row == 0 {
+            self.move_to_line_start();
+        } else {
+            self.set_cursor(TextPosition::new(self.cursor.row - 1, self.cursor.column));
+        }
+    }
+
+    /// Moves the cursor to the next line, or to the end of the text on the last line.
+    pub fn move_down(&mut self) {
+        if self.cursor.row + 1 == self.lines.len() {
+            self.move_to_line_end();
+        } else {
+            self.set_cursor(TextPosition::new(self.cursor.row + 1, self.cursor.column));
+        }
+    }
+
+    /// Moves the cursor to the start of the previous word.
+    #[expect(clippy::string_slice)] // byte indexes come from UnicodeSegmentation
+    pub fn move_word_left(&mut self) {
+        let line = &self.lines[self.cursor.row];
+        if self.cursor.column == 0 {
+            self.move_left();
+            return;
+        }
+        let column = line
+            .split_word_bound_indices()
+            .rev()
+            .filter(|(_, word)| !word.trim().is_empty())
+            .map(|(index, _)| grapheme_count(&line[..index]))
+            .find(|&column| column < self.cursor.column)
+            .unwrap_or(0);
+        self.set_cursor(TextPosition::new(self.cursor.row, column));
+    }
+
+    /// Moves the cursor to the end of the next word.
+    #[expect(clippy::string_slice)] // byte indexes come from UnicodeSegmentation
+    pub fn move_word_right(&mut self) {
+        let line = &self.lines[self.cursor.row];
+        let line_len = grapheme_count(line);
+        if self.cursor.column == line_len {
+            self.move_right();
+            return;
+        }
+        let column = line
+            .split_word_bound_indices()
+            .filter(|(_, word)| !word.trim().is_empty())
+            .map(|(index, word)| grapheme_count(&line[..index + word.len()]))
+            .find(|&column| column > self.cursor.column)
+            .unwrap_or(line_len);
+        self.set_cursor(TextPosition::new(self.cursor.row, column));
+    }
+
+    /// Moves the cursor to the start of the line.
+    pub fn move_to_line_start(&mut self) {
+        self.set_cursor(TextPosition::new(self.cursor.row, 0));
+    }
+
+    /// Moves the cursor to the end of the line.
+    pub fn move_to_line_end(&mut self) {
+        self.set_cursor(TextPosition::new(self.cursor.row, usize::MAX));
+    }
+
+    /// Moves the cursor to the start of the text.
+    pub fn move_to_start(&mut self) {
+        self.set_cursor(TextPosition::default());
+    }
+
+    /// Moves the cursor to the end of the text.
+    pub fn move_to_end(&mut self) {
+        self.set_cursor(TextPosition::new(usize::MAX, usize::MAX));
+    }
+
+    /// Undoes the last edit. Returns whether there was an edit to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.history.undo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.history.redo.push(current);
+        true
+    }
+
+    /// Redoes the last undone edit. Returns whether there was an edit to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.history.redo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.history.undo.push(current);
+        true
+    }
+
+    /// Replaces the text and cursor with the snapshot, returning the previous text and cursor.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let previous = Snapshot {
+            lines: core::mem::replace(&mut self.lines, snapshot.lines),
+            cursor: self.cursor,
+        };
+        self.anchor = None;
+        self.history.typing = false;
+        self.set_cursor(snapshot.cursor);
+        previous
+    }
+
+    /// Records the current text before an edit.
+    ///
+    /// Consecutive edits that type a word are recorded as a single edit.
+    fn save_history(&mut self, typing: bool) {
+        let history = &mut self.history;
+        if !(typing && history.typing) {
+            if history.undo.len() == HISTORY_LIMIT {
+                history.undo.remove(0);
+            }
+            history.undo.push(Snapshot {
+                lines: self.lines.clone(),
+                cursor: self.cursor,
+            });
+        }
+        history.redo.clear();
+        history.typing = typing;
+    }
+
+    /// Deletes the selected text, moving the cursor to the start of the selection.
+    ///
+    /// Returns whether anything was deleted.
+    #[expect(clippy::string_slice)] // byte indexes come from UnicodeSegmentation
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.anchor = None;
+        let Some((start, end)) = selection else {
+            return false;
+        };
+        let last = &self.lines[end.row];
+        let tail = last[byte_index(last, end.column)..].to_string();
+        self.lines.drain(start.row + 1..=end.row);
+        let line = &mut self.lines[start.row];
+        line.truncate(byte_index(line, start.column));
+        line.push_str(&tail);
+        self.cursor = start;
+        true
+    }
+}
+
+/// Splits the text into lines, always returning at least one line.
+fn split_lines(text: &str) -> Vec<String> {
+    if text.is_empty() {
+        return vec![String::new()];
+    }
+    text.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+        .collect()
+}
+
+/// Returns the number of grapheme clusters in the line.
+pub(crate) fn grapheme_count(line: &str) -> usize {
+    line.graphemes(true).count()
+}
+
+/// Returns the byte index of the grapheme cluster at `column`, or the length of the line if the
+/// column is past the end.
+fn byte_index(line: &str, column: usize) -> usize {
+    line.grapheme_indices(true)
+        .nth(column)
+        .map_or(line.len(), |(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let state = TextInputState::new("ab\r\ncd");
+        assert_eq!(state.lines(), ["ab", "cd"]);
+        assert_eq!(state.cursor(), TextPosition::new(1, 2));
+        assert_eq!(state.text(), "ab\ncd");
+        assert!(TextInputState::default().is_empty());
+    }
+
+    #[test]
+    fn insert_char_grapheme_aware() {
+        let mut state = TextInputState::new("e");
+        // a combining acute accent joins the previous grapheme
+        state.insert_char('\u{301}');
+        assert_eq!(state.cursor(), TextPosition::new(0, 1));
+        state.insert_char('x');
+        assert_eq!(state.text(), "e\u{301}x");
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        state.move_left();
+        state.move_left();
+        state.insert_char('a');
+        assert_eq!(state.text(), "ae\u{301}x");
+    }
+
+    #[test]
+    fn insert_str_multiline() {
+        let mut state = TextInputState::new("ad");
+        state.set_cursor(TextPosition::new(0, 1));
+        state.insert_str("b\nc");
+        assert_eq!(state.lines(), ["ab", "cd"]);
+        assert_eq!(state.cursor(), TextPosition::new(1, 1));
+    }
+
+    #[test]
+    fn insert_newline() {
+        let mut state = TextInputState::new("abcd");
+        state.set_cursor(TextPosition::new(0, 2));
+        state.insert_char('\n');
+        assert_eq!(state.lines(), ["ab", "cd"]);
+        assert_eq!(state.cursor(), TextPosition::new(1, 0));
+    }
+
+    #[test]
+    fn delete_backward() {
+        let mut state = TextInputState::new("ab\n👍c");
+        state.set_cursor(TextPosition::new(1, 1));
+        assert!(state.delete_backward());
+        assert_eq!(state.lines(), ["ab", "c"]);
+        assert!(state.delete_backward());
+        assert_eq!(state.lines(), ["abc"]);
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        state.move_to_start();
+        assert!(!state.delete_backward());
+    }
+
+    #[test]
+    fn delete_forward() {
+        let mut state = TextInputState::new("a👍\nb");
+        state.set_cursor(TextPosition::new(0, 1));
+        assert!(state.delete_forward());
+        assert_eq!(state.lines(), ["a", "b"]);
+        assert!(state.delete_forward());
+        assert_eq!(state.lines(), ["ab"]);
+        state.move_to_end();
+        assert!(!state.delete_forward());
+    }
+
+    #[test]
+    fn movement() {
+        let mut state = TextInputState::new("abc\nd");
+        state.move_to_start();
+        state.move_down();
+        assert_eq!(state.cursor(), TextPosition::new(1, 0));
+        state.move_to_line_end();
+        state.move_up();
+        assert_eq!(state.cursor(), TextPosition::new(0, 1));
+        state.move_left();
+        state.move_left();
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+        state.move_up();
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+        state.move_to_line_end();
+        state.move_right();
+        assert_eq!(state.cursor(), TextPosition::new(1, 0));
+        state.move_left();
+        assert_eq!(state.cursor(), TextPosition::new(0, 3));
+        state.move_down();
+        state.move_down();
+        assert_eq!(state.cursor(), TextPosition::new(1, 1));
+    }
+
+    #[test]
+    fn word_movement() {
+        let mut state = TextInputState::new("hello big world");
+        state.move_word_left();
+        assert_eq!(state.cursor().column, 10);
+        state.move_word_left();
+        assert_eq!(state.cursor().column, 6);
+        state.move_word_right();
+        assert_eq!(state.cursor().column, 9);
+        state.move_word_right();
+        assert_eq!(state.cursor().column, 15);
+    }
+
+    #[test]
+    fn selection() {
+        let mut state = TextInputState::new("abc\ndef");
+        state.set_cursor(TextPosition::new(0, 1));
+        state.start_selection();
+        assert_eq!(state.selection(), None);
+        state.move_down();
+        assert_eq!(
+            state.selection(),
+            Some((TextPosition::new(0, 1), TextPosition::new(1, 1)))
+        );
+        assert_eq!(state.selected_text().as_deref(), Some("bc\nd"));
+        state.insert_char('x');
+        assert_eq!(state.lines(), ["axef"]);
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn selection_backwards() {
+        let mut state = TextInputState::new("abcd");
+        state.start_selection();
+        state.move_left();
+        state.move_left();
+        assert_eq!(state.selected_text().as_deref(), Some("cd"));
+        assert!(state.delete_backward());
+        assert_eq!(state.text(), "ab");
+    }
+
+    #[test]
+    fn select_all() {
+        let mut state = TextInputState::new("ab\ncd");
+        state.select_all();
+        assert_eq!(state.selected_text().as_deref(), Some("ab\ncd"));
+        state.clear_selection();
+        assert_eq!(state.selected_text(), None);
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut state = TextInputState::new("");
+        for c in "hello world".chars() {
+            state.insert_char(c);
+        }
+        state.delete_backward();
+        assert_eq!(state.text(), "hello worl");
+        assert!(state.undo());
+        assert_eq!(state.text(), "hello world");
+        // typing a word is undone in a single step
+        assert!(state.undo());
+        assert_eq!(state.text(), "hello ");
+        assert!(state.undo());
+        assert_eq!(state.text(), "hello");
+        assert!(state.undo());
+        assert_eq!(state.text(), "");
+        assert!(!state.undo());
+        assert!(state.redo());
+        assert_eq!(state.text(), "hello");
+        assert_eq!(state.cursor(), TextPosition::new(0, 5));
+        state.insert_char('!');
+        assert!(!state.redo());
+    }
+
+    #[test]
+    fn undo_set_text() {
+        let mut state = TextInputState::new("old");
+        state.set_text("new");
+        assert!(state.undo());
+        assert_eq!(state.text(), "old");
+    }
+
+    #[test]
+    fn history_limit() {
+        let mut state = TextInputState::new("");
+        for _ in 0..HISTORY_LIMIT + 10 {
+            state.insert_char(' ');
+        }
+        let mut undone = 0;
+        while state.undo() {
+            undone += 1;
+        }
+        assert_eq!(undone, HISTORY_LIMIT);
+        assert_eq!(state.text().len(), 10);
+    }
+}
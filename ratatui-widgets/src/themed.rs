@@ -0,0 +1,24 @@
+//! Rendering with the theme set on a widget.
+
+use core::mem;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::style::Theme;
+
+/// Renders with the theme of the buffer replaced by `theme` if it is `Some`.
+///
+/// The theme set on a widget applies to the widgets nested in it (e.g. the block of a list), which
+/// read their default styles from the theme of the buffer.
+pub(crate) fn with_theme<R>(
+    buf: &mut Buffer,
+    theme: Option<&Theme>,
+    render: impl FnOnce(&mut Buffer) -> R,
+) -> R {
+    let Some(theme) = theme else {
+        return render(buf);
+    };
+    let previous = mem::replace(&mut buf.theme, *theme);
+    let result = render(buf);
+    buf.theme = previous;
+    result
+}
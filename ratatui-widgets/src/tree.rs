@@ -0,0 +1,647 @@
+//! The [`Tree`] widget is used to display hierarchical data with expandable nodes.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::symbols::line;
+use ratatui_core::text::Line;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_width::UnicodeWidthStr;
+
+pub use self::item::TreeItem;
+pub use self::state::TreeState;
+use crate::block::{Block, BlockExt};
+use crate::table::HighlightSpacing;
+
+mod item;
+mod state;
+
+/// A widget to display a tree of items, where each item can be opened to display its children
+///
+/// A tree is a collection of root [`TreeItem`]s, each of which can have children of its own. Which
+/// nodes are opened, which node is selected and how far the tree is scrolled is stored in a
+/// [`TreeState`], which also provides keyboard-agnostic methods to navigate the tree such as
+/// [`TreeState::toggle`], [`TreeState::select_parent`] and [`TreeState::select_first_child`].
+///
+/// Each visible node takes one row. Nested nodes are indented and connected to their parent with
+/// guides drawn using the characters of a [`line::Set`] (see [`Tree::guide_symbols`]).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::{Block, Tree, TreeItem, TreeState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let items = [
+///     TreeItem::new("src").children([
+///         TreeItem::new("widgets").children(["list.rs", "tree.rs"]),
+///         TreeItem::new("lib.rs"),
+///     ]),
+///     TreeItem::new("Cargo.toml"),
+/// ];
+/// let tree = Tree::new(items)
+///     .block(Block::bordered().title("Files"))
+///     .highlight_style(Style::new().reversed())
+///     .highlight_symbol(">> ");
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default().with_opened([vec![0]]);
+/// state.select_first();
+///
+/// frame.render_stateful_widget(tree, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Tree<'a> {
+    /// An optional block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The root items of the tree
+    items: Vec<TreeItem<'a>>,
+    /// Style used as a base style for the widget
+    style: Style,
+    /// Style used to render the selected item
+    highlight_style: Style,
+    /// Symbol in front of the selected item
+    highlight_symbol: Option<Line<'a>>,
+    /// Decides when to allocate spacing for the selection symbol
+    highlight_spacing: HighlightSpacing,
+    /// Style used to render the indentation guides
+    guide_style: Style,
+    /// Characters used to draw the indentation guides
+    guide_symbols: line::Set<'a>,
+    /// Symbol in front of an opened item that has children
+    opened_symbol: &'a str,
+    /// Symbol in front of a closed item that has children
+    closed_symbol: &'a str,
+}
+
+impl Default for Tree<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            items: Vec::new(),
+            style: Style::default(),
+            highlight_style: Style::default(),
+            highlight_symbol: None,
+            highlight_spacing: HighlightSpacing::default(),
+            guide_style: Style::default(),
+            guide_symbols: line::NORMAL,
+            opened_symbol: "▼ ",
+            closed_symbol: "▶ ",
+        }
+    }
+}
+
+impl<'a> Tree<'a> {
+    /// Creates a new tree from the given root items
+    ///
+    /// `items` accepts any iterator whose items can be converted into a [`TreeItem`], such as
+    /// strings or [`Line`]s.
+    pub fn new<T>(items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<TreeItem<'a>>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the root items of the tree
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<T>(mut self, items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<TreeItem<'a>>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Wraps the tree with a custom [`Block`] widget
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected item
+    ///
+    /// This style is patched over the style of the item, the guides and the highlight symbol.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the symbol to be displayed in front of the selected item
+    ///
+    /// By default there is no highlight symbol.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_symbol<L: Into<Line<'a>>>(mut self, highlight_symbol: L) -> Self {
+        self.highlight_symbol = Some(highlight_symbol.into());
+        self
+    }
+
+    /// Sets when to show the highlight spacing
+    ///
+    /// See [`HighlightSpacing`] about which variant affects spacing in which way.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_spacing(mut self, value: HighlightSpacing) -> Self {
+        self.highlight_spacing = value;
+        self
+    }
+
+    /// Sets the style of the indentation guides
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn guide_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.guide_style = style.into();
+        self
+    }
+
+    /// Sets the characters used to draw the indentation guides
+    ///
+    /// The guides use the `vertical`, `vertical_right`, `bottom_left` and `horizontal` characters
+    /// of the set. Defaults to [`line::NORMAL`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::symbols::line;
+    /// use ratatui::widgets::Tree;
+    ///
+    /// let tree = Tree::new(["a", "b"]).guide_symbols(line::ROUNDED);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn guide_symbols(mut self, symbols: line::Set<'a>) -> Self {
+        self.guide_symbols = symbols;
+        self
+    }
+
+    /// Sets the symbols displayed in front of items that have children
+    ///
+    /// `opened` is displayed when the children of the item are visible and `closed` when they are
+    /// hidden. Items without children are padded to the same width. Defaults to `"▼ "` and
+    /// `"▶ "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn node_symbols(mut self, opened: &'a str, closed: &'a str) -> Self {
+        self.opened_symbol = opened;
+        self.closed_symbol = closed;
+        self
+    }
+
+    /// Returns the number of root items in the tree
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if the tree contains no items
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Collects the rows that are visible with the given opened nodes, in display order
+    fn visible_rows<'t>(&'t self, state: &TreeState) -> Vec<Row<'a, 't>> {
+        fn visit<'a, 't>(
+            items: &'t [TreeItem<'a>],
+            path: &[usize],
+            ancestors_last: &[bool],
+            state: &TreeState,
+            rows: &mut Vec<Row<'a, 't>>,
+        ) {
+            for (index, item) in items.iter().enumerate() {
+                let mut row_path = path.to_vec();
+                row_path.push(index);
+                let row = Row {
+                    item,
+                    path: row_path,
+                    ancestors_last: ancestors_last.to_vec(),
+                    is_last: index + 1 == items.len(),
+                };
+                if item.has_children() && state.is_open(&row.path) {
+                    let path = row.path.clone();
+                    let mut ancestors_last = row.ancestors_last.clone();
+                    // the guides start below the root items
+                    if path.len() > 1 {
+                        ancestors_last.push(row.is_last);
+                    }
+                    rows.push(row);
+                    visit(&item.children, &path, &ancestors_last, state, rows);
+                } else {
+                    rows.push(row);
+                }
+            }
+        }
+        let mut rows = Vec::new();
+        visit(&self.items, &[], &[], state, &mut rows);
+        rows
+    }
+
+    /// Returns the indentation guides and node symbol displayed in front of a row
+    fn prefix(&self, row: &Row) -> String {
+        let symbols = &self.guide_symbols;
+        let mut prefix = String::new();
+        for &is_last in &row.ancestors_last {
+            if is_last {
+                prefix.push_str("   ");
+            } else {
+                prefix.push_str(symbols.vertical);
+                prefix.push_str("  ");
+            }
+        }
+        if row.path.len() > 1 {
+            let connector = if row.is_last {
+                symbols.bottom_left
+            } else {
+                symbols.vertical_right
+            };
+            prefix.push_str(connector);
+            prefix.push_str(symbols.horizontal);
+            prefix.push(' ');
+        }
+        prefix
+    }
+}
+
+/// A visible row of the tree
+#[derive(Debug, Clone)]
+struct Row<'a, 't> {
+    item: &'t TreeItem<'a>,
+    path: Vec<usize>,
+    /// For each ancestor below the root level, whether it is the last of its siblings
+    ancestors_last: Vec<bool>,
+    is_last: bool,
+}
+
+impl Widget for Tree<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Tree<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TreeState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Tree<'_> {
+    type State = TreeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Tree<'_> {
+    type State = TreeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let tree_area = self.block.inner_if_some(area);
+
+        let rows = self.visible_rows(state);
+        state.visible = Some(
+            rows.iter()
+                .map(|row| (row.path.clone(), row.item.has_children()))
+                .collect(),
+        );
+
+        if rows.is_empty() {
+            state.select(None);
+            return;
+        }
+
+        // If the selected node is hidden inside a closed node, select the closest visible
+        // ancestor, and if it does not exist, select the last row
+        if let Some(selected) = state.selected.as_mut() {
+            while selected.len() > 1 && !rows.iter().any(|row| row.path == *selected) {
+                selected.pop();
+            }
+            if !rows.iter().any(|row| row.path == *selected) {
+                selected.clone_from(&rows[rows.len() - 1].path);
+            }
+        }
+        let selected_index = state
+            .selected
+            .as_ref()
+            .and_then(|selected| rows.iter().position(|row| row.path == *selected));
+
+        if tree_area.is_empty() {
+            return;
+        }
+
+        // Important: this changes the state's offset so that the selected row is visible
+        let height = tree_area.height as usize;
+        state.offset = state.offset.min(rows.len() - 1);
+        if let Some(index) = selected_index {
+            state.offset = state
+                .offset
+                .clamp((index + 1).saturating_sub(height), index);
+        }
+
+        let highlight_symbol = self.highlight_symbol.clone().unwrap_or_default();
+        let highlight_symbol_width = highlight_symbol.width() as u16;
+        let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
+        // flat trees don't need any space for the node symbols
+        let node_symbol_width = if self.items.iter().any(TreeItem::has_children) {
+            self.opened_symbol.width().max(self.closed_symbol.width())
+        } else {
+            0
+        };
+
+        let visible = rows.iter().enumerate().skip(state.offset);
+        for ((i, row), y) in visible.zip(tree_area.top()..tree_area.bottom()) {
+            let is_selected = selected_index == Some(i);
+            let row_area = Rect::new(tree_area.x, y, tree_area.width, 1);
+            buf.set_style(row_area, row.item.style);
+
+            let mut x = row_area.x;
+            let right = row_area.right();
+            if selection_spacing {
+                let symbol_area = Rect::new(x, y, highlight_symbol_width, 1).intersection(row_area);
+                if is_selected {
+                    highlight_symbol.clone().render(symbol_area, buf);
+                }
+                x = symbol_area.right();
+            }
+            let prefix = self.prefix(row);
+            (x, _) = buf.set_stringn(
+                x,
+                y,
+                &prefix,
+                right.saturating_sub(x) as usize,
+                self.guide_style,
+            );
+            let node_symbol = if !row.item.has_children() {
+                ""
+            } else if state.is_open(&row.path) {
+                self.opened_symbol
+            } else {
+                self.closed_symbol
+            };
+            // the symbols are padded by their display width, which `format!` does not know
+            let padding = node_symbol_width.saturating_sub(node_symbol.width());
+            let node_symbol = format!("{node_symbol}{:padding$}", "");
+            (x, _) = buf.set_stringn(
+                x,
+                y,
+                &node_symbol,
+                right.saturating_sub(x) as usize,
+                Style::default(),
+            );
+            let content_area = Rect::new(x, y, right.saturating_sub(x), 1);
+            (&row.item.content).render(content_area, buf);
+
+            if is_selected {
+                buf.set_style(row_area, self.highlight_style);
+            }
+        }
+    }
+}
+
+impl Styled for Tree<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    fn items() -> Vec<TreeItem<'static>> {
+        vec![
+            TreeItem::new("src").children([
+                TreeItem::new("widgets").children(["list.rs", "tree.rs"]),
+                TreeItem::new("lib.rs"),
+            ]),
+            TreeItem::new("Cargo.toml"),
+        ]
+    }
+
+    fn render(tree: &Tree, state: &mut TreeState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(tree, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn closed() {
+        let tree = Tree::new(items());
+        let buf = render(&tree, &mut TreeState::default(), 16, 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▶ src          ", "  Cargo.toml    ", "                "])
+        );
+    }
+
+    #[test]
+    fn opened() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default().with_opened([vec![0], vec![0, 0]]);
+        let buf = render(&tree, &mut state, 20, 6);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "▼ src               ",
+                "├─ ▼ widgets        ",
+                "│  ├─   list.rs     ",
+                "│  └─   tree.rs     ",
+                "└─   lib.rs         ",
+                "  Cargo.toml        ",
+            ])
+        );
+        assert_eq!(
+            state.visible.unwrap(),
+            [
+                (vec![0], true),
+                (vec![0, 0], true),
+                (vec![0, 0, 0], false),
+                (vec![0, 0, 1], false),
+                (vec![0, 1], false),
+                (vec![1], false),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_in_closed_node_is_hidden() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default().with_opened([vec![0, 0]]);
+        let buf = render(&tree, &mut state, 16, 2);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▶ src          ", "  Cargo.toml    "])
+        );
+    }
+
+    #[test]
+    fn guide_symbols() {
+        let tree = Tree::new(items())
+            .guide_symbols(line::DOUBLE)
+            .node_symbols("- ", "+ ");
+        let mut state = TreeState::default().with_opened([vec![0]]);
+        let buf = render(&tree, &mut state, 16, 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["- src           ", "╠═ + widgets    ", "╚═   lib.rs     "])
+        );
+    }
+
+    #[test]
+    fn wide_node_symbols() {
+        let tree = Tree::new(items()).node_symbols("📂 ", "▶");
+        let mut state = TreeState::default().with_opened([vec![0]]);
+        let buf = render(&tree, &mut state, 16, 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["📂 src          ", "├─ ▶  widgets   ", "└─    lib.rs    "])
+        );
+    }
+
+    #[test]
+    fn highlight() {
+        let tree = Tree::new(items())
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().yellow())
+            .guide_style(Style::new().dark_gray());
+        let mut state = TreeState::default()
+            .with_opened([vec![0]])
+            .with_selected(Some(vec![0, 1]));
+        let buf = render(&tree, &mut state, 14, 4);
+        let mut expected = Buffer::with_lines([
+            "  ▼ src       ",
+            "  ├─ ▶ widgets",
+            ">>└─   lib.rs ",
+            "    Cargo.toml",
+        ]);
+        expected.set_style(Rect::new(2, 1, 3, 2), Style::new().dark_gray());
+        expected.set_style(Rect::new(0, 2, 14, 1), Style::new().yellow());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn item_style() {
+        let tree = Tree::new([TreeItem::new("a").red(), TreeItem::new("b")]);
+        let buf = render(&tree, &mut TreeState::default(), 3, 2);
+        let mut expected = Buffer::with_lines(["a  ", "b  "]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn scrolls_to_selected() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default()
+            .with_opened([vec![0], vec![0, 0]])
+            .with_selected(Some(vec![0, 1]));
+        let buf = render(&tree, &mut state, 15, 2);
+        assert_eq!(state.offset(), 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["│  └─   tree.rs", "└─   lib.rs    "])
+        );
+
+        state.select(Some(vec![0]));
+        render(&tree, &mut state, 14, 2);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn closing_selects_visible_ancestor() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default()
+            .with_opened([vec![0]])
+            .with_selected(Some(vec![0, 0, 1]));
+        render(&tree, &mut state, 14, 4);
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+
+        state.close(&[0]);
+        render(&tree, &mut state, 14, 4);
+        assert_eq!(state.selected(), Some([0].as_slice()));
+
+        state.select(Some(vec![5]));
+        render(&tree, &mut state, 14, 4);
+        assert_eq!(state.selected(), Some([1].as_slice()));
+    }
+
+    #[test]
+    fn empty() {
+        let tree = Tree::default();
+        let mut state = TreeState::default().with_selected(Some(vec![0]));
+        render(&tree, &mut state, 4, 1);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn navigation_after_render() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default();
+        state.select_first();
+        render(&tree, &mut state, 14, 4);
+        state.select_first_child();
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+
+        render(&tree, &mut state, 14, 4);
+        state.select_next();
+        assert_eq!(state.selected(), Some([0, 1].as_slice()));
+        state.select_parent();
+        state.toggle_selected();
+        render(&tree, &mut state, 14, 4);
+        state.select_last();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::Line;
+
+/// A single node in a [`Tree`]
+///
+/// Each item displays a single [`Line`] and can have any number of children, which are displayed
+/// below it, indented, when the item is opened (see [`TreeState::open`]).
+///
+/// You can set the style of an item with [`TreeItem::style`] or using the [`Stylize`] trait. This
+/// [`Style`] is applied to the whole row of the item.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::TreeItem;
+///
+/// let item = TreeItem::new("src").blue().children([
+///     TreeItem::new("main.rs"),
+///     TreeItem::new("lib.rs"),
+/// ]);
+/// assert!(item.has_children());
+/// ```
+///
+/// [`Tree`]: crate::tree::Tree
+/// [`TreeState::open`]: crate::tree::TreeState::open
+/// [`Stylize`]: ratatui_core::style::Stylize
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TreeItem<'a> {
+    pub(crate) content: Line<'a>,
+    pub(crate) style: Style,
+    pub(crate) children: Vec<Self>,
+}
+
+impl<'a> TreeItem<'a> {
+    /// Creates a new [`TreeItem`] without children
+    ///
+    /// The `content` parameter accepts any value that can be converted into a [`Line`].
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            content: content.into(),
+            style: Style::default(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the children of the item
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn children<T>(mut self, children: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Self>,
+    {
+        self.children = children.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the item style
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns whether the item has children
+    pub fn has_children(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+impl<'a, T> From<T> for TreeItem<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Styled for TreeItem<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let item = TreeItem::new("root");
+        assert_eq!(item.content, Line::from("root"));
+        assert_eq!(item.style, Style::default());
+        assert!(!item.has_children());
+    }
+
+    #[test]
+    fn children() {
+        let item = TreeItem::new("root").children(["a", "b"]);
+        assert_eq!(item.children, [TreeItem::new("a"), TreeItem::new("b")]);
+        assert!(item.has_children());
+    }
+
+    #[test]
+    fn style() {
+        let item = TreeItem::new("root").red().on_blue();
+        assert_eq!(item.style, Style::new().red().on_blue());
+    }
+}
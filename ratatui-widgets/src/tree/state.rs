@@ -0,0 +1,444 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+/// State of the [`Tree`] widget
+///
+/// Nodes of a tree are identified by their path: the index of the node in its parent's children,
+/// preceded by the indexes of each of its ancestors. For example, `[1, 0]` is the first child of the
+/// second root item.
+///
+/// The state consists of three parts:
+/// - [`offset`]: the index of the first visible row to be displayed
+/// - [`selected`]: the path of the selected node, which can be `None` if no node is selected
+/// - [`opened`]: the paths of the nodes whose children are displayed
+///
+/// The navigation methods such as [`select_next`] and [`select_first_child`] work on the rows
+/// that were visible when the tree was last rendered, which makes them independent of the keys
+/// or mouse events that trigger them.
+///
+/// [`offset`]: TreeState::offset()
+/// [`selected`]: TreeState::selected()
+/// [`opened`]: TreeState::opened()
+/// [`select_next`]: TreeState::select_next
+/// [`select_first_child`]: TreeState::select_first_child
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::Frame;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Tree, TreeItem, TreeState};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let items = [TreeItem::new("src").children(["main.rs", "lib.rs"])];
+/// let tree = Tree::new(items);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default();
+///
+/// state.open(vec![0]); // display the children of the first item
+/// state.select(Some(vec![0, 1])); // select "lib.rs"
+///
+/// frame.render_stateful_widget(tree, area, &mut state);
+/// # }
+/// ```
+///
+/// [`Tree`]: super::Tree
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeState {
+    pub(crate) offset: usize,
+    pub(crate) selected: Option<Vec<usize>>,
+    pub(crate) opened: BTreeSet<Vec<usize>>,
+    /// The visible rows of the last render, as the node path and whether the node has children,
+    /// or `None` if the tree has not been rendered yet
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) visible: Option<Vec<(Vec<usize>, bool)>>,
+}
+
+impl TreeState {
+    /// Sets the index of the first visible row to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the path of the selected node
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<Vec<usize>>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Opens the nodes at the given paths
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TreeState;
+    ///
+    /// let state = TreeState::default().with_opened([vec![0], vec![0, 2]]);
+    /// assert!(state.is_open(&[0, 2]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_opened<I: IntoIterator<Item = Vec<usize>>>(mut self, opened: I) -> Self {
+        self.opened.extend(opened);
+        self
+    }
+
+    /// Index of the first visible row to be displayed
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first visible row to be displayed
+    pub const fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Path of the selected node
+    ///
+    /// Returns `None` if no node is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TreeState;
+    ///
+    /// let state = TreeState::default().with_selected(Some(vec![1, 0]));
+    /// assert_eq!(state.selected(), Some([1, 0].as_slice()));
+    /// ```
+    pub fn selected(&self) -> Option<&[usize]> {
+        self.selected.as_deref()
+    }
+
+    /// Sets the path of the selected node
+    ///
+    /// Set to `None` if no node is selected. This will also reset the offset to `0`.
+    pub fn select(&mut self, path: Option<Vec<usize>>) {
+        self.selected = path;
+        if self.selected.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Paths of the opened nodes, in depth first order
+    pub fn opened(&self) -> impl Iterator<Item = &[usize]> {
+        self.opened.iter().map(Vec::as_slice)
+    }
+
+    /// Returns whether the node at the given path is open
+    pub fn is_open(&self, path: &[usize]) -> bool {
+        self.opened.contains(path)
+    }
+
+    /// Opens the node at the given path so that its children are displayed
+    ///
+    /// Returns `false` if the node was already open. Opening a node does not open its ancestors,
+    /// so its children are only displayed once all the ancestors are open as well.
+    pub fn open(&mut self, path: Vec<usize>) -> bool {
+        !path.is_empty() && self.opened.insert(path)
+    }
+
+    /// Closes the node at the given path so that its children are hidden
+    ///
+    /// Returns `false` if the node was not open. The opened descendants of the node are
+    /// remembered and shown again when the node is reopened.
+    pub fn close(&mut self, path: &[usize]) -> bool {
+        self.opened.remove(path)
+    }
+
+    /// Opens the node at the given path if it is closed, or closes it if it is open
+    pub fn toggle(&mut self, path: Vec<usize>) {
+        if !self.close(&path) {
+            self.open(path);
+        }
+    }
+
+    /// Toggles the selected node
+    ///
+    /// Does nothing if no node is selected.
+    pub fn toggle_selected(&mut self) {
+        if let Some(path) = self.selected.clone() {
+            self.toggle(path);
+        }
+    }
+
+    /// Closes all the nodes
+    pub fn close_all(&mut self) {
+        self.opened.clear();
+    }
+
+    /// Selects the next visible node, or the first one if no node is selected
+    ///
+    /// Does nothing if the last node is already selected.
+    pub fn select_next(&mut self) {
+        let index = self
+            .selected_index()
+            .map_or(0, |index| index.saturating_add(1));
+        self.select_visible(index);
+    }
+
+    /// Selects the previous visible node, or the last one if no node is selected
+    ///
+    /// Does nothing if the first node is already selected.
+    pub fn select_previous(&mut self) {
+        let index = self
+            .selected_index()
+            .map_or(usize::MAX, |index| index.saturating_sub(1));
+        self.select_visible(index);
+    }
+
+    /// Selects the first node
+    ///
+    /// This works before the tree has been rendered, as the first node of a tree is always `[0]`.
+    /// Once an empty tree has been rendered, no node is selected.
+    pub fn select_first(&mut self) {
+        let first = match &self.visible {
+            Some(visible) => visible.first().map(|(path, _)| path.clone()),
+            None => Some(vec![0]),
+        };
+        self.select(first);
+    }
+
+    /// Selects the last visible node
+    ///
+    /// Does nothing until the tree has been rendered.
+    pub fn select_last(&mut self) {
+        self.select_visible(usize::MAX);
+    }
+
+    /// Selects the parent of the selected node
+    ///
+    /// Does nothing if no node or a root node is selected.
+    pub fn select_parent(&mut self) {
+        if let Some(path) = self.selected.as_mut().filter(|path| path.len() > 1) {
+            path.pop();
+        }
+    }
+
+    /// Opens the selected node and selects its first child
+    ///
+    /// Does nothing if no node is selected or if the selected node has no children.
+    pub fn select_first_child(&mut self) {
+        let Some(path) = self.selected.clone() else {
+            return;
+        };
+        let has_children = self
+            .visible_rows()
+            .iter()
+            .any(|(visible, has_children)| *visible == path && *has_children);
+        if has_children {
+            self.open(path.clone());
+            let mut child = path;
+            child.push(0);
+            self.selected = Some(child);
+        }
+    }
+
+    /// Index of the selected node in the visible rows of the last render
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        self.visible_rows()
+            .iter()
+            .position(|(path, _)| path == selected)
+    }
+
+    /// The visible rows of the last render, or none if the tree has not been rendered yet
+    fn visible_rows(&self) -> &[(Vec<usize>, bool)] {
+        self.visible.as_deref().unwrap_or_default()
+    }
+
+    /// Selects the visible row at `index`, clamped to the last row
+    fn select_visible(&mut self, index: usize) {
+        let visible = self.visible_rows();
+        let index = index.min(visible.len().saturating_sub(1));
+        if let Some((path, _)) = visible.get(index) {
+            self.selected = Some(path.clone());
+        }
+    }
+}
+
+/// Compares the offsets, selections and opened nodes of the states, ignoring the rows of the last
+/// render, so that equality does not depend on whether the tree was rendered.
+impl PartialEq for TreeState {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.selected == other.selected
+            && self.opened == other.opened
+    }
+}
+
+impl Eq for TreeState {}
+
+impl Hash for TreeState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+        self.selected.hash(state);
+        self.opened.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// A state whose last render showed `[0]`, `[0, 0]`, `[0, 1]` and `[1]`
+    fn rendered() -> TreeState {
+        TreeState {
+            opened: BTreeSet::from([vec![0]]),
+            visible: Some(vec![
+                (vec![0], true),
+                (vec![0, 0], false),
+                (vec![0, 1], true),
+                (vec![1], false),
+            ]),
+            ..TreeState::default()
+        }
+    }
+
+    #[test]
+    fn select() {
+        let mut state = TreeState::default().with_offset(2);
+        state.select(Some(vec![1, 2]));
+        assert_eq!(state.selected(), Some([1, 2].as_slice()));
+        assert_eq!(state.offset(), 2);
+
+        state.select(None);
+        assert_eq!(state.selected(), None);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn open_close() {
+        let mut state = TreeState::default();
+        assert!(state.open(vec![0, 1]));
+        assert!(!state.open(vec![0, 1]));
+        assert!(!state.open(vec![]));
+        assert!(state.is_open(&[0, 1]));
+
+        assert!(state.close(&[0, 1]));
+        assert!(!state.close(&[0, 1]));
+        assert!(!state.is_open(&[0, 1]));
+    }
+
+    #[test]
+    fn toggle() {
+        let mut state = TreeState::default();
+        state.toggle(vec![2]);
+        assert!(state.is_open(&[2]));
+        state.toggle(vec![2]);
+        assert!(!state.is_open(&[2]));
+
+        state.toggle_selected();
+        assert_eq!(state.opened().count(), 0);
+        state.select(Some(vec![1]));
+        state.toggle_selected();
+        assert!(state.is_open(&[1]));
+    }
+
+    #[test]
+    fn close_all() {
+        let mut state = TreeState::default().with_opened([vec![0], vec![0, 1]]);
+        assert_eq!(
+            state.opened().collect::<Vec<_>>(),
+            [[0].as_slice(), &[0, 1]]
+        );
+        state.close_all();
+        assert_eq!(state.opened().count(), 0);
+    }
+
+    #[test]
+    fn select_next_previous() {
+        let mut state = rendered();
+        state.select_next();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+        state.select_next();
+        assert_eq!(state.selected(), Some([0, 0].as_slice()));
+        state.select_previous();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+        state.select_previous();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+
+        state.select(None);
+        state.select_previous();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+        state.select_next();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+    }
+
+    #[test]
+    fn select_first_last() {
+        let mut state = TreeState::default();
+        state.select_last();
+        assert_eq!(state.selected(), None);
+        state.select_first();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+
+        let mut state = rendered();
+        state.select_last();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+        state.select_first();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+
+        // an empty tree was rendered
+        let mut state = TreeState {
+            visible: Some(vec![]),
+            ..TreeState::default()
+        };
+        state.select_first();
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn eq_ignores_rendered_rows() {
+        let state = TreeState::default().with_selected(Some(vec![0]));
+        let rendered = rendered().with_selected(Some(vec![0]));
+        assert_eq!(
+            rendered,
+            state.clone().with_opened([vec![0]]),
+            "the visible rows are not compared"
+        );
+        assert_ne!(rendered, state);
+    }
+
+    #[test]
+    fn select_parent() {
+        let mut state = TreeState::default();
+        state.select_parent();
+        assert_eq!(state.selected(), None);
+
+        state.select(Some(vec![0, 1, 2]));
+        state.select_parent();
+        assert_eq!(state.selected(), Some([0, 1].as_slice()));
+        state.select_parent();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+        state.select_parent();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+    }
+
+    #[test]
+    fn select_first_child() {
+        let mut state = rendered().with_selected(Some(vec![0, 1]));
+        state.select_first_child();
+        assert!(state.is_open(&[0, 1]));
+        assert_eq!(state.selected(), Some([0, 1, 0].as_slice()));
+
+        let mut state = rendered().with_selected(Some(vec![1]));
+        state.select_first_child();
+        assert!(!state.is_open(&[1]));
+        assert_eq!(state.selected(), Some([1].as_slice()));
+    }
+}
@@ -7,6 +7,7 @@ pub mod main {
     pub mod list;
     pub mod paragraph;
     pub mod rect;
+    pub mod scroll;
     pub mod sparkline;
     pub mod table;
 }
@@ -20,6 +21,7 @@ criterion::criterion_main!(
     list::benches,
     paragraph::benches,
     rect::benches,
+    scroll::benches,
     sparkline::benches,
     table::benches,
     constraints::benches,
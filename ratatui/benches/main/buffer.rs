@@ -1,11 +1,21 @@
 use std::hint::black_box;
 
 use criterion::{BenchmarkId, Criterion};
+use ratatui::backend::{Backend, CrosstermBackend};
 use ratatui::buffer::{Buffer, Cell};
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Style, Stylize};
 use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Widget, Wrap};
 
-criterion::criterion_group!(benches, empty, filled, with_lines);
+criterion::criterion_group!(
+    benches,
+    empty,
+    filled,
+    with_lines,
+    diff_bytes,
+    render_panels
+);
 
 const fn rect(size: u16) -> Rect {
     Rect::new(0, 0, size, size)
@@ -58,3 +68,122 @@ fn with_lines(c: &mut Criterion) {
     }
     group.finish();
 }
+
+/// Compares the time taken to draw the changes between two frames of a typical 80x24 terminal,
+/// cell by cell (`naive`) or planned with a cost model (`planned`).
+///
+/// The number of bytes written for the same scenarios is checked by the
+/// `backend_crossterm_planned_diff_writes_fewer_bytes` test.
+fn diff_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer/diff_bytes");
+    let area = Rect::new(0, 0, 80, 24);
+    let text = fakeit::words::paragraph(8, 6, 12, "\n".into());
+    let frame = |text: &str, scroll: u16| {
+        let mut buffer = Buffer::empty(area);
+        Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .scroll((scroll, 0))
+            .block(Block::bordered().title("Log".bold()).blue())
+            .render(area, &mut buffer);
+        buffer
+    };
+    let mut styled = frame(&text, 0);
+    styled.set_style(Rect::new(1, 4, 78, 1), Style::new().reversed());
+    let scenarios = [
+        ("first_frame", Buffer::empty(area), frame(&text, 0)),
+        (
+            "edit",
+            frame(&text, 0),
+            frame(&text.replacen(' ', "_", 5), 0),
+        ),
+        ("scroll", frame(&text, 0), frame(&text, 1)),
+        ("highlight", frame(&text, 0), styled),
+        ("clear", frame(&text, 0), frame("", 0)),
+    ];
+    for (name, previous, next) in &scenarios {
+        let updates = previous.diff(next);
+        for mode in ["naive", "planned"] {
+            let draw = |output: &mut Vec<u8>| {
+                let mut backend = CrosstermBackend::new(output);
+                if mode == "planned" {
+                    backend.draw_diff(next, &updates, Some(area.width)).unwrap();
+                } else {
+                    backend.draw(updates.iter().copied()).unwrap();
+                }
+            };
+            let mut output = Vec::new();
+            draw(&mut output);
+            let capacity = output.len();
+            group.bench_function(BenchmarkId::new(mode, name), |b| {
+                b.iter(|| draw(black_box(&mut Vec::with_capacity(capacity))));
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Compares rendering the panels of a dashboard one after another (`sequential`) with rendering
+/// them on scoped threads into disjoint views of the buffer (`parallel`).
+///
+/// Each panel is a bordered paragraph of wrapped text, and the dashboard is a square grid of 4, 16
+/// or 64 panels on a 240x80 screen.
+fn render_panels(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer/render_panels");
+    let area = Rect::new(0, 0, 240, 80);
+    let text = fakeit::words::paragraph(40, 8, 12, "\n".into());
+    let threads = std::thread::available_parallelism().map_or(4, usize::from);
+    for side in [2, 4, 8] {
+        let count = side * side;
+        let rows = Layout::vertical(vec![Constraint::Fill(1); side]).split(area);
+        let panels: Vec<Rect> = rows
+            .iter()
+            .flat_map(|&row| {
+                Layout::horizontal(vec![Constraint::Fill(1); side])
+                    .split(row)
+                    .to_vec()
+            })
+            .collect();
+        let panel = |index: usize| {
+            Paragraph::new(text.as_str())
+                .wrap(Wrap { trim: true })
+                .block(Block::bordered().title(format!("Panel {index}").bold()))
+        };
+        group.bench_with_input(
+            BenchmarkId::new("sequential", count),
+            &panels,
+            |b, panels| {
+                b.iter(|| {
+                    let mut buffer = Buffer::empty(area);
+                    for (index, &area) in panels.iter().enumerate() {
+                        panel(index).render(area, &mut buffer);
+                    }
+                    black_box(buffer)
+                });
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("parallel", count), &panels, |b, panels| {
+            b.iter(|| {
+                let mut buffer = Buffer::empty(area);
+                let mut views: Vec<_> = buffer
+                    .split_mut(panels)
+                    .unwrap()
+                    .into_iter()
+                    .enumerate()
+                    .collect();
+                let chunk_size = views.len().div_ceil(threads);
+                std::thread::scope(|scope| {
+                    for chunk in views.chunks_mut(chunk_size) {
+                        scope.spawn(|| {
+                            for (index, view) in chunk {
+                                view.render_widget(panel(*index));
+                            }
+                        });
+                    }
+                });
+                drop(views);
+                black_box(buffer)
+            });
+        });
+    }
+    group.finish();
+}
@@ -0,0 +1,68 @@
+use std::hint::black_box;
+use std::io::{self, Sink};
+
+use criterion::{BatchSize, BenchmarkId, Criterion};
+use ratatui::backend::{CrosstermBackend, WindowSize};
+use ratatui::layout::Size;
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::{Terminal, TerminalOptions};
+
+criterion::criterion_group!(benches, scroll);
+
+/// Compares the time taken to draw a frame in which a log scrolled by one row, when the moved rows
+/// are drawn again (`redraw`, with [`TerminalOptions::redraw_moved_rows`]) or scrolled (`scroll`).
+///
+/// The `main_screen` log has a border, so the first row of the screen does not move, and the
+/// `alternate_screen` log fills the screen, so its first row is scrolled too.
+///
+/// Rows are only scrolled with the `scrolling-regions` feature, so run this with
+/// `cargo bench --features scrolling-regions --bench main -- terminal/scroll`. The bytes written
+/// by both methods are compared by the `backend_crossterm_redraws_moved_rows` test.
+fn scroll(c: &mut Criterion) {
+    let mut group = c.benchmark_group("terminal/scroll");
+    let text = fakeit::words::paragraph(400, 1, 8, "\n".into());
+    for (width, height) in [(80, 24), (240, 80)] {
+        for screen in ["main_screen", "alternate_screen"] {
+            let log = |scroll: u16| {
+                let log = Paragraph::new(text.as_str()).scroll((scroll, 0));
+                if screen == "main_screen" {
+                    log.block(Block::bordered().title("Log"))
+                } else {
+                    log
+                }
+            };
+            for mode in ["redraw", "scroll"] {
+                let options = TerminalOptions {
+                    alternate_screen: screen == "alternate_screen",
+                    redraw_moved_rows: mode == "redraw",
+                    ..TerminalOptions::default()
+                };
+                let setup = || {
+                    let backend = CrosstermBackend::new(io::sink()).with_window_size(WindowSize {
+                        columns_rows: Size::new(width, height),
+                        pixels: Size::default(),
+                    });
+                    let mut terminal = Terminal::with_options(backend, options.clone()).unwrap();
+                    terminal
+                        .draw(|frame| frame.render_widget(log(0), frame.area()))
+                        .unwrap();
+                    terminal
+                };
+                let draw = |terminal: &mut Terminal<CrosstermBackend<Sink>>| {
+                    terminal
+                        .draw(|frame| frame.render_widget(log(1), frame.area()))
+                        .unwrap();
+                };
+                let name = format!("{mode}/{screen}");
+                group.bench_function(BenchmarkId::new(name, format!("{width}x{height}")), |b| {
+                    b.iter_batched_ref(
+                        setup,
+                        |terminal| draw(black_box(terminal)),
+                        BatchSize::SmallInput,
+                    );
+                });
+            }
+        }
+    }
+    group.finish();
+}
@@ -44,6 +44,7 @@
 //! - [`restore`] - Restores the terminal to its original state. Prints errors to stderr but does
 //!   not panic.
 //! - [`try_restore`] - Same as [`restore`] but returns a `Result` instead of printing errors.
+//! - [`set_save_title`] - Sets whether the title of the window is saved and restored.
 //!
 //! # Usage Guide
 //!
@@ -85,6 +86,7 @@
 //!
 //! let options = TerminalOptions {
 //!     viewport: Viewport::Inline(10),
+//!     ..Default::default()
 //! };
 //!
 //! // Using init_with_options() - panics on failure
@@ -95,6 +97,7 @@
 //! // Using try_init_with_options() - returns Result for custom error handling
 //! let options = TerminalOptions {
 //!     viewport: Viewport::Inline(10),
+//!     ..Default::default()
 //! };
 //! let mut terminal = ratatui::try_init_with_options(options)?;
 //! // ... app logic ...
@@ -128,16 +131,35 @@
 //!
 //! **Important**: Call the initialization functions *after* installing any other panic hooks to
 //! ensure the terminal is restored before other hooks run.
+//!
+//! # Window Title
+//!
+//! The initialization functions save the title of the window on the title stack of the terminal
+//! (XTWINOPS 22), and the restoration functions restore it (XTWINOPS 23), so that a title set by the
+//! application does not outlive it. Terminals that do not support the title stack ignore these
+//! sequences, but applications that never set a title, or that run in terminals that handle them
+//! poorly, can disable them with [`set_save_title`] before initializing the terminal.
 
+use core::sync::atomic::{AtomicBool, Ordering};
 use std::io::{self, Stdout, stdout};
 
+use ratatui_core::backend::TerminalCommand;
+use ratatui_core::style::ColorDepth;
 use ratatui_core::terminal::{Terminal, TerminalOptions};
 use ratatui_crossterm::CrosstermBackend;
+use ratatui_crossterm::crossterm::cursor::SetCursorStyle;
 use ratatui_crossterm::crossterm::execute;
+use ratatui_crossterm::crossterm::style::Print;
 use ratatui_crossterm::crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
 };
 
+/// Whether the initialization functions save the title of the window
+static SAVE_TITLE: AtomicBool = AtomicBool::new(true);
+
+/// Whether the title of the window was saved and not restored yet
+static TITLE_SAVED: AtomicBool = AtomicBool::new(false);
+
 /// A type alias for the default terminal type.
 ///
 /// This is a [`Terminal`] using the [`CrosstermBackend`] which writes to [`Stdout`]. This is a
@@ -258,13 +280,82 @@ where
     result
 }
 
+/// Initializes a terminal, runs the given async closure, and restores the terminal.
+///
+/// This is the async equivalent of [`run`], for use with the [`runtime`] module or any other
+/// async code. The terminal is restored when the closure completes, when it panics, and when the
+/// returned future is dropped before completion, e.g. because the task running it was cancelled.
+///
+/// This function is available with the `runtime` feature.
+///
+/// # Panics
+///
+/// This function will panic if any of the following steps fail:
+///
+/// - Enabling raw mode
+/// - Entering the alternate screen buffer
+/// - Creating the terminal fails due to being unable to calculate the terminal size
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use ratatui::backend::CrosstermBackend;
+/// use ratatui::event::KeyCode;
+/// use ratatui::runtime::{Event, EventStream};
+///
+/// # async fn example() -> std::io::Result<()> {
+/// use futures::StreamExt;
+///
+/// ratatui::run_async(async |terminal| {
+///     let mut events = EventStream::<_, ()>::new(CrosstermBackend::new(std::io::stdout()));
+///     terminal.draw(|frame| frame.render_widget("Press q to quit", frame.area()))?;
+///     while let Some(event) = events.next().await {
+///         if let Event::Input(input) = event? {
+///             if input.as_key_press_event().is_some_and(|key| key.code == KeyCode::Char('q')) {
+///                 break;
+///             }
+///         }
+///     }
+///     Ok(())
+/// })
+/// .await
+/// # }
+/// ```
+///
+/// [`runtime`]: crate::runtime
+#[cfg(feature = "runtime")]
+pub async fn run_async<F, R>(f: F) -> R
+where
+    F: AsyncFnOnce(&mut DefaultTerminal) -> R,
+{
+    /// Restores the terminal when dropped, including on panic and cancellation
+    struct RestoreGuard;
+
+    impl Drop for RestoreGuard {
+        fn drop(&mut self) {
+            restore();
+        }
+    }
+
+    let mut terminal = init();
+    let _guard = RestoreGuard;
+    f(&mut terminal).await
+}
+
 /// Initialize a terminal with reasonable defaults for most applications.
 ///
 /// This will create a new [`DefaultTerminal`] and initialize it with the following defaults:
 ///
 /// - Backend: [`CrosstermBackend`] writing to [`Stdout`]
+/// - Colors: only limited when `NO_COLOR` is set or `TERM` limits them, see
+///   [`ColorDepth::from_env_limits`]
 /// - Raw mode is enabled
 /// - Alternate screen buffer enabled
+/// - The window title is saved, so that [`restore`] can restore it after the application sets
+///   its own title (see [`CommandBackend::set_title`](crate::backend::CommandBackend::set_title)),
+///   unless disabled with [`set_save_title`]
 /// - A panic hook is installed that restores the terminal before panicking. Ensure that this method
 ///   is called after any other panic hooks that may be installed to ensure that the terminal is
 ///   restored before those hooks are called.
@@ -306,8 +397,13 @@ pub fn init() -> DefaultTerminal {
 ///
 /// - Raw mode is enabled
 /// - Alternate screen buffer enabled
+/// - The window title is saved, so that [`restore`] can restore it after the application sets
+///   its own title (see [`CommandBackend::set_title`](crate::backend::CommandBackend::set_title)),
+///   unless disabled with [`set_save_title`]
 /// - A panic hook is installed that restores the terminal before panicking.
 /// - A [`Terminal`] is created using [`CrosstermBackend`] writing to [`Stdout`]
+/// - The colors are only limited when `NO_COLOR` is set or `TERM` limits them, see
+///   [`ColorDepth::from_env_limits`]
 ///
 /// If any of these steps fail, the error is returned.
 ///
@@ -331,8 +427,13 @@ pub fn try_init() -> io::Result<DefaultTerminal> {
     set_panic_hook();
     enable_raw_mode()?;
     execute!(stdout(), EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout());
-    Terminal::new(backend)
+    save_title()?;
+    let backend = CrosstermBackend::new(stdout()).with_color_depth(ColorDepth::from_env_limits());
+    let options = TerminalOptions {
+        alternate_screen: true,
+        ..TerminalOptions::default()
+    };
+    Terminal::with_options(backend, options)
 }
 
 /// Initialize a terminal with the given options and reasonable defaults.
@@ -344,6 +445,9 @@ pub fn try_init() -> io::Result<DefaultTerminal> {
 /// [`Viewport`]: crate::Viewport
 ///
 /// - Raw mode is enabled
+/// - The window title is saved, so that [`restore`] can restore it after the application sets
+///   its own title (see [`CommandBackend::set_title`](crate::backend::CommandBackend::set_title)),
+///   unless disabled with [`set_save_title`]
 /// - A panic hook is installed that restores the terminal before panicking.
 ///
 /// Unlike [`init`], this function does not enter the alternate screen buffer as this may not be
@@ -376,6 +480,7 @@ pub fn try_init() -> io::Result<DefaultTerminal> {
 ///
 /// let options = TerminalOptions {
 ///     viewport: Viewport::Inline(5),
+///     ..Default::default()
 /// };
 /// let terminal = ratatui::init_with_options(options);
 /// ```
@@ -392,7 +497,12 @@ pub fn init_with_options(options: TerminalOptions) -> DefaultTerminal {
 /// [`Viewport`]: crate::Viewport
 ///
 /// - Raw mode is enabled
+/// - The window title is saved, so that [`restore`] can restore it after the application sets
+///   its own title (see [`CommandBackend::set_title`](crate::backend::CommandBackend::set_title)),
+///   unless disabled with [`set_save_title`]
 /// - A panic hook is installed that restores the terminal before panicking.
+/// - The colors are only limited when `NO_COLOR` is set or `TERM` limits them, see
+///   [`ColorDepth::from_env_limits`]
 ///
 /// Unlike [`try_init`], this function does not enter the alternate screen buffer as this may not be
 /// desired in all cases. If you need the alternate screen buffer, you should enable it manually
@@ -418,6 +528,7 @@ pub fn init_with_options(options: TerminalOptions) -> DefaultTerminal {
 ///
 /// let options = TerminalOptions {
 ///     viewport: Viewport::Inline(5),
+///     ..Default::default()
 /// };
 /// let terminal = ratatui::try_init_with_options(options)?;
 /// # Ok::<(), std::io::Error>(())
@@ -425,7 +536,8 @@ pub fn init_with_options(options: TerminalOptions) -> DefaultTerminal {
 pub fn try_init_with_options(options: TerminalOptions) -> io::Result<DefaultTerminal> {
     set_panic_hook();
     enable_raw_mode()?;
-    let backend = CrosstermBackend::new(stdout());
+    save_title()?;
+    let backend = CrosstermBackend::new(stdout()).with_color_depth(ColorDepth::from_env_limits());
     Terminal::with_options(backend, options)
 }
 
@@ -439,8 +551,10 @@ pub fn try_init_with_options(options: TerminalOptions) -> io::Result<DefaultTerm
 ///
 /// 1. Raw mode is disabled.
 /// 2. The alternate screen buffer is left.
+/// 3. The cursor style is reset to the user's default (see [`Frame::set_cursor_style`](crate::Frame::set_cursor_style)).
+/// 4. The window title saved by [`init`] is restored, if it was saved (see [`set_save_title`]).
 ///
-/// If either of these steps fail, the error is printed to stderr and ignored.
+/// If any of these steps fail, the error is printed to stderr and ignored.
 ///
 /// Use this function over [`try_restore`] when you don't need to handle the error yourself, as
 /// ignoring the error is generally the correct behavior when cleaning up before exiting. If you
@@ -468,8 +582,10 @@ pub fn restore() {
 ///
 /// 1. Raw mode is disabled.
 /// 2. The alternate screen buffer is left.
+/// 3. The cursor style is reset to the user's default (see [`Frame::set_cursor_style`](crate::Frame::set_cursor_style)).
+/// 4. The window title saved by [`init`] is restored, if it was saved (see [`set_save_title`]).
 ///
-/// If either of these steps fail, the error is returned.
+/// If any of these steps fail, the error is returned.
 ///
 /// Use [`restore`] instead of this function when you don't need to handle the error yourself, as
 /// ignoring the error is generally the correct behavior when cleaning up before exiting. If you
@@ -488,7 +604,41 @@ pub fn try_restore() -> io::Result<()> {
     // disabling raw mode first is important as it has more side effects than leaving the alternate
     // screen buffer
     disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen)?;
+    execute!(
+        stdout(),
+        LeaveAlternateScreen,
+        SetCursorStyle::DefaultUserShape
+    )?;
+    if TITLE_SAVED.swap(false, Ordering::Relaxed) {
+        execute!(stdout(), Print(TerminalCommand::RestoreTitle))?;
+    }
+    Ok(())
+}
+
+/// Sets whether the initialization functions save the title of the window, so that the
+/// restoration functions can restore it.
+///
+/// The title is saved by default. Disable it before initializing the terminal when the application
+/// does not set a title, so that no title stack sequences are written to the terminal. See the
+/// [module-level documentation](mod@crate::init#window-title) for more details.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// ratatui::set_save_title(false);
+/// let terminal = ratatui::init();
+/// // ... app logic ...
+/// ratatui::restore();
+/// ```
+pub fn set_save_title(save: bool) {
+    SAVE_TITLE.store(save, Ordering::Relaxed);
+}
+
+/// Saves the title of the window, unless disabled with [`set_save_title`] or already saved.
+fn save_title() -> io::Result<()> {
+    if SAVE_TITLE.load(Ordering::Relaxed) && !TITLE_SAVED.swap(true, Ordering::Relaxed) {
+        execute!(stdout(), Print(TerminalCommand::SaveTitle))?;
+    }
     Ok(())
 }
 
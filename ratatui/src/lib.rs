@@ -428,8 +428,10 @@ extern crate std;
 /// re-export the `palette` crate so that users don't have to add it as a dependency
 #[cfg(feature = "palette")]
 pub use palette;
-pub use ratatui_core::terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport};
-pub use ratatui_core::{buffer, layout};
+pub use ratatui_core::terminal::{
+    CompletedFrame, Frame, FrameStats, HitRegion, Terminal, TerminalOptions, Viewport,
+};
+pub use ratatui_core::{animation, buffer, event, layout};
 /// re-export the `crossterm` crate so that users don't have to add it as a dependency
 #[cfg(feature = "crossterm")]
 pub use ratatui_crossterm::crossterm;
@@ -442,20 +444,32 @@ pub use ratatui_termion::termion;
 #[cfg(feature = "termwiz")]
 pub use ratatui_termwiz::termwiz;
 
+#[cfg(all(feature = "crossterm", feature = "runtime"))]
+#[doc(inline)]
+pub use crate::init::run_async;
 #[cfg(feature = "crossterm")]
 #[doc(inline)]
 pub use crate::init::{
-    DefaultTerminal, init, init_with_options, restore, run, try_init, try_init_with_options,
-    try_restore,
+    DefaultTerminal, init, init_with_options, restore, run, set_save_title, try_init,
+    try_init_with_options, try_restore,
 };
 
 /// Re-exports for the backend implementations.
 pub mod backend {
-    pub use ratatui_core::backend::{Backend, ClearType, TestBackend, WindowSize};
+    #[cfg(feature = "asciicast")]
+    pub use ratatui_core::backend::{
+        Asciicast, AsciicastBackend, AsciicastError, AsciicastEvent, AsciicastEventKind,
+        AsciicastFrame, ParseAsciicastError,
+    };
+    pub use ratatui_core::backend::{
+        Backend, ClearType, CommandBackend, CursorStyle, DrawCommand, DrawPlanner, GraphicsBackend,
+        GraphicsProtocol, OutputStats, QueryBackend, StyleChange, TaskbarProgress,
+        TerminalCapabilities, TerminalCommand, TestBackend, VirtualTerminal, WindowSize,
+    };
     #[cfg(feature = "crossterm")]
     pub use ratatui_crossterm::{CrosstermBackend, FromCrossterm, IntoCrossterm};
     #[cfg(all(not(windows), feature = "termion"))]
-    pub use ratatui_termion::{FromTermion, IntoTermion, TermionBackend};
+    pub use ratatui_termion::{FromTermion, IntoTermion, TermionBackend, TermionEventReader};
     #[cfg(feature = "termwiz")]
     pub use ratatui_termwiz::{FromTermwiz, IntoTermwiz, TermwizBackend};
 }
@@ -466,3 +480,5 @@ pub mod widgets;
 pub use ratatui_widgets::border;
 #[cfg(feature = "crossterm")]
 pub mod init;
+#[cfg(feature = "runtime")]
+pub mod runtime;
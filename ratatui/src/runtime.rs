@@ -0,0 +1,507 @@
+//! An asynchronous event loop for applications.
+//!
+//! Most applications need the same event loop: wait for input, periodic ticks or messages from
+//! background tasks, update the state, and redraw. This module provides the pieces for such a
+//! loop, independent of the async executor:
+//!
+//! - [`EventStream`] merges input events from an [`EventSource`] (any [`EventReader`], such as a
+//!   backend), periodic [`Event::Tick`]s and application messages sent with a [`MessageSender`]
+//!   into a single [`Stream`].
+//! - [`Runner`] drives an [`App`] with an event stream. It only redraws when the app reports that
+//!   its state changed, and limits how often the terminal is redrawn.
+//! - [`run_async`] initializes the terminal like [`run`], and restores it when the future
+//!   completes, panics or is cancelled.
+//!
+//! This module is available with the `runtime` feature.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use ratatui::Frame;
+//! use ratatui::backend::CrosstermBackend;
+//! use ratatui::event::{Event as InputEvent, KeyCode};
+//! use ratatui::runtime::{App, Control, Event, Runner};
+//!
+//! #[derive(Default)]
+//! struct Counter {
+//!     ticks: u64,
+//! }
+//!
+//! impl App<InputEvent, ()> for Counter {
+//!     fn update(&mut self, event: Event<InputEvent, ()>) -> Control {
+//!         match event {
+//!             Event::Input(input)
+//!                 if input
+//!                     .as_key_press_event()
+//!                     .is_some_and(|key| key.code == KeyCode::Char('q')) =>
+//!             {
+//!                 Control::Quit
+//!             }
+//!             Event::Tick => {
+//!                 self.ticks += 1;
+//!                 Control::Redraw
+//!             }
+//!             _ => Control::Continue,
+//!         }
+//!     }
+//!
+//!     fn draw(&mut self, frame: &mut Frame) {
+//!         frame.render_widget(format!("{} ticks", self.ticks), frame.area());
+//!     }
+//! }
+//!
+//! # async fn example() -> std::io::Result<()> {
+//! ratatui::run_async(async |terminal| {
+//!     // crossterm reads input from the terminal itself, so a second backend can read the events
+//!     Runner::new(CrosstermBackend::new(std::io::stdout()))
+//!         .tick_rate(Duration::from_secs(1))
+//!         .run(terminal, &mut Counter::default())
+//!         .await
+//! })
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`EventReader`]: crate::event::EventReader
+//! [`Stream`]: futures::Stream
+//! [`run`]: crate::run
+//! [`run_async`]: crate::run_async
+
+use core::fmt;
+use core::time::Duration;
+use std::io;
+use std::time::Instant;
+
+use ratatui_core::backend::Backend;
+use ratatui_core::terminal::{Frame, Terminal};
+
+pub use self::source::EventSource;
+use self::stream::Next;
+pub use self::stream::{EventStream, MessageSender};
+
+mod source;
+mod stream;
+
+/// An event emitted by an [`EventStream`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Event<E, M> {
+    /// An input event read from the [`EventSource`].
+    Input(E),
+    /// A periodic tick, emitted at the tick rate of the stream.
+    Tick,
+    /// A message sent with a [`MessageSender`].
+    Message(M),
+}
+
+/// What the [`Runner`] should do after an [`App`] handled an event.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Control {
+    /// Keep running without redrawing.
+    #[default]
+    Continue,
+    /// The state of the app changed, so the terminal should be redrawn.
+    Redraw,
+    /// Stop running.
+    Quit,
+}
+
+/// An application driven by a [`Runner`].
+///
+/// `E` is the type of the input events and `M` the type of the application messages.
+pub trait App<E, M> {
+    /// Updates the state of the app for an event and returns what the runner should do next.
+    fn update(&mut self, event: Event<E, M>) -> Control;
+
+    /// Draws the app to the frame.
+    fn draw(&mut self, frame: &mut Frame);
+}
+
+/// Drives an [`App`] with the events of an [`EventStream`].
+///
+/// The runner draws the app once when it starts, then only when [`App::update`] returns
+/// [`Control::Redraw`]. Redraws are limited to the [maximum frame
+/// rate](Runner::max_frame_rate): when the state changes again shortly after a redraw, the
+/// changes are drawn together at the start of the next frame.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct Runner<E, M> {
+    events: EventStream<E, M>,
+    frame_interval: Duration,
+}
+
+/// An error that stopped a [`Runner`].
+#[derive(Debug)]
+pub enum Error<E> {
+    /// Drawing to the backend failed.
+    Backend(E),
+    /// Reading input from the event source failed.
+    Input(io::Error),
+}
+
+impl<E, M> Runner<E, M>
+where
+    E: Send + 'static,
+{
+    /// Creates a runner for the events of the given source, without ticks.
+    pub fn new<S>(source: S) -> Self
+    where
+        S: EventSource<Event = E>,
+    {
+        Self::with_events(EventStream::new(source))
+    }
+}
+
+impl<E, M> Runner<E, M> {
+    /// The default maximum frame rate, in frames per second
+    const DEFAULT_FRAME_RATE: u32 = 60;
+
+    /// Creates a runner for an existing event stream.
+    pub fn with_events(events: EventStream<E, M>) -> Self {
+        Self {
+            events,
+            frame_interval: Duration::from_secs(1) / Self::DEFAULT_FRAME_RATE,
+        }
+    }
+
+    /// Sets the interval between [`Event::Tick`]s.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tick_rate(self, tick_rate: Duration) -> Self {
+        self.events.set_tick_rate(Some(tick_rate));
+        self
+    }
+
+    /// Sets the maximum number of times per second that the terminal is redrawn.
+    ///
+    /// Defaults to 60. A value of 0 removes the limit.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_frame_rate(mut self, frames_per_second: u32) -> Self {
+        self.frame_interval = Duration::from_secs(1)
+            .checked_div(frames_per_second)
+            .unwrap_or_default();
+        self
+    }
+
+    /// Returns a sender for messages to the app.
+    pub fn sender(&self) -> MessageSender<M> {
+        self.events.sender()
+    }
+
+    /// Runs the app until [`App::update`] returns [`Control::Quit`], or until reading input or
+    /// drawing fails.
+    ///
+    /// The terminal is neither initialized nor restored by this method. Use [`run_async`] for
+    /// that, or see the [`init`] module.
+    ///
+    /// [`run_async`]: crate::run_async
+    /// [`init`]: mod@crate::init
+    pub async fn run<B, A>(
+        mut self,
+        terminal: &mut Terminal<B>,
+        app: &mut A,
+    ) -> Result<(), Error<B::Error>>
+    where
+        B: Backend,
+        A: App<E, M>,
+    {
+        let mut dirty = true;
+        let mut last_draw: Option<Instant> = None;
+        let mut wake_up_requested = false;
+        loop {
+            if dirty {
+                let next_frame = last_draw.map(|last| last + self.frame_interval);
+                match next_frame {
+                    Some(next_frame) if Instant::now() < next_frame => {
+                        if !wake_up_requested {
+                            self.events.wake_at(next_frame);
+                            wake_up_requested = true;
+                        }
+                    }
+                    _ => {
+                        terminal
+                            .draw(|frame| app.draw(frame))
+                            .map_err(Error::Backend)?;
+                        last_draw = Some(Instant::now());
+                        dirty = false;
+                    }
+                }
+            }
+            match self.events.next_item().await {
+                Next::Event(event) => match app.update(event) {
+                    Control::Continue => {}
+                    Control::Redraw => dirty = true,
+                    Control::Quit => return Ok(()),
+                },
+                Next::Error(err) => return Err(Error::Input(err)),
+                Next::Wake => wake_up_requested = false,
+            }
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Backend(err) => write!(f, "failed to draw to the terminal: {err}"),
+            Self::Input(err) => write!(f, "failed to read input: {err}"),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Backend(err) => Some(err),
+            Self::Input(err) => Some(err),
+        }
+    }
+}
+
+impl From<Error<Self>> for io::Error {
+    fn from(err: Error<Self>) -> Self {
+        match err {
+            Error::Backend(err) | Error::Input(err) => err,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use std::sync::mpsc as std_mpsc;
+
+    use futures::StreamExt;
+    use futures::executor::block_on;
+    use ratatui_core::backend::TestBackend;
+    use ratatui_core::event::{Event as InputEvent, EventReader, KeyCode, KeyEvent};
+
+    use super::*;
+
+    /// Emits the input events sent through a channel, waiting for them up to the timeout
+    struct Script {
+        events: std_mpsc::Receiver<io::Result<char>>,
+        /// Keeps the channel open, so that the source waits for events rather than ending
+        _sender: std_mpsc::Sender<io::Result<char>>,
+    }
+
+    impl Script {
+        /// Returns a source that emits the given events, and a sender for more events
+        fn new<I>(events: I) -> (Self, std_mpsc::Sender<io::Result<char>>)
+        where
+            I: IntoIterator<Item = io::Result<char>>,
+        {
+            let (sender, receiver) = std_mpsc::channel();
+            for event in events {
+                sender.send(event).unwrap();
+            }
+            let script = Self {
+                events: receiver,
+                _sender: sender.clone(),
+            };
+            (script, sender)
+        }
+
+        /// Returns a source that emits the given characters, then no events at all
+        fn chars<I: IntoIterator<Item = char>>(chars: I) -> Self {
+            Self::new(chars.into_iter().map(Ok)).0
+        }
+    }
+
+    impl EventSource for Script {
+        type Event = char;
+
+        fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<char>> {
+            self.events
+                .recv_timeout(timeout)
+                .map_or(Ok(None), |event| event.map(Some))
+        }
+    }
+
+    /// Reads the given key presses, then no events at all
+    struct Keys {
+        codes: std_mpsc::Receiver<KeyCode>,
+        /// Keeps the channel open, so that the reader waits for events rather than ending
+        _sender: std_mpsc::Sender<KeyCode>,
+    }
+
+    impl Keys {
+        fn new<I: IntoIterator<Item = KeyCode>>(codes: I) -> Self {
+            let (sender, receiver) = std_mpsc::channel();
+            for code in codes {
+                sender.send(code).unwrap();
+            }
+            Self {
+                codes: receiver,
+                _sender: sender,
+            }
+        }
+    }
+
+    impl EventReader for Keys {
+        type Error = io::Error;
+
+        fn poll_event(&mut self, timeout: Option<Duration>) -> io::Result<Option<InputEvent>> {
+            let code = match timeout {
+                Some(timeout) => self.codes.recv_timeout(timeout).ok(),
+                None => self.codes.recv().ok(),
+            };
+            Ok(code.map(|code| KeyEvent::from(code).into()))
+        }
+    }
+
+    /// Quits on `q` or on any message, redraws on `r`, and records the events and draws
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<Event<char, u8>>,
+        draws: usize,
+        /// Receives the number of draws after each draw
+        drawn: Option<std_mpsc::Sender<usize>>,
+    }
+
+    impl App<char, u8> for Recorder {
+        fn update(&mut self, event: Event<char, u8>) -> Control {
+            let control = match event {
+                Event::Input('q') | Event::Message(_) => Control::Quit,
+                Event::Input('r') => Control::Redraw,
+                _ => Control::Continue,
+            };
+            self.events.push(event);
+            control
+        }
+
+        fn draw(&mut self, frame: &mut Frame) {
+            self.draws += 1;
+            if let Some(drawn) = &self.drawn {
+                let _ = drawn.send(self.draws);
+            }
+            frame.render_widget(alloc::format!("draw {}", self.draws), frame.area());
+        }
+    }
+
+    #[test]
+    fn stream_merges_input_ticks_and_messages() {
+        let mut events =
+            EventStream::<_, u8>::new(Script::chars(['a'])).tick_rate(Duration::from_millis(10));
+        events.sender().send(1).unwrap();
+        let mut seen = Vec::new();
+        block_on(async {
+            while !(seen.contains(&Event::Input('a'))
+                && seen.contains(&Event::Tick)
+                && seen.contains(&Event::Message(1)))
+            {
+                seen.push(events.next().await.unwrap().unwrap());
+            }
+        });
+    }
+
+    #[test]
+    fn stream_returns_input_errors() {
+        let (source, _) = Script::new([Err(io::Error::other("oops")), Ok('a')]);
+        let mut events = EventStream::<_, u8>::new(source);
+        block_on(async {
+            let err = events.next().await.unwrap().unwrap_err();
+            assert_eq!(err.to_string(), "oops");
+            assert_eq!(events.next().await.unwrap().unwrap(), Event::Input('a'));
+        });
+    }
+
+    #[test]
+    fn stream_stops_reading_unsupported_source() {
+        let unsupported = io::Error::from(io::ErrorKind::Unsupported);
+        let (source, _) = Script::new([Err(unsupported), Ok('a')]);
+        let mut events = EventStream::<_, u8>::new(source).tick_rate(Duration::from_millis(10));
+        block_on(async {
+            let err = events.next().await.unwrap().unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+            assert_eq!(events.next().await.unwrap().unwrap(), Event::Tick);
+        });
+    }
+
+    #[test]
+    fn stream_reads_events_of_event_reader() {
+        let mut events = EventStream::<_, u8>::new(Keys::new([KeyCode::Enter]));
+        let event = block_on(events.next()).unwrap().unwrap();
+        assert_eq!(event, Event::Input(KeyEvent::from(KeyCode::Enter).into()));
+    }
+
+    #[test]
+    fn backends_are_event_sources() {
+        const fn assert_source<S: EventSource<Event = InputEvent>>() {}
+
+        #[cfg(feature = "crossterm")]
+        assert_source::<crate::backend::CrosstermBackend<std::io::Stdout>>();
+        #[cfg(all(not(windows), feature = "termion"))]
+        assert_source::<crate::backend::TermionEventReader>();
+    }
+
+    #[test]
+    fn message_sender_is_closed_when_stream_is_dropped() {
+        let events = EventStream::<_, u8>::new(Script::chars([]));
+        let sender = events.sender();
+        assert!(!sender.is_closed());
+        drop(events);
+        assert!(sender.is_closed());
+        assert_eq!(sender.send(1), Err(1));
+    }
+
+    #[test]
+    fn runner_redraws_only_when_dirty() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let mut app = Recorder::default();
+        let runner = Runner::new(Script::chars(['r', 'x', 'q'])).max_frame_rate(0);
+        block_on(runner.run(&mut terminal, &mut app)).unwrap();
+        assert_eq!(
+            app.events,
+            [Event::Input('r'), Event::Input('x'), Event::Input('q')]
+        );
+        assert_eq!(app.draws, 2);
+        terminal.backend().assert_buffer_lines(["draw 2    "]);
+    }
+
+    #[test]
+    fn runner_limits_frame_rate() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let mut app = Recorder::default();
+        let runner = Runner::new(Script::chars(['r', 'r', 'r', 'q'])).max_frame_rate(1);
+        block_on(runner.run(&mut terminal, &mut app)).unwrap();
+        assert_eq!(app.draws, 1);
+    }
+
+    #[test]
+    fn runner_draws_pending_changes_on_next_frame() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let (drawn, draw_counts) = std_mpsc::channel();
+        let mut app = Recorder {
+            drawn: Some(drawn),
+            ..Recorder::default()
+        };
+        let (source, input) = Script::new([Ok('r'), Ok('r')]);
+        let runner = Runner::new(source).max_frame_rate(50);
+        // quit once the pending changes are drawn, or when no draw follows for a long time
+        std::thread::spawn(move || {
+            while draw_counts
+                .recv_timeout(Duration::from_secs(10))
+                .is_ok_and(|draws| draws < 2)
+            {}
+            input.send(Ok('q'))
+        });
+        block_on(runner.run(&mut terminal, &mut app)).unwrap();
+        assert_eq!(app.draws, 2);
+    }
+
+    #[test]
+    fn runner_stops_on_input_error() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let (source, _) = Script::new([Err(io::Error::other("oops"))]);
+        let result = block_on(Runner::new(source).run(&mut terminal, &mut Recorder::default()));
+        assert!(matches!(result, Err(Error::Input(err)) if err.to_string() == "oops"));
+    }
+}
@@ -0,0 +1,44 @@
+use core::time::Duration;
+use std::io;
+
+use ratatui_core::event::{Event, EventReader};
+
+/// A source of input events that can be read from a background thread.
+///
+/// [`EventStream`] polls the source on a dedicated thread and forwards the events to the stream.
+/// The timeout is used to check regularly whether the stream has been dropped, so implementations
+/// must not block for much longer than the timeout.
+///
+/// Every [`EventReader`] that can be sent to another thread is a source of [`Event`]s, so the
+/// input of the crossterm and termion backends can be used directly: a [`CrosstermBackend`]
+/// (crossterm reads input from the terminal itself, so any backend works unless it writes to a
+/// remote terminal with a window size set), or a [`TermionEventReader`].
+///
+/// The termwiz backend reads input through the same [`SystemTerminal`] that it draws to, and a
+/// second terminal cannot be opened for reading input only, so the termwiz backend is not
+/// supported as a source yet. Read its events with [`EventReader`] on the thread that draws
+/// instead.
+///
+/// [`EventStream`]: crate::runtime::EventStream
+/// [`CrosstermBackend`]: crate::backend::CrosstermBackend
+/// [`TermionEventReader`]: crate::backend::TermionEventReader
+/// [`SystemTerminal`]: https://docs.rs/termwiz/latest/termwiz/terminal/type.SystemTerminal.html
+pub trait EventSource: Send + 'static {
+    /// The type of the events read from the source.
+    type Event: Send + 'static;
+
+    /// Waits up to `timeout` for the next event, returning `None` if no event was available.
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Self::Event>>;
+}
+
+impl<R> EventSource for R
+where
+    R: EventReader + Send + 'static,
+    R::Error: Into<io::Error>,
+{
+    type Event = Event;
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        EventReader::poll_event(self, Some(timeout)).map_err(Into::into)
+    }
+}
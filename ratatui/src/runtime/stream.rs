@@ -0,0 +1,312 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll};
+use core::time::Duration;
+use std::io;
+use std::sync::mpsc as std_mpsc;
+use std::thread;
+use std::time::Instant;
+
+use futures::channel::mpsc;
+use futures::{Stream, StreamExt};
+
+use crate::runtime::{Event, EventSource};
+
+/// A stream that merges input events, periodic ticks and application messages.
+///
+/// The stream reads input from an [`EventSource`] on a background thread, and emits
+/// [`Event::Tick`] at the [tick rate](EventStream::tick_rate) from another thread. Messages can be
+/// sent to the stream from anywhere (other threads, async tasks, callbacks) with a
+/// [`MessageSender`].
+///
+/// The stream is executor-agnostic: it only relies on threads and channels, so it can be polled
+/// from tokio, async-std, smol or a simple `block_on`. The background threads stop shortly after
+/// the stream is dropped, e.g. when the task polling it is cancelled.
+///
+/// The stream never ends. Errors reading input are returned as items, after which the stream
+/// keeps reading input, except for [`io::ErrorKind::Unsupported`] errors, after which the source
+/// is not read anymore.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use futures::StreamExt;
+/// use ratatui::backend::CrosstermBackend;
+/// use ratatui::event::KeyCode;
+/// use ratatui::runtime::{Event, EventStream};
+///
+/// # async fn example() -> std::io::Result<()> {
+/// let mut events = EventStream::<_, String>::new(CrosstermBackend::new(std::io::stdout()))
+///     .tick_rate(Duration::from_millis(250));
+/// let sender = events.sender();
+/// std::thread::spawn(move || sender.send("hello from a thread".to_string()));
+///
+/// while let Some(event) = events.next().await {
+///     match event? {
+///         Event::Input(input) => {
+///             if input.as_key_press_event().is_some_and(|key| key.code == KeyCode::Char('q')) {
+///                 break;
+///             }
+///         }
+///         Event::Tick => {}
+///         Event::Message(message) => println!("{message}"),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EventStream<E, M> {
+    signals: mpsc::UnboundedReceiver<Signal<E>>,
+    messages: mpsc::UnboundedReceiver<M>,
+    message_sender: mpsc::UnboundedSender<M>,
+    scheduler: std_mpsc::Sender<Schedule>,
+    stop: Arc<AtomicBool>,
+    /// Whether to check messages before signals on the next poll, so that neither starves
+    messages_first: bool,
+}
+
+/// Sends messages to an [`EventStream`], where they are emitted as [`Event::Message`].
+///
+/// Senders are cheap to clone and can be moved to other threads and tasks.
+#[derive(Debug)]
+pub struct MessageSender<M> {
+    sender: mpsc::UnboundedSender<M>,
+}
+
+/// An item produced by the background threads of an [`EventStream`]
+#[derive(Debug)]
+pub(crate) enum Signal<E> {
+    Input(E),
+    Tick,
+    Error(io::Error),
+    /// A deadline requested with [`EventStream::wake_at`] was reached
+    Wake,
+}
+
+/// The next item of an [`EventStream`], as seen by the runner
+#[derive(Debug)]
+pub(crate) enum Next<E, M> {
+    Event(Event<E, M>),
+    Error(io::Error),
+    /// A deadline requested with [`EventStream::wake_at`] was reached
+    Wake,
+}
+
+/// A command sent to the scheduler thread
+#[derive(Debug)]
+enum Schedule {
+    TickRate(Option<Duration>),
+    WakeAt(Instant),
+}
+
+impl<E, M> EventStream<E, M>
+where
+    E: Send + 'static,
+{
+    /// How often the input thread checks whether the stream has been dropped
+    const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// Creates a stream of the events of the given source, without ticks.
+    ///
+    /// This spawns the background threads that read the input and emit ticks.
+    pub fn new<S>(source: S) -> Self
+    where
+        S: EventSource<Event = E>,
+    {
+        let (signal_sender, signals) = mpsc::unbounded();
+        let (message_sender, messages) = mpsc::unbounded();
+        let stop = Arc::new(AtomicBool::new(false));
+        spawn_input(source, signal_sender.clone(), Arc::clone(&stop));
+        let scheduler = spawn_scheduler(signal_sender);
+        Self {
+            signals,
+            messages,
+            message_sender,
+            scheduler,
+            stop,
+            messages_first: false,
+        }
+    }
+}
+
+impl<E, M> EventStream<E, M> {
+    /// Sets the interval between [`Event::Tick`]s.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tick_rate(self, tick_rate: Duration) -> Self {
+        self.set_tick_rate(Some(tick_rate));
+        self
+    }
+
+    /// Sets the interval between [`Event::Tick`]s, or stops the ticks if `None`.
+    ///
+    /// The next tick is emitted one interval after this call.
+    pub fn set_tick_rate(&self, tick_rate: Option<Duration>) {
+        // the scheduler only stops when the stream is dropped
+        let _ = self.scheduler.send(Schedule::TickRate(tick_rate));
+    }
+
+    /// Returns a sender for messages to this stream.
+    pub fn sender(&self) -> MessageSender<M> {
+        MessageSender {
+            sender: self.message_sender.clone(),
+        }
+    }
+
+    /// Makes the stream return [`Next::Wake`] at the given time.
+    pub(crate) fn wake_at(&self, deadline: Instant) {
+        let _ = self.scheduler.send(Schedule::WakeAt(deadline));
+    }
+
+    /// Polls the next event, error or wake-up requested with [`EventStream::wake_at`].
+    pub(crate) fn poll_next_item(&mut self, cx: &mut Context<'_>) -> Poll<Next<E, M>> {
+        // alternate which channel is checked first, so that neither can starve the other
+        self.messages_first = !self.messages_first;
+        if self.messages_first {
+            if let Poll::Ready(Some(message)) = self.messages.poll_next_unpin(cx) {
+                return Poll::Ready(Next::Event(Event::Message(message)));
+            }
+        }
+        if let Poll::Ready(Some(signal)) = self.signals.poll_next_unpin(cx) {
+            return Poll::Ready(match signal {
+                Signal::Input(input) => Next::Event(Event::Input(input)),
+                Signal::Tick => Next::Event(Event::Tick),
+                Signal::Error(err) => Next::Error(err),
+                Signal::Wake => Next::Wake,
+            });
+        }
+        if let Poll::Ready(Some(message)) = self.messages.poll_next_unpin(cx) {
+            return Poll::Ready(Next::Event(Event::Message(message)));
+        }
+        // neither channel can end, as the stream owns a message sender and the scheduler only
+        // stops when the stream is dropped
+        Poll::Pending
+    }
+
+    /// Returns the next event, error or wake-up requested with [`EventStream::wake_at`].
+    pub(crate) async fn next_item(&mut self) -> Next<E, M> {
+        core::future::poll_fn(|cx| self.poll_next_item(cx)).await
+    }
+}
+
+impl<E, M> Stream for EventStream<E, M> {
+    type Item = io::Result<Event<E, M>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match this.poll_next_item(cx) {
+                Poll::Ready(Next::Event(event)) => return Poll::Ready(Some(Ok(event))),
+                Poll::Ready(Next::Error(err)) => return Poll::Ready(Some(Err(err))),
+                // wake-ups are only requested by the runner
+                Poll::Ready(Next::Wake) => {}
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<E, M> Drop for EventStream<E, M> {
+    fn drop(&mut self) {
+        // the scheduler stops when its channel is closed, the input thread checks this flag
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+impl<M> MessageSender<M> {
+    /// Sends a message to the stream.
+    ///
+    /// Returns the message back if the stream has been dropped.
+    pub fn send(&self, message: M) -> Result<(), M> {
+        self.sender
+            .unbounded_send(message)
+            .map_err(mpsc::TrySendError::into_inner)
+    }
+
+    /// Returns whether the stream has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+impl<M> Clone for MessageSender<M> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+/// Spawns the thread that reads events from the source until the stream is dropped
+fn spawn_input<S: EventSource>(
+    mut source: S,
+    sender: mpsc::UnboundedSender<Signal<S::Event>>,
+    stop: Arc<AtomicBool>,
+) {
+    thread::spawn(move || {
+        while !stop.load(Ordering::Relaxed) {
+            let signal = match source.poll_event(EventStream::<S::Event, ()>::STOP_CHECK_INTERVAL) {
+                Ok(Some(event)) => Signal::Input(event),
+                Ok(None) => continue,
+                Err(err) => Signal::Error(err),
+            };
+            // the source would return the same error again on each poll
+            let unsupported =
+                matches!(&signal, Signal::Error(err) if err.kind() == io::ErrorKind::Unsupported);
+            if sender.unbounded_send(signal).is_err() || unsupported {
+                break;
+            }
+        }
+    });
+}
+
+/// Spawns the thread that emits ticks and wake-ups until the stream is dropped
+fn spawn_scheduler<E: Send + 'static>(
+    sender: mpsc::UnboundedSender<Signal<E>>,
+) -> std_mpsc::Sender<Schedule> {
+    let (scheduler, commands) = std_mpsc::channel();
+    thread::spawn(move || {
+        let mut tick_rate = None;
+        let mut next_tick = None;
+        let mut wake_ups: Vec<Instant> = Vec::new();
+        loop {
+            let now = Instant::now();
+            if next_tick.is_some_and(|tick| tick <= now) {
+                if sender.unbounded_send(Signal::Tick).is_err() {
+                    break;
+                }
+                next_tick = tick_rate.map(|rate| now + rate);
+            }
+            if wake_ups.iter().any(|&wake_up| wake_up <= now) {
+                wake_ups.retain(|&wake_up| wake_up > now);
+                if sender.unbounded_send(Signal::Wake).is_err() {
+                    break;
+                }
+            }
+            let deadline = wake_ups.iter().copied().chain(next_tick).min();
+            let command = match deadline {
+                Some(deadline) => commands.recv_timeout(deadline.saturating_duration_since(now)),
+                None => match commands.recv() {
+                    Ok(command) => Ok(command),
+                    Err(std_mpsc::RecvError) => Err(std_mpsc::RecvTimeoutError::Disconnected),
+                },
+            };
+            match command {
+                Ok(Schedule::TickRate(rate)) => {
+                    tick_rate = rate;
+                    next_tick = rate.map(|rate| Instant::now() + rate);
+                }
+                Ok(Schedule::WakeAt(deadline)) => wake_ups.push(deadline),
+                Err(std_mpsc::RecvTimeoutError::Timeout) => {}
+                Err(std_mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    });
+    scheduler
+}
@@ -49,7 +49,9 @@
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`FrameStatsOverlay`]: displays the frame rate and render statistics of the terminal.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Image`]: displays an image using a terminal graphics protocol or half block characters.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
@@ -57,6 +59,9 @@
 //! - [`Sparkline`]: display a single data set as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TextArea`]: edits multiple lines of text.
+//! - [`TextInput`]: edits a single line of text.
+//! - [`Tree`]: displays hierarchical data with expandable nodes.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //!
@@ -668,8 +673,10 @@ pub use ratatui_widgets::calendar;
 pub use ratatui_widgets::canvas;
 pub use ratatui_widgets::chart::{Axis, Chart, Dataset, GraphType, LegendPosition};
 pub use ratatui_widgets::clear::Clear;
+pub use ratatui_widgets::frame_stats::FrameStatsOverlay;
 pub use ratatui_widgets::gauge::{Gauge, LineGauge};
-pub use ratatui_widgets::list::{List, ListDirection, ListItem, ListState};
+pub use ratatui_widgets::image::{Image, ImageState};
+pub use ratatui_widgets::list::{List, ListAreas, ListDirection, ListItem, ListState};
 pub use ratatui_widgets::logo::{RatatuiLogo, Size as RatatuiLogoSize};
 pub use ratatui_widgets::mascot::{MascotEyeColor, RatatuiMascot};
 pub use ratatui_widgets::paragraph::{Paragraph, Wrap};
@@ -677,8 +684,10 @@ pub use ratatui_widgets::scrollbar::{
     ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState,
 };
 pub use ratatui_widgets::sparkline::{RenderDirection, Sparkline, SparklineBar};
-pub use ratatui_widgets::table::{Cell, HighlightSpacing, Row, Table, TableState};
-pub use ratatui_widgets::tabs::Tabs;
+pub use ratatui_widgets::table::{Cell, HighlightSpacing, Row, Table, TableAreas, TableState};
+pub use ratatui_widgets::tabs::{TabAreas, Tabs};
+pub use ratatui_widgets::text_input::{TextArea, TextInput, TextInputState, TextPosition};
+pub use ratatui_widgets::tree::{Tree, TreeItem, TreeState};
 #[instability::unstable(feature = "widget-ref")]
 pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
 
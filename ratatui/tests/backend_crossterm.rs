@@ -0,0 +1,286 @@
+#![cfg(feature = "crossterm")]
+
+use ratatui::backend::{Backend, CrosstermBackend, VirtualTerminal, WindowSize};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect, Size};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Paragraph, Widget, Wrap};
+use ratatui::{Terminal, TerminalOptions, Viewport};
+use rstest::rstest;
+
+fn paragraph(text: &str) -> Paragraph<'_> {
+    Paragraph::new(Line::from_iter([
+        "Hello ".bold().red(),
+        text.italic().on_blue(),
+    ]))
+    .block(
+        Block::bordered()
+            .title("界".dim())
+            .border_style(Style::new().green()),
+    )
+}
+
+/// Checks that a terminal displays what was rendered after the crossterm backend writes the
+/// changes of each frame.
+#[test]
+fn backend_crossterm_output_matches_rendered_buffer() -> Result<(), Box<dyn std::error::Error>> {
+    let area = Rect::new(0, 0, 16, 3);
+    let mut virtual_terminal = VirtualTerminal::new(area.width, area.height);
+    {
+        let mut terminal = Terminal::with_options(
+            CrosstermBackend::new(&mut virtual_terminal),
+            TerminalOptions {
+                viewport: Viewport::Fixed(area),
+                ..Default::default()
+            },
+        )?;
+        terminal.draw(|frame| frame.render_widget(paragraph("world"), area))?;
+        terminal.draw(|frame| {
+            frame.render_widget(paragraph("there"), area);
+            frame.set_cursor_position((3, 1));
+        })?;
+    }
+
+    let mut expected = Buffer::empty(area);
+    paragraph("there").render(area, &mut expected);
+    assert_eq!(virtual_terminal.buffer(), &expected);
+    assert_eq!(virtual_terminal.cursor_position(), Position::new(3, 1));
+    Ok(())
+}
+
+/// Checks that planning the changes of a frame writes fewer bytes than drawing them cell by cell,
+/// and that the terminal displays the same frame, with or without background color erase.
+///
+/// These are the scenarios of the `buffer/diff_bytes` benchmark, which measures the time taken.
+#[rstest]
+fn backend_crossterm_planned_diff_writes_fewer_bytes(
+    #[values("first_frame", "edit", "scroll", "highlight", "clear")] scenario: &str,
+    #[values(true, false)] background_color_erase: bool,
+) {
+    let area = Rect::new(0, 0, 80, 24);
+    let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor \
+        incididunt ut labore et dolore magna aliqua.\n"
+        .repeat(12);
+    let frame = |text: &str, scroll: u16| {
+        let mut buffer = Buffer::empty(area);
+        Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .scroll((scroll, 0))
+            .block(Block::bordered().title("Log".bold()).blue())
+            .render(area, &mut buffer);
+        buffer
+    };
+    let (previous, next) = match scenario {
+        "first_frame" => (Buffer::empty(area), frame(&text, 0)),
+        "edit" => (frame(&text, 0), frame(&text.replacen(' ', "_", 5), 0)),
+        "scroll" => (frame(&text, 0), frame(&text, 1)),
+        "highlight" => {
+            let mut styled = frame(&text, 0);
+            styled.set_style(Rect::new(1, 4, 78, 1), Style::new().reversed());
+            (frame(&text, 0), styled)
+        }
+        _ => (frame(&text, 0), frame("", 0)),
+    };
+    let updates = previous.diff(&next);
+
+    let mut naive = CrosstermBackend::new(Vec::new());
+    naive.draw(updates.iter().copied()).unwrap();
+    let mut planned =
+        CrosstermBackend::new(Vec::new()).with_background_color_erase(background_color_erase);
+    planned
+        .draw_diff(&next, &updates, Some(area.width))
+        .unwrap();
+    let (naive, planned) = (naive.writer(), planned.writer());
+    assert!(
+        planned.len() < naive.len(),
+        "{scenario}: {} planned bytes, {} naive bytes",
+        planned.len(),
+        naive.len()
+    );
+
+    let mut virtual_terminal = VirtualTerminal::new(area.width, area.height);
+    let mut first_frame = CrosstermBackend::new(Vec::new());
+    first_frame
+        .draw(Buffer::empty(area).diff(&previous).into_iter())
+        .unwrap();
+    virtual_terminal.process(first_frame.writer());
+    virtual_terminal.process(planned);
+    // blank cells are erased, which does not keep their foreground color
+    let visible = |buffer: &Buffer| {
+        let mut buffer = buffer.clone();
+        for cell in &mut buffer.content {
+            if cell.symbol() == " " {
+                cell.fg = Color::Reset;
+            }
+        }
+        buffer
+    };
+    assert_eq!(visible(virtual_terminal.buffer()), visible(&next));
+}
+
+/// Checks that terminals of different sizes can write to byte streams, and are resized when the
+/// size supplied to the backend changes.
+#[test]
+fn backend_crossterm_with_window_size() -> Result<(), Box<dyn std::error::Error>> {
+    let window_size = |width, height| WindowSize {
+        columns_rows: Size::new(width, height),
+        pixels: Size::new(width * 8, height * 16),
+    };
+    let mut small = VirtualTerminal::new(4, 1);
+    let mut large = VirtualTerminal::new(6, 2);
+    {
+        let mut small_terminal =
+            Terminal::new(CrosstermBackend::new(&mut small).with_window_size(window_size(4, 1)))?;
+        let mut large_terminal =
+            Terminal::new(CrosstermBackend::new(&mut large).with_window_size(window_size(5, 2)))?;
+        small_terminal.draw(|frame| frame.render_widget("abcdef", frame.area()))?;
+        large_terminal.draw(|frame| frame.render_widget("abcdef", frame.area()))?;
+
+        large_terminal
+            .backend_mut()
+            .set_window_size(window_size(6, 2));
+        let frame = large_terminal.draw(|frame| frame.render_widget("abcdef", frame.area()))?;
+        assert_eq!(frame.area, Rect::new(0, 0, 6, 2));
+    }
+
+    assert_eq!(small.buffer(), &Buffer::with_lines(["abcd"]));
+    assert_eq!(large.buffer(), &Buffer::with_lines(["abcdef", "      "]));
+    Ok(())
+}
+
+/// A writer whose output can be read while a terminal writes to it.
+#[cfg(feature = "scrolling-regions")]
+#[derive(Debug, Default, Clone)]
+struct SharedOutput(std::rc::Rc<std::cell::RefCell<Vec<u8>>>);
+
+#[cfg(feature = "scrolling-regions")]
+impl std::io::Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Checks that rows that moved between frames are scrolled rather than drawn again.
+#[test]
+#[cfg(feature = "scrolling-regions")]
+fn backend_crossterm_scrolls_moved_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let messages = [
+        "alpha: connected",
+        "bravo: joined",
+        "charlie: hello",
+        "delta: hi there",
+        "echo: welcome",
+    ];
+    let log = |first: usize| {
+        Paragraph::new(messages[first..first + 4].join("\n")).block(Block::bordered().title("Log"))
+    };
+    let output = SharedOutput::default();
+    let backend = CrosstermBackend::new(output.clone()).with_window_size(WindowSize {
+        columns_rows: Size::new(20, 6),
+        pixels: Size::new(160, 96),
+    });
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|frame| frame.render_widget(log(0), frame.area()))?;
+    let first_frame = output.0.borrow().len();
+    terminal.draw(|frame| frame.render_widget(log(1), frame.area()))?;
+
+    let second_frame = String::from_utf8(output.0.borrow()[first_frame..].to_vec())?;
+    assert!(second_frame.starts_with("\x1b[2;5r\x1b[1S\x1b[r"));
+    assert!(second_frame.len() < 60, "{second_frame:?}");
+    let mut virtual_terminal = VirtualTerminal::new(20, 6);
+    virtual_terminal.process(&output.0.borrow());
+    let mut expected = Buffer::empty(Rect::new(0, 0, 20, 6));
+    log(1).render(expected.area, &mut expected);
+    assert_eq!(virtual_terminal.buffer(), &expected);
+    Ok(())
+}
+
+/// Checks that rows that moved between frames are drawn again when scrolling is disabled, which
+/// writes more bytes than scrolling them.
+#[test]
+#[cfg(feature = "scrolling-regions")]
+fn backend_crossterm_redraws_moved_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let messages = ["alpha", "bravo", "charlie", "delta", "echo"];
+    let log = |first: usize| Paragraph::new(messages[first..first + 4].join("\n"));
+    let mut second_frames = Vec::new();
+    for redraw_moved_rows in [true, false] {
+        let output = SharedOutput::default();
+        let backend = CrosstermBackend::new(output.clone()).with_window_size(WindowSize {
+            columns_rows: Size::new(20, 4),
+            pixels: Size::new(160, 64),
+        });
+        let options = TerminalOptions {
+            alternate_screen: true,
+            redraw_moved_rows,
+            ..Default::default()
+        };
+        let mut terminal = Terminal::with_options(backend, options)?;
+        terminal.draw(|frame| frame.render_widget(log(0), frame.area()))?;
+        let first_frame = output.0.borrow().len();
+        terminal.draw(|frame| frame.render_widget(log(1), frame.area()))?;
+
+        let mut virtual_terminal = VirtualTerminal::new(20, 4);
+        virtual_terminal.process(&output.0.borrow());
+        let mut expected = Buffer::empty(Rect::new(0, 0, 20, 4));
+        log(1).render(expected.area, &mut expected);
+        assert_eq!(virtual_terminal.buffer(), &expected);
+        second_frames.push(String::from_utf8(
+            output.0.borrow()[first_frame..].to_vec(),
+        )?);
+    }
+
+    let (redrawn, scrolled) = (&second_frames[0], &second_frames[1]);
+    assert!(!redrawn.contains("\x1b[1;4r"), "{redrawn:?}");
+    assert!(scrolled.starts_with("\x1b[1;4r"), "{scrolled:?}");
+    assert!(scrolled.len() < redrawn.len(), "{scrolled:?} {redrawn:?}");
+    Ok(())
+}
+
+/// Checks that the first row of a fullscreen viewport is scrolled in the alternate screen, which
+/// has no scrollback.
+#[test]
+#[cfg(feature = "scrolling-regions")]
+fn backend_crossterm_scrolls_first_row_in_alternate_screen()
+-> Result<(), Box<dyn std::error::Error>> {
+    let messages = [
+        "alpha: connected",
+        "bravo: joined",
+        "charlie: hello",
+        "delta: hi there",
+        "echo: welcome",
+        "foxtrot: bye",
+        "golf: hello again",
+    ];
+    let log = |first: usize| Paragraph::new(messages[first..first + 6].join("\n"));
+    let output = SharedOutput::default();
+    let backend = CrosstermBackend::new(output.clone()).with_window_size(WindowSize {
+        columns_rows: Size::new(20, 6),
+        pixels: Size::new(160, 96),
+    });
+    let options = TerminalOptions {
+        alternate_screen: true,
+        ..Default::default()
+    };
+    let mut terminal = Terminal::with_options(backend, options)?;
+    terminal.draw(|frame| frame.render_widget(log(0), frame.area()))?;
+    let first_frame = output.0.borrow().len();
+    terminal.draw(|frame| frame.render_widget(log(1), frame.area()))?;
+
+    let second_frame = String::from_utf8(output.0.borrow()[first_frame..].to_vec())?;
+    assert!(
+        second_frame.starts_with("\x1b[1;6r\x1b[1S\x1b[r"),
+        "{second_frame:?}"
+    );
+    assert!(second_frame.len() < 60, "{second_frame:?}");
+    let mut virtual_terminal = VirtualTerminal::new(20, 6);
+    virtual_terminal.process(&output.0.borrow());
+    let mut expected = Buffer::empty(Rect::new(0, 0, 20, 6));
+    log(1).render(expected.area, &mut expected);
+    assert_eq!(virtual_terminal.buffer(), &expected);
+    Ok(())
+}
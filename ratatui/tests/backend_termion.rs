@@ -17,6 +17,7 @@ fn backend_termion_should_only_write_diffs() -> Result<(), Box<dyn std::error::E
             backend,
             TerminalOptions {
                 viewport: Viewport::Fixed(area),
+                ..Default::default()
             },
         )?;
         terminal.draw(|f| {
@@ -31,28 +32,19 @@ fn backend_termion_should_only_write_diffs() -> Result<(), Box<dyn std::error::E
     }
 
     let expected = {
-        use ratatui::termion::{color, cursor, style};
+        use ratatui::termion::cursor;
         let mut s = String::new();
         // First draw
         write!(s, "{}", cursor::Goto(1, 1))?;
         s.push('a');
-        write!(s, "{}", color::Fg(color::Reset))?;
-        write!(s, "{}", color::Bg(color::Reset))?;
-        write!(s, "{}", style::Reset)?;
         write!(s, "{}", cursor::Hide)?;
         // Second draw
         write!(s, "{}", cursor::Goto(2, 1))?;
         s.push('b');
-        write!(s, "{}", color::Fg(color::Reset))?;
-        write!(s, "{}", color::Bg(color::Reset))?;
-        write!(s, "{}", style::Reset)?;
         write!(s, "{}", cursor::Hide)?;
         // Third draw
         write!(s, "{}", cursor::Goto(3, 1))?;
         s.push('c');
-        write!(s, "{}", color::Fg(color::Reset))?;
-        write!(s, "{}", color::Bg(color::Reset))?;
-        write!(s, "{}", style::Reset)?;
         write!(s, "{}", cursor::Hide)?;
         // Terminal drop
         write!(s, "{}", cursor::Show)?;
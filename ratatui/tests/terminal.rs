@@ -1,7 +1,10 @@
 use std::error::Error;
+use std::time::Duration;
 
-use ratatui::backend::TestBackend;
-use ratatui::layout::Rect;
+use ratatui::backend::{Backend, CursorStyle, TestBackend};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Style, Theme};
 use ratatui::widgets::{Block, Paragraph, Widget};
 use ratatui::{Terminal, TerminalOptions, Viewport};
 
@@ -62,6 +65,83 @@ fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_hit_test_returns_the_topmost_region() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        f.register_hit_region("main", f.area());
+        f.register_hit_region("popup", Rect::new(2, 2, 4, 4));
+        f.register_hit_region("outside", Rect::new(20, 20, 4, 4));
+        assert_eq!(f.hit_regions().len(), 3);
+    })?;
+    let hit = |terminal: &Terminal<TestBackend>, x, y| {
+        terminal
+            .hit_test(Position::new(x, y))
+            .map(|region| region.id.clone())
+    };
+    assert_eq!(hit(&terminal, 0, 0).as_deref(), Some("main"));
+    assert_eq!(hit(&terminal, 3, 3).as_deref(), Some("popup"));
+    assert_eq!(hit(&terminal, 10, 10), None);
+
+    // regions are replaced on each draw
+    terminal.draw(|_| {})?;
+    assert!(terminal.hit_regions().is_empty());
+    assert_eq!(hit(&terminal, 0, 0), None);
+    Ok(())
+}
+
+#[test]
+fn terminal_theme_styles_widgets() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(6, 3);
+    let mut terminal = Terminal::new(backend)?;
+    let theme = Theme {
+        border: Style::new().blue(),
+        title: Style::new().bold(),
+        ..Theme::new()
+    };
+    terminal.set_theme(theme);
+    let frame = terminal.draw(|f| {
+        assert_eq!(f.theme(), &theme);
+        f.render_widget(Block::bordered().title("test"), f.area());
+    })?;
+    let mut expected = Buffer::with_lines(["┌test┐", "│    │", "└────┘"]);
+    expected.set_style(expected.area, theme.border);
+    expected.set_style(Rect::new(1, 1, 4, 1), Style::reset());
+    expected.set_style(Rect::new(1, 0, 4, 1), theme.title);
+    expected.theme = theme;
+    assert_eq!(frame.buffer, &expected);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_with_synchronized_output() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(4, 1);
+    let options = TerminalOptions {
+        synchronized_output: true,
+        ..Default::default()
+    };
+    let mut terminal = Terminal::with_options(backend, options)?;
+    terminal.draw(|f| f.render_widget("one", f.area()))?;
+    terminal.draw(|f| f.render_widget("two", f.area()))?;
+    let backend = terminal.backend();
+    assert!(!backend.is_synchronized_update());
+    assert_eq!(
+        backend.synchronized_frames(),
+        [Buffer::with_lines(["one "]), Buffer::with_lines(["two "])]
+    );
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_without_synchronized_output() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(4, 1);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| f.render_widget("one", f.area()))?;
+    assert!(terminal.backend().synchronized_frames().is_empty());
+    Ok(())
+}
+
 #[test]
 fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     // When we have a terminal with 5 lines, and a single line viewport, if we insert a
@@ -73,6 +153,7 @@ fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -116,6 +197,7 @@ fn terminal_insert_before_moves_viewport_does_not_clobber() -> Result<(), Box<dy
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -156,6 +238,7 @@ fn terminal_insert_before_scrolls_on_large_input() -> Result<(), Box<dyn Error>>
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -201,6 +284,7 @@ fn terminal_insert_before_scrolls_on_large_input_does_not_clobber() -> Result<()
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -247,6 +331,7 @@ fn terminal_insert_before_scrolls_on_many_inserts() -> Result<(), Box<dyn Error>
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -301,6 +386,7 @@ fn terminal_insert_before_scrolls_on_many_inserts_does_not_clobber() -> Result<(
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -353,6 +439,7 @@ fn terminal_insert_before_large_viewport() -> Result<(), Box<dyn Error>> {
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(3),
+            ..Default::default()
         },
     )?;
 
@@ -423,6 +510,7 @@ fn terminal_insert_before_large_viewport_does_not_clobber() -> Result<(), Box<dy
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(3),
+            ..Default::default()
         },
     )?;
 
@@ -480,3 +568,209 @@ fn terminal_insert_before_large_viewport_does_not_clobber() -> Result<(), Box<dy
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "scrolling-regions")]
+fn terminal_draw_scrolls_moved_rows() -> Result<(), Box<dyn Error>> {
+    let messages = [
+        "alpha: connected",
+        "bravo: joined",
+        "charlie: hello",
+        "delta: hi there",
+        "echo: welcome",
+        "foxtrot: bye",
+    ];
+    let log = |first: usize| {
+        Paragraph::new(messages[first..first + 4].join("\n")).block(Block::bordered().title("Log"))
+    };
+    let mut terminal = Terminal::new(TestBackend::new(20, 6))?;
+    terminal.draw(|f| f.render_widget(log(0), f.area()))?;
+    terminal.draw(|f| f.render_widget(log(2), f.area()))?;
+    terminal.backend().assert_buffer_lines([
+        "┌Log───────────────┐",
+        "│charlie: hello    │",
+        "│delta: hi there   │",
+        "│echo: welcome     │",
+        "│foxtrot: bye      │",
+        "└──────────────────┘",
+    ]);
+    terminal.draw(|f| f.render_widget(log(1), f.area()))?;
+    terminal.backend().assert_buffer_lines([
+        "┌Log───────────────┐",
+        "│bravo: joined     │",
+        "│charlie: hello    │",
+        "│delta: hi there   │",
+        "│echo: welcome     │",
+        "└──────────────────┘",
+    ]);
+    terminal.backend().assert_scrollback_empty();
+    Ok(())
+}
+
+#[test]
+fn terminal_set_inline_height() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Inline(2),
+            ..Default::default()
+        },
+    )?;
+    terminal.insert_before(3, |buf| {
+        Paragraph::new(vec!["line 1".into(), "line 2".into(), "line 3".into()])
+            .render(buf.area, buf);
+    })?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("a\nb"), f.area()))?;
+    terminal.backend().assert_buffer_lines([
+        "line 1    ",
+        "line 2    ",
+        "line 3    ",
+        "a         ",
+        "b         ",
+    ]);
+
+    // growing scrolls the lines above the viewport into the scrollback
+    terminal.set_inline_height(4)?;
+    let frame = terminal.draw(|f| f.render_widget(Paragraph::new("1\n2\n3\n4"), f.area()))?;
+    assert_eq!(frame.buffer.area, Rect::new(0, 1, 10, 4));
+    terminal.backend().assert_buffer_lines([
+        "line 3    ",
+        "1         ",
+        "2         ",
+        "3         ",
+        "4         ",
+    ]);
+    terminal
+        .backend()
+        .assert_scrollback_lines(["line 1    ", "line 2    "]);
+
+    // shrinking clears the rows below the viewport
+    terminal.set_inline_height(1)?;
+    let frame = terminal.draw(|f| f.render_widget(Paragraph::new("x"), f.area()))?;
+    assert_eq!(frame.buffer.area, Rect::new(0, 1, 10, 1));
+    terminal.backend().assert_buffer_lines([
+        "line 3    ",
+        "x         ",
+        "          ",
+        "          ",
+        "          ",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_inline_auto_fits_rendered_height() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 5);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::InlineAuto(4),
+            ..Default::default()
+        },
+    )?;
+
+    let frame = terminal.draw(|f| {
+        assert_eq!(f.area(), Rect::new(0, 0, 10, 4));
+        f.render_widget(Paragraph::new("a\nb"), f.area());
+    })?;
+    assert_eq!(frame.buffer.area, Rect::new(0, 0, 10, 2));
+
+    let frame = terminal.draw(|f| f.render_widget(Paragraph::new("a\nb\nc\nd\ne"), f.area()))?;
+    assert_eq!(frame.buffer.area, Rect::new(0, 0, 10, 4));
+    terminal.backend().assert_buffer_lines([
+        "a         ",
+        "b         ",
+        "c         ",
+        "d         ",
+        "          ",
+    ]);
+
+    // the cursor keeps the rows above it in the viewport
+    let frame = terminal.draw(|f| {
+        f.render_widget(Paragraph::new("z"), f.area());
+        f.set_cursor_position((0, 2));
+    })?;
+    assert_eq!(frame.buffer.area, Rect::new(0, 0, 10, 3));
+    terminal.backend().assert_buffer_lines([
+        "z         ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+    ]);
+
+    // a lower maximum shrinks the viewport right away
+    terminal.set_inline_height(1)?;
+    let frame = terminal.draw(|f| f.render_widget(Paragraph::new("1\n2"), f.area()))?;
+    assert_eq!(frame.buffer.area, Rect::new(0, 0, 10, 1));
+    terminal.backend().assert_buffer_lines([
+        "1         ",
+        "          ",
+        "          ",
+        "          ",
+        "          ",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_inline_auto_grows_into_scrollback() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::with_lines(["one", "two", ""]);
+    backend.set_cursor_position((0, 2))?;
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::InlineAuto(3),
+            ..Default::default()
+        },
+    )?;
+
+    let frame = terminal.draw(|f| {
+        assert_eq!(f.area(), Rect::new(0, 2, 3, 3));
+        f.render_widget(Paragraph::new("a\nb"), f.area());
+        f.set_cursor_position((1, 4));
+    })?;
+    assert_eq!(frame.buffer.area, Rect::new(0, 0, 3, 3));
+    terminal
+        .backend()
+        .assert_buffer_lines(["a  ", "b  ", "   "]);
+    terminal.backend().assert_scrollback_lines(["one", "two"]);
+    terminal.backend_mut().assert_cursor_position((1, 2));
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_sets_cursor_style() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    assert_eq!(terminal.backend().cursor_style(), CursorStyle::Default);
+
+    terminal.draw(|f| {
+        f.set_cursor_position((1, 0));
+        f.set_cursor_style(CursorStyle::SteadyBar);
+    })?;
+    assert_eq!(terminal.backend().cursor_style(), CursorStyle::SteadyBar);
+
+    // the style is reset when a frame does not set it
+    terminal.draw(|f| f.set_cursor_position((1, 0)))?;
+    assert_eq!(terminal.backend().cursor_style(), CursorStyle::Default);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_reports_frame_stats() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+
+    let stats = terminal.draw(|f| f.render_widget("hello", f.area()))?.stats;
+    assert_eq!(stats.changed_cells, 5);
+    assert_eq!(stats.frame_interval, Duration::ZERO);
+    // the test backend does not count its output
+    assert_eq!(stats.output, None);
+
+    // only the changed cells are counted
+    let stats = terminal.draw(|f| f.render_widget("help", f.area()))?.stats;
+    assert_eq!(stats.changed_cells, 2);
+    Ok(())
+}